@@ -75,6 +75,7 @@ pub struct ParseOptions {
   filename: *const c_char,
   nesting: bool,
   custom_media: bool,
+  draft_properties: bool,
   css_modules: bool,
   css_modules_pattern: *const c_char,
   css_modules_dashed_idents: bool,
@@ -185,6 +186,7 @@ impl Into<MinifyOptions> for TransformOptions {
         None
       },
       unused_symbols,
+      ..MinifyOptions::default()
     }
   }
 }
@@ -262,6 +264,7 @@ pub extern "C" fn lightningcss_stylesheet_parse(
     },
     nesting: options.nesting,
     custom_media: options.custom_media,
+    draft_properties: options.draft_properties,
     css_modules: if options.css_modules {
       let pattern = if !options.css_modules_pattern.is_null() {
         let pattern =
@@ -284,6 +287,7 @@ pub extern "C" fn lightningcss_stylesheet_parse(
     error_recovery: options.error_recovery,
     source_index: 0,
     warnings: Some(warnings.clone()),
+    ..ParserOptions::default()
   };
 
   let stylesheet = unwrap!(StyleSheet::parse(code, opts), error, std::ptr::null_mut());
@@ -335,6 +339,10 @@ pub extern "C" fn lightningcss_stylesheet_to_css(
     } else {
       None
     },
+    env: None,
+    ascii_only: false,
+    align_grid_areas: false,
+    prefer_keywords: false,
   };
 
   let res = unwrap!(wrapper.stylesheet.to_css(opts), error, ToCssResult::default());
@@ -35,6 +35,48 @@ export interface TransformOptions {
    * selectors but individual names (without any . or # prefixes).
    */
   unusedSymbols?: string[],
+  /**
+   * Whether to remove `@keyframes` rules that are never referenced by an animation, and
+   * `@property` rules for custom properties that never appear in the style sheet.
+   */
+  removeUnusedRules?: boolean,
+  /** Whether to also remove `@font-face` rules whose font family is never used. */
+  removeUnusedFontFaces?: boolean,
+  /**
+   * A list of `@keyframes` names, custom property names, and font family names that are
+   * referenced externally (e.g. from JavaScript), and should not be removed by `removeUnusedRules`.
+   */
+  usedSymbols?: string[],
+  /**
+   * Values to substitute for `env()` references, keyed by environment variable name,
+   * e.g. `{'safe-area-inset-top': '0px'}`. References with indices are not substituted.
+   */
+  env?: {[name: string]: string},
+  /**
+   * Whether to nest style rules that begin with the same compound selector to reduce
+   * output size. Only applied when all targets support CSS nesting.
+   */
+  nestRules?: boolean,
+  /**
+   * Whether to generate `::-webkit-scrollbar` rules that emulate `scrollbar-width` and
+   * `scrollbar-color` for WebKit-based targets that do not support them.
+   */
+  webkitScrollbarRules?: boolean,
+  /**
+   * Whether to escape non-ASCII characters in identifiers, strings, and URLs,
+   * for environments that require 7-bit output.
+   */
+  asciiOnly?: boolean,
+  /**
+   * Whether to pad the columns of `grid-template-areas` strings so that they line up
+   * across rows. Only applies when not minifying.
+   */
+  alignGridAreas?: boolean,
+  /**
+   * Whether to print the keyword form of values that have an equivalent keyword,
+   * e.g. `font-weight: bold` rather than `700`. Only applies when not minifying.
+   */
+  preferKeywords?: boolean,
   /**
    * Whether to ignore invalid rules and declarations rather than erroring.
    * When enabled, warnings are returned, and the invalid rule or declaration is
@@ -65,7 +107,9 @@ export interface Drafts {
   /** Whether to enable CSS nesting. */
   nesting?: boolean,
   /** Whether to enable @custom-media rules. */
-  customMedia?: boolean
+  customMedia?: boolean,
+  /** Whether to enable typed parsing of draft properties, e.g. field-sizing. */
+  draftProperties?: boolean
 }
 
 export interface PseudoClasses {
@@ -12,7 +12,7 @@ use lightningcss::stylesheet::{
 use lightningcss::targets::Browsers;
 use parcel_sourcemap::SourceMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ffi::c_void;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -469,6 +469,15 @@ struct Config {
   pub analyze_dependencies: Option<bool>,
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
+  pub remove_unused_rules: Option<bool>,
+  pub remove_unused_font_faces: Option<bool>,
+  pub used_symbols: Option<HashSet<String>>,
+  pub env: Option<HashMap<String, String>>,
+  pub nest_rules: Option<bool>,
+  pub webkit_scrollbar_rules: Option<bool>,
+  pub ascii_only: Option<bool>,
+  pub align_grid_areas: Option<bool>,
+  pub prefer_keywords: Option<bool>,
   pub error_recovery: Option<bool>,
 }
 
@@ -498,6 +507,15 @@ struct BundleConfig {
   pub analyze_dependencies: Option<bool>,
   pub pseudo_classes: Option<OwnedPseudoClasses>,
   pub unused_symbols: Option<HashSet<String>>,
+  pub remove_unused_rules: Option<bool>,
+  pub remove_unused_font_faces: Option<bool>,
+  pub used_symbols: Option<HashSet<String>>,
+  pub env: Option<HashMap<String, String>>,
+  pub nest_rules: Option<bool>,
+  pub webkit_scrollbar_rules: Option<bool>,
+  pub ascii_only: Option<bool>,
+  pub align_grid_areas: Option<bool>,
+  pub prefer_keywords: Option<bool>,
   pub error_recovery: Option<bool>,
 }
 
@@ -530,11 +548,14 @@ struct Drafts {
   nesting: bool,
   #[serde(default)]
   custom_media: bool,
+  #[serde(default)]
+  draft_properties: bool,
 }
 
 fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, CompileError<'i, std::io::Error>> {
   let drafts = config.drafts.as_ref();
   let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+  let minify_warnings = Some(Arc::new(RwLock::new(Vec::new())));
 
   let filename = config.filename.clone().unwrap_or_default();
   let mut source_map = if config.source_map.unwrap_or_default() {
@@ -553,6 +574,7 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
         filename: filename.clone(),
         nesting: matches!(drafts, Some(d) if d.nesting),
         custom_media: matches!(drafts, Some(d) if d.custom_media),
+        draft_properties: matches!(drafts, Some(d) if d.draft_properties),
         css_modules: if let Some(css_modules) = &config.css_modules {
           match css_modules {
             CssModulesOption::Bool(true) => Some(lightningcss::css_modules::Config::default()),
@@ -575,11 +597,19 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
         source_index: 0,
         error_recovery: config.error_recovery.unwrap_or_default(),
         warnings: warnings.clone(),
+        ..ParserOptions::default()
       },
     )?;
     stylesheet.minify(MinifyOptions {
       targets: config.targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      remove_unused_rules: config.remove_unused_rules.unwrap_or_default(),
+      remove_unused_font_faces: config.remove_unused_font_faces.unwrap_or_default(),
+      used_symbols: config.used_symbols.clone().unwrap_or_default(),
+      nest_rules: config.nest_rules.unwrap_or_default(),
+      webkit_scrollbar_rules: config.webkit_scrollbar_rules.unwrap_or_default(),
+      warnings: minify_warnings.clone(),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -588,6 +618,10 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
       targets: config.targets,
       analyze_dependencies: config.analyze_dependencies.unwrap_or_default(),
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      env: config.env.as_ref(),
+      ascii_only: config.ascii_only.unwrap_or_default(),
+      align_grid_areas: config.align_grid_areas.unwrap_or_default(),
+      prefer_keywords: config.prefer_keywords.unwrap_or_default(),
     })?
   };
 
@@ -609,15 +643,10 @@ fn compile<'i>(code: &'i str, config: &Config) -> Result<TransformResult<'i>, Co
     exports: res.exports,
     references: res.references,
     dependencies: res.dependencies,
-    warnings: warnings.map_or(Vec::new(), |w| {
-      Arc::try_unwrap(w)
-        .unwrap()
-        .into_inner()
-        .unwrap()
-        .into_iter()
-        .map(|w| w.into())
-        .collect()
-    }),
+    warnings: take_warnings(warnings)
+      .into_iter()
+      .chain(take_warnings(minify_warnings))
+      .collect(),
   })
 }
 
@@ -631,11 +660,13 @@ fn compile_bundle<'i, P: SourceProvider>(
     None
   };
   let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+  let minify_warnings = Some(Arc::new(RwLock::new(Vec::new())));
   let res = {
     let drafts = config.drafts.as_ref();
     let parser_options = ParserOptions {
       nesting: matches!(drafts, Some(d) if d.nesting),
       custom_media: matches!(drafts, Some(d) if d.custom_media),
+      draft_properties: matches!(drafts, Some(d) if d.draft_properties),
       css_modules: if let Some(css_modules) = &config.css_modules {
         match css_modules {
           CssModulesOption::Bool(true) => Some(lightningcss::css_modules::Config::default()),
@@ -666,6 +697,13 @@ fn compile_bundle<'i, P: SourceProvider>(
     stylesheet.minify(MinifyOptions {
       targets: config.targets,
       unused_symbols: config.unused_symbols.clone().unwrap_or_default(),
+      remove_unused_rules: config.remove_unused_rules.unwrap_or_default(),
+      remove_unused_font_faces: config.remove_unused_font_faces.unwrap_or_default(),
+      used_symbols: config.used_symbols.clone().unwrap_or_default(),
+      nest_rules: config.nest_rules.unwrap_or_default(),
+      webkit_scrollbar_rules: config.webkit_scrollbar_rules.unwrap_or_default(),
+      warnings: minify_warnings.clone(),
+      ..MinifyOptions::default()
     })?;
 
     stylesheet.to_css(PrinterOptions {
@@ -674,6 +712,10 @@ fn compile_bundle<'i, P: SourceProvider>(
       targets: config.targets,
       analyze_dependencies: config.analyze_dependencies.unwrap_or_default(),
       pseudo_classes: config.pseudo_classes.as_ref().map(|p| p.into()),
+      env: config.env.as_ref(),
+      ascii_only: config.ascii_only.unwrap_or_default(),
+      align_grid_areas: config.align_grid_areas.unwrap_or_default(),
+      prefer_keywords: config.prefer_keywords.unwrap_or_default(),
     })?
   };
 
@@ -689,15 +731,10 @@ fn compile_bundle<'i, P: SourceProvider>(
     exports: res.exports,
     references: res.references,
     dependencies: res.dependencies,
-    warnings: warnings.map_or(Vec::new(), |w| {
-      Arc::try_unwrap(w)
-        .unwrap()
-        .into_inner()
-        .unwrap()
-        .into_iter()
-        .map(|w| w.into())
-        .collect()
-    }),
+    warnings: take_warnings(warnings)
+      .into_iter()
+      .chain(take_warnings(minify_warnings))
+      .collect(),
   })
 }
 
@@ -747,6 +784,7 @@ fn compile_attr<'i>(
   } else {
     None
   };
+  let minify_warnings = Some(Arc::new(RwLock::new(Vec::new())));
   let res = {
     let mut attr = StyleAttribute::parse(
       &code,
@@ -758,6 +796,7 @@ fn compile_attr<'i>(
     )?;
     attr.minify(MinifyOptions {
       targets: config.targets,
+      warnings: minify_warnings.clone(),
       ..MinifyOptions::default()
     });
     attr.to_css(PrinterOptions {
@@ -766,20 +805,19 @@ fn compile_attr<'i>(
       targets: config.targets,
       analyze_dependencies: config.analyze_dependencies,
       pseudo_classes: None,
+      env: None,
+      ascii_only: false,
+      align_grid_areas: false,
+      prefer_keywords: false,
     })?
   };
   Ok(AttrResult {
     code: res.code.into_bytes(),
     dependencies: res.dependencies,
-    warnings: warnings.map_or(Vec::new(), |w| {
-      Arc::try_unwrap(w)
-        .unwrap()
-        .into_inner()
-        .unwrap()
-        .into_iter()
-        .map(|w| w.into())
-        .collect()
-    }),
+    warnings: take_warnings(warnings)
+      .into_iter()
+      .chain(take_warnings(minify_warnings))
+      .collect(),
   })
 }
 
@@ -905,10 +943,17 @@ impl<'i, E: std::error::Error> From<CompileError<'i, E>> for wasm_bindgen::JsVal
 struct Warning<'i> {
   message: String,
   #[serde(flatten)]
-  data: ParserError<'i>,
+  data: WarningKind<'i>,
   loc: Option<ErrorLocation>,
 }
 
+#[derive(Serialize)]
+#[serde(untagged)]
+enum WarningKind<'i> {
+  Parser(ParserError<'i>),
+  Minify(MinifyErrorKind),
+}
+
 impl<'i> From<Error<ParserError<'i>>> for Warning<'i> {
   fn from(mut e: Error<ParserError<'i>>) -> Self {
     // Convert to 1-based line numbers.
@@ -917,8 +962,37 @@ impl<'i> From<Error<ParserError<'i>>> for Warning<'i> {
     }
     Warning {
       message: e.kind.to_string(),
-      data: e.kind,
+      data: WarningKind::Parser(e.kind),
+      loc: e.loc,
+    }
+  }
+}
+
+impl<'i> From<Error<MinifyErrorKind>> for Warning<'i> {
+  fn from(mut e: Error<MinifyErrorKind>) -> Self {
+    // Convert to 1-based line numbers.
+    if let Some(loc) = &mut e.loc {
+      loc.line += 1;
+    }
+    Warning {
+      message: e.kind.to_string(),
+      data: WarningKind::Minify(e.kind),
       loc: e.loc,
     }
   }
 }
+
+fn take_warnings<'i, T>(warnings: Option<Arc<RwLock<Vec<Error<T>>>>>) -> Vec<Warning<'i>>
+where
+  Warning<'i>: From<Error<T>>,
+{
+  warnings.map_or(Vec::new(), |w| {
+    Arc::try_unwrap(w)
+      .unwrap()
+      .into_inner()
+      .unwrap()
+      .into_iter()
+      .map(|w| w.into())
+      .collect()
+  })
+}
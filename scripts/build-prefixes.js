@@ -39,6 +39,10 @@ prefixes['clip-path'].browsers = prefixes['clip-path'].browsers.filter(b => {
   );
 });
 
+// -ms-writing-mode only accepts the legacy SVG values (e.g. tb-rl), which are not equivalent
+// to the modern values, so only the -webkit- prefix is added.
+prefixes['writing-mode'].browsers = prefixes['writing-mode'].browsers.filter(b => !b.startsWith('ie '));
+
 prefixes['any-pseudo'] = {
   browsers: Object.entries(mdn.css.selectors.is.__compat.support)
     .flatMap(([key, value]) => {
@@ -58,6 +62,26 @@ prefixes['any-pseudo'] = {
     })
 }
 
+// Autoprefixer does not include hyphenate-character, so derive the prefixed versions from MDN.
+prefixes['hyphenate-character'] = {
+  browsers: Object.entries(mdn.css.properties['hyphenate-character'].__compat.support)
+    .flatMap(([key, value]) => {
+      key = MDN_BROWSER_MAPPING[key] === undefined ? key : MDN_BROWSER_MAPPING[key];
+      if (!key || !Array.isArray(value)) {
+        return [];
+      }
+
+      let prefixed = value.find(v => v.prefix === '-webkit-')?.version_added;
+      if (!prefixed) {
+        return [];
+      }
+
+      let unprefixed = value.find(v => !v.prefix && !v.alternative_name)?.version_added;
+      let last = Object.keys(browsers[key].release_date).filter(v => unprefixed == null || parseVersion(v) < parseVersion(unprefixed)).pop();
+      return [`${key} ${prefixed.replace('≤', '')}`, `${key} ${last}`];
+    })
+};
+
 let flexSpec = {};
 let oldGradient = {};
 let p = new Map();
@@ -210,6 +234,8 @@ let mdnFeatures = {
   placeContent: mdn.css.properties['place-content'].__compat.support,
   placeItems: mdn.css.properties['place-items'].__compat.support,
   overflowShorthand: mdn.css.properties['overflow'].multiple_keywords.__compat.support,
+  overflowWrap: mdn.css.properties['overflow-wrap'].__compat.support,
+  breakProperties: mdn.css.properties['break-before'].__compat.support,
   mediaRangeSyntax: mdn.css['at-rules'].media.range_syntax.__compat.support,
   mediaIntervalSyntax: {}, // currently no browsers
   logicalBorders: mdn.css.properties['border-inline-start'].__compat.support,
@@ -221,6 +247,9 @@ let mdnFeatures = {
   logicalPaddingShorthand: mdn.css.properties['padding-inline'].__compat.support,
   logicalInset: mdn.css.properties['inset-inline-start'].__compat.support,
   logicalSize: mdn.css.properties['inline-size'].__compat.support,
+  logicalResize: mdn.css.properties['resize'].block.__compat.support,
+  imageRenderingCrispEdges: mdn.css.properties['image-rendering']['crisp-edges'].__compat.support,
+  imageRenderingPixelated: mdn.css.properties['image-rendering'].pixelated.__compat.support,
   logicalTextAlign: mdn.css.properties['text-align']['flow_relative_values_start_and_end'].__compat.support,
   labColors: mdn.css.types.color.lab.__compat.support,
   oklabColors: {},
@@ -228,6 +257,9 @@ let mdnFeatures = {
   spaceSeparatedColorFunction: mdn.css.types.color.rgb.space_separated_parameters.__compat.support,
   textDecorationThicknessPercent: mdn.css.properties['text-decoration-thickness'].percentage.__compat.support,
   textDecorationThicknessShorthand: mdn.css.properties['text-decoration']['text-decoration-thickness'].__compat.support,
+  textUnderlineOffset: mdn.css.properties['text-underline-offset'].__compat.support,
+  textUnderlinePosition: mdn.css.properties['text-underline-position'].__compat.support,
+  textUnderlinePositionSide: mdn.css.properties['text-underline-position'].left.__compat.support,
   cue: mdn.css.selectors.cue.__compat.support,
   cueFunction: mdn.css.selectors.cue.selector_argument.__compat.support,
   anyPseudo: Object.fromEntries(
@@ -247,7 +279,13 @@ let mdnFeatures = {
       })
   ),
   imageSet: mdn.css.types.image['image-set'].__compat.support,
-  xResolutionUnit: mdn.css.types.resolution.x.__compat.support
+  xResolutionUnit: mdn.css.types.resolution.x.__compat.support,
+  scrollbarColor: mdn.css.properties['scrollbar-color'].__compat.support,
+  scrollbarWidth: mdn.css.properties['scrollbar-width'].__compat.support,
+  gap: mdn.css.properties.gap.grid_context.__compat.support,
+  flexStartEnd: mdn.css.properties['align-items'].flex_context.start_end.__compat.support,
+  transitionBehavior: mdn.css.properties['transition-behavior'].__compat.support,
+  individualTransforms: mdn.css.properties.translate.__compat.support
 };
 
 for (let feature in mdnFeatures) {
@@ -293,6 +331,20 @@ addValue(compat, {
   ios_saf: parseVersion('10.3')
 }, 'langList');
 
+addValue(compat, {
+  // The whitespace separated form of rect() predates the comma separated form, and is
+  // accepted by all browsers that support the clip property.
+  chrome: parseVersion('1'),
+  edge: parseVersion('12'),
+  firefox: parseVersion('1'),
+  opera: parseVersion('7'),
+  safari: parseVersion('1'),
+  ios_saf: parseVersion('1'),
+  samsung: parseVersion('1'),
+  android: parseVersion('1'),
+  ie: parseVersion('4')
+}, 'clipRectWhitespace');
+
 let prefixMapping = {
   webkit: 'WebKit',
   moz: 'Moz',
@@ -27,7 +27,9 @@ use crate::{
   error::ErrorLocation,
   properties::{
     css_modules::Specifier,
-    custom::{CustomProperty, TokenList, TokenOrValue, UnparsedProperty, UnresolvedColor},
+    custom::{
+      CustomProperty, EnvironmentVariableName, TokenList, TokenOrValue, UnparsedProperty, UnresolvedColor,
+    },
     Property,
   },
   rules::{
@@ -39,6 +41,7 @@ use crate::{
 use crate::{
   error::{Error, ParserError},
   media_query::MediaList,
+  parser::starts_with_ignore_ascii_case,
   rules::{
     import::ImportRule,
     media::MediaRule,
@@ -66,6 +69,7 @@ pub struct Bundler<'a, 'o, 's, P> {
   source_indexes: DashMap<PathBuf, u32>,
   stylesheets: Mutex<Vec<BundleStyleSheet<'a, 'o>>>,
   options: ParserOptions<'o, 'a>,
+  hoist_external_imports: bool,
 }
 
 #[derive(Debug)]
@@ -79,6 +83,9 @@ struct BundleStyleSheet<'i, 'o> {
   supports: Option<SupportsCondition<'i>>,
   media: MediaList<'i>,
   loc: Location,
+  /// Indices of `@import` rules referencing external style sheets, which are preserved
+  /// rather than inlined.
+  external_imports: Vec<usize>,
 }
 
 /// A trait to provide the contents of files to a Bundler.
@@ -95,6 +102,14 @@ pub trait SourceProvider: Send + Sync {
   /// Resolves the given import specifier to a file path given the file
   /// which the import originated from.
   fn resolve(&self, specifier: &str, originating_file: &Path) -> Result<PathBuf, Self::Error>;
+
+  /// Returns whether the given import specifier should be left as an `@import` rule
+  /// rather than being resolved and bundled. Absolute `http:` and `https:` URLs, as well
+  /// as protocol-relative URLs, are always left as is. By default, all other specifiers
+  /// are bundled.
+  fn is_external(&self, _specifier: &str, _originating_file: &Path) -> bool {
+    false
+  }
 }
 
 /// Provides an implementation of [SourceProvider](SourceProvider)
@@ -198,9 +213,18 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
       source_indexes: DashMap::new(),
       stylesheets: Mutex::new(Vec::new()),
       options,
+      hoist_external_imports: true,
     }
   }
 
+  /// Sets whether `@import` rules referencing external style sheets are moved to the top
+  /// of the bundle, as required for them to be valid. This is enabled by default. When disabled,
+  /// they are left in place relative to the surrounding rules, and browsers will ignore any
+  /// that follow other rules.
+  pub fn set_hoist_external_imports(&mut self, hoist: bool) {
+    self.hoist_external_imports = hoist;
+  }
+
   /// Bundles the given entry file and all dependencies into a single style sheet.
   pub fn bundle<'e>(
     &mut self,
@@ -227,7 +251,16 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
 
     // Phase 3: concatenate.
     let mut rules: Vec<CssRule<'a>> = Vec::new();
-    self.inline(&mut rules);
+    let mut external_imports = Vec::new();
+    self.inline(&mut rules, &mut external_imports);
+
+    // External @import rules must precede all other rules except @layer statements.
+    // These are only collected when hoisting is enabled.
+    let index = rules
+      .iter()
+      .position(|rule| !matches!(rule, CssRule::LayerStatement(..)))
+      .unwrap_or(rules.len());
+    rules.splice(index..index, external_imports.into_iter().map(CssRule::Import));
 
     let sources = self
       .stylesheets
@@ -311,6 +344,7 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
           css_modules_deps: Vec::new(),
           parent_source_index: 0,
           parent_dep_index: 0,
+          external_imports: Vec::new(),
         });
 
         source_index
@@ -337,6 +371,18 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
 
     let mut stylesheet = StyleSheet::parse(code, opts)?;
 
+    // Combine the conditions of external imports with the parent style sheet, since
+    // they are not wrapped in its @media and @supports rules when inlined.
+    let mut external_imports = Vec::new();
+    for (index, r) in stylesheet.rules.0.iter_mut().enumerate() {
+      if let CssRule::Import(import) = r {
+        if self.is_external(&import.url, file) {
+          *import = self.combine_import(&rule, import)?;
+          external_imports.push(index);
+        }
+      }
+    }
+
     // Collect and load dependencies for this stylesheet in parallel.
     let dependencies: Result<Vec<u32>, _> = stylesheet
       .rules
@@ -353,57 +399,16 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
         }
 
         if let CssRule::Import(import) = r {
-          let specifier = &import.url;
-
-          // Combine media queries and supports conditions from parent
-          // stylesheet with @import rule using a logical and operator.
-          let mut media = rule.media.clone();
-          let result = media.and(&import.media).map_err(|_| Error {
-            kind: BundleErrorKind::UnsupportedMediaBooleanLogic,
-            loc: Some(ErrorLocation::new(
-              import.loc,
-              self.find_filename(import.loc.source_index),
-            )),
-          });
-
-          if let Err(e) = result {
-            return Some(Err(e));
+          if self.is_external(&import.url, file) {
+            return None;
           }
 
-          let layer = if (rule.layer == Some(None) && import.layer.is_some())
-            || (import.layer == Some(None) && rule.layer.is_some())
-          {
-            // Cannot combine anonymous layers
-            return Some(Err(Error {
-              kind: BundleErrorKind::UnsupportedLayerCombination,
-              loc: Some(ErrorLocation::new(
-                import.loc,
-                self.find_filename(import.loc.source_index),
-              )),
-            }));
-          } else if let Some(Some(a)) = &rule.layer {
-            if let Some(Some(b)) = &import.layer {
-              let mut name = a.clone();
-              name.0.extend(b.0.iter().cloned());
-              Some(Some(name))
-            } else {
-              Some(Some(a.clone()))
-            }
-          } else {
-            import.layer.clone()
+          let combined = match self.combine_import(&rule, import) {
+            Ok(combined) => combined,
+            Err(e) => return Some(Err(e)),
           };
-
-          let result = match self.fs.resolve(&specifier, file) {
-            Ok(path) => self.load_file(
-              &path,
-              ImportRule {
-                layer,
-                media,
-                supports: combine_supports(rule.supports.clone(), &import.supports),
-                url: "".into(),
-                loc: import.loc,
-              },
-            ),
+          let result = match self.fs.resolve(&import.url, file) {
+            Ok(path) => self.load_file(&path, combined),
             Err(err) => Err(Error {
               kind: BundleErrorKind::ResolverError(err),
               loc: Some(ErrorLocation::new(
@@ -476,10 +481,68 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
     entry.stylesheet = Some(stylesheet);
     entry.dependencies = dependencies?;
     entry.css_modules_deps = css_modules_deps?;
+    entry.external_imports = external_imports;
 
     Ok(source_index)
   }
 
+  fn is_external(&self, specifier: &str, originating_file: &Path) -> bool {
+    specifier.starts_with("//")
+      || starts_with_ignore_ascii_case(specifier, "http://")
+      || starts_with_ignore_ascii_case(specifier, "https://")
+      || self.fs.is_external(specifier, originating_file)
+  }
+
+  /// Combines the conditions of an `@import` rule with those of the rule that imported
+  /// the style sheet containing it.
+  fn combine_import(
+    &self,
+    rule: &ImportRule<'a>,
+    import: &ImportRule<'a>,
+  ) -> Result<ImportRule<'a>, Error<BundleErrorKind<'a, P::Error>>> {
+    // Combine media queries and supports conditions from parent
+    // stylesheet with @import rule using a logical and operator.
+    let mut media = rule.media.clone();
+    media.and(&import.media).map_err(|_| Error {
+      kind: BundleErrorKind::UnsupportedMediaBooleanLogic,
+      loc: Some(ErrorLocation::new(
+        import.loc,
+        self.find_filename(import.loc.source_index),
+      )),
+    })?;
+
+    let layer = if (rule.layer == Some(None) && import.layer.is_some())
+      || (import.layer == Some(None) && rule.layer.is_some())
+    {
+      // Cannot combine anonymous layers
+      return Err(Error {
+        kind: BundleErrorKind::UnsupportedLayerCombination,
+        loc: Some(ErrorLocation::new(
+          import.loc,
+          self.find_filename(import.loc.source_index),
+        )),
+      });
+    } else if let Some(Some(a)) = &rule.layer {
+      if let Some(Some(b)) = &import.layer {
+        let mut name = a.clone();
+        name.0.extend(b.0.iter().cloned());
+        Some(Some(name))
+      } else {
+        Some(Some(a.clone()))
+      }
+    } else {
+      import.layer.clone()
+    };
+
+    Ok(ImportRule {
+      layer,
+      media,
+      supports: combine_supports(rule.supports.clone(), &import.supports),
+      url: import.url.clone(),
+      loc: import.loc,
+    })
+  }
+
   fn add_css_module_dep(
     &self,
     file: &Path,
@@ -565,13 +628,16 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
     }
   }
 
-  fn inline(&mut self, dest: &mut Vec<CssRule<'a>>) {
-    process(self.stylesheets.get_mut().unwrap(), 0, dest);
+  fn inline(&mut self, dest: &mut Vec<CssRule<'a>>, imports: &mut Vec<ImportRule<'a>>) {
+    let hoist = self.hoist_external_imports;
+    process(self.stylesheets.get_mut().unwrap(), 0, dest, imports, hoist);
 
     fn process<'a>(
       stylesheets: &mut Vec<BundleStyleSheet<'a, '_>>,
       source_index: u32,
       dest: &mut Vec<CssRule<'a>>,
+      imports: &mut Vec<ImportRule<'a>>,
+      hoist: bool,
     ) {
       let stylesheet = &mut stylesheets[source_index as usize];
       let mut rules = std::mem::take(&mut stylesheet.stylesheet.as_mut().unwrap().rules.0);
@@ -584,22 +650,32 @@ impl<'a, 'o, 's, P: SourceProvider> Bundler<'a, 'o, 's, P> {
 
         // Include the dependency if this is the first instance as computed earlier.
         if resolved.parent_source_index == source_index && resolved.parent_dep_index == dep_index as u32 {
-          process(stylesheets, dep_source_index, dest);
+          process(stylesheets, dep_source_index, dest, imports, hoist);
         }
 
         dep_index += 1;
       }
 
       let mut import_index = 0;
-      for rule in &mut rules {
+      for (index, rule) in rules.iter_mut().enumerate() {
         match rule {
+          CssRule::Import(_) if stylesheets[source_index as usize].external_imports.contains(&index) => {
+            let import = std::mem::replace(rule, CssRule::Ignored);
+            if hoist {
+              if let CssRule::Import(import) = import {
+                imports.push(import);
+              }
+            } else {
+              dest.push(import);
+            }
+          }
           CssRule::Import(_) => {
             let dep_source_index = stylesheets[source_index as usize].dependencies[import_index];
             let resolved = &stylesheets[dep_source_index as usize];
 
             // Include the dependency if this is the last instance as computed earlier.
             if resolved.parent_source_index == source_index && resolved.parent_dep_index == dep_index {
-              process(stylesheets, dep_source_index, dest);
+              process(stylesheets, dep_source_index, dest, imports, hoist);
             }
 
             *rule = CssRule::Ignored;
@@ -676,6 +752,19 @@ fn visit_vars<'a, 'b>(
           }
           return Some(&mut var.name);
         }
+        Some(TokenOrValue::Attr(attr)) => {
+          if let Some(fallback) = &mut attr.fallback {
+            stack.push(fallback.0.iter_mut());
+          }
+        }
+        Some(TokenOrValue::Env(env)) => {
+          if let Some(fallback) = &mut env.fallback {
+            stack.push(fallback.0.iter_mut());
+          }
+          if let EnvironmentVariableName::Custom(name) = &mut env.name {
+            return Some(name);
+          }
+        }
         Some(TokenOrValue::UnresolvedColor(color)) => match color {
           UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => {
             stack.push(alpha.0.iter_mut());
@@ -770,6 +859,13 @@ mod tests {
     stylesheet.to_css(PrinterOptions::default()).unwrap().code
   }
 
+  fn bundle_in_place<P: SourceProvider>(fs: P, entry: &str) -> String {
+    let mut bundler = Bundler::new(&fs, None, ParserOptions::default());
+    bundler.set_hoist_external_imports(false);
+    let stylesheet = bundler.bundle(Path::new(entry)).unwrap();
+    stylesheet.to_css(PrinterOptions::default()).unwrap().code
+  }
+
   fn bundle_css_module<P: SourceProvider>(fs: P, entry: &str) -> (String, CssModuleExports) {
     let mut bundler = Bundler::new(
       &fs,
@@ -1599,6 +1695,130 @@ mod tests {
     // }, "/a.css");
   }
 
+  #[test]
+  fn test_external_imports() {
+    struct ExternalProvider {
+      map: HashMap<PathBuf, String>,
+    }
+
+    impl SourceProvider for ExternalProvider {
+      type Error = std::io::Error;
+
+      fn read<'a>(&'a self, file: &Path) -> Result<&'a str, Self::Error> {
+        Ok(self.map.get(file).unwrap())
+      }
+
+      fn resolve(&self, specifier: &str, originating_file: &Path) -> Result<PathBuf, Self::Error> {
+        Ok(originating_file.with_file_name(specifier))
+      }
+
+      fn is_external(&self, specifier: &str, _originating_file: &Path) -> bool {
+        specifier.starts_with("external/")
+      }
+    }
+
+    let res = bundle_in_place(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css";
+          @import "https://example.com/a.css" supports(display: grid);
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @import url("//cdn.example.com/b.css");
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @import "//cdn.example.com/b.css";
+
+      .b {
+        color: green;
+      }
+
+      @import "https://example.com/a.css" supports(display: grid);
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    let res = bundle(
+      TestProvider {
+        map: fs! {
+          "/a.css": r#"
+          @import "b.css";
+          @import "https://example.com/a.css" supports(display: grid);
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @import url("//cdn.example.com/b.css");
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @import "//cdn.example.com/b.css";
+      @import "https://example.com/a.css" supports(display: grid);
+
+      .b {
+        color: green;
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+
+    let res = bundle(
+      ExternalProvider {
+        map: fs! {
+          "/a.css": r#"
+          @layer foo;
+          @import "b.css" print;
+          @import "HTTP://example.com/a.css";
+          .a { color: red }
+        "#,
+          "/b.css": r#"
+          @import "external/b.css" layer(x);
+          .b { color: green }
+        "#
+        },
+      },
+      "/a.css",
+    );
+    assert_eq!(
+      res,
+      indoc! { r#"
+      @layer foo;
+      @import "external/b.css" layer(x) print;
+      @import "HTTP://example.com/a.css";
+
+      @media print {
+        .b {
+          color: green;
+        }
+      }
+
+      .a {
+        color: red;
+      }
+    "#}
+    );
+  }
+
   #[test]
   fn test_css_module() {
     macro_rules! map {
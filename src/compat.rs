@@ -5,7 +5,9 @@ use crate::targets::Browsers;
 #[derive(Clone, Copy, PartialEq)]
 pub enum Feature {
   AnyPseudo,
+  BreakProperties,
   Clamp,
+  ClipRectWhitespace,
   ColorFunction,
   CssAnyLink,
   CssAutofill,
@@ -38,10 +40,15 @@ pub enum Feature {
   CustomMediaQueries,
   Dialog,
   DoublePositionGradients,
+  FlexStartEnd,
   FontFamilySystemUi,
   FormValidation,
   Fullscreen,
+  Gap,
+  ImageRenderingCrispEdges,
+  ImageRenderingPixelated,
   ImageSet,
+  IndividualTransforms,
   LabColors,
   LangList,
   LogicalBorderRadius,
@@ -52,20 +59,28 @@ pub enum Feature {
   LogicalMarginShorthand,
   LogicalPadding,
   LogicalPaddingShorthand,
+  LogicalResize,
   LogicalSize,
   LogicalTextAlign,
   MediaIntervalSyntax,
   MediaRangeSyntax,
   OklabColors,
   OverflowShorthand,
+  OverflowWrap,
   P3Colors,
   PlaceContent,
   PlaceItems,
   PlaceSelf,
+  ScrollbarColor,
+  ScrollbarWidth,
   Shadowdomv1,
   SpaceSeparatedColorFunction,
   TextDecorationThicknessPercent,
   TextDecorationThicknessShorthand,
+  TextUnderlineOffset,
+  TextUnderlinePosition,
+  TextUnderlinePositionSide,
+  TransitionBehavior,
   XResolutionUnit,
 }
 
@@ -1542,6 +1557,98 @@ impl Feature {
           return false;
         }
       }
+      Feature::OverflowWrap => {
+        if let Some(version) = browsers.chrome {
+          if version < 1507328 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3211264 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 786688 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::BreakProperties => {
+        if let Some(version) = browsers.chrome {
+          if version < 3276800 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 2424832 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 3276800 {
+            return false;
+          }
+        }
+      }
       Feature::MediaRangeSyntax => {
         if let Some(version) = browsers.chrome {
           if version < 6815744 {
@@ -1883,6 +1990,122 @@ impl Feature {
           return false;
         }
       }
+      Feature::LogicalResize => {
+        if let Some(version) = browsers.chrome {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7733248 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::ImageRenderingCrispEdges => {
+        if let Some(version) = browsers.firefox {
+          if version < 4259840 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if browsers.android.is_some()
+          || browsers.chrome.is_some()
+          || browsers.edge.is_some()
+          || browsers.ie.is_some()
+          || browsers.opera.is_some()
+          || browsers.samsung.is_some()
+        {
+          return false;
+        }
+      }
+      Feature::ImageRenderingPixelated => {
+        if let Some(version) = browsers.chrome {
+          if version < 2686976 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 655360 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 2686976 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::LabColors | Feature::ColorFunction => {
         if let Some(version) = browsers.safari {
           if version < 983040 {
@@ -2003,44 +2226,44 @@ impl Feature {
           return false;
         }
       }
-      Feature::Cue => {
+      Feature::TextUnderlineOffset => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 5701632 {
             return false;
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 3604480 {
+          if version < 4587520 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 4784128 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 786688 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 917504 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 5701632 {
             return false;
           }
         }
@@ -2048,49 +2271,56 @@ impl Feature {
           return false;
         }
       }
-      Feature::CueFunction => {
+      Feature::TextUnderlinePosition => {
         if let Some(version) = browsers.chrome {
-          if version < 1703936 {
+          if version < 2162688 {
             return false;
           }
         }
         if let Some(version) = browsers.edge {
-          if version < 5177344 {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4849664 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 393216 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 1310720 {
             return false;
           }
         }
         if let Some(version) = browsers.safari {
-          if version < 458752 {
+          if version < 786688 {
             return false;
           }
         }
         if let Some(version) = browsers.ios_saf {
-          if version < 458752 {
+          if version < 786944 {
             return false;
           }
         }
         if let Some(version) = browsers.samsung {
-          if version < 66816 {
+          if version < 131072 {
             return false;
           }
         }
         if let Some(version) = browsers.android {
-          if version < 263168 {
+          if version < 2424832 {
             return false;
           }
         }
-        if browsers.firefox.is_some() || browsers.ie.is_some() {
-          return false;
-        }
       }
-      Feature::AnyPseudo => {
+      Feature::TextUnderlinePositionSide => {
         if let Some(version) = browsers.chrome {
-          if version < 1179648 {
+          if version < 4653056 {
             return false;
           }
         }
@@ -2100,28 +2330,148 @@ impl Feature {
           }
         }
         if let Some(version) = browsers.firefox {
-          if version < 262144 {
+          if version < 4849664 {
             return false;
           }
         }
         if let Some(version) = browsers.opera {
-          if version < 917504 {
+          if version < 3801088 {
             return false;
           }
         }
-        if let Some(version) = browsers.safari {
-          if version < 327680 {
+        if let Some(version) = browsers.samsung {
+          if version < 655360 {
             return false;
           }
         }
-        if let Some(version) = browsers.ios_saf {
-          if version < 327680 {
+        if let Some(version) = browsers.android {
+          if version < 4653056 {
             return false;
           }
         }
-        if let Some(version) = browsers.samsung {
-          if version < 65536 {
-            return false;
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+          return false;
+        }
+      }
+      Feature::Cue => {
+        if let Some(version) = browsers.chrome {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3604480 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::CueFunction => {
+        if let Some(version) = browsers.chrome {
+          if version < 1703936 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 66816 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 263168 {
+            return false;
+          }
+        }
+        if browsers.firefox.is_some() || browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::AnyPseudo => {
+        if let Some(version) = browsers.chrome {
+          if version < 1179648 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 262144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 917504 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 327680 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
           }
         }
         if let Some(version) = browsers.android {
@@ -2213,6 +2563,266 @@ impl Feature {
           return false;
         }
       }
+      Feature::ScrollbarColor => {
+        if let Some(version) = browsers.chrome {
+          if version < 7929856 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7929856 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4194304 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7012352 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7929856 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() || browsers.ios_saf.is_some() || browsers.safari.is_some() {
+          return false;
+        }
+      }
+      Feature::ScrollbarWidth => {
+        if let Some(version) = browsers.chrome {
+          if version < 7929856 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7929856 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4194304 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 7012352 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1180160 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1180160 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1638400 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7929856 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::Gap => {
+        if let Some(version) = browsers.chrome {
+          if version < 4325376 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 1048576 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 3997696 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 3473408 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 589824 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 4325376 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::FlexStartEnd => {
+        if let Some(version) = browsers.chrome {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4128768 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5177344 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 720896 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1114112 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6094848 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::IndividualTransforms => {
+        if let Some(version) = browsers.chrome {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 4718592 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 5898240 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 917760 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 918784 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1310720 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 6815744 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
+      Feature::TransitionBehavior => {
+        if let Some(version) = browsers.chrome {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 8454144 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 6750208 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 1115136 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 1572864 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 7667712 {
+            return false;
+          }
+        }
+        if browsers.ie.is_some() {
+          return false;
+        }
+      }
       Feature::P3Colors | Feature::LangList => {
         if let Some(version) = browsers.safari {
           if version < 655616 {
@@ -2235,6 +2845,53 @@ impl Feature {
           return false;
         }
       }
+      Feature::ClipRectWhitespace => {
+        if let Some(version) = browsers.chrome {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version < 786432 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.firefox {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version < 458752 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.android {
+          if version < 65536 {
+            return false;
+          }
+        }
+        if let Some(version) = browsers.ie {
+          if version < 262144 {
+            return false;
+          }
+        }
+      }
     }
     true
   }
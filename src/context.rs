@@ -1,15 +1,21 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
 
 use crate::compat::Feature;
 use crate::declaration::DeclarationBlock;
+use crate::error::{Error, ErrorLocation, MinifyErrorKind};
 use crate::properties::custom::UnparsedProperty;
+use crate::properties::size::WritingMode;
+use crate::properties::text::TextDirection;
 use crate::properties::Property;
 use crate::rules::supports::{SupportsCondition, SupportsRule};
-use crate::rules::{style::StyleRule, CssRule, CssRuleList};
-use crate::selector::{Direction, PseudoClass};
+use crate::rules::{style::StyleRule, CssRule, CssRuleList, Location};
+use crate::selector::{Direction, PseudoClass, PseudoElement, WebKitScrollbarPseudoElement};
 use crate::targets::Browsers;
+use crate::values::string::CowArcStr;
+use crate::values::syntax::SyntaxString;
 use crate::vendor_prefix::VendorPrefix;
-use parcel_selectors::parser::Component;
+use parcel_selectors::parser::{Combinator, Component, Selector};
 
 #[derive(Debug)]
 pub(crate) struct SupportsEntry<'i> {
@@ -18,6 +24,13 @@ pub(crate) struct SupportsEntry<'i> {
   pub important_declarations: Vec<Property<'i>>,
 }
 
+#[derive(Debug)]
+pub(crate) struct ScrollbarEntry<'i> {
+  pub pseudo_element: WebKitScrollbarPseudoElement,
+  pub declarations: Vec<Property<'i>>,
+  pub important_declarations: Vec<Property<'i>>,
+}
+
 #[derive(Debug, PartialEq)]
 pub(crate) enum DeclarationContext {
   None,
@@ -33,8 +46,16 @@ pub(crate) struct PropertyHandlerContext<'i, 'o> {
   supports: Vec<SupportsEntry<'i>>,
   ltr: Vec<Property<'i>>,
   rtl: Vec<Property<'i>>,
+  scrollbar: Vec<ScrollbarEntry<'i>>,
   pub context: DeclarationContext,
   pub unused_symbols: &'o HashSet<String>,
+  pub writing_mode: WritingMode,
+  pub direction: Option<TextDirection>,
+  pub webkit_scrollbar_rules: bool,
+  pub warnings: Option<Arc<RwLock<Vec<Error<MinifyErrorKind>>>>>,
+  pub loc: Option<Location>,
+  pub sources: &'o [String],
+  pub registered_properties: HashMap<CowArcStr<'i>, SyntaxString>,
 }
 
 impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
@@ -45,8 +66,29 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
       supports: Vec::new(),
       ltr: Vec::new(),
       rtl: Vec::new(),
+      scrollbar: Vec::new(),
       context: DeclarationContext::None,
       unused_symbols,
+      writing_mode: WritingMode::default(),
+      direction: None,
+      webkit_scrollbar_rules: false,
+      warnings: None,
+      loc: None,
+      sources: &[],
+      registered_properties: HashMap::new(),
+    }
+  }
+
+  pub fn warn(&self, kind: MinifyErrorKind) {
+    if let Some(warnings) = &self.warnings {
+      if let Ok(mut warnings) = warnings.write() {
+        // Warnings are reported at the location of the rule whose declarations are being minified.
+        let loc = self.loc.map(|loc| {
+          let filename = self.sources.get(loc.source_index as usize).cloned().unwrap_or_default();
+          ErrorLocation::new(loc, filename)
+        });
+        warnings.push(Error { kind, loc });
+      }
     }
   }
 
@@ -69,6 +111,28 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     self.rtl.push(rtl);
   }
 
+  /// Discards the declarations added via `add_logical_rule`, e.g. when they are reset by `all`.
+  pub fn clear_logical_rules(&mut self) {
+    self.ltr.clear();
+    self.rtl.clear();
+  }
+
+  /// Takes the physical declarations added via `add_logical_rule` for the configured direction.
+  /// If no direction is set, these are left to be emitted as `:dir()` rules instead.
+  pub fn take_directional_declarations(&mut self) -> Vec<Property<'i>> {
+    match self.direction {
+      Some(TextDirection::Ltr) => {
+        self.rtl.clear();
+        std::mem::take(&mut self.ltr)
+      }
+      Some(TextDirection::Rtl) => {
+        self.ltr.clear();
+        std::mem::take(&mut self.rtl)
+      }
+      None => Vec::new(),
+    }
+  }
+
   pub fn get_logical_rules(&mut self, style_rule: &StyleRule<'i>) -> Vec<CssRule<'i>> {
     // TODO: :dir/:lang raises the specificity of the selector. Use :where to lower it?
     let mut dest = Vec::new();
@@ -106,6 +170,85 @@ impl<'i, 'o> PropertyHandlerContext<'i, 'o> {
     dest
   }
 
+  pub fn add_scrollbar_property(&mut self, pseudo_element: WebKitScrollbarPseudoElement, property: Property<'i>) {
+    if self.context != DeclarationContext::StyleRule {
+      return;
+    }
+
+    let index = match self.scrollbar.iter().position(|e| e.pseudo_element == pseudo_element) {
+      Some(index) => index,
+      None => {
+        self.scrollbar.push(ScrollbarEntry {
+          pseudo_element,
+          declarations: Vec::new(),
+          important_declarations: Vec::new(),
+        });
+        self.scrollbar.len() - 1
+      }
+    };
+
+    let entry = &mut self.scrollbar[index];
+
+    if self.is_important {
+      entry.important_declarations.push(property);
+    } else {
+      entry.declarations.push(property);
+    }
+  }
+
+  pub fn get_scrollbar_rules(&mut self, style_rule: &StyleRule<'i>) -> Vec<CssRule<'i>> {
+    if self.scrollbar.is_empty() {
+      return Vec::new();
+    }
+
+    let mut dest = Vec::new();
+    let scrollbar = std::mem::take(&mut self.scrollbar);
+    for entry in scrollbar {
+      // Selectors that already target a pseudo element cannot have another one appended.
+      let mut selectors = style_rule.selectors.clone();
+      selectors.0.retain(|selector| {
+        !selector
+          .iter_raw_match_order()
+          .any(|c| matches!(c, Component::PseudoElement(..)))
+      });
+      if selectors.0.is_empty() {
+        continue;
+      }
+
+      for selector in &mut selectors.0 {
+        // Components are stored in match order, with compound selectors in reverse order
+        // but simple selectors within each compound in parse order.
+        let combinators = selector.iter_raw_match_order().rev().filter_map(|c| c.as_combinator());
+        let compounds = selector.iter_raw_match_order().as_slice().split(|c| c.is_combinator()).rev();
+        let mut components = Vec::new();
+        for (compound, combinator) in compounds.zip(combinators.map(Some).chain(std::iter::once(None))) {
+          components.extend(compound.iter().cloned());
+          if let Some(combinator) = combinator {
+            components.push(Component::Combinator(combinator));
+          }
+        }
+        components.push(Component::Combinator(Combinator::PseudoElement));
+        components.push(Component::PseudoElement(PseudoElement::WebKitScrollbar(
+          entry.pseudo_element.clone(),
+        )));
+        *selector = Selector::from_vec2(components);
+      }
+
+      dest.push(CssRule::Style(StyleRule {
+        selectors,
+        vendor_prefix: VendorPrefix::None,
+        declarations: DeclarationBlock {
+          declarations: entry.declarations,
+          important_declarations: entry.important_declarations,
+        },
+        rules: CssRuleList(vec![]),
+        loc: style_rule.loc.clone(),
+      }));
+    }
+
+    dest
+  }
+
   pub fn add_conditional_property(&mut self, condition: SupportsCondition<'i>, property: Property<'i>) {
     if self.context != DeclarationContext::StyleRule {
       return;
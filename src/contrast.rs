@@ -0,0 +1,191 @@
+//! Color contrast analysis.
+//!
+//! Style rules that declare both a foreground `color` and a background color (via `background-color`
+//! or the `background` shorthand) can be checked for sufficient contrast using
+//! [StyleSheet::check_contrast](super::stylesheet::StyleSheet::check_contrast). Each pair of colors
+//! with a contrast below the configured threshold is reported as a warning, along with the location
+//! of the style rule. Contrast is measured using either the WCAG 2.1 or APCA algorithm.
+//!
+//! When the `inherit` option is enabled, nested style rules inherit the colors of their parent rule,
+//! so a nested rule that only changes the `color` is checked against the background of its parent.
+
+use crate::error::{Error, ErrorLocation};
+use crate::printer::PrinterOptions;
+use crate::properties::Property;
+use crate::rules::{CssRule, CssRuleList, Location};
+use crate::traits::ToCss;
+use crate::values::color::{ContrastAlgorithm, CssColor, SRGB};
+use serde::Serialize;
+use std::fmt;
+
+/// Options for [StyleSheet::check_contrast](super::stylesheet::StyleSheet::check_contrast).
+#[derive(Debug, Clone, Default)]
+pub struct ContrastOptions {
+  /// The algorithm used to compute contrast.
+  pub algorithm: ContrastAlgorithm,
+  /// The minimum acceptable contrast. Defaults to 4.5 for WCAG 2.1 (level AA for normal text),
+  /// or a lightness contrast of 60 for APCA. APCA values are compared by magnitude.
+  pub threshold: Option<f32>,
+  /// Whether nested style rules inherit the colors declared by their parent rule.
+  pub inherit: bool,
+}
+
+impl ContrastOptions {
+  fn threshold(&self) -> f32 {
+    self.threshold.unwrap_or(match self.algorithm {
+      ContrastAlgorithm::Wcag21 => 4.5,
+      ContrastAlgorithm::Apca => 60.0,
+    })
+  }
+}
+
+/// A warning about insufficient contrast between a foreground and background color.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct ContrastWarning {
+  /// The foreground color.
+  pub foreground: String,
+  /// The background color.
+  pub background: String,
+  /// The computed contrast between the colors.
+  pub contrast: f32,
+  /// The minimum acceptable contrast.
+  pub threshold: f32,
+}
+
+impl fmt::Display for ContrastWarning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "Insufficient contrast between {} and {}: {:.2} is below the minimum of {}",
+      self.foreground, self.background, self.contrast, self.threshold
+    )
+  }
+}
+
+#[derive(Clone, Default)]
+struct Colors<'a> {
+  foreground: Option<&'a CssColor>,
+  background: Option<&'a CssColor>,
+}
+
+struct ContrastChecker<'a, 'o> {
+  options: &'o ContrastOptions,
+  sources: &'a Vec<String>,
+  warnings: Vec<Error<ContrastWarning>>,
+}
+
+pub(crate) fn check_contrast(
+  rules: &CssRuleList,
+  sources: &Vec<String>,
+  options: &ContrastOptions,
+) -> Vec<Error<ContrastWarning>> {
+  let mut checker = ContrastChecker {
+    options,
+    sources,
+    warnings: Vec::new(),
+  };
+  checker.check_rules(rules, &Colors::default());
+  checker.warnings
+}
+
+impl<'a, 'o> ContrastChecker<'a, 'o> {
+  fn check_rules<'i>(&mut self, rules: &'a CssRuleList<'i>, parent: &Colors<'a>) {
+    for rule in &rules.0 {
+      match rule {
+        CssRule::Style(style) => self.check_style(&style.declarations, &style.rules, style.loc, parent),
+        CssRule::Nesting(nesting) => self.check_style(
+          &nesting.style.declarations,
+          &nesting.style.rules,
+          nesting.style.loc,
+          parent,
+        ),
+        CssRule::Media(media) => self.check_rules(&media.rules, parent),
+        CssRule::Supports(supports) => self.check_rules(&supports.rules, parent),
+        CssRule::Container(container) => self.check_rules(&container.rules, parent),
+        CssRule::LayerBlock(layer) => self.check_rules(&layer.rules, parent),
+        CssRule::MozDocument(document) => self.check_rules(&document.rules, parent),
+        _ => {}
+      }
+    }
+  }
+
+  fn check_style<'i>(
+    &mut self,
+    declarations: &'a crate::declaration::DeclarationBlock<'i>,
+    rules: &'a CssRuleList<'i>,
+    loc: Location,
+    parent: &Colors<'a>,
+  ) {
+    let mut colors = Colors::default();
+    // Important declarations override normal ones, so visit them last.
+    for property in declarations
+      .declarations
+      .iter()
+      .chain(declarations.important_declarations.iter())
+    {
+      match property {
+        Property::Color(color) => colors.foreground = Some(color),
+        Property::BackgroundColor(color) => colors.background = Some(color),
+        Property::Background(backgrounds) => {
+          if let Some(background) = backgrounds.last() {
+            colors.background = Some(&background.color)
+          }
+        }
+        _ => {}
+      }
+    }
+
+    let declared = colors.foreground.is_some() || colors.background.is_some();
+    if self.options.inherit {
+      colors.foreground = colors.foreground.or(parent.foreground);
+      colors.background = colors.background.or(parent.background);
+    }
+
+    if declared {
+      if let (Some(foreground), Some(background)) = (colors.foreground, colors.background) {
+        self.check_pair(foreground, background, loc);
+      }
+    }
+
+    let inherited = if self.options.inherit {
+      colors
+    } else {
+      Colors::default()
+    };
+    self.check_rules(rules, &inherited);
+  }
+
+  fn check_pair(&mut self, foreground: &CssColor, background: &CssColor, loc: Location) {
+    // The result depends on what is rendered behind translucent colors, so it cannot be determined statically.
+    if is_translucent(foreground) || is_translucent(background) {
+      return;
+    }
+
+    let contrast = match foreground.contrast(background, self.options.algorithm) {
+      Some(contrast) => contrast,
+      None => return,
+    };
+
+    let threshold = self.options.threshold();
+    if contrast.abs() >= threshold {
+      return;
+    }
+
+    self.warnings.push(Error {
+      kind: ContrastWarning {
+        foreground: foreground.to_css_string(PrinterOptions::default()).unwrap_or_default(),
+        background: background.to_css_string(PrinterOptions::default()).unwrap_or_default(),
+        contrast,
+        threshold,
+      },
+      loc: Some(ErrorLocation::new(loc, self.sources[loc.source_index as usize].clone())),
+    });
+  }
+}
+
+fn is_translucent(color: &CssColor) -> bool {
+  match color {
+    CssColor::CurrentColor => false,
+    _ => SRGB::from(color).resolve().alpha < 1.0,
+  }
+}
@@ -11,24 +11,28 @@ use crate::properties::box_shadow::BoxShadowHandler;
 use crate::properties::masking::MaskHandler;
 use crate::properties::{
   align::AlignHandler,
-  animation::AnimationHandler,
+  animation::{AnimationHandler, TimelineHandler},
   background::BackgroundHandler,
   border::BorderHandler,
-  contain::ContainerHandler,
+  columns::ColumnsHandler,
+  contain::{ContainIntrinsicSizeHandler, ContainerHandler},
   display::DisplayHandler,
   flex::FlexHandler,
   font::FontHandler,
+  fragmentation::BreakHandler,
   grid::GridHandler,
   list::ListStyleHandler,
   margin_padding::*,
   outline::OutlineHandler,
-  overflow::OverflowHandler,
+  overflow::{OverflowHandler, OverscrollBehaviorHandler},
   position::PositionHandler,
   prefix_handler::{FallbackHandler, PrefixHandler},
   size::SizeHandler,
-  text::TextDecorationHandler,
+  svg::ImageRenderingHandler,
+  text::{TextDecorationHandler, WhiteSpaceHandler},
   transform::TransformHandler,
   transition::TransitionHandler,
+  ui::{CaretHandler, PrintColorAdjustHandler, ResizeHandler, ScrollbarHandler, UserSelectHandler},
 };
 use crate::properties::{Property, PropertyId};
 use crate::targets::Browsers;
@@ -41,16 +45,105 @@ use cssparser::*;
 /// Properties are separated into a list of `!important` declararations,
 /// and a list of normal declarations. This reduces memory usage compared
 /// with storing a boolean along with each property.
+///
+/// When serialized, the block is represented as a list of [Declaration](Declaration)
+/// values, each of which includes whether it is `!important`.
 #[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclarationBlock<'i> {
   /// A list of `!important` declarations in the block.
-  #[cfg_attr(feature = "serde", serde(borrow))]
   pub important_declarations: Vec<Property<'i>>,
   /// A list of normal declarations in the block.
   pub declarations: Vec<Property<'i>>,
 }
 
+/// A single declaration, along with whether it is `!important`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Declaration<'i> {
+  /// The property and its value.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub property: Property<'i>,
+  /// Whether the declaration is `!important`.
+  pub important: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<'i> serde::Serialize for DeclarationBlock<'i> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    use serde::ser::SerializeSeq;
+
+    #[derive(serde::Serialize)]
+    struct DeclarationRef<'a, 'i> {
+      property: &'a Property<'i>,
+      important: bool,
+    }
+
+    let mut seq = serializer.serialize_seq(Some(self.declarations.len() + self.important_declarations.len()))?;
+    for (property, important) in self.iter() {
+      seq.serialize_element(&DeclarationRef { property, important })?;
+    }
+    seq.end()
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'i, 'de: 'i> serde::Deserialize<'de> for DeclarationBlock<'i> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let declarations: Vec<Declaration<'i>> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(declarations.into_iter().collect())
+  }
+}
+
+impl<'i> std::iter::FromIterator<Declaration<'i>> for DeclarationBlock<'i> {
+  fn from_iter<T: IntoIterator<Item = Declaration<'i>>>(iter: T) -> Self {
+    let mut block = DeclarationBlock {
+      important_declarations: vec![],
+      declarations: vec![],
+    };
+    for declaration in iter {
+      if declaration.important {
+        block.important_declarations.push(declaration.property);
+      } else {
+        block.declarations.push(declaration.property);
+      }
+    }
+    block
+  }
+}
+
+type DeclarationIntoIter<'i> =
+  std::iter::Map<std::vec::IntoIter<Property<'i>>, fn(Property<'i>) -> Declaration<'i>>;
+
+impl<'i> IntoIterator for DeclarationBlock<'i> {
+  type Item = Declaration<'i>;
+  type IntoIter = std::iter::Chain<DeclarationIntoIter<'i>, DeclarationIntoIter<'i>>;
+
+  /// Returns an iterator over the declarations in the block, with normal
+  /// declarations first, followed by `!important` declarations.
+  fn into_iter(self) -> Self::IntoIter {
+    let normal: fn(Property<'i>) -> Declaration<'i> = |property| Declaration {
+      property,
+      important: false,
+    };
+    let important: fn(Property<'i>) -> Declaration<'i> = |property| Declaration {
+      property,
+      important: true,
+    };
+
+    self
+      .declarations
+      .into_iter()
+      .map(normal)
+      .chain(self.important_declarations.into_iter().map(important))
+  }
+}
+
 impl<'i> DeclarationBlock<'i> {
   /// Parses a declaration block from CSS syntax.
   pub fn parse<'a, 'o, 't>(
@@ -83,6 +176,49 @@ impl<'i> DeclarationBlock<'i> {
     })
   }
 
+  /// Parses a declaration block from CSS syntax, passing each declaration to the callback as it is
+  /// parsed rather than collecting them into a `DeclarationBlock`. The callback also receives whether
+  /// the declaration is `!important`.
+  pub fn parse_streaming<'a, 'o, 't, F>(
+    input: &mut Parser<'i, 't>,
+    options: &'a ParserOptions<'o, 'i>,
+    mut callback: F,
+  ) -> Result<(), ParseError<'i, ParserError<'i>>>
+  where
+    F: FnMut(Property<'i>, bool),
+  {
+    let mut important_declarations = DeclarationList::new();
+    let mut declarations = DeclarationList::new();
+    let mut parser = DeclarationListParser::new(
+      input,
+      PropertyDeclarationParser {
+        important_declarations: &mut important_declarations,
+        declarations: &mut declarations,
+        options,
+      },
+    );
+    while let Some(res) = parser.next() {
+      if let Err((err, _)) = res {
+        if options.error_recovery {
+          options.warn(err);
+          continue;
+        }
+        return Err(err);
+      }
+
+      // Each declaration is pushed to one of the lists, so drain them before parsing the next one.
+      let declaration_parser = &mut parser.parser;
+      for property in declaration_parser.declarations.drain(..) {
+        callback(property, false);
+      }
+      for property in declaration_parser.important_declarations.drain(..) {
+        callback(property, true);
+      }
+    }
+
+    Ok(())
+  }
+
   /// Parses a declaration block from a string.
   pub fn parse_string<'o>(
     input: &'i str,
@@ -164,6 +300,16 @@ impl<'i> DeclarationBlock<'i> {
     important_handler: &mut DeclarationHandler<'i>,
     context: &mut PropertyHandlerContext<'i, '_>,
   ) {
+    if !context.registered_properties.is_empty() {
+      for property in self.iter_mut() {
+        if let Property::Custom(custom) = property {
+          if let Some(syntax) = context.registered_properties.get(&custom.name) {
+            custom.apply_syntax(syntax);
+          }
+        }
+      }
+    }
+
     macro_rules! handle {
       ($decls: expr, $handler: expr, $important: literal) => {
         for decl in $decls.iter() {
@@ -181,7 +327,9 @@ impl<'i> DeclarationBlock<'i> {
     handle!(self.declarations, handler, false);
 
     handler.finalize(context);
+    handler.decls.extend(context.take_directional_declarations());
     important_handler.finalize(context);
+    important_handler.decls.extend(context.take_directional_declarations());
     self.important_declarations = std::mem::take(&mut important_handler.decls);
     self.declarations = std::mem::take(&mut handler.decls);
   }
@@ -328,54 +476,85 @@ impl<'i> DeclarationBlock<'i> {
   /// the shorthand will be split apart into its component longhand properties, minus the property
   /// to remove. When removing a shorthand, all included longhand properties are also removed.
   pub fn remove(&mut self, property_id: &PropertyId) {
-    fn remove<'i, 'a>(declarations: &mut Vec<Property<'i>>, property_id: &PropertyId<'a>) {
-      let longhands = property_id.longhands().unwrap_or(vec![]);
-      let mut i = 0;
-      while i < declarations.len() {
-        let replacement = {
-          let property = &declarations[i];
-          let id = property.property_id();
-          if id == *property_id || longhands.contains(&id) {
-            // If the property matches the requested property id, or is a longhand
-            // property that is included in the requested shorthand, remove it.
-            None
-          } else if longhands.is_empty() && id.longhands().unwrap_or(vec![]).contains(&property_id) {
-            // If this is a shorthand property that includes the requested longhand,
-            // split it apart into its component longhands, excluding the requested one.
-            Some(
-              id.longhands()
-                .unwrap()
-                .iter()
-                .filter_map(|longhand| {
-                  if *longhand == *property_id {
-                    None
-                  } else {
-                    property.longhand(longhand)
-                  }
-                })
-                .collect::<Vec<Property>>(),
-            )
-          } else {
-            i += 1;
-            continue;
-          }
-        };
+    remove_declarations(&mut self.declarations, property_id);
+    remove_declarations(&mut self.important_declarations, property_id);
+  }
 
-        match replacement {
-          Some(properties) => {
-            let count = properties.len();
-            declarations.splice(i..i + 1, properties);
-            i += count;
-          }
-          None => {
-            declarations.remove(i);
-          }
-        }
-      }
+  /// Returns whether the declaration for the given property id is `!important`,
+  /// or `None` if the property is not declared.
+  ///
+  /// Shorthands are resolved in the same way as [get](DeclarationBlock::get).
+  pub fn is_important(&self, property_id: &PropertyId) -> Option<bool> {
+    self.get(property_id).map(|(_, important)| important)
+  }
+
+  /// Sets whether the declaration for the given property id is `!important`, preserving the value
+  /// that currently applies. Returns whether the property is declared.
+  ///
+  /// Shorthands are resolved in the same way as [get](DeclarationBlock::get). When the importance
+  /// changes, existing declarations are removed as with [remove](DeclarationBlock::remove), and the
+  /// value is set again with the new importance.
+  pub fn set_important(&mut self, property_id: &PropertyId, important: bool) -> bool {
+    let (property, current) = match self.get(property_id) {
+      Some((property, current)) => (property.into_owned(), current),
+      None => return false,
+    };
+
+    if current != important {
+      self.remove(property_id);
+      self.set(property, important);
+    } else if important {
+      // The `!important` declaration already overrides any normal declarations.
+      remove_declarations(&mut self.declarations, property_id);
     }
 
-    remove(&mut self.declarations, property_id);
-    remove(&mut self.important_declarations, property_id);
+    true
+  }
+}
+
+fn remove_declarations<'i, 'a>(declarations: &mut Vec<Property<'i>>, property_id: &PropertyId<'a>) {
+  let longhands = property_id.longhands().unwrap_or(vec![]);
+  let mut i = 0;
+  while i < declarations.len() {
+    let replacement = {
+      let property = &declarations[i];
+      let id = property.property_id();
+      if id == *property_id || longhands.contains(&id) {
+        // If the property matches the requested property id, or is a longhand
+        // property that is included in the requested shorthand, remove it.
+        None
+      } else if longhands.is_empty() && id.longhands().unwrap_or(vec![]).contains(&property_id) {
+        // If this is a shorthand property that includes the requested longhand,
+        // split it apart into its component longhands, excluding the requested one.
+        Some(
+          id.longhands()
+            .unwrap()
+            .iter()
+            .filter_map(|longhand| {
+              if *longhand == *property_id {
+                None
+              } else {
+                property.longhand(longhand)
+              }
+            })
+            .collect::<Vec<Property>>(),
+        )
+      } else {
+        i += 1;
+        continue;
+      }
+    };
+
+    match replacement {
+      Some(properties) => {
+        let count = properties.len();
+        declarations.splice(i..i + 1, properties);
+        i += count;
+      }
+      None => {
+        declarations.remove(i);
+      }
+    }
   }
 }
 
@@ -417,7 +596,7 @@ pub(crate) fn parse_declaration<'i, 't>(
   input: &mut cssparser::Parser<'i, 't>,
   declarations: &mut DeclarationList<'i>,
   important_declarations: &mut DeclarationList<'i>,
-  options: &ParserOptions,
+  options: &ParserOptions<'_, 'i>,
 ) -> Result<(), cssparser::ParseError<'i, ParserError<'i>>> {
   let property = input.parse_until_before(Delimiter::Bang, |input| {
     Property::parse(PropertyId::from(CowArcStr::from(name)), input, options)
@@ -443,6 +622,8 @@ pub(crate) struct DeclarationHandler<'i> {
   border: BorderHandler<'i>,
   outline: OutlineHandler,
   flex: FlexHandler,
+  columns: ColumnsHandler,
+  breaks: BreakHandler,
   grid: GridHandler<'i>,
   align: AlignHandler,
   size: SizeHandler,
@@ -452,17 +633,27 @@ pub(crate) struct DeclarationHandler<'i> {
   scroll_padding: ScrollPaddingHandler<'i>,
   font: FontHandler<'i>,
   text: TextDecorationHandler<'i>,
+  white_space: WhiteSpaceHandler,
   list: ListStyleHandler<'i>,
   transition: TransitionHandler<'i>,
   animation: AnimationHandler<'i>,
+  timeline: TimelineHandler<'i>,
   display: DisplayHandler<'i>,
   position: PositionHandler,
   inset: InsetHandler<'i>,
   overflow: OverflowHandler,
+  overscroll: OverscrollBehaviorHandler,
   transform: TransformHandler,
   box_shadow: BoxShadowHandler,
   mask: MaskHandler<'i>,
   container: ContainerHandler<'i>,
+  contain_intrinsic_size: ContainIntrinsicSizeHandler,
+  scrollbar: ScrollbarHandler,
+  caret: CaretHandler,
+  print_color_adjust: PrintColorAdjustHandler,
+  user_select: UserSelectHandler,
+  resize: ResizeHandler,
+  image_rendering: ImageRenderingHandler,
   fallback: FallbackHandler,
   prefix: PrefixHandler,
   decls: DeclarationList<'i>,
@@ -475,6 +666,8 @@ impl<'i> DeclarationHandler<'i> {
       border: BorderHandler::new(targets),
       outline: OutlineHandler::new(targets),
       flex: FlexHandler::new(targets),
+      columns: ColumnsHandler::new(targets),
+      breaks: BreakHandler::new(targets),
       grid: GridHandler::default(),
       align: AlignHandler::new(targets),
       size: SizeHandler::default(),
@@ -484,17 +677,27 @@ impl<'i> DeclarationHandler<'i> {
       scroll_padding: ScrollPaddingHandler::default(),
       font: FontHandler::default(),
       text: TextDecorationHandler::new(targets),
+      white_space: WhiteSpaceHandler::default(),
       list: ListStyleHandler::new(targets),
       transition: TransitionHandler::new(targets),
       animation: AnimationHandler::new(targets),
+      timeline: TimelineHandler::default(),
       display: DisplayHandler::new(targets),
       position: PositionHandler::new(targets),
       inset: InsetHandler::default(),
       overflow: OverflowHandler::new(targets),
+      overscroll: OverscrollBehaviorHandler::new(targets),
       transform: TransformHandler::new(targets),
       box_shadow: BoxShadowHandler::new(targets),
       mask: MaskHandler::default(),
       container: ContainerHandler::default(),
+      contain_intrinsic_size: ContainIntrinsicSizeHandler::default(),
+      scrollbar: ScrollbarHandler::new(targets),
+      caret: CaretHandler::new(targets),
+      print_color_adjust: PrintColorAdjustHandler::new(targets),
+      user_select: UserSelectHandler::new(targets),
+      resize: ResizeHandler::new(targets),
+      image_rendering: ImageRenderingHandler::new(targets),
       fallback: FallbackHandler::new(targets),
       prefix: PrefixHandler::new(targets),
       decls: DeclarationList::new(),
@@ -512,10 +715,28 @@ impl<'i> DeclarationHandler<'i> {
       return true;
     }
 
+    // Handlers treat CSS-wide keywords the same way as unparsed values, resetting any previous
+    // declarations of the property. They are converted back when the handlers are finalized.
+    if let Property::CSSWideKeyword(wide) = property {
+      return self.handle_property(&Property::Unparsed(wide.to_unparsed()), context);
+    }
+
+    // The `all` shorthand resets every property other than custom properties, `direction` and `unicode-bidi`
+    // (which are not parsed, and are stored as custom properties), so previous declarations can be discarded.
+    if matches!(property, Property::Unparsed(unparsed) if unparsed.property_id == PropertyId::All) {
+      self.finalize(context);
+      context.clear_logical_rules();
+      self.decls.retain(|decl| matches!(decl, Property::Custom(..)));
+      self.decls.push(property.clone());
+      return true;
+    }
+
     self.background.handle_property(property, &mut self.decls, context)
       || self.border.handle_property(property, &mut self.decls, context)
       || self.outline.handle_property(property, &mut self.decls, context)
       || self.flex.handle_property(property, &mut self.decls, context)
+      || self.columns.handle_property(property, &mut self.decls, context)
+      || self.breaks.handle_property(property, &mut self.decls, context)
       || self.grid.handle_property(property, &mut self.decls, context)
       || self.align.handle_property(property, &mut self.decls, context)
       || self.size.handle_property(property, &mut self.decls, context)
@@ -525,17 +746,27 @@ impl<'i> DeclarationHandler<'i> {
       || self.scroll_padding.handle_property(property, &mut self.decls, context)
       || self.font.handle_property(property, &mut self.decls, context)
       || self.text.handle_property(property, &mut self.decls, context)
+      || self.white_space.handle_property(property, &mut self.decls, context)
       || self.list.handle_property(property, &mut self.decls, context)
       || self.transition.handle_property(property, &mut self.decls, context)
       || self.animation.handle_property(property, &mut self.decls, context)
+      || self.timeline.handle_property(property, &mut self.decls, context)
       || self.display.handle_property(property, &mut self.decls, context)
       || self.position.handle_property(property, &mut self.decls, context)
       || self.inset.handle_property(property, &mut self.decls, context)
       || self.overflow.handle_property(property, &mut self.decls, context)
+      || self.overscroll.handle_property(property, &mut self.decls, context)
       || self.transform.handle_property(property, &mut self.decls, context)
       || self.box_shadow.handle_property(property, &mut self.decls, context)
       || self.mask.handle_property(property, &mut self.decls, context)
       || self.container.handle_property(property, &mut self.decls, context)
+      || self.contain_intrinsic_size.handle_property(property, &mut self.decls, context)
+      || self.scrollbar.handle_property(property, &mut self.decls, context)
+      || self.caret.handle_property(property, &mut self.decls, context)
+      || self.print_color_adjust.handle_property(property, &mut self.decls, context)
+      || self.user_select.handle_property(property, &mut self.decls, context)
+      || self.resize.handle_property(property, &mut self.decls, context)
+      || self.image_rendering.handle_property(property, &mut self.decls, context)
       || self.fallback.handle_property(property, &mut self.decls, context)
       || self.prefix.handle_property(property, &mut self.decls, context)
   }
@@ -545,6 +776,8 @@ impl<'i> DeclarationHandler<'i> {
     self.border.finalize(&mut self.decls, context);
     self.outline.finalize(&mut self.decls, context);
     self.flex.finalize(&mut self.decls, context);
+    self.columns.finalize(&mut self.decls, context);
+    self.breaks.finalize(&mut self.decls, context);
     self.grid.finalize(&mut self.decls, context);
     self.align.finalize(&mut self.decls, context);
     self.size.finalize(&mut self.decls, context);
@@ -554,18 +787,36 @@ impl<'i> DeclarationHandler<'i> {
     self.scroll_padding.finalize(&mut self.decls, context);
     self.font.finalize(&mut self.decls, context);
     self.text.finalize(&mut self.decls, context);
+    self.white_space.finalize(&mut self.decls, context);
     self.list.finalize(&mut self.decls, context);
     self.transition.finalize(&mut self.decls, context);
     self.animation.finalize(&mut self.decls, context);
+    self.timeline.finalize(&mut self.decls, context);
     self.display.finalize(&mut self.decls, context);
     self.position.finalize(&mut self.decls, context);
     self.inset.finalize(&mut self.decls, context);
     self.overflow.finalize(&mut self.decls, context);
+    self.overscroll.finalize(&mut self.decls, context);
     self.transform.finalize(&mut self.decls, context);
     self.box_shadow.finalize(&mut self.decls, context);
     self.mask.finalize(&mut self.decls, context);
     self.container.finalize(&mut self.decls, context);
+    self.contain_intrinsic_size.finalize(&mut self.decls, context);
+    self.scrollbar.finalize(&mut self.decls, context);
+    self.caret.finalize(&mut self.decls, context);
+    self.print_color_adjust.finalize(&mut self.decls, context);
+    self.user_select.finalize(&mut self.decls, context);
+    self.resize.finalize(&mut self.decls, context);
+    self.image_rendering.finalize(&mut self.decls, context);
     self.fallback.finalize(&mut self.decls, context);
     self.prefix.finalize(&mut self.decls, context);
+
+    for decl in &mut self.decls {
+      if let Property::Unparsed(unparsed) = decl {
+        if let Some(wide) = unparsed.as_css_wide_keyword() {
+          *decl = Property::CSSWideKeyword(wide);
+        }
+      }
+    }
   }
 }
@@ -71,6 +71,12 @@ pub enum ParserError<'i> {
   EndOfInput,
   /// A declaration was invalid.
   InvalidDeclaration,
+  /// A named grid area in `grid-template-areas` did not form a rectangle.
+  InvalidGridArea(CowArcStr<'i>),
+  /// The rows in `grid-template-areas` did not all have the same number of columns.
+  InvalidGridTemplateAreasRow,
+  /// A keyword that cannot be used as a feature in `will-change`, e.g. `all`, or an unknown property name.
+  InvalidWillChangeFeature(CowArcStr<'i>),
   /// A media query was invalid.
   InvalidMediaQuery,
   /// Invalid CSS nesting.
@@ -101,6 +107,12 @@ impl<'i> fmt::Display for ParserError<'i> {
       AtRuleInvalid(name) => write!(f, "Unknown at rule: @{}", name),
       EndOfInput => write!(f, "Unexpected end of input"),
       InvalidDeclaration => write!(f, "Invalid declaration"),
+      InvalidGridArea(name) => write!(f, "Grid area \"{}\" must be rectangular", name),
+      InvalidGridTemplateAreasRow => write!(
+        f,
+        "Each row in grid-template-areas must have the same number of columns"
+      ),
+      InvalidWillChangeFeature(name) => write!(f, "\"{}\" cannot be used in will-change", name),
       InvalidMediaQuery => write!(f, "Invalid media query"),
       InvalidNesting => write!(f, "Invalid nesting"),
       InvalidPageSelector => write!(f, "Invalid page selector"),
@@ -153,6 +165,18 @@ impl<'i> From<SelectorParseErrorKind<'i>> for ParserError<'i> {
 }
 
 impl<'i> ParserError<'i> {
+  /// Returns whether the error indicates a value that is well formed, but violates a
+  /// constraint of the property it was parsed for, e.g. a grid area that is not rectangular.
+  /// Such values are still preserved as unparsed, but should be reported as warnings.
+  pub fn is_constraint_violation(&self) -> bool {
+    matches!(
+      self,
+      ParserError::InvalidGridArea(..)
+        | ParserError::InvalidGridTemplateAreasRow
+        | ParserError::InvalidWillChangeFeature(..)
+    )
+  }
+
   #[deprecated(note = "use `ParserError::to_string()` or `fmt::Display` instead")]
   #[allow(missing_docs)]
   pub fn reason(&self) -> String {
@@ -306,6 +330,16 @@ pub enum MinifyErrorKind {
     /// The source location of the `@custom-media` rule with unsupported boolean logic.
     custom_media_loc: Location,
   },
+  /// The `translate`, `rotate`, or `scale` properties are not supported by the configured targets,
+  /// and could not be combined with a `transform` property in the same declaration block without
+  /// changing the order they are applied in.
+  IndividualTransformLowering,
+  /// A declaration is not supported by all of the configured targets, and cannot be
+  /// converted to an equivalent that is.
+  UnsupportedByTargets {
+    /// The name of the unsupported property.
+    property: String,
+  },
 }
 
 impl fmt::Display for MinifyErrorKind {
@@ -318,6 +352,15 @@ impl fmt::Display for MinifyErrorKind {
         f,
         "Boolean logic with media types in @custom-media rules is not supported by Lightning CSS"
       ),
+      IndividualTransformLowering => write!(
+        f,
+        "The translate, rotate, and scale properties are not supported by the configured targets, and cannot be combined with a transform in the same declaration block"
+      ),
+      UnsupportedByTargets { property } => write!(
+        f,
+        "The value of the {} property is not supported by all of the configured targets",
+        property
+      ),
     }
   }
 }
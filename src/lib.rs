@@ -19,6 +19,7 @@
 pub mod bundler;
 mod compat;
 mod context;
+pub mod contrast;
 pub mod css_modules;
 pub mod declaration;
 pub mod dependencies;
@@ -32,14 +33,17 @@ pub mod printer;
 pub mod properties;
 pub mod rules;
 mod selector;
+pub mod stream;
 pub mod stylesheet;
 pub mod targets;
 pub mod traits;
 pub mod values;
+pub mod variables;
 pub mod vendor_prefix;
 
 #[cfg(test)]
 mod tests {
+  use crate::contrast::ContrastOptions;
   use crate::css_modules::{CssModuleExport, CssModuleExports, CssModuleReference, CssModuleReferences};
   use crate::dependencies::Dependency;
   use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterErrorKind, SelectorError};
@@ -198,6 +202,25 @@ mod tests {
     assert_eq!(res.code, expected);
   }
 
+  fn draft_properties_test(source: &str, expected: &str) {
+    let mut stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        draft_properties: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, expected);
+  }
+
   fn error_test(source: &str, error: ParserError) {
     let res = StyleSheet::parse(&source, ParserOptions::default());
     match res {
@@ -516,6 +539,19 @@ mod tests {
     );
 
     minify_test(".foo { border-width: 0 0 1px; }", ".foo{border-width:0 0 1px}");
+    minify_test(".foo { border-color: red red red red }", ".foo{border-color:red}");
+    minify_test(
+      ".foo { border-color: red green red green }",
+      ".foo{border-color:red green}",
+    );
+    minify_test(
+      ".foo { border-color: red green blue green }",
+      ".foo{border-color:red green #00f}",
+    );
+    minify_test(
+      ".foo { border-color: red green blue yellow }",
+      ".foo{border-color:red green #00f #ff0}",
+    );
     test(
       r#"
       .foo {
@@ -1637,6 +1673,15 @@ mod tests {
       },
     );
 
+    minify_test(
+      ".foo { border-image: url(foo.png) 10 / / 2px }",
+      ".foo{border-image:url(foo.png) 10//2px}",
+    );
+    minify_test(
+      ".foo { border-image: url(foo.png) 10 / 5px / 2px space }",
+      ".foo{border-image:url(foo.png) 10/5px/2px space}",
+    );
+
     test(
       r#"
       .foo {
@@ -1987,7 +2032,7 @@ mod tests {
     "#,
       indoc! {r#"
       .foo {
-        border-radius: 10px 100px / 120px;
+        border-radius: 10px 100px 100px 10px / 120px;
       }
     "#
       },
@@ -2061,6 +2106,45 @@ mod tests {
       },
     );
 
+    test(
+      r#"
+      .foo {
+        border-radius: 10px;
+        border-bottom-right-radius: 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        border-radius: 10px 10px 20px;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        border-radius: 10px;
+        border-bottom-left-radius: 20px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        border-radius: 10px 10px 10px 20px;
+      }
+    "#
+      },
+    );
+
+    minify_test(
+      ".foo { border-radius: 1px 2px 3px 4px / 1px 2px 3px 4px }",
+      ".foo{border-radius:1px 2px 3px 4px}",
+    );
+    minify_test(
+      ".foo { border-radius: 1px 2px 1px 2px / 3px }",
+      ".foo{border-radius:1px 2px/3px}",
+    );
+
     test(
       r#"
       .foo {
@@ -2404,6 +2488,47 @@ mod tests {
 
   #[test]
   pub fn test_outline() {
+    minify_test(".foo { outline-offset: 2px }", ".foo{outline-offset:2px}");
+    minify_test(".foo { outline-offset: -0.5em }", ".foo{outline-offset:-.5em}");
+    minify_test(".foo { outline-offset: 0px }", ".foo{outline-offset:0}");
+    minify_test(".foo { outline-color: invert }", ".foo{outline-color:invert}");
+    minify_test(".foo { outline: 2px solid invert }", ".foo{outline:2px solid invert}");
+
+    // invert is not a <color>, so the longhands cannot be combined into the shorthand.
+    test(
+      r#"
+      .foo {
+        outline-width: 2px;
+        outline-style: solid;
+        outline-color: invert;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        outline-width: 2px;
+        outline-style: solid;
+        outline-color: invert;
+      }
+    "#
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        outline: 2px solid blue;
+        outline-offset: 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        outline-offset: 4px;
+        outline: 2px solid #00f;
+      }
+    "#
+      },
+    );
+
     test(
       r#"
       .foo {
@@ -2788,6 +2913,57 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    prefix_test(
+      r#"
+      .foo {
+        margin-block-start: 2px;
+        margin-inline-start: 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        margin-top: 2px;
+      }
+
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        margin-left: 4px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        margin-right: 4px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        margin-inline: 2px 4px;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi)) {
+        margin-left: 2px;
+        margin-right: 4px;
+      }
+
+      .foo:lang(ae, ar, arc, bcc, bqi, ckb, dv, fa, glk, he, ku, mzn, nqo, pnb, ps, sd, ug, ur, yi) {
+        margin-left: 4px;
+        margin-right: 2px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -3137,6 +3313,47 @@ mod tests {
       },
     );
 
+    let targets = Some(Browsers {
+      safari: Some(8 << 16),
+      ..Browsers::default()
+    });
+    let mut stylesheet = StyleSheet::parse(
+      r#"
+      .foo {
+        block-size: 25px;
+        inline-size: 50px;
+        max-block-size: var(--size);
+        min-inline-size: 10px;
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        writing_mode: crate::properties::size::WritingMode::VerticalRl,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      indoc! {r#"
+      .foo {
+        width: 25px;
+        height: 50px;
+        max-width: var(--size);
+        min-height: 10px;
+      }
+    "#}
+    );
+
     for (in_prop, out_prop) in [
       ("width", "width"),
       ("height", "height"),
@@ -3303,6 +3520,35 @@ mod tests {
         },
       );
     }
+
+    minify_test(".foo { aspect-ratio: auto }", ".foo{aspect-ratio:auto}");
+    minify_test(".foo { aspect-ratio: 16 / 9 }", ".foo{aspect-ratio:16/9}");
+    minify_test(".foo { aspect-ratio: 16 / 8 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: 32 / 18 }", ".foo{aspect-ratio:16/9}");
+    minify_test(".foo { aspect-ratio: 2 / 1 }", ".foo{aspect-ratio:2}");
+    minify_test(".foo { aspect-ratio: 1.5 / 3 }", ".foo{aspect-ratio:1.5/3}");
+    minify_test(".foo { aspect-ratio: auto 4 / 3 }", ".foo{aspect-ratio:auto 4/3}");
+    minify_test(".foo { aspect-ratio: 4 / 3 auto }", ".foo{aspect-ratio:auto 4/3}");
+    minify_test(".foo { aspect-ratio: 4 }", ".foo{aspect-ratio:4}");
+    minify_test(".foo { aspect-ratio: auto 0 / 1 }", ".foo{aspect-ratio:auto 0}");
+    for value in ["-16 / 9", "16 / -9", "auto -1", "auto auto", "16 / 9 / 2"] {
+      assert!(
+        matches!(
+          Property::parse_string("aspect-ratio".into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}",
+        value
+      );
+    }
+    test(
+      ".foo { aspect-ratio: 16 / 8 }",
+      indoc! {r#"
+      .foo {
+        aspect-ratio: 16 / 8;
+      }
+    "#},
+    );
   }
 
   #[test]
@@ -3452,7 +3698,7 @@ mod tests {
     );
     minify_test(
       ".foo { background-position: left 10px center }",
-      ".foo{background-position:10px 50%}",
+      ".foo{background-position:10px}",
     );
     minify_test(
       ".foo { background-position: right 10px center }",
@@ -3478,6 +3724,36 @@ mod tests {
       ".foo { background-position: bottom right }",
       ".foo{background-position:100% 100%}",
     );
+    minify_test(
+      ".foo { background-position: right 25% bottom 25% }",
+      ".foo{background-position:75% 75%}",
+    );
+    minify_test(
+      ".foo { background-position: right 0 bottom 0 }",
+      ".foo{background-position:100% 100%}",
+    );
+    minify_test(
+      ".foo { background-position: left 50% top 50% }",
+      ".foo{background-position:50%}",
+    );
+    minify_test(
+      ".foo { background-position: right 10px bottom 25% }",
+      ".foo{background-position:right 10px top 75%}",
+    );
+    minify_test(".foo { object-position: center center }", ".foo{object-position:50%}");
+    minify_test(".foo { object-position: left 50% }", ".foo{object-position:0}");
+    minify_test(
+      ".foo { object-position: bottom 10px right 20px }",
+      ".foo{object-position:right 20px bottom 10px}",
+    );
+    test(
+      ".foo { object-position: center center }",
+      indoc! {r#"
+        .foo {
+          object-position: center;
+        }
+      "#},
+    );
 
     minify_test(
       ".foo { background: url('img-sprite.png') no-repeat bottom right }",
@@ -3605,6 +3881,27 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+      .foo {
+        background: url(a.png), url(b.png) gray;
+        background-clip: text, border-box;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        background: url("a.png"), gray url("b.png");
+        -webkit-background-clip: text, border-box;
+        background-clip: text, border-box;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
     minify_test(".foo { background: none center }", ".foo{background:50%}");
     minify_test(".foo { background: none }", ".foo{background:0 0}");
 
@@ -3835,6 +4132,49 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+      .foo {
+        flex: var(--flex);
+        flex-direction: var(--direction);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-flex: var(--flex);
+        flex: var(--flex);
+        -webkit-flex-direction: var(--direction);
+        flex-direction: var(--direction);
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        flex: var(--flex);
+        order: var(--order);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-flex: var(--flex);
+        flex: var(--flex);
+        order: var(--order);
+      }
+    "#
+      },
+      Browsers {
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
     test(
       r#"
       .foo {
@@ -4044,17 +4384,88 @@ mod tests {
       },
     );
 
-    test(
+    minify_test(".foo { grid-gap: 10px 20px }", ".foo{gap:10px 20px}");
+    minify_test(".foo { grid-row-gap: 10px }", ".foo{row-gap:10px}");
+    minify_test(".foo { grid-column-gap: 10px }", ".foo{column-gap:10px}");
+    minify_test(".foo { grid-row-gap: 10px; grid-column-gap: 10px }", ".foo{gap:10px}");
+    minify_test(".foo { gap: 10px; grid-column-gap: 20px }", ".foo{gap:10px 20px}");
+
+    let block =
+      crate::declaration::DeclarationBlock::parse_string("grid-gap: 10px 20px", ParserOptions::default()).unwrap();
+    assert_eq!(
+      block
+        .get(&crate::properties::PropertyId::ColumnGap)
+        .map(|(property, _)| property.value_to_css_string(PrinterOptions::default()).unwrap()),
+      Some("20px".into())
+    );
+
+    prefix_test(
       r#"
       .foo {
-        -webkit-flex-grow: 1;
-        -webkit-flex-shrink: 1;
-        -webkit-flex-basis: auto;
+        gap: 10px 20px;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-flex: auto;
+        grid-gap: 10px 20px;
+        gap: 10px 20px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        grid-row-gap: 10px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        grid-row-gap: 10px;
+        row-gap: 10px;
+      }
+    "#
+      },
+      Browsers {
+        chrome: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        grid-gap: 10px;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        gap: 10px;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    test(
+      r#"
+      .foo {
+        -webkit-flex-grow: 1;
+        -webkit-flex-shrink: 1;
+        -webkit-flex-basis: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-flex: auto;
       }
     "#
       },
@@ -4765,6 +5176,42 @@ mod tests {
         ..Browsers::default()
       },
     );
+    prefix_test(
+      r#"
+      .foo {
+        justify-content: safe end;
+        align-items: start;
+        align-self: self-start;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        justify-content: safe flex-end;
+        align-self: self-start;
+        align-items: flex-start;
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        align-items: start;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        align-items: start;
+      }
+    "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
     prefix_test(
       r#"
       .foo {
@@ -5039,6 +5486,231 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(".foo { font-weight: normal }", ".foo{font-weight:400}");
+    minify_test(".foo { font-weight: 700 }", ".foo{font-weight:700}");
+    minify_test(".foo { font-stretch: condensed }", ".foo{font-stretch:75%}");
+    minify_test(".foo { font-stretch: 62.5% }", ".foo{font-stretch:62.5%}");
+    minify_test(".foo { opacity: 50% }", ".foo{opacity:.5}");
+    minify_test(".foo { opacity: 0.01 }", ".foo{opacity:.01}");
+    test(".foo { fill-opacity: 100% }", ".foo {\n  fill-opacity: 1;\n}\n");
+    test(".foo { font-weight: 700 }", ".foo {\n  font-weight: 700;\n}\n");
+    test(".foo { font-stretch: 75% }", ".foo {\n  font-stretch: 75%;\n}\n");
+
+    let res = StyleSheet::parse(
+      r#"
+      .foo {
+        font-weight: 700;
+        font-stretch: 75%;
+        opacity: .5;
+      }
+
+      .bar {
+        font-weight: 600;
+        font-stretch: 80%;
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap()
+    .to_css(PrinterOptions {
+      prefer_keywords: true,
+      ..PrinterOptions::default()
+    })
+    .unwrap();
+    assert_eq!(
+      res.code,
+      indoc! { r#"
+      .foo {
+        font-weight: bold;
+        font-stretch: condensed;
+        opacity: .5;
+      }
+
+      .bar {
+        font-weight: 600;
+        font-stretch: 80%;
+      }
+    "#}
+    );
+  }
+
+  #[test]
+  fn test_font_feature_settings() {
+    minify_test(
+      ".foo { font-feature-settings: normal }",
+      ".foo{font-feature-settings:normal}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: \"liga\" 1 }",
+      ".foo{font-feature-settings:\"liga\"}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: \"liga\" on }",
+      ".foo{font-feature-settings:\"liga\"}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: \"liga\" off }",
+      ".foo{font-feature-settings:\"liga\" 0}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: 'swsh' 2, 'smcp' }",
+      ".foo{font-feature-settings:\"swsh\" 2,\"smcp\"}",
+    );
+
+    for value in [
+      "\"lig\"",
+      "\"ligat\"",
+      "\"liga\" -1",
+      "liga",
+      "\"liga\" on 1",
+      "\"lig\u{e9}\"",
+      "normal, \"liga\"",
+    ] {
+      assert!(matches!(
+        Property::parse_string("font-feature-settings".into(), value, ParserOptions::default()),
+        Ok(Property::Unparsed(..))
+      ));
+    }
+
+    prefix_test(
+      ".foo { font-feature-settings: \"liga\" off }",
+      indoc! { r#"
+        .foo {
+          -moz-font-feature-settings: "liga" 0;
+          font-feature-settings: "liga" 0;
+        }
+      "#},
+      Browsers {
+        firefox: Some(30 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { -webkit-font-feature-settings: \"liga\"; font-feature-settings: \"liga\" }",
+      indoc! { r#"
+        .foo {
+          font-feature-settings: "liga";
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(
+      ".foo { font: 12px serif; font-feature-settings: \"liga\" off }",
+      ".foo{font:12px serif;font-feature-settings:\"liga\" 0}",
+    );
+    minify_test(
+      ".foo { font-feature-settings: \"liga\" off; font: 12px serif }",
+      ".foo{font:12px serif}",
+    );
+  }
+
+  #[test]
+  fn test_font_variant() {
+    minify_test(
+      ".foo { font-variant-ligatures: none }",
+      ".foo{font-variant-ligatures:none}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: no-contextual common-ligatures }",
+      ".foo{font-variant-ligatures:common-ligatures no-contextual}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: historical-ligatures no-discretionary-ligatures }",
+      ".foo{font-variant-ligatures:no-discretionary-ligatures historical-ligatures}",
+    );
+    minify_test(
+      ".foo { font-variant-numeric: slashed-zero tabular-nums }",
+      ".foo{font-variant-numeric:tabular-nums slashed-zero}",
+    );
+    minify_test(
+      ".foo { font-variant-numeric: ordinal diagonal-fractions oldstyle-nums }",
+      ".foo{font-variant-numeric:oldstyle-nums diagonal-fractions ordinal}",
+    );
+    minify_test(
+      ".foo { font-variant-east-asian: ruby jis78 }",
+      ".foo{font-variant-east-asian:jis78 ruby}",
+    );
+    minify_test(
+      ".foo { font-variant-east-asian: proportional-width }",
+      ".foo{font-variant-east-asian:proportional-width}",
+    );
+    minify_test(
+      ".foo { font-variant-alternates: styleset(a, b) historical-forms }",
+      ".foo{font-variant-alternates:historical-forms styleset(a,b)}",
+    );
+    minify_test(
+      ".foo { font-variant-alternates: swash(fancy) stylistic(alt) }",
+      ".foo{font-variant-alternates:stylistic(alt) swash(fancy)}",
+    );
+    minify_test(
+      ".foo { font-variant-position: super }",
+      ".foo{font-variant-position:super}",
+    );
+    minify_test(".foo { font-variant: normal }", ".foo{font-variant:normal}");
+    minify_test(".foo { font-variant: none }", ".foo{font-variant:none}");
+    minify_test(
+      ".foo { font-variant: small-caps common-ligatures }",
+      ".foo{font-variant:common-ligatures small-caps}",
+    );
+    minify_test(
+      ".foo { font-variant: sub ordinal no-contextual }",
+      ".foo{font-variant:no-contextual ordinal sub}",
+    );
+
+    for (property, value) in [
+      ("font-variant", "normal small-caps"),
+      ("font-variant", "none common-ligatures"),
+      ("font-variant", "small-caps small-caps"),
+      ("font-variant", "small-caps normal"),
+      ("font-variant-ligatures", "common-ligatures no-common-ligatures"),
+      ("font-variant-ligatures", "none contextual"),
+      ("font-variant-numeric", "normal ordinal"),
+      ("font-variant-east-asian", "jis78 simplified"),
+      ("font-variant-alternates", "swash(a) swash(b)"),
+    ] {
+      assert!(matches!(
+        Property::parse_string(property.into(), value, ParserOptions::default()),
+        Ok(Property::Unparsed(..))
+      ));
+    }
+
+    minify_test(".foo { font-variant-ligatures: none; font-variant-caps: normal; font-variant-alternates: normal; font-variant-numeric: normal; font-variant-east-asian: normal; font-variant-position: normal }", ".foo{font-variant:none}");
+    minify_test(
+      ".foo { font-variant: small-caps; font-variant-numeric: ordinal }",
+      ".foo{font-variant:small-caps ordinal}",
+    );
+    minify_test(
+      ".foo { font-variant: none; font-variant-caps: small-caps }",
+      ".foo{font-variant:small-caps;font-variant-ligatures:none}",
+    );
+    minify_test(
+      ".foo { font-variant-numeric: ordinal; font-size: 12px }",
+      ".foo{font-size:12px;font-variant-numeric:ordinal}",
+    );
+    minify_test(
+      ".foo { font-variant: small-caps; font-variant-numeric: var(--x) }",
+      ".foo{font-variant:small-caps;font-variant-numeric:var(--x)}",
+    );
+    minify_test(
+      ".foo { font: 12px serif; font-variant-numeric: ordinal }",
+      ".foo{font:12px serif;font-variant-numeric:ordinal}",
+    );
+    minify_test(
+      ".foo { font: 12px serif; font-variant: all-small-caps }",
+      ".foo{font:12px serif;font-variant-caps:all-small-caps}",
+    );
+    minify_test(
+      ".foo { font-variant-ligatures: none; font: 12px serif }",
+      ".foo{font:12px serif}",
+    );
+    minify_test(
+      ".foo { font-variant: small-caps slashed-zero; font: 12px serif }",
+      ".foo{font:12px serif}",
+    );
   }
 
   #[test]
@@ -6232,6 +6904,18 @@ mod tests {
       ".foo { box-shadow: 64px 64px 12px 40px rgba(0,0,0,0.4), 12px 12px 0px 8px rgba(0,0,0,0.4) inset }",
       ".foo{box-shadow:64px 64px 12px 40px #0006,inset 12px 12px 0 8px #0006}",
     );
+    minify_test(
+      ".foo { box-shadow: 12px 12px 0 -4px red }",
+      ".foo{box-shadow:12px 12px 0 -4px red}",
+    );
+    minify_test(
+      ".foo { box-shadow: 12px 12px -4px red }",
+      ".foo{box-shadow:12px 12px -4px red}",
+    );
+    assert!(matches!(
+      Property::parse_string("box-shadow".into(), "12px 12px -4px red", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
 
     prefix_test(
       ".foo { box-shadow: 12px 12px lab(40% 56.6 39) }",
@@ -6342,6 +7026,20 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    prefix_test(
+      ".foo { box-shadow: var(--foo) 12px red }",
+      indoc! { r#"
+        .foo {
+          -webkit-box-shadow: var(--foo) 12px red;
+          box-shadow: var(--foo) 12px red;
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -6418,6 +7116,14 @@ mod tests {
       "@media (aspect-ratio: 2) { .foo { color: chartreuse }}",
       "@media (aspect-ratio:2){.foo{color:#7fff00}}",
     );
+    minify_test(
+      "@media (min-aspect-ratio: 32/18) { .foo { color: chartreuse }}",
+      "@media (min-aspect-ratio:16/9){.foo{color:#7fff00}}",
+    );
+    minify_test(
+      "@media (aspect-ratio: 16/8) { .foo { color: chartreuse }}",
+      "@media (aspect-ratio:2){.foo{color:#7fff00}}",
+    );
     minify_test(
       "@media not screen and (color) { .foo { color: chartreuse }}",
       "@media not screen and (color){.foo{color:#7fff00}}",
@@ -7907,20 +8613,12 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        transition: margin-inline-start 2s;
+        transition: margin-block 1s, opacity 2s ease-in;
       }
     "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        transition: margin-left 2s;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s;
+      .foo {
+        transition: margin-top 1s, margin-bottom 1s, opacity 2s ease-in;
       }
     "#
       },
@@ -7930,28 +8628,35 @@ mod tests {
       },
     );
 
+    minify_test(
+      ".foo { transition: display 1s allow-discrete }",
+      ".foo{transition:display 1s allow-discrete}",
+    );
+    minify_test(
+      ".foo { transition: allow-discrete display 1s ease-in }",
+      ".foo{transition:display 1s ease-in allow-discrete}",
+    );
+    minify_test(".foo { transition: opacity 1s normal }", ".foo{transition:opacity 1s}");
+    minify_test(
+      ".foo { transition: opacity 1s, display 1s allow-discrete }",
+      ".foo{transition:opacity 1s,display 1s allow-discrete}",
+    );
+
     prefix_test(
       r#"
       .foo {
-        transition: margin-inline-start 2s, padding-inline-start 2s;
+        transition: opacity 1s, display 1s allow-discrete;
       }
     "#,
       indoc! {r#"
-      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
-        transition: margin-left 2s, padding-left 2s;
-      }
-
-      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s, padding-right 2s;
-      }
-
-      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
-        transition: margin-right 2s, padding-right 2s;
+      .foo {
+        transition: opacity 1s, display 1s;
+        transition-behavior: normal, allow-discrete;
       }
     "#
       },
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
@@ -7959,17 +8664,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        transition: margin-block-start 2s;
+        transition: opacity 1s, display 1s allow-discrete;
       }
     "#,
       indoc! {r#"
       .foo {
-        transition: margin-top 2s;
+        transition: opacity 1s, display 1s allow-discrete;
       }
     "#
       },
       Browsers {
-        safari: Some(8 << 16),
+        chrome: Some(120 << 16),
         ..Browsers::default()
       },
     );
@@ -7977,17 +8682,131 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        transition: transform;
+        transition: margin-inline-start 2s;
       }
     "#,
       indoc! {r#"
-      .foo {
-        -webkit-transition: -webkit-transform, transform;
-        transition: -webkit-transform, transform;
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        transition: margin-left 2s;
       }
-    "#
-      },
-      Browsers {
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: margin-inline-start 2s, padding-inline-start 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo:not(:lang(ae)):not(:lang(ar)):not(:lang(arc)):not(:lang(bcc)):not(:lang(bqi)):not(:lang(ckb)):not(:lang(dv)):not(:lang(fa)):not(:lang(glk)):not(:lang(he)):not(:lang(ku)):not(:lang(mzn)):not(:lang(nqo)):not(:lang(pnb)):not(:lang(ps)):not(:lang(sd)):not(:lang(ug)):not(:lang(ur)):not(:lang(yi)) {
+        transition: margin-left 2s, padding-left 2s;
+      }
+
+      .foo:-webkit-any(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s, padding-right 2s;
+      }
+
+      .foo:is(:lang(ae), :lang(ar), :lang(arc), :lang(bcc), :lang(bqi), :lang(ckb), :lang(dv), :lang(fa), :lang(glk), :lang(he), :lang(ku), :lang(mzn), :lang(nqo), :lang(pnb), :lang(ps), :lang(sd), :lang(ug), :lang(ur), :lang(yi)) {
+        transition: margin-right 2s, padding-right 2s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: margin-block-start 2s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        transition: margin-top 2s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition: transform;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition: -webkit-transform, transform;
+        transition: -webkit-transform, transform;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition-property: transform, opacity;
+        transition-duration: 2s, 1s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition-property: -webkit-transform, transform, opacity;
+        transition-property: -webkit-transform, transform, opacity;
+        -webkit-transition-duration: 2s, 2s, 1s;
+        transition-duration: 2s, 2s, 1s;
+      }
+    "#
+      },
+      Browsers {
+        safari: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        transition-property: opacity, transform;
+        transition-duration: 1s;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transition-property: opacity, -webkit-transform, transform;
+        transition-property: opacity, -webkit-transform, transform;
+        -webkit-transition-duration: 1s;
+        transition-duration: 1s;
+      }
+    "#
+      },
+      Browsers {
         safari: Some(6 << 16),
         ..Browsers::default()
       },
@@ -8083,6 +8902,20 @@ mod tests {
     minify_test(".foo { animation-name: test }", ".foo{animation-name:test}");
     minify_test(".foo { animation-name: \"test\" }", ".foo{animation-name:test}");
     minify_test(".foo { animation-name: foo, bar }", ".foo{animation-name:foo,bar}");
+    // CSS-wide keywords and `default` are not valid as a `<custom-ident>`, but may be quoted.
+    for name in ["default", "inherit", "revert-layer"] {
+      let property = Property::parse_string(
+        "animation-name".into(),
+        &format!("foo, {}", name),
+        ParserOptions::default(),
+      )
+      .unwrap();
+      assert!(matches!(property, Property::Unparsed(..)));
+    }
+    minify_test(
+      ".foo { animation-name: \"default\" }",
+      ".foo{animation-name:\"default\"}",
+    );
     minify_test(".foo { animation-duration: 100ms }", ".foo{animation-duration:.1s}");
     minify_test(
       ".foo { animation-duration: 100ms, 2000ms }",
@@ -8157,7 +8990,15 @@ mod tests {
       ".foo { animation: 3s slidein paused ease 1s 1 reverse both }",
       ".foo{animation:slidein 3s 1s reverse both paused}",
     );
-    minify_test(".foo { animation: 3s ease ease }", ".foo{animation:ease 3s ease}");
+    minify_test(".foo { animation: 3s ease ease }", ".foo{animation:3s ease ease}");
+    minify_test(".foo { animation: 3s linear ease }", ".foo{animation:3s linear ease}");
+    minify_test(".foo { animation: normal reverse }", ".foo{animation:normal reverse}");
+    minify_test(".foo { animation: 2 infinite }", ".foo{animation:2 infinite}");
+    minify_test(".foo { animation: paused running }", ".foo{animation:paused running}");
+    minify_test(
+      ".foo { animation: both forwards 1s }",
+      ".foo{animation:1s both forwards}",
+    );
     minify_test(
       ".foo { animation: 3s cubic-bezier(0.25, 0.1, 0.25, 1) foo }",
       ".foo{animation:foo 3s}",
@@ -8408,6 +9249,10 @@ mod tests {
       ".foo { transform: translate3d(2px, 3px, 0px)",
       ".foo{transform:translate(2px,3px)}",
     );
+    minify_test(
+      ".foo { transform: translate3d(0px, 0px, 0px)",
+      ".foo{transform:translate(0)}",
+    );
     minify_test(".foo { transform: scale(2, 3)", ".foo{transform:scale(2,3)}");
     minify_test(".foo { transform: scale(10%, 20%)", ".foo{transform:scale(.1,.2)}");
     minify_test(".foo { transform: scale(2, 2)", ".foo{transform:scale(2)}");
@@ -8442,6 +9287,18 @@ mod tests {
       ".foo { transform: rotate3d(0, 0, 1, 20deg)",
       ".foo{transform:rotate(20deg)}",
     );
+    minify_test(
+      ".foo { transform: rotate3d(2, 0, 0, 20deg)",
+      ".foo{transform:rotateX(20deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotate3d(0, 0.5, 0, 20deg)",
+      ".foo{transform:rotateY(20deg)}",
+    );
+    minify_test(
+      ".foo { transform: rotate3d(0, 0, 3, 20deg)",
+      ".foo{transform:rotate(20deg)}",
+    );
     minify_test(".foo { transform: rotate(405deg)}", ".foo{transform:rotate(405deg)}");
     minify_test(".foo { transform: rotateX(405deg)}", ".foo{transform:rotateX(405deg)}");
     minify_test(".foo { transform: rotateY(405deg)}", ".foo{transform:rotateY(405deg)}");
@@ -8560,6 +9417,41 @@ mod tests {
       ".foo{-webkit-transform:scale(.3)}",
     );
 
+    minify_test(".foo { transform-origin: left top }", ".foo{transform-origin:0 0}");
+    minify_test(".foo { transform-origin: center }", ".foo{transform-origin:50%}");
+    minify_test(
+      ".foo { transform-origin: right bottom 10px }",
+      ".foo{transform-origin:100% 100% 10px}",
+    );
+    minify_test(
+      ".foo { transform-origin: center center 5px }",
+      ".foo{transform-origin:50% 50% 5px}",
+    );
+    minify_test(
+      ".foo { transform-origin: 10px 20px 0px }",
+      ".foo{transform-origin:10px 20px}",
+    );
+    minify_test(
+      ".foo { transform-origin: left 10px 0 }",
+      ".foo{transform-origin:0 10px}",
+    );
+    minify_test(
+      ".foo { transform-origin: top left 10px }",
+      ".foo{transform-origin:0 0 10px}",
+    );
+    minify_test(
+      ".foo { transform-origin: center bottom 2em }",
+      ".foo{transform-origin:50% 100% 2em}",
+    );
+    minify_test(
+      ".foo { perspective-origin: right top }",
+      ".foo{perspective-origin:100% 0}",
+    );
+    minify_test(
+      ".foo { perspective-origin: center bottom }",
+      ".foo{perspective-origin:bottom}",
+    );
+
     minify_test(".foo { translate: 1px 2px 3px }", ".foo{translate:1px 2px 3px}");
     minify_test(".foo { translate: 1px 0px 0px }", ".foo{translate:1px}");
     minify_test(".foo { translate: 1px 2px 0px }", ".foo{translate:1px 2px}");
@@ -8583,8 +9475,77 @@ mod tests {
     minify_test(".foo { scale: 1 0 1 }", ".foo{scale:1 0}");
     minify_test(".foo { scale: 1 0 0 }", ".foo{scale:1 0 0}");
 
-    minify_test(".foo { transform: scale(3); scale: 0.5 }", ".foo{transform:scale(1.5)}");
-    minify_test(".foo { scale: 0.5; transform: scale(3); }", ".foo{transform:scale(3)}");
+    minify_test(
+      ".foo { transform: scale(3); scale: 0.5 }",
+      ".foo{transform:scale(3);scale:.5}",
+    );
+    minify_test(
+      ".foo { scale: 0.5; transform: scale(3); }",
+      ".foo{transform:scale(3);scale:.5}",
+    );
+
+    let chrome = |version: u32| {
+      Some(Browsers {
+        chrome: Some(version << 16),
+        ..Browsers::default()
+      })
+    };
+    attr_test("translate: 10px 20px", "translate:10px 20px", true, chrome(90));
+    attr_test(
+      "rotate: 45deg; transform: translate(10px)",
+      "transform:rotate(45deg)translate(10px)",
+      true,
+      chrome(90),
+    );
+    attr_test(
+      "transform: translate(10px); scale: 2; rotate: 45deg; translate: 10%",
+      "transform:translate(10%)rotate(45deg)scale(2)translate(10px)",
+      true,
+      chrome(90),
+    );
+    attr_test(
+      "rotate: 45deg; transform: translate(10px)",
+      "transform:translate(10px);rotate:45deg",
+      true,
+      chrome(110),
+    );
+
+    // Unparsed values cannot be combined, and transforms from other rules would be overridden
+    // without a transform in the same block, so a warning is emitted.
+    use std::sync::{Arc, RwLock};
+    for source in [
+      ".foo { rotate: 45deg; transform: var(--transform) }",
+      ".foo { rotate: 45deg }",
+    ] {
+      let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+      let mut stylesheet = StyleSheet::parse(
+        source,
+        ParserOptions {
+          filename: "test.css".into(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets: chrome(90),
+          warnings: warnings.clone(),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let w = warnings.unwrap();
+      assert_eq!(
+        *w.read().unwrap(),
+        vec![Error {
+          kind: MinifyErrorKind::IndividualTransformLowering,
+          loc: Some(ErrorLocation {
+            filename: "test.css".into(),
+            line: 0,
+            column: 1
+          })
+        }]
+      );
+    }
 
     prefix_test(
       r#"
@@ -8625,6 +9586,77 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    prefix_test(
+      r#"
+      .foo {
+        perspective: 100px;
+        transform-style: preserve-3d;
+        backface-visibility: hidden;
+        transform: rotateY(45deg);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-transform: rotateY(45deg);
+        transform: rotateY(45deg);
+        -webkit-transform-style: preserve-3d;
+        transform-style: preserve-3d;
+        -webkit-backface-visibility: hidden;
+        backface-visibility: hidden;
+        -webkit-perspective: 100px;
+        perspective: 100px;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-backface-visibility: hidden;
+        backface-visibility: hidden;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        backface-visibility: hidden;
+      }
+    "#},
+      Browsers {
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        perspective: var(--perspective);
+        backface-visibility: var(--visibility);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-perspective: var(--perspective);
+        perspective: var(--perspective);
+        -webkit-backface-visibility: var(--visibility);
+        backface-visibility: var(--visibility);
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(
+      ".foo { -webkit-perspective: 100px; perspective: 100px; -webkit-transform-style: flat }",
+      ".foo{-webkit-transform-style:flat;-webkit-perspective:100px;perspective:100px}",
+    );
   }
 
   #[test]
@@ -9848,6 +10880,13 @@ mod tests {
       }
     "#},
     );
+
+    for name in ["none", "decimal", "disc", "DISCLOSURE-OPEN", "inherit"] {
+      error_test(
+        &format!("@counter-style {} {{ system: cyclic; symbols: x; }}", name),
+        ParserError::UnexpectedToken(crate::properties::custom::Token::Ident(name.into())),
+      );
+    }
   }
 
   #[test]
@@ -10276,6 +11315,22 @@ mod tests {
       ".foo { text-transform: full-width uppercase full-size-kana }",
       ".foo{text-transform:uppercase full-width full-size-kana}",
     );
+    minify_test(
+      ".foo { text-transform: full-size-kana full-width }",
+      ".foo{text-transform:full-width full-size-kana}",
+    );
+    minify_test(
+      ".foo { text-transform: full-width full-width }",
+      ".foo{text-transform:full-width full-width}",
+    );
+    minify_test(
+      ".foo { text-transform: full-width none }",
+      ".foo{text-transform:full-width none}",
+    );
+    minify_test(
+      ".foo { text-transform: none full-width }",
+      ".foo{text-transform:none full-width}",
+    );
   }
 
   #[test]
@@ -10287,27 +11342,118 @@ mod tests {
     minify_test(".foo { white-space: break-spaces }", ".foo{white-space:break-spaces}");
     minify_test(".foo { white-space: pre-line }", ".foo{white-space:pre-line}");
     minify_test(".foo { white-space: NoWrAp }", ".foo{white-space:nowrap}");
-  }
 
-  #[test]
-  fn test_tab_size() {
-    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
-    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
-    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
-    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
-    prefix_test(
-      ".foo{ tab-size: 4 }",
-      indoc! {r#"
-      .foo {
-        -moz-tab-size: 4;
-        -o-tab-size: 4;
-        tab-size: 4;
-      }
-      "#},
-      Browsers {
-        safari: Some(8 << 16),
-        firefox: Some(50 << 16),
-        opera: Some(12 << 16),
+    minify_test(
+      ".foo { white-space-collapse: preserve-breaks }",
+      ".foo{white-space-collapse:preserve-breaks}",
+    );
+    minify_test(".foo { text-wrap-mode: nowrap }", ".foo{text-wrap-mode:nowrap}");
+    minify_test(".foo { text-wrap-style: balance }", ".foo{text-wrap-style:balance}");
+    minify_test(".foo { text-wrap: balance }", ".foo{text-wrap:balance}");
+    minify_test(".foo { text-wrap: wrap pretty }", ".foo{text-wrap:pretty}");
+    minify_test(".foo { text-wrap: stable nowrap }", ".foo{text-wrap:nowrap stable}");
+    minify_test(".foo { text-wrap: wrap }", ".foo{text-wrap:wrap}");
+    minify_test(".foo { text-wrap: nowrap auto }", ".foo{text-wrap:nowrap}");
+    minify_test(
+      ".foo { text-wrap-mode: nowrap; text-wrap-style: pretty }",
+      ".foo{text-wrap:nowrap pretty}",
+    );
+    minify_test(
+      ".foo { white-space-collapse: preserve; text-wrap-mode: nowrap }",
+      ".foo{white-space:pre}",
+    );
+    minify_test(
+      ".foo { white-space-collapse: preserve-breaks; text-wrap-mode: wrap }",
+      ".foo{white-space:pre-line}",
+    );
+    minify_test(
+      ".foo { white-space-collapse: preserve-breaks; text-wrap-mode: nowrap }",
+      ".foo{white-space-collapse:preserve-breaks;text-wrap-mode:nowrap}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap-mode: wrap }",
+      ".foo{white-space:pre-wrap}",
+    );
+    minify_test(
+      ".foo { white-space: nowrap; white-space-collapse: preserve }",
+      ".foo{white-space:pre}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap: balance }",
+      ".foo{white-space:pre-wrap;text-wrap:balance}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap-style: balance }",
+      ".foo{white-space:pre;text-wrap-style:balance}",
+    );
+    minify_test(
+      ".foo { white-space: pre; text-wrap-mode: var(--mode) }",
+      ".foo{white-space:pre;text-wrap-mode:var(--mode)}",
+    );
+  }
+
+  #[test]
+  fn test_tab_size() {
+    minify_test(".foo { tab-size: 8 }", ".foo{tab-size:8}");
+    minify_test(".foo { tab-size: 4px }", ".foo{tab-size:4px}");
+    minify_test(".foo { -moz-tab-size: 4px }", ".foo{-moz-tab-size:4px}");
+    minify_test(".foo { -o-tab-size: 4px }", ".foo{-o-tab-size:4px}");
+    minify_test(".foo { tab-size: 0 }", ".foo{tab-size:0}");
+    minify_test(".foo { tab-size: 2.5 }", ".foo{tab-size:2.5}");
+    minify_test(".foo { tab-size: calc(2px + 1em) }", ".foo{tab-size:calc(2px + 1em)}");
+
+    // Negative values are invalid.
+    assert!(matches!(
+      Property::parse_string("tab-size".into(), "-4", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("tab-size".into(), "-4px", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("border-image-outset".into(), "1 -2px", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+
+    prefix_test(
+      ".foo{ tab-size: 4px }",
+      indoc! {r#"
+      .foo {
+        -moz-tab-size: 4px;
+        tab-size: 4px;
+      }
+      "#},
+      Browsers {
+        firefox: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ tab-size: 4px }",
+      indoc! {r#"
+      .foo {
+        tab-size: 4px;
+      }
+      "#},
+      Browsers {
+        firefox: Some(91 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ tab-size: 4 }",
+      indoc! {r#"
+      .foo {
+        -moz-tab-size: 4;
+        -o-tab-size: 4;
+        tab-size: 4;
+      }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(50 << 16),
+        opera: Some(12 << 16),
         ..Browsers::default()
       },
     );
@@ -10339,6 +11485,8 @@ mod tests {
     minify_test(".foo { word-break: keep-all }", ".foo{word-break:keep-all}");
     minify_test(".foo { word-break: break-all }", ".foo{word-break:break-all}");
     minify_test(".foo { word-break: break-word }", ".foo{word-break:break-word}");
+    minify_test(".foo { word-break: manual }", ".foo{word-break:manual}");
+    minify_test(".foo { word-break: Auto-Phrase }", ".foo{word-break:auto-phrase}");
   }
 
   #[test]
@@ -10353,9 +11501,59 @@ mod tests {
     minify_test(".foo { overflow-wrap: nOrmal }", ".foo{overflow-wrap:normal}");
     minify_test(".foo { overflow-wrap: break-Word }", ".foo{overflow-wrap:break-word}");
     minify_test(".foo { overflow-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
-    minify_test(".foo { word-wrap: Normal }", ".foo{word-wrap:normal}");
-    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{word-wrap:break-word}");
-    minify_test(".foo { word-wrap: Anywhere }", ".foo{word-wrap:anywhere}");
+    minify_test(".foo { word-wrap: Normal }", ".foo{overflow-wrap:normal}");
+    minify_test(".foo { word-wrap: Break-wOrd }", ".foo{overflow-wrap:break-word}");
+    minify_test(".foo { word-wrap: Anywhere }", ".foo{overflow-wrap:anywhere}");
+    minify_test(
+      ".foo { word-wrap: break-word; overflow-wrap: break-word }",
+      ".foo{overflow-wrap:break-word}",
+    );
+    minify_test(
+      ".foo { word-wrap: break-word; overflow-wrap: var(--wrap) }",
+      ".foo{overflow-wrap:break-word;overflow-wrap:var(--wrap)}",
+    );
+
+    prefix_test(
+      ".foo { overflow-wrap: break-word }",
+      indoc! {r#"
+        .foo {
+          word-wrap: break-word;
+          overflow-wrap: break-word;
+        }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { word-wrap: break-word; overflow-wrap: break-word }",
+      indoc! {r#"
+        .foo {
+          word-wrap: break-word;
+          overflow-wrap: break-word;
+        }
+      "#},
+      Browsers {
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { word-wrap: break-word }",
+      indoc! {r#"
+        .foo {
+          overflow-wrap: break-word;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -10427,6 +11625,185 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(".foo { hyphenate-character: auto }", ".foo{hyphenate-character:auto}");
+    minify_test(".foo { hyphenate-character: \"=\" }", ".foo{hyphenate-character:\"=\"}");
+    minify_test(
+      ".foo { -webkit-hyphenate-character: \"-\" }",
+      ".foo{-webkit-hyphenate-character:\"-\"}",
+    );
+    minify_test(".foo { hyphenate-character: none }", ".foo{hyphenate-character:none}");
+    prefix_test(
+      ".foo{ hyphenate-character: \"-\" }",
+      indoc! {r#"
+      .foo {
+        -webkit-hyphenate-character: "-";
+        hyphenate-character: "-";
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-hyphenate-character: "-";
+        hyphenate-character: "-";
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        hyphenate-character: "-";
+      }
+      "#},
+      Browsers {
+        safari: Some(17 << 16),
+        chrome: Some(110 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(
+      ".foo { hyphenate-limit-chars: auto }",
+      ".foo{hyphenate-limit-chars:auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: auto auto }",
+      ".foo{hyphenate-limit-chars:auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 5 auto auto }",
+      ".foo{hyphenate-limit-chars:5}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 10 4 }",
+      ".foo{hyphenate-limit-chars:10 4}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 10 4 4 }",
+      ".foo{hyphenate-limit-chars:10 4}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 10 4 3 }",
+      ".foo{hyphenate-limit-chars:10 4 3}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 10 auto 3 }",
+      ".foo{hyphenate-limit-chars:10 auto 3}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 10 3 auto }",
+      ".foo{hyphenate-limit-chars:10 3 auto}",
+    );
+    minify_test(
+      ".foo { hyphenate-limit-chars: 1 2 3 4 }",
+      ".foo{hyphenate-limit-chars:1 2 3 4}",
+    );
+  }
+
+  #[test]
+  fn test_writing_mode() {
+    minify_test(
+      ".foo { writing-mode: horizontal-tb }",
+      ".foo{writing-mode:horizontal-tb}",
+    );
+    minify_test(".foo { writing-mode: vertical-rl }", ".foo{writing-mode:vertical-rl}");
+    minify_test(".foo { writing-mode: vertical-lr }", ".foo{writing-mode:vertical-lr}");
+    minify_test(".foo { writing-mode: sideways-rl }", ".foo{writing-mode:sideways-rl}");
+    minify_test(".foo { writing-mode: sideways-lr }", ".foo{writing-mode:sideways-lr}");
+    minify_test(".foo { writing-mode: lr }", ".foo{writing-mode:horizontal-tb}");
+    minify_test(".foo { writing-mode: lr-tb }", ".foo{writing-mode:horizontal-tb}");
+    minify_test(".foo { writing-mode: rl }", ".foo{writing-mode:horizontal-tb}");
+    minify_test(".foo { writing-mode: rl-tb }", ".foo{writing-mode:horizontal-tb}");
+    minify_test(".foo { writing-mode: tb }", ".foo{writing-mode:vertical-rl}");
+    minify_test(".foo { writing-mode: tb-rl }", ".foo{writing-mode:vertical-rl}");
+    minify_test(".foo { writing-mode: tb-lr }", ".foo{writing-mode:vertical-lr}");
+    minify_test(
+      ".foo { -webkit-writing-mode: tb-rl }",
+      ".foo{-webkit-writing-mode:vertical-rl}",
+    );
+    prefix_test(
+      ".foo{ writing-mode: vertical-rl }",
+      indoc! {r#"
+      .foo {
+        -webkit-writing-mode: vertical-rl;
+        writing-mode: vertical-rl;
+      }
+      "#},
+      Browsers {
+        chrome: Some(40 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-writing-mode: vertical-rl;
+        writing-mode: vertical-rl;
+      }
+      "#,
+      indoc! {r#"
+      .foo {
+        writing-mode: vertical-rl;
+      }
+      "#},
+      Browsers {
+        chrome: Some(60 << 16),
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo { text-orientation: mixed }", ".foo{text-orientation:mixed}");
+    minify_test(".foo { text-orientation: upright }", ".foo{text-orientation:upright}");
+    minify_test(".foo { text-orientation: sideways }", ".foo{text-orientation:sideways}");
+    prefix_test(
+      ".foo{ text-orientation: upright }",
+      indoc! {r#"
+      .foo {
+        -webkit-text-orientation: upright;
+        text-orientation: upright;
+      }
+      "#},
+      Browsers {
+        safari: Some(12 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo{ text-orientation: upright }",
+      indoc! {r#"
+      .foo {
+        text-orientation: upright;
+      }
+      "#},
+      Browsers {
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo { text-combine-upright: none }", ".foo{text-combine-upright:none}");
+    minify_test(".foo { text-combine-upright: all }", ".foo{text-combine-upright:all}");
+    minify_test(
+      ".foo { text-combine-upright: digits }",
+      ".foo{text-combine-upright:digits}",
+    );
+    minify_test(
+      ".foo { text-combine-upright: digits 2 }",
+      ".foo{text-combine-upright:digits}",
+    );
+    minify_test(
+      ".foo { text-combine-upright: digits 4 }",
+      ".foo{text-combine-upright:digits 4}",
+    );
+    assert!(matches!(
+      Property::parse_string("text-combine-upright".into(), "digits 5", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
   }
 
   #[test]
@@ -10563,6 +11940,60 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    let targets = Some(Browsers {
+      safari: Some(2 << 16),
+      ..Browsers::default()
+    });
+    for (direction, expected) in [
+      (crate::properties::text::TextDirection::Ltr, ("left", "right")),
+      (crate::properties::text::TextDirection::Rtl, ("right", "left")),
+    ] {
+      let mut stylesheet = StyleSheet::parse(
+        ".foo { text-align: start } .bar { text-align: end } .baz { text-align: center }",
+        ParserOptions::default(),
+      )
+      .unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets,
+          direction: Some(direction),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(
+        res.code,
+        format!(
+          ".foo{{text-align:{}}}.bar{{text-align:{}}}.baz{{text-align:center}}",
+          expected.0, expected.1
+        )
+      );
+
+      let mut stylesheet =
+        StyleSheet::parse(".foo { margin-inline-start: 2px }", ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets,
+          direction: Some(direction),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, format!(".foo{{margin-{}:2px}}", expected.0));
+    }
   }
 
   #[test]
@@ -10631,16 +12062,29 @@ mod tests {
     );
     minify_test(
       ".foo { text-indent: 3em each-line hanging }",
-      ".foo{text-indent:3em hanging each-line}",
+      ".foo{text-indent:3em each-line hanging}",
     );
     minify_test(
       ".foo { text-indent: each-line 3em hanging }",
-      ".foo{text-indent:3em hanging each-line}",
+      ".foo{text-indent:3em each-line hanging}",
     );
     minify_test(
-      ".foo { text-indent: each-line hanging 3em }",
+      ".foo { text-indent: hanging each-line 3em }",
       ".foo{text-indent:3em hanging each-line}",
     );
+    minify_test(".foo { text-indent: hanging 3em }", ".foo{text-indent:3em hanging}");
+    minify_test(
+      ".foo { text-indent: 3em hanging hanging }",
+      ".foo{text-indent:3em hanging hanging}",
+    );
+    assert!(matches!(
+      Property::parse_string("text-indent".into(), "3em hanging hanging", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("text-indent".into(), "each-line", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
   }
 
   #[test]
@@ -11117,36 +12561,150 @@ mod tests {
         ..Browsers::default()
       },
     );
-  }
 
-  #[test]
-  fn test_text_emphasis() {
-    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
     minify_test(
-      ".foo { text-emphasis-style: filled }",
-      ".foo{text-emphasis-style:filled}",
+      ".foo { text-underline-offset: auto }",
+      ".foo{text-underline-offset:auto}",
     );
-    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
-    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
     minify_test(
-      ".foo { text-emphasis-style: filled dot }",
-      ".foo{text-emphasis-style:dot}",
+      ".foo { text-underline-offset: 0.2em }",
+      ".foo{text-underline-offset:.2em}",
     );
+    minify_test(".foo { text-underline-offset: 10% }", ".foo{text-underline-offset:10%}");
     minify_test(
-      ".foo { text-emphasis-style: dot filled }",
-      ".foo{text-emphasis-style:dot}",
+      ".foo { text-underline-position: auto }",
+      ".foo{text-underline-position:auto}",
     );
     minify_test(
-      ".foo { text-emphasis-style: open dot }",
-      ".foo{text-emphasis-style:open dot}",
+      ".foo { text-underline-position: from-font }",
+      ".foo{text-underline-position:from-font}",
     );
     minify_test(
-      ".foo { text-emphasis-style: dot open }",
-      ".foo{text-emphasis-style:open dot}",
+      ".foo { text-underline-position: under }",
+      ".foo{text-underline-position:under}",
     );
-    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
-
-    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
+    minify_test(
+      ".foo { text-underline-position: left }",
+      ".foo{text-underline-position:left}",
+    );
+    minify_test(
+      ".foo { text-underline-position: right under }",
+      ".foo{text-underline-position:under right}",
+    );
+    minify_test(
+      ".foo { text-underline-position: under under }",
+      ".foo{text-underline-position:under under}",
+    );
+
+    // A warning is emitted when the underline properties are not supported by the targets.
+    use std::sync::{Arc, RwLock};
+    let underline_warnings = |source: &str, targets: Option<Browsers>| {
+      let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+      let mut stylesheet = StyleSheet::parse(source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets,
+          warnings: warnings.clone(),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let w = warnings.unwrap();
+      let w = w.read().unwrap();
+      w.iter().map(|w| w.kind.to_string()).collect::<Vec<_>>()
+    };
+    let unsupported = |property: &str| {
+      MinifyErrorKind::UnsupportedByTargets {
+        property: property.into(),
+      }
+      .to_string()
+    };
+
+    assert_eq!(
+      underline_warnings(
+        ".foo { text-underline-offset: 2px; text-underline-position: under }",
+        Some(Browsers {
+          chrome: Some(90 << 16),
+          firefox: Some(90 << 16),
+          safari: Some(15 << 16),
+          ..Browsers::default()
+        })
+      ),
+      Vec::<String>::new()
+    );
+    assert_eq!(
+      underline_warnings(".foo { text-underline-offset: 2px }", None),
+      Vec::<String>::new()
+    );
+    assert_eq!(
+      underline_warnings(
+        ".foo { text-underline-offset: 2px }",
+        Some(Browsers {
+          chrome: Some(80 << 16),
+          ..Browsers::default()
+        })
+      ),
+      vec![unsupported("text-underline-offset")]
+    );
+    assert_eq!(
+      underline_warnings(
+        ".foo { text-underline-position: under }",
+        Some(Browsers {
+          firefox: Some(70 << 16),
+          ..Browsers::default()
+        })
+      ),
+      vec![unsupported("text-underline-position")]
+    );
+    assert_eq!(
+      underline_warnings(
+        ".foo { text-underline-position: under left }",
+        Some(Browsers {
+          safari: Some(15 << 16),
+          ..Browsers::default()
+        })
+      ),
+      vec![unsupported("text-underline-position")]
+    );
+    assert_eq!(
+      underline_warnings(
+        ".foo { text-underline-position: right }",
+        Some(Browsers {
+          chrome: Some(90 << 16),
+          ..Browsers::default()
+        })
+      ),
+      Vec::<String>::new()
+    );
+  }
+
+  #[test]
+  fn test_text_emphasis() {
+    minify_test(".foo { text-emphasis-style: none }", ".foo{text-emphasis-style:none}");
+    minify_test(
+      ".foo { text-emphasis-style: filled }",
+      ".foo{text-emphasis-style:filled}",
+    );
+    minify_test(".foo { text-emphasis-style: open }", ".foo{text-emphasis-style:open}");
+    minify_test(".foo { text-emphasis-style: dot }", ".foo{text-emphasis-style:dot}");
+    minify_test(
+      ".foo { text-emphasis-style: filled dot }",
+      ".foo{text-emphasis-style:dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: dot filled }",
+      ".foo{text-emphasis-style:dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: open dot }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(
+      ".foo { text-emphasis-style: dot open }",
+      ".foo{text-emphasis-style:open dot}",
+    );
+    minify_test(".foo { text-emphasis-style: \"x\" }", ".foo{text-emphasis-style:\"x\"}");
+
+    minify_test(".foo { text-emphasis-color: yellow }", ".foo{text-emphasis-color:#ff0}");
 
     minify_test(".foo { text-emphasis: none }", ".foo{text-emphasis:none}");
     minify_test(".foo { text-emphasis: filled }", ".foo{text-emphasis:filled}");
@@ -11158,6 +12716,9 @@ mod tests {
       ".foo { text-emphasis: dot filled yellow }",
       ".foo{text-emphasis:dot #ff0}",
     );
+    minify_test(".foo { text-emphasis: none yellow }", ".foo{text-emphasis:none #ff0}");
+    minify_test(".foo { text-emphasis: yellow }", ".foo{text-emphasis:none #ff0}");
+    minify_test(".foo { text-emphasis: \"x\" yellow }", ".foo{text-emphasis:\"x\" #ff0}");
 
     test(
       r#"
@@ -11222,6 +12783,26 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+      .foo {
+        text-emphasis: "x" red;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-text-emphasis: "x" red;
+        text-emphasis: "x" red;
+      }
+    "#},
+      Browsers {
+        safari: Some(10 << 16),
+        chrome: Some(30 << 16),
+        firefox: Some(45 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       r#"
       .foo {
@@ -11405,6 +12986,22 @@ mod tests {
       ".foo { text-shadow: 1px 1px yellow, 2px 3px red; }",
       ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
     );
+    minify_test(
+      ".foo { text-shadow: 1px 1px yellow, 1px 1px 0 yellow, 2px 3px red; }",
+      ".foo{text-shadow:1px 1px #ff0,2px 3px red}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px 2px yellow, 1px 1px 2px yellow; }",
+      ".foo{text-shadow:1px 1px 2px #ff0,1px 1px 2px #ff0}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px rgba(0, 0, 0, .5), 1px 1px rgba(0, 0, 0, .5); }",
+      ".foo{text-shadow:1px 1px #00000080,1px 1px #00000080}",
+    );
+    minify_test(
+      ".foo { text-shadow: 1px 1px, 1px 1px; }",
+      ".foo{text-shadow:1px 1px,1px 1px}",
+    );
 
     prefix_test(
       ".foo { text-shadow: 12px 12px lab(40% 56.6 39) }",
@@ -11470,8 +13067,142 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_text_stroke() {
+    minify_test(
+      ".foo { -webkit-text-stroke: 1px red }",
+      ".foo{-webkit-text-stroke:1px red}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: red thin }",
+      ".foo{-webkit-text-stroke:thin red}",
+    );
+    minify_test(".foo { -webkit-text-stroke: 2px }", ".foo{-webkit-text-stroke:2px}");
+    minify_test(".foo { -webkit-text-stroke: yellow }", ".foo{-webkit-text-stroke:#ff0}");
+    minify_test(".foo { -webkit-text-stroke: 0 }", ".foo{-webkit-text-stroke:0}");
+    minify_test(
+      ".foo { -webkit-text-stroke-width: 1px; -webkit-text-stroke-color: red }",
+      ".foo{-webkit-text-stroke:1px red}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: 1px red; -webkit-text-stroke-color: yellow }",
+      ".foo{-webkit-text-stroke:1px #ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke-width: medium }",
+      ".foo{-webkit-text-stroke-width:medium}",
+    );
+    minify_test(
+      ".foo { -webkit-text-fill-color: yellow }",
+      ".foo{-webkit-text-fill-color:#ff0}",
+    );
+    minify_test(
+      ".foo { -webkit-text-stroke: 1px red; -webkit-text-stroke-width: var(--width) }",
+      ".foo{-webkit-text-stroke:1px red;-webkit-text-stroke-width:var(--width)}",
+    );
+    minify_test(".foo { text-stroke: 1px red }", ".foo{text-stroke:1px red}");
+
+    prefix_test(
+      ".foo { -webkit-text-stroke: 1px lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          -webkit-text-stroke: 1px #b32323;
+          -webkit-text-stroke: 1px lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { -webkit-text-fill-color: lab(40% 56.6 39) }",
+      indoc! { r#"
+        .foo {
+          -webkit-text-fill-color: #b32323;
+          -webkit-text-fill-color: lab(40% 56.6 39);
+        }
+      "#},
+      Browsers {
+        chrome: Some(4 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
   #[test]
   fn test_break() {
+    minify_test(".foo { break-before: page }", ".foo{break-before:page}");
+    minify_test(".foo { break-after: avoid-column }", ".foo{break-after:avoid-column}");
+    minify_test(".foo { break-inside: avoid-page }", ".foo{break-inside:avoid-page}");
+    minify_test(".foo { page-break-before: always }", ".foo{break-before:page}");
+    minify_test(".foo { page-break-after: left }", ".foo{break-after:left}");
+    minify_test(".foo { page-break-inside: avoid }", ".foo{break-inside:avoid}");
+    minify_test(
+      ".foo { break-before: column; page-break-before: avoid }",
+      ".foo{break-before:avoid}",
+    );
+    minify_test(
+      ".foo { page-break-before: always; break-before: var(--break) }",
+      ".foo{break-before:page;break-before:var(--break)}",
+    );
+    minify_test(".foo { orphans: 3; widows: 2 }", ".foo{orphans:3;widows:2}");
+    minify_test(".foo { orphans: 0; widows: -1 }", ".foo{orphans:0;widows:-1}");
+    assert!(matches!(
+      Property::parse_string("orphans".into(), "0", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("page-break-before".into(), "page", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("page-break-inside".into(), "avoid-page", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+
+    prefix_test(
+      r#"
+      .foo {
+        break-before: page;
+        break-after: column;
+        page-break-inside: avoid;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        page-break-before: always;
+        break-before: page;
+        break-after: column;
+        page-break-inside: avoid;
+        break-inside: avoid;
+      }
+    "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        page-break-before: always;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        break-before: page;
+      }
+    "#},
+      Browsers {
+        firefox: Some(65 << 16),
+        chrome: Some(50 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       r#"
       .foo {
@@ -11506,6 +13237,52 @@ mod tests {
         ..Browsers::default()
       },
     );
+
+    minify_test(".foo { box-decoration-break: slice }", ".foo{box-decoration-break:slice}");
+    minify_test(
+      ".foo { -webkit-box-decoration-break: clone }",
+      ".foo{-webkit-box-decoration-break:clone}",
+    );
+    assert!(matches!(
+      Property::parse_string("box-decoration-break".into(), "clone slice", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+
+    prefix_test(
+      r#"
+      .foo {
+        box-decoration-break: slice;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-box-decoration-break: slice;
+        box-decoration-break: slice;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-box-decoration-break: clone;
+        box-decoration-break: clone;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        box-decoration-break: clone;
+      }
+    "#},
+      Browsers {
+        firefox: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
   }
 
   #[test]
@@ -11623,6 +13400,49 @@ mod tests {
     "#},
     );
 
+    prefix_test(
+      r#"
+      .foo {
+        top: 0;
+        left: 0;
+        bottom: 0;
+        right: 0;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        top: 0;
+        bottom: 0;
+        left: 0;
+        right: 0;
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        inset: 2px auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        top: 2px;
+        bottom: 2px;
+        left: auto;
+        right: auto;
+      }
+    "#},
+      Browsers {
+        safari: Some(13 << 16),
+        ..Browsers::default()
+      },
+    );
+
     test(
       r#"
       .foo {
@@ -11811,13 +13631,123 @@ mod tests {
   }
 
   #[test]
-  fn test_overflow() {
-    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
-    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+  fn test_anchor_functions() {
+    use crate::properties::position::InsetValue;
+    use crate::properties::size::Size;
 
+    minify_test(".foo { top: anchor(--a bottom) }", ".foo{top:anchor(--a bottom)}");
+    minify_test(
+      ".foo { top: anchor(bottom --a, 10.0px) }",
+      ".foo{top:anchor(--a bottom,10px)}",
+    );
+    minify_test(".foo { left: anchor(50.0%) }", ".foo{left:anchor(50%)}");
+    minify_test(
+      ".foo { inset-inline-start: anchor(--a self-end, calc(10px + 5px)) }",
+      ".foo{inset-inline-start:anchor(--a self-end,15px)}",
+    );
+    minify_test(
+      ".foo { inset: anchor(--a bottom) auto auto anchor(--a left) }",
+      ".foo{inset:anchor(--a bottom) auto auto anchor(--a left)}",
+    );
+    minify_test(
+      ".foo { top: anchor(--a top); right: 0; bottom: 0; left: 0 }",
+      ".foo{inset:anchor(--a top) 0 0}",
+    );
     test(
-      r#"
+      ".foo { top: anchor(--a top, 5px) }",
+      indoc! {r#"
+      .foo {
+        top: anchor(--a top, 5px);
+      }
+    "#},
+    );
+
+    minify_test(
+      ".foo { width: anchor-size(--a width) }",
+      ".foo{width:anchor-size(--a width)}",
+    );
+    minify_test(
+      ".foo { width: anchor-size(width --a, calc(10px + 5px)) }",
+      ".foo{width:anchor-size(--a width,15px)}",
+    );
+    minify_test(
+      ".foo { max-height: anchor-size(self-block) }",
+      ".foo{max-height:anchor-size(self-block)}",
+    );
+    minify_test(
+      ".foo { min-width: anchor-size(--a) }",
+      ".foo{min-width:anchor-size(--a)}",
+    );
+    minify_test(".foo { height: anchor-size(100px) }", ".foo{height:anchor-size(100px)}");
+
+    assert!(matches!(
+      Property::parse_string("top".into(), "anchor(--a top)", ParserOptions::default()),
+      Ok(Property::Top(InsetValue::Anchor(..)))
+    ));
+    assert!(matches!(
+      Property::parse_string("block-size".into(), "anchor-size(--a inline)", ParserOptions::default()),
+      Ok(Property::BlockSize(Size::AnchorSize(..)))
+    ));
+
+    // anchor() is only valid in the inset properties, and requires a side.
+    assert!(matches!(
+      Property::parse_string("margin-top".into(), "anchor(--a top)", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("top".into(), "anchor(--a)", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    assert!(matches!(
+      Property::parse_string("width".into(), "anchor(--a top)", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+  }
+
+  #[test]
+  fn test_overflow() {
+    minify_test(".foo { overflow: hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden hidden }", ".foo{overflow:hidden}");
+    minify_test(".foo { overflow: hidden auto }", ".foo{overflow:hidden auto}");
+    minify_test(".foo { overflow-clip-margin: 10px }", ".foo{overflow-clip-margin:10px}");
+    minify_test(".foo { overflow-clip-margin: 0px }", ".foo{overflow-clip-margin:0}");
+    minify_test(
+      ".foo { overflow-clip-margin: content-box }",
+      ".foo{overflow-clip-margin:content-box}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: content-box 0px }",
+      ".foo{overflow-clip-margin:content-box}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: 1em border-box }",
+      ".foo{overflow-clip-margin:border-box 1em}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: padding-box 5px }",
+      ".foo{overflow-clip-margin:5px}",
+    );
+    minify_test(
+      ".foo { overflow-clip-margin: padding-box }",
+      ".foo{overflow-clip-margin:0}",
+    );
+    for value in ["-1px", "content-box border-box", "10px 20px", "auto"] {
+      assert!(matches!(
+        Property::parse_string("overflow-clip-margin".into(), value, ParserOptions::default()),
+        Ok(Property::Unparsed(..))
+      ));
+    }
+    minify_test(".foo { overflow: overlay }", ".foo{overflow:auto}");
+    minify_test(".foo { overflow: hidden overlay }", ".foo{overflow:hidden auto}");
+    minify_test(".foo { overflow: overlay auto }", ".foo{overflow:auto}");
+    minify_test(".foo { overflow-y: overlay }", ".foo{overflow-y:auto}");
+    minify_test(
+      ".foo { overflow-x: hidden; overflow-y: overlay }",
+      ".foo{overflow:hidden auto}",
+    );
+
+    test(
+      r#"
       .foo {
         overflow-x: hidden;
         overflow-y: auto;
@@ -11908,6 +13838,21 @@ mod tests {
     );
 
     minify_test(".foo { text-overflow: ellipsis }", ".foo{text-overflow:ellipsis}");
+    minify_test(".foo { text-overflow: clip }", ".foo{text-overflow:clip}");
+    minify_test(".foo { text-overflow: \"…\" }", ".foo{text-overflow:\"…\"}");
+    minify_test(
+      ".foo { text-overflow: clip ellipsis }",
+      ".foo{text-overflow:clip ellipsis}",
+    );
+    minify_test(
+      ".foo { text-overflow: ellipsis \"[..]\" }",
+      ".foo{text-overflow:ellipsis \"[..]\"}",
+    );
+    minify_test(".foo { text-overflow: clip clip }", ".foo{text-overflow:clip}");
+    minify_test(
+      ".foo { text-overflow: ellipsis ellipsis }",
+      ".foo{text-overflow:ellipsis ellipsis}",
+    );
     prefix_test(
       r#"
       .foo {
@@ -11927,6 +13872,42 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+      .foo {
+        text-overflow: clip ellipsis;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-overflow: clip ellipsis;
+      }
+    "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        text-overflow: "…";
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        text-overflow: "…";
+      }
+    "#},
+      Browsers {
+        safari: Some(4 << 16),
+        opera: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       r#"
       .foo {
@@ -11948,82 +13929,164 @@ mod tests {
   }
 
   #[test]
-  fn test_ui() {
-    minify_test(".foo { resize: both }", ".foo{resize:both}");
-    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
-    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
-    minify_test(
-      ".foo { cursor: url(\"test.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),ew-resize}",
+  fn test_line_clamp() {
+    minify_test(".foo { line-clamp: 3 }", ".foo{line-clamp:3}");
+    minify_test(".foo { line-clamp: none }", ".foo{line-clamp:none}");
+    minify_test(".foo { -webkit-line-clamp: 2 }", ".foo{-webkit-line-clamp:2}");
+    minify_test(".foo { line-clamp: 0 }", ".foo{line-clamp:0}");
+    assert!(matches!(
+      Property::parse_string("line-clamp".into(), "0", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+
+    prefix_test(
+      r#"
+      .foo {
+        line-clamp: 3;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        display: -webkit-box;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        overflow: hidden;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        firefox: Some(100 << 16),
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(
-      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
-      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
+
+    prefix_test(
+      r#"
+      .foo {
+        display: block;
+        line-clamp: 3;
+        overflow: clip;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        display: block;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        overflow: clip;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
-    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
-    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
-    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
-    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
-    minify_test(".foo { caret: block }", ".foo{caret:block}");
-    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
-    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
-    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
-    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
-    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
-    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
-    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
-    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
-    minify_test(
-      ".foo { -webkit-appearance: textfield }",
-      ".foo{-webkit-appearance:textfield}",
+
+    prefix_test(
+      r#"
+      .foo {
+        overflow: auto;
+        line-clamp: 3;
+        display: flex;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        display: flex;
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: 3;
+        overflow: auto;
+      }
+    "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
     );
 
     prefix_test(
       r#"
       .foo {
-        user-select: none;
+        line-clamp: none;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        -webkit-line-clamp: none;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        opera: Some(5 << 16),
-        firefox: Some(10 << 16),
-        ie: Some(10 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_columns() {
+    minify_test(".foo { columns: 3 }", ".foo{columns:3}");
+    minify_test(".foo { columns: 10em }", ".foo{columns:10em}");
+    minify_test(".foo { columns: auto }", ".foo{columns:auto}");
+    minify_test(".foo { columns: auto auto }", ".foo{columns:auto}");
+    minify_test(".foo { columns: 3 auto }", ".foo{columns:3}");
+    minify_test(".foo { columns: auto 10em }", ".foo{columns:10em}");
+    minify_test(".foo { columns: 3 10em }", ".foo{columns:10em 3}");
+    minify_test(".foo { column-width: 10em; column-count: 2 }", ".foo{columns:10em 2}");
+    minify_test(".foo { columns: 10em; column-count: 4 }", ".foo{columns:10em 4}");
+    minify_test(".foo { column-count: auto }", ".foo{column-count:auto}");
+    minify_test(".foo { -webkit-columns: 2 }", ".foo{-webkit-columns:2}");
+    minify_test(".foo { column-rule: 2px solid red }", ".foo{column-rule:2px solid red}");
+    minify_test(".foo { column-rule: none }", ".foo{column-rule:none}");
+    minify_test(
+      ".foo { column-rule-width: 1px; column-rule-style: dotted; column-rule-color: currentColor }",
+      ".foo{column-rule:1px dotted}",
+    );
+    minify_test(".foo { column-rule: solid; column-rule-color: blue }", ".foo{column-rule:solid #00f}");
+    minify_test(".foo { column-span: all }", ".foo{column-span:all}");
+    minify_test(".foo { column-fill: balance-all }", ".foo{column-fill:balance-all}");
+    for (property, value) in [
+      ("column-count", "0"),
+      ("column-width", "-10px"),
+      ("columns", "auto auto auto"),
+      ("columns", "10em 20em"),
+      ("column-fill", "none"),
+    ] {
+      assert!(
+        matches!(
+          Property::parse_string(property.into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}: {}",
+        property,
+        value
+      );
+    }
 
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        column-width: 10em;
+        column-count: 2;
+        column-rule: 1px solid red;
+        column-span: all;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-user-select: none;
-        user-select: none;
+        -webkit-columns: 10em 2;
+        -moz-columns: 10em 2;
+        columns: 10em 2;
+        -webkit-column-rule: 1px solid red;
+        -moz-column-rule: 1px solid red;
+        column-rule: 1px solid red;
+        -webkit-column-span: all;
+        column-span: all;
       }
     "#},
       Browsers {
-        safari: Some(8 << 16),
-        opera: Some(80 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(80 << 16),
+        chrome: Some(40 << 16),
+        firefox: Some(40 << 16),
         ..Browsers::default()
       },
     );
@@ -12031,21 +14094,17 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-user-select: none;
-        -moz-user-select: none;
-        -ms-user-select: none;
-        user-select: none;
+        -webkit-columns: 2;
+        columns: 2;
       }
     "#,
       indoc! {r#"
       .foo {
-        user-select: none;
+        columns: 2;
       }
     "#},
       Browsers {
-        opera: Some(80 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(80 << 16),
+        chrome: Some(100 << 16),
         ..Browsers::default()
       },
     );
@@ -12053,21 +14112,94 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        appearance: none;
+        column-count: var(--count);
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        -webkit-column-count: var(--count);
+        column-count: var(--count);
       }
     "#},
       Browsers {
         safari: Some(8 << 16),
-        chrome: Some(80 << 16),
-        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_scroll_behavior() {
+    minify_test(".foo { scroll-behavior: smooth }", ".foo{scroll-behavior:smooth}");
+    minify_test(".foo { scroll-behavior: AUTO }", ".foo{scroll-behavior:auto}");
+    assert!(matches!(
+      Property::parse_string("scroll-behavior".into(), "instant", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+  }
+
+  #[test]
+  fn test_overscroll_behavior() {
+    minify_test(".foo { overscroll-behavior: contain }", ".foo{overscroll-behavior:contain}");
+    minify_test(".foo { overscroll-behavior: none none }", ".foo{overscroll-behavior:none}");
+    minify_test(".foo { overscroll-behavior: auto contain }", ".foo{overscroll-behavior:auto contain}");
+    minify_test(".foo { overscroll-behavior-x: contain }", ".foo{overscroll-behavior-x:contain}");
+    minify_test(".foo { overscroll-behavior-y: none }", ".foo{overscroll-behavior-y:none}");
+    minify_test(
+      ".foo { overscroll-behavior-x: contain; overscroll-behavior-y: contain }",
+      ".foo{overscroll-behavior:contain}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior-x: auto; overscroll-behavior-y: none }",
+      ".foo{overscroll-behavior:auto none}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: none; overscroll-behavior-y: auto }",
+      ".foo{overscroll-behavior:none auto}",
+    );
+    minify_test(
+      ".foo { overscroll-behavior: contain; overscroll-behavior-x: var(--x) }",
+      ".foo{overscroll-behavior:contain;overscroll-behavior-x:var(--x)}",
+    );
+    minify_test(".foo { -ms-scroll-chaining: none }", ".foo{-ms-scroll-chaining:none}");
+    assert!(matches!(
+      Property::parse_string("overscroll-behavior".into(), "auto auto auto", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+
+    prefix_test(
+      r#"
+      .foo {
+        overscroll-behavior: contain;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-scroll-chaining: none;
+        overscroll-behavior: contain;
+      }
+    "#},
+      Browsers {
+        edge: Some(16 << 16),
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        overscroll-behavior-x: auto;
+        overscroll-behavior-y: auto;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -ms-scroll-chaining: chained;
+        overscroll-behavior: auto;
+      }
+    "#},
+      Browsers {
         ie: Some(11 << 16),
         ..Browsers::default()
       },
@@ -12076,23 +14208,16 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        overscroll-behavior: auto contain;
       }
     "#,
       indoc! {r#"
       .foo {
-        -webkit-appearance: none;
-        appearance: none;
+        overscroll-behavior: auto contain;
       }
     "#},
       Browsers {
-        safari: Some(15 << 16),
-        chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(85 << 16),
+        edge: Some(16 << 16),
         ..Browsers::default()
       },
     );
@@ -12100,77 +14225,837 @@ mod tests {
     prefix_test(
       r#"
       .foo {
-        -webkit-appearance: none;
-        -moz-appearance: none;
-        -ms-appearance: none;
-        appearance: none;
+        overscroll-behavior: contain;
       }
     "#,
       indoc! {r#"
       .foo {
-        appearance: none;
+        overscroll-behavior: contain;
       }
     "#},
       Browsers {
-        chrome: Some(85 << 16),
-        firefox: Some(80 << 16),
-        edge: Some(85 << 16),
+        edge: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_ui() {
+    minify_test(".foo { resize: both }", ".foo{resize:both}");
+    minify_test(".foo { resize: Horizontal }", ".foo{resize:horizontal}");
+    minify_test(".foo { resize: block }", ".foo{resize:block}");
+    minify_test(".foo { resize: inline }", ".foo{resize:inline}");
+    prefix_test(
+      ".foo { resize: inline }",
+      indoc! { r#"
+        .foo {
+          resize: horizontal;
+          resize: inline;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { resize: block }",
+      indoc! { r#"
+        .foo {
+          resize: vertical;
+          resize: block;
+        }
+      "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { resize: block }",
+      indoc! { r#"
+        .foo {
+          resize: block;
+        }
+      "#},
+      Browsers {
+        chrome: Some(118 << 16),
+        firefox: Some(63 << 16),
+        safari: Some(16 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { resize: both }",
+      indoc! { r#"
+        .foo {
+          resize: both;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    let targets = Some(Browsers {
+      safari: Some(15 << 16),
+      ..Browsers::default()
+    });
+    let mut stylesheet = StyleSheet::parse(
+      ".foo { resize: block } .bar { resize: inline }",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    stylesheet
+      .minify(MinifyOptions {
+        targets,
+        writing_mode: crate::properties::size::WritingMode::VerticalRl,
+        ..MinifyOptions::default()
+      })
+      .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        targets,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{resize:horizontal;resize:block}.bar{resize:vertical;resize:inline}"
+    );
+
+    minify_test(".foo { cursor: ew-resize }", ".foo{cursor:ew-resize}");
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url(\"test.cur\"), url(\"foo.cur\"), ew-resize }",
+      ".foo{cursor:url(test.cur),url(foo.cur),ew-resize}",
+    );
+    minify_test(
+      ".foo { cursor: url('test.cur') 4 12, auto }",
+      ".foo{cursor:url(test.cur) 4 12,auto}",
+    );
+    minify_test(
+      ".foo { cursor: image-set(\"cursor.png\" 1x, \"cursor-2x.png\" 2x) 2 2, pointer }",
+      ".foo{cursor:image-set(\"cursor.png\" 1x,\"cursor-2x.png\" 2x) 2 2,pointer}",
+    );
+    minify_test(
+      ".foo { cursor: image-set(url(a.png) 1x), url(b.cur), default }",
+      ".foo{cursor:image-set(\"a.png\" 1x),url(b.cur),default}",
+    );
+
+    for value in [
+      "url(test.cur)",
+      "url(test.cur) 4, auto",
+      "linear-gradient(red, blue), auto",
+      "none, auto",
+      "auto, url(test.cur)",
+    ] {
+      assert!(
+        matches!(
+          Property::parse_string("cursor".into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}",
+        value
+      );
+    }
+    minify_test(".foo { caret-color: auto }", ".foo{caret-color:auto}");
+    minify_test(".foo { caret-color: yellow }", ".foo{caret-color:#ff0}");
+    minify_test(".foo { caret-shape: block }", ".foo{caret-shape:block}");
+    minify_test(".foo { caret: yellow block }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block yellow }", ".foo{caret:#ff0 block}");
+    minify_test(".foo { caret: block }", ".foo{caret:block}");
+    minify_test(".foo { caret: yellow }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: auto }", ".foo{caret:auto}");
+    minify_test(".foo { caret: yellow auto }", ".foo{caret:#ff0}");
+    minify_test(".foo { caret: auto block }", ".foo{caret:block}");
+    minify_test(
+      ".foo { caret-color: yellow; caret-shape: block }",
+      ".foo{caret:#ff0 block}",
+    );
+    minify_test(
+      ".foo { caret-color: auto; caret-shape: auto }",
+      ".foo{caret:auto}",
+    );
+    minify_test(".foo { caret: red; caret-shape: bar }", ".foo{caret:red bar}");
+    minify_test(".foo { caret: red bar; caret-color: auto }", ".foo{caret:bar}");
+    minify_test(
+      ".foo { caret-color: red; caret-shape: var(--shape) }",
+      ".foo{caret-color:red;caret-shape:var(--shape)}",
+    );
+    minify_test(".foo { user-select: none }", ".foo{user-select:none}");
+    minify_test(".foo { -webkit-user-select: none }", ".foo{-webkit-user-select:none}");
+    minify_test(
+      ".foo { -moz-user-select: -moz-none }",
+      ".foo{-moz-user-select:-moz-none}",
+    );
+    minify_test(".foo { pointer-events: none }", ".foo{pointer-events:none}");
+    minify_test(".foo { pointer-events: AUTO }", ".foo{pointer-events:auto}");
+    minify_test(
+      ".foo { pointer-events: visiblePainted }",
+      ".foo{pointer-events:visiblePainted}",
+    );
+    minify_test(
+      ".foo { pointer-events: visiblestroke }",
+      ".foo{pointer-events:visibleStroke}",
+    );
+    minify_test(".foo { pointer-events: stroke }", ".foo{pointer-events:stroke}");
+    minify_test(
+      ".foo { pointer-events: bounding-box }",
+      ".foo{pointer-events:bounding-box}",
+    );
+    assert!(matches!(
+      Property::parse_string("pointer-events".into(), "visible-painted", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+    minify_test(".foo { accent-color: auto }", ".foo{accent-color:auto}");
+    minify_test(".foo { accent-color: yellow }", ".foo{accent-color:#ff0}");
+    minify_test(".foo { appearance: None }", ".foo{appearance:none}");
+    minify_test(
+      ".foo { -webkit-appearance: textfield }",
+      ".foo{-webkit-appearance:textfield}",
+    );
+    minify_test(".foo { appearance: TextField }", ".foo{appearance:textfield}");
+    minify_test(
+      ".foo { -webkit-appearance: Media-Slider }",
+      ".foo{-webkit-appearance:media-slider}",
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: -moz-none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        opera: Some(5 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -moz-user-select: none;
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        firefox: Some(21 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -moz-user-select: -moz-none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        firefox: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        user-select: -moz-none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        user-select: -moz-none;
+      }
+    "#},
+      Browsers {
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        user-select: text;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -moz-user-select: text;
+        user-select: text;
+      }
+    "#},
+      Browsers {
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        user-select: var(--select);
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: var(--select);
+        user-select: var(--select);
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-user-select: none;
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-user-select: none;
+        -moz-user-select: none;
+        -ms-user-select: none;
+        user-select: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        user-select: none;
+      }
+    "#},
+      Browsers {
+        opera: Some(80 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-appearance: Button;
+        -moz-appearance: button;
+        appearance: BUTTON;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: button;
+        appearance: button;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-appearance: Searchfield-Decoration;
+        appearance: searchfield-decoration;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: searchfield-decoration;
+        appearance: searchfield-decoration;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        appearance: textfield;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: textfield;
+        -moz-appearance: textfield;
+        appearance: textfield;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        firefox: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        appearance: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(8 << 16),
+        chrome: Some(80 << 16),
+        firefox: Some(10 << 16),
+        ie: Some(11 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        -webkit-appearance: none;
+        appearance: none;
+      }
+    "#},
+      Browsers {
+        safari: Some(15 << 16),
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      .foo {
+        -webkit-appearance: none;
+        -moz-appearance: none;
+        -ms-appearance: none;
+        appearance: none;
+      }
+    "#,
+      indoc! {r#"
+      .foo {
+        appearance: none;
+      }
+    "#},
+      Browsers {
+        chrome: Some(85 << 16),
+        firefox: Some(80 << 16),
+        edge: Some(85 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret-color: lch(50.998% 135.363 338) }",
+      indoc! { r#"
+        .foo {
+          caret-color: #ee00be;
+          caret-color: color(display-p3 .972962 -.362078 .804206);
+          caret-color: lch(50.998% 135.363 338);
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret: lch(50.998% 135.363 338) block }",
+      indoc! { r#"
+        .foo {
+          caret: #ee00be block;
+          caret: color(display-p3 .972962 -.362078 .804206) block;
+          caret: lch(50.998% 135.363 338) block;
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        safari: Some(14 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
+      indoc! { r#"
+        .foo {
+          caret: #ee00be var(--foo);
+        }
+
+        @supports (color: lab(0% 0 0)) {
+          .foo {
+            caret: lab(50.998% 125.506 -50.7078) var(--foo);
+          }
+        }
+      "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo { scrollbar-width: thin }", ".foo{scrollbar-width:thin}");
+    minify_test(".foo { scrollbar-width: none }", ".foo{scrollbar-width:none}");
+    minify_test(".foo { scrollbar-color: auto }", ".foo{scrollbar-color:auto}");
+    minify_test(".foo { scrollbar-color: red blue }", ".foo{scrollbar-color:red #00f}");
+    minify_test(".foo { scrollbar-color: red }", ".foo{scrollbar-color:red}");
+
+    let scrollbar_rules_test = |source: &str, expected: &str, targets: Browsers| {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets: Some(targets),
+          webkit_scrollbar_rules: true,
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          targets: Some(targets),
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    };
+
+    scrollbar_rules_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      indoc! { r#"
+        .foo {
+          scrollbar-width: thin;
+          scrollbar-color: red #00f;
+        }
+
+        .foo::-webkit-scrollbar {
+          width: 8px;
+          height: 8px;
+        }
+
+        .foo::-webkit-scrollbar-thumb {
+          background-color: red;
+        }
+
+        .foo::-webkit-scrollbar-track {
+          background-color: #00f;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    scrollbar_rules_test(
+      ".foo, .bar::before { scrollbar-width: none }",
+      indoc! { r#"
+        .foo, .bar:before {
+          scrollbar-width: none;
+        }
+
+        .foo::-webkit-scrollbar {
+          display: none;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      indoc! { r#"
+        .foo {
+          scrollbar-width: thin;
+          scrollbar-color: red #00f;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      indoc! { r#"
+        .foo {
+          scrollbar-width: thin;
+          scrollbar-color: red #00f;
+        }
+      "#},
+      Browsers {
+        chrome: Some(121 << 16),
+        firefox: Some(64 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { scrollbar-width: thin; scrollbar-color: red blue }",
+      indoc! { r#"
+        .foo {
+          scrollbar-width: thin;
+          scrollbar-color: red #00f;
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { scrollbar-color: lab(40% 56.6 39) red }",
+      indoc! { r#"
+        .foo {
+          scrollbar-color: #b32323 red;
+          scrollbar-color: lab(40% 56.6 39) red;
+        }
+      "#},
+      Browsers {
+        firefox: Some(64 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { caret-color: lch(50.998% 135.363 338) }",
+      ".foo { scrollbar-color: red lab(40% 56.6 39) }",
       indoc! { r#"
         .foo {
-          caret-color: #ee00be;
-          caret-color: color(display-p3 .972962 -.362078 .804206);
-          caret-color: lch(50.998% 135.363 338);
+          scrollbar-color: red #b32323;
+          scrollbar-color: red lab(40% 56.6 39);
         }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        firefox: Some(64 << 16),
         ..Browsers::default()
       },
     );
+  }
+
+  #[test]
+  fn test_color_adjust() {
+    minify_test(".foo { color-scheme: normal }", ".foo{color-scheme:normal}");
+    minify_test(".foo { color-scheme: light }", ".foo{color-scheme:light}");
+    minify_test(".foo { color-scheme: light dark }", ".foo{color-scheme:light dark}");
+    minify_test(".foo { color-scheme: DARK Light }", ".foo{color-scheme:dark light}");
+    minify_test(".foo { color-scheme: only light }", ".foo{color-scheme:light only}");
+    minify_test(".foo { color-scheme: dark only }", ".foo{color-scheme:dark only}");
+    minify_test(
+      ".foo { color-scheme: light sepia dark }",
+      ".foo{color-scheme:light sepia dark}",
+    );
+    minify_test(".foo { forced-color-adjust: none }", ".foo{forced-color-adjust:none}");
+    minify_test(
+      ".foo { forced-color-adjust: preserve-parent-color }",
+      ".foo{forced-color-adjust:preserve-parent-color}",
+    );
+    minify_test(".foo { print-color-adjust: exact }", ".foo{print-color-adjust:exact}");
+    minify_test(
+      ".foo { -webkit-print-color-adjust: economy }",
+      ".foo{-webkit-print-color-adjust:economy}",
+    );
+
+    for (property, value) in [
+      ("color-scheme", "only"),
+      ("color-scheme", "normal dark"),
+      ("color-scheme", "light only only"),
+      ("color-scheme", "light only dark"),
+      ("color-scheme", "only light only"),
+      ("color-scheme", "light inherit"),
+      ("forced-color-adjust", "preserve"),
+      ("print-color-adjust", "auto"),
+    ] {
+      assert!(
+        matches!(
+          Property::parse_string(property.into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}: {}",
+        property,
+        value
+      );
+    }
 
     prefix_test(
-      ".foo { caret: lch(50.998% 135.363 338) block }",
+      ".foo { print-color-adjust: exact }",
       indoc! { r#"
         .foo {
-          caret: #ee00be block;
-          caret: color(display-p3 .972962 -.362078 .804206) block;
-          caret: lch(50.998% 135.363 338) block;
+          -webkit-print-color-adjust: exact;
+          print-color-adjust: exact;
         }
       "#},
       Browsers {
         chrome: Some(90 << 16),
-        safari: Some(14 << 16),
+        firefox: Some(90 << 16),
         ..Browsers::default()
       },
     );
 
     prefix_test(
-      ".foo { caret: lch(50.998% 135.363 338) var(--foo) }",
+      ".foo { -webkit-print-color-adjust: exact; print-color-adjust: exact }",
       indoc! { r#"
         .foo {
-          caret: #ee00be var(--foo);
+          print-color-adjust: exact;
         }
+      "#},
+      Browsers {
+        firefox: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
 
-        @supports (color: lab(0% 0 0)) {
-          .foo {
-            caret: lab(50.998% 125.506 -50.7078) var(--foo);
-          }
+    prefix_test(
+      ".foo { -webkit-print-color-adjust: exact; print-color-adjust: economy }",
+      indoc! { r#"
+        .foo {
+          -webkit-print-color-adjust: exact;
+          -webkit-print-color-adjust: economy;
+          print-color-adjust: economy;
         }
       "#},
       Browsers {
-        chrome: Some(90 << 16),
+        safari: Some(15 << 16),
         ..Browsers::default()
       },
     );
   }
 
+  #[test]
+  fn test_will_change() {
+    minify_test(".foo { will-change: auto }", ".foo{will-change:auto}");
+    minify_test(".foo { will-change: transform }", ".foo{will-change:transform}");
+    minify_test(
+      ".foo { will-change: Transform, opacity }",
+      ".foo{will-change:transform,opacity}",
+    );
+    minify_test(
+      ".foo { will-change: scroll-position, contents }",
+      ".foo{will-change:scroll-position,contents}",
+    );
+    minify_test(
+      ".foo { will-change: -webkit-transform }",
+      ".foo{will-change:-webkit-transform}",
+    );
+    minify_test(".foo { will-change: foo-bar }", ".foo{will-change:foo-bar}");
+    minify_test(
+      ".foo { will-change: --foo, opacity }",
+      ".foo{will-change:--foo,opacity}",
+    );
+    minify_test(".foo { will-change: all }", ".foo{will-change:all}");
+    minify_test(".foo { will-change: opacity, auto }", ".foo{will-change:opacity,auto}");
+
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let property = Property::parse_string(
+      "will-change".into(),
+      "transform, all",
+      ParserOptions {
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+    let property = Property::parse_string(
+      "will-change".into(),
+      "opacity, foo-bar",
+      ParserOptions {
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+    assert_eq!(
+      warnings
+        .unwrap()
+        .read()
+        .unwrap()
+        .iter()
+        .map(|w| w.kind.clone())
+        .collect::<Vec<_>>(),
+      vec![
+        ParserError::InvalidWillChangeFeature("all".into()),
+        ParserError::InvalidWillChangeFeature("foo-bar".into())
+      ]
+    );
+  }
+
   #[test]
   fn test_list() {
     minify_test(".foo { list-style-type: disc; }", ".foo{list-style-type:disc}");
@@ -12203,6 +15088,36 @@ mod tests {
       ".foo { list-style: \"★\" url(ellipse.png) outside; }",
       ".foo{list-style:\"★\" url(ellipse.png)}",
     );
+    minify_test(".foo { list-style: none }", ".foo{list-style:none}");
+    minify_test(".foo { list-style: none none }", ".foo{list-style:none}");
+    minify_test(".foo { list-style: none inside }", ".foo{list-style:none inside}");
+    minify_test(".foo { list-style: inside }", ".foo{list-style:inside}");
+    minify_test(".foo { list-style: square none }", ".foo{list-style:square}");
+    minify_test(".foo { list-style: none square }", ".foo{list-style:square}");
+    minify_test(
+      ".foo { list-style: none url(ellipse.png) }",
+      ".foo{list-style:none url(ellipse.png)}",
+    );
+    minify_test(
+      ".foo { list-style: url(ellipse.png) none }",
+      ".foo{list-style:none url(ellipse.png)}",
+    );
+    minify_test(".foo { list-style: \"-\" inside }", ".foo{list-style:\"-\" inside}");
+    minify_test(
+      ".foo { list-style: inside symbols(cyclic \"*\" \"+\") }",
+      ".foo{list-style:symbols(cyclic \"*\" \"+\") inside}",
+    );
+    for invalid in [
+      "none none none",
+      "none square url(ellipse.png)",
+      "none none square",
+      "square disc",
+    ] {
+      assert!(matches!(
+        Property::parse_string("list-style".into(), invalid, ParserOptions::default()),
+        Ok(Property::Unparsed(..))
+      ));
+    }
 
     test(
       r#"
@@ -12298,6 +15213,153 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_content() {
+    minify_test(".foo { content: normal }", ".foo{content:normal}");
+    minify_test(".foo { content: none }", ".foo{content:none}");
+    minify_test(".foo { content: \"foo\" }", ".foo{content:\"foo\"}");
+    minify_test(".foo { content: 'foo' 'bar' }", ".foo{content:\"foobar\"}");
+    minify_test(
+      ".foo { content: 'a' \"b\" counter(x) 'c' 'd' open-quote 'e' / 'f' 'g' counter(x) }",
+      ".foo{content:\"ab\" counter(x) \"cd\" open-quote \"e\"/\"fg\" counter(x)}",
+    );
+    minify_test(".foo { content: '' 'foo' '' }", ".foo{content:\"foo\"}");
+    minify_test(".foo { content: open-quote }", ".foo{content:open-quote}");
+    minify_test(
+      ".foo { content: no-open-quote \"x\" close-quote }",
+      ".foo{content:no-open-quote \"x\" close-quote}",
+    );
+    minify_test(".foo { content: url(foo.png) }", ".foo{content:url(foo.png)}");
+    minify_test(
+      ".foo { content: url(foo.png) / \"Alt text\" }",
+      ".foo{content:url(foo.png)/\"Alt text\"}",
+    );
+    minify_test(".foo { content: counter(item) }", ".foo{content:counter(item)}");
+    minify_test(
+      ".foo { content: counter(item, decimal) }",
+      ".foo{content:counter(item)}",
+    );
+    minify_test(
+      ".foo { content: counter(item, DECIMAL) }",
+      ".foo{content:counter(item)}",
+    );
+    minify_test(
+      ".foo { content: counter(item, upper-roman) }",
+      ".foo{content:counter(item,upper-roman)}",
+    );
+    minify_test(
+      ".foo { content: counter(item, my-style) }",
+      ".foo{content:counter(item,my-style)}",
+    );
+    minify_test(
+      ".foo { content: counter(item, symbols(cyclic \"*\")) }",
+      ".foo{content:counter(item,symbols(cyclic \"*\"))}",
+    );
+    minify_test(
+      ".foo { content: counters(item, \".\") }",
+      ".foo{content:counters(item,\".\")}",
+    );
+    minify_test(
+      ".foo { content: counters(item, \".\", decimal) }",
+      ".foo{content:counters(item,\".\")}",
+    );
+    minify_test(
+      ".foo { content: counters(item, '.', lower-alpha) \") \" }",
+      ".foo{content:counters(item,\".\",lower-alpha) \") \"}",
+    );
+    minify_test(
+      ".foo { content: \"Chapter \" counter(chapter) \". \" / \"Chapter \" counter(chapter) }",
+      ".foo{content:\"Chapter \" counter(chapter) \". \"/\"Chapter \" counter(chapter)}",
+    );
+    test(
+      ".foo { content: counters(item, \".\", upper-roman) / \"Item\" }",
+      indoc! {r#"
+      .foo {
+        content: counters(item, ".", upper-roman) / "Item";
+      }
+    "#},
+    );
+
+    // Invalid counter names and styles are preserved as is.
+    minify_test(".foo { content: counter(none) }", ".foo{content:counter(none)}");
+    minify_test(".foo { content: counter(inherit) }", ".foo{content:counter(inherit)}");
+    minify_test(
+      ".foo { content: counter(item, inherit) }",
+      ".foo{content:counter(item,inherit)}",
+    );
+    minify_test(".foo { content: counters(item) }", ".foo{content:counters(item)}");
+    minify_test(
+      ".foo { content: url(foo.png) / none }",
+      ".foo{content:url(foo.png)/none}",
+    );
+    minify_test(".foo { content: attr(data-foo) }", ".foo{content:attr(data-foo)}");
+  }
+
+  #[test]
+  fn test_quotes() {
+    minify_test(".foo { quotes: auto }", ".foo{quotes:auto}");
+    minify_test(".foo { quotes: none }", ".foo{quotes:none}");
+    minify_test(".foo { quotes: '«' '»' }", ".foo{quotes:\"«\" \"»\"}");
+    minify_test(
+      ".foo { quotes: '\"' '\"' \"'\" \"'\" }",
+      ".foo{quotes:\"\\\"\" \"\\\"\" \"'\" \"'\"}",
+    );
+    minify_test(".foo { quotes: '\\201C' '\\201D' }", ".foo{quotes:\"“\" \"”\"}");
+    minify_test(".foo { quotes: '' '' }", ".foo{quotes:\"\" \"\"}");
+
+    for value in ["'«'", "'«' '»' '‹'", "auto '«' '»'", "none none"] {
+      assert!(matches!(
+        Property::parse_string("quotes".into(), value, ParserOptions::default()),
+        Ok(Property::Unparsed(..))
+      ));
+    }
+  }
+
+  #[test]
+  fn test_counters() {
+    minify_test(".foo { counter-reset: none }", ".foo{counter-reset:none}");
+    minify_test(".foo { counter-reset: item }", ".foo{counter-reset:item}");
+    minify_test(".foo { counter-reset: item 0 }", ".foo{counter-reset:item}");
+    minify_test(".foo { counter-reset: item 3 }", ".foo{counter-reset:item 3}");
+    minify_test(".foo { counter-reset: item -1 }", ".foo{counter-reset:item -1}");
+    minify_test(
+      ".foo { counter-reset: item 0 section 2 other }",
+      ".foo{counter-reset:item section 2 other}",
+    );
+    minify_test(
+      ".foo { counter-reset: reversed(item) }",
+      ".foo{counter-reset:reversed(item)}",
+    );
+    minify_test(
+      ".foo { counter-reset: reversed(item) 0 }",
+      ".foo{counter-reset:reversed(item) 0}",
+    );
+    minify_test(
+      ".foo { counter-reset: reversed( item ) 10 section }",
+      ".foo{counter-reset:reversed(item) 10 section}",
+    );
+    minify_test(".foo { counter-increment: none }", ".foo{counter-increment:none}");
+    minify_test(".foo { counter-increment: item 1 }", ".foo{counter-increment:item}");
+    minify_test(".foo { counter-increment: item 0 }", ".foo{counter-increment:item 0}");
+    minify_test(".foo { counter-increment: item -2 }", ".foo{counter-increment:item -2}");
+    minify_test(".foo { counter-set: item 0 }", ".foo{counter-set:item}");
+    minify_test(".foo { counter-set: item 1 }", ".foo{counter-set:item 1}");
+
+    for (name, value) in [
+      ("counter-reset", "item none"),
+      ("counter-reset", "none item"),
+      ("counter-reset", "item 1.5"),
+      ("counter-reset", "reversed(item 2)"),
+      ("counter-increment", "reversed(item)"),
+      ("counter-set", "reversed(item) 2"),
+    ] {
+      assert!(matches!(
+        Property::parse_string(name.into(), value, ParserOptions::default()),
+        Ok(Property::Unparsed(..))
+      ));
+    }
+  }
+
   #[test]
   fn test_image_set() {
     // Spec: https://drafts.csswg.org/css-images-4/#image-set-notation
@@ -14625,9 +17687,222 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_color_contrast() {
+    minify_test(
+      ".foo { color: color-contrast(wheat vs tan, sienna, #d2691e) }",
+      ".foo{color:sienna}",
+    );
+    minify_test(
+      ".foo { color: color-contrast(wheat vs tan, #d2691e, sienna to 2.5) }",
+      ".foo{color:#d2691e}",
+    );
+    minify_test(
+      ".foo { color: color-contrast(wheat vs tan, sienna, navy to AA) }",
+      ".foo{color:navy}",
+    );
+    minify_test(
+      ".foo { color: color-contrast(wheat vs tan, sienna, navy to aaa-large) }",
+      ".foo{color:navy}",
+    );
+    minify_test(".foo { color: contrast-color(navy) }", ".foo{color:#fff}");
+    minify_test(".foo { color: contrast-color(wheat) }", ".foo{color:#000}");
+    minify_test(
+      ".foo { color: contrast-color(currentColor) }",
+      ".foo{color:contrast-color(currentColor)}",
+    );
+    minify_test(
+      ".foo { color: color-contrast(wheat vs tan) }",
+      ".foo{color:color-contrast(wheat vs tan)}",
+    );
+    minify_test(
+      ".foo { color: color-contrast(var(--bg) vs red, blue) }",
+      ".foo{color:color-contrast(var(--bg) vs red,blue)}",
+    );
+    minify_test(
+      ".foo { color: contrast-color(rgb(from red r g b)) }",
+      ".foo{color:contrast-color(rgb(from red r g b))}",
+    );
+    minify_test(".foo { color: contrast-color(red) }", ".foo{color:#000}");
+
+    let apca_test = |source: &str, expected: &str| {
+      let mut stylesheet = StyleSheet::parse(
+        &source,
+        ParserOptions {
+          contrast_algorithm: crate::values::color::ContrastAlgorithm::Apca,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    };
+
+    apca_test(".foo { color: contrast-color(red) }", ".foo{color:#fff}");
+    apca_test(".foo { color: contrast-color(wheat) }", ".foo{color:#000}");
+    apca_test(
+      ".foo { color: color-contrast(wheat vs tan, sienna, navy to 75) }",
+      ".foo{color:navy}",
+    );
+    apca_test(
+      ".foo { color: color-contrast(wheat vs tan, sienna, navy to AA) }",
+      ".foo{color:color-contrast(wheat vs tan,sienna,navy to AA)}",
+    );
+
+    let mut stylesheet = StyleSheet::parse(
+      ".foo { color: contrast-color(navy); background: color-contrast(wheat vs tan, sienna) }",
+      ParserOptions {
+        preserve_contrast_functions: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{color:contrast-color(navy);background:color-contrast(wheat vs tan,sienna)}"
+    );
+  }
+
+  fn contrast_test(source: &str, options: ContrastOptions, expected: Vec<(&str, &str, u32)>) {
+    let stylesheet = StyleSheet::parse(
+      &source,
+      ParserOptions {
+        nesting: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let warnings = stylesheet.check_contrast(&options);
+    let warnings: Vec<_> = warnings
+      .iter()
+      .map(|w| {
+        (
+          w.kind.foreground.as_str(),
+          w.kind.background.as_str(),
+          w.loc.as_ref().unwrap().line,
+        )
+      })
+      .collect();
+    assert_eq!(warnings, expected);
+  }
+
+  #[test]
+  fn test_contrast_lint() {
+    use crate::values::color::ContrastAlgorithm;
+
+    contrast_test(
+      ".foo { color: #777; background-color: #fff }",
+      ContrastOptions::default(),
+      vec![("#777", "#fff", 0)],
+    );
+    contrast_test(
+      ".foo { color: #767676; background-color: #fff }",
+      ContrastOptions::default(),
+      vec![],
+    );
+    contrast_test(
+      ".foo { color: #777; background: url(foo.png) #fff }",
+      ContrastOptions::default(),
+      vec![("#777", "#fff", 0)],
+    );
+    contrast_test(
+      ".foo { color: #777 !important; color: #000; background-color: #fff }",
+      ContrastOptions::default(),
+      vec![("#777", "#fff", 0)],
+    );
+    contrast_test(
+      ".foo { color: #777; background-color: #fff }",
+      ContrastOptions {
+        threshold: Some(4.4),
+        ..ContrastOptions::default()
+      },
+      vec![],
+    );
+    contrast_test(
+      ".foo { color: #777; background-color: #fff }",
+      ContrastOptions {
+        algorithm: ContrastAlgorithm::Apca,
+        ..ContrastOptions::default()
+      },
+      vec![],
+    );
+    contrast_test(
+      ".foo { color: #aaa; background-color: #fff }",
+      ContrastOptions {
+        algorithm: ContrastAlgorithm::Apca,
+        ..ContrastOptions::default()
+      },
+      vec![("#aaa", "#fff", 0)],
+    );
+    contrast_test(
+      ".foo { color: rgba(0, 0, 0, .2); background-color: #fff }",
+      ContrastOptions::default(),
+      vec![],
+    );
+    contrast_test(
+      ".foo { color: currentColor; background-color: #fff }",
+      ContrastOptions::default(),
+      vec![],
+    );
+    contrast_test(
+      indoc! {r#"
+        @media print {
+          .foo { color: #777; background-color: #fff }
+        }
+      "#},
+      ContrastOptions::default(),
+      vec![("#777", "#fff", 1)],
+    );
+
+    let nested = indoc! {r#"
+      .foo {
+        color: #000;
+        background-color: #fff;
+        & .bar {
+          color: #777;
+        }
+      }
+    "#};
+    contrast_test(nested, ContrastOptions::default(), vec![]);
+    contrast_test(
+      nested,
+      ContrastOptions {
+        inherit: true,
+        ..ContrastOptions::default()
+      },
+      vec![("#777", "#fff", 3)],
+    );
+  }
+
   #[cfg(feature = "grid")]
   #[test]
   fn test_grid() {
+    // `span` and `auto` are not valid line names.
+    for (property_id, value) in [
+      ("grid-row", "span auto"),
+      ("grid-column", "1 span"),
+      ("grid-row", "span 0"),
+      ("grid-row", "span -2"),
+      ("grid-column", "span -1 foo"),
+      ("grid-template-rows", "[auto] 1fr"),
+    ] {
+      let property = Property::parse_string(property_id.into(), value, ParserOptions::default()).unwrap();
+      assert!(matches!(property, Property::Unparsed(..)));
+    }
+
     minify_test(
       ".foo { grid-template-columns: [first nav-start]  150px [main-start] 1fr [last]; }",
       ".foo{grid-template-columns:[first nav-start]150px[main-start]1fr[last]}",
@@ -14781,12 +18056,98 @@ mod tests {
     "#,
       indoc! { r#"
       .foo {
-        grid-template-areas: "head head"
-                             "nav main"
-                             "foot .";
+        grid-template-areas: "head head"
+                             "nav main"
+                             "foot .";
+      }
+    "#},
+    );
+
+    let res = StyleSheet::parse(
+      r#"
+      .foo {
+        grid-template-areas: "head head . ."
+                             "nav  main main main"
+                             "nav  foot foot foot";
+      }
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap()
+    .to_css(PrinterOptions {
+      align_grid_areas: true,
+      ..PrinterOptions::default()
+    })
+    .unwrap();
+    assert_eq!(
+      res.code,
+      indoc! { r#"
+      .foo {
+        grid-template-areas: "head head .... ...."
+                             "nav  main main main"
+                             "nav  foot foot foot";
       }
-    "#},
+    "#}
+    );
+
+    use crate::properties::grid::GridTemplateAreas;
+    use cssparser::ParseErrorKind;
+
+    assert_eq!(
+      <GridTemplateAreas as Parse>::parse_string(r#""a a" "b a""#).unwrap_err().kind,
+      ParseErrorKind::Custom(ParserError::InvalidGridArea("a".into()))
     );
+    assert_eq!(
+      <GridTemplateAreas as Parse>::parse_string(r#""a b" "c . a""#).unwrap_err().kind,
+      ParseErrorKind::Custom(ParserError::InvalidGridTemplateAreasRow)
+    );
+    assert!(<GridTemplateAreas as Parse>::parse_string("").is_err());
+    assert!(<GridTemplateAreas as Parse>::parse_string("a").is_err());
+    assert_eq!(
+      <GridTemplateAreas as Parse>::parse_string(r#""a a b" "a a c""#),
+      Ok(GridTemplateAreas::Areas {
+        columns: 3,
+        areas: vec![
+          Some("a".into()),
+          Some("a".into()),
+          Some("b".into()),
+          Some("a".into()),
+          Some("a".into()),
+          Some("c".into())
+        ]
+      })
+    );
+
+    // Invalid areas are preserved as unparsed values, with a warning.
+    use std::sync::{Arc, RwLock};
+    let warnings = Some(Arc::new(RwLock::new(Vec::new())));
+    let property = Property::parse_string(
+      "grid-template-areas".into(),
+      r#""a b" "b b""#,
+      ParserOptions {
+        warnings: warnings.clone(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+    assert_eq!(
+      warnings
+        .unwrap()
+        .read()
+        .unwrap()
+        .iter()
+        .map(|w| w.kind.clone())
+        .collect::<Vec<_>>(),
+      vec![ParserError::InvalidGridArea("b".into())]
+    );
+    let property = Property::parse_string(
+      "grid-template".into(),
+      r#""a b" "b b" / 1fr 1fr"#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
 
     minify_test(
       r#"
@@ -14957,6 +18318,8 @@ mod tests {
     minify_test(".foo { grid-row: 1 / 1 }", ".foo{grid-row:1/1}");
     minify_test(".foo { grid-row: 1 / 3 }", ".foo{grid-row:1/3}");
     minify_test(".foo { grid-row: 1 / span 2 }", ".foo{grid-row:1/span 2}");
+    minify_test(".foo { grid-row: -1 / span foo 2 }", ".foo{grid-row:-1/span 2 foo}");
+    minify_test(".foo { grid-row: span 1 foo / auto }", ".foo{grid-row:span foo}");
     minify_test(".foo { grid-row: main-start }", ".foo{grid-row:main-start}");
     minify_test(
       ".foo { grid-row: main-start / main-end }",
@@ -15450,6 +18813,23 @@ mod tests {
     minify_test(".foo { --test:foo; }", ".foo{--test:foo}");
     minify_test(".foo { --test:foo ; }", ".foo{--test:foo}");
     minify_test(".foo { --test: var(--foo, 20px); }", ".foo{--test:var(--foo,20px)}");
+    minify_test(".foo { --test: VAR(--foo, 20px); }", ".foo{--test:var(--foo,20px)}");
+    minify_test(".foo { --test: var(--foo, ); }", ".foo{--test:var(--foo,)}");
+    minify_test(".foo { --test: var(--foo,/* comment */); }", ".foo{--test:var(--foo,)}");
+    minify_test(".foo { --test: [ a ] calc( 1px ); }", ".foo{--test:[ a ] calc( 1px )}");
+    minify_test(".foo { width: foo( 1px ); }", ".foo{width:foo(1px)}");
+    minify_test(
+      ".foo { width: var( --foo , var(--bar, /* comment */ calc( 1px  +  2px ) ) ); }",
+      ".foo{width:var(--foo,var(--bar,calc(1px + 2px)))}",
+    );
+    minify_test(
+      ".foo { margin: var(--foo, 1px   2px); margin-left: 3px }",
+      ".foo{margin:var(--foo,1px 2px);margin-left:3px}",
+    );
+    minify_test(
+      ".foo { margin-top: 1px; margin-right: var(--foo, 2px); margin-bottom: 3px; margin-left: 4px }",
+      ".foo{margin-top:1px;margin-right:var(--foo,2px);margin-bottom:3px;margin-left:4px}",
+    );
     minify_test(
       ".foo { transition: var(--foo, 20px),\nvar(--bar, 40px); }",
       ".foo{transition:var(--foo,20px),var(--bar,40px)}",
@@ -15939,80 +19319,573 @@ mod tests {
       },
     );
 
-    prefix_test(
+    prefix_test(
+      r#"
+      @keyframes foo {
+        from {
+          --custom: #ff0;
+          opacity: 0;
+        }
+
+        to {
+          --custom: lch(50.998% 135.363 338);
+          opacity: 1;
+        }
+      }
+    "#,
+      indoc! {r#"
+      @keyframes foo {
+        from {
+          --custom: #ff0;
+          opacity: 0;
+        }
+
+        to {
+          --custom: #ee00be;
+          opacity: 1;
+        }
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        @keyframes foo {
+          from {
+            --custom: #ff0;
+            opacity: 0;
+          }
+  
+          to {
+            --custom: lab(50.998% 125.506 -50.7078);
+            opacity: 1;
+          }
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      r#"
+      @keyframes foo {
+        from {
+          text-decoration: var(--foo) lab(29.2345% 39.3825 20.0664);
+        }
+      }
+    "#,
+      indoc! {r#"
+      @keyframes foo {
+        from {
+          text-decoration: var(--foo) #7d2329;
+        }
+      }
+
+      @supports (color: lab(0% 0 0)) {
+        @keyframes foo {
+          from {
+            text-decoration: var(--foo) lab(29.2345% 39.3825 20.0664);
+          }
+        }
+      }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+  }
+
+  #[test]
+  fn test_attr() {
+    use crate::properties::custom::TokenOrValue;
+
+    minify_test(".foo { content: attr(data-foo) }", ".foo{content:attr(data-foo)}");
+    minify_test(
+      ".foo { content: attr(data-foo raw-string, \"default\") }",
+      ".foo{content:attr(data-foo raw-string,\"default\")}",
+    );
+    minify_test(
+      ".foo { content: attr(data-foo string) }",
+      ".foo{content:attr(data-foo string)}",
+    );
+    minify_test(".foo { content: attr(xlink|href) }", ".foo{content:attr(xlink|href)}");
+    minify_test(
+      ".foo { width: attr(data-width px, 10px) }",
+      ".foo{width:attr(data-width px,10px)}",
+    );
+    minify_test(".foo { width: attr(data-width %) }", ".foo{width:attr(data-width %)}");
+    minify_test(
+      ".foo { color: attr(data-color type(<color>), #ff0000) }",
+      ".foo{color:attr(data-color type(<color>),red)}",
+    );
+    minify_test(
+      ".foo { color: attr(data-color type(<color> | <length>+), rgb(0, 0, 255)) }",
+      ".foo{color:attr(data-color type(<color>|<length>+),#00f)}",
+    );
+    minify_test(
+      ".foo { width: calc(attr(data-width px) * 2) }",
+      ".foo{width:calc(attr(data-width px)*2)}",
+    );
+    minify_test(
+      ".foo { background: attr(data-bg type(<color>), var(--bg, #ff0000)) }",
+      ".foo{background:attr(data-bg type(<color>),var(--bg,red))}",
+    );
+    minify_test(".foo { content: attr() }", ".foo{content:attr()}");
+    minify_test(
+      ".foo { width: attr(data-width bogus) }",
+      ".foo{width:attr(data-width bogus)}",
+    );
+
+    let is_attr = |value: &'static str| {
+      let property = Property::parse_string("width".into(), value, ParserOptions::default());
+      matches!(
+        property,
+        Ok(Property::Unparsed(unparsed)) if matches!(unparsed.value.0.first(), Some(TokenOrValue::Attr(..)))
+      )
+    };
+    assert!(is_attr("attr(data-width px)"));
+    assert!(is_attr("attr(data-width Q)"));
+    assert!(is_attr("attr(data-width deg)"));
+    assert!(is_attr("attr(data-width %)"));
+    assert!(!is_attr("attr(data-width bogus)"));
+    test(
+      ".foo { color: attr(data-color type(<color> | <length>), #ff0000) }",
+      indoc! {r#"
+      .foo {
+        color: attr(data-color type(<color> | <length>), red);
+      }
+    "#},
+    );
+  }
+
+  #[test]
+  fn test_css_wide_keywords() {
+    use crate::properties::custom::{CSSWideKeyword, CSSWideKeywordProperty};
+    use crate::properties::PropertyId;
+
+    for (keyword, expected) in [
+      ("initial", CSSWideKeyword::Initial),
+      ("inherit", CSSWideKeyword::Inherit),
+      ("unset", CSSWideKeyword::Unset),
+      ("revert", CSSWideKeyword::Revert),
+      ("REVERT-LAYER", CSSWideKeyword::RevertLayer),
+    ] {
+      let property = Property::parse_string("margin".into(), keyword, ParserOptions::default()).unwrap();
+      assert_eq!(
+        property,
+        Property::CSSWideKeyword(CSSWideKeywordProperty {
+          property_id: PropertyId::Margin,
+          keyword: expected,
+        })
+      );
+    }
+
+    // Custom properties keep their value as tokens.
+    let property = Property::parse_string("--foo".into(), "inherit", ParserOptions::default()).unwrap();
+    assert!(matches!(property, Property::Custom(..)));
+    let property = Property::parse_string("color".into(), "inherit red", ParserOptions::default()).unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+
+    minify_test(".foo { color: INHERIT }", ".foo{color:inherit}");
+    minify_test(".foo { all: revert-layer }", ".foo{all:revert-layer}");
+    minify_test(
+      ".foo { margin: 1px; color: red; all: unset; margin-left: 2px }",
+      ".foo{all:unset;margin-left:2px}",
+    );
+    minify_test(
+      ".foo { --x: 1px; color: red; all: initial; color: green }",
+      ".foo{--x:1px;all:initial;color:green}",
+    );
+    minify_test(
+      ".foo { color: red !important; all: inherit }",
+      ".foo{all:inherit;color:red!important}",
+    );
+    prefix_test(
+      ".foo { margin-inline-start: 2px; all: initial }",
+      indoc! { r#"
+        .foo {
+          all: initial;
+        }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+    minify_test(".foo { -webkit-transition: unset }", ".foo{-webkit-transition:unset}");
+    minify_test(
+      ".foo { margin: inherit; margin-left: 1px }",
+      ".foo{margin:inherit;margin-left:1px}",
+    );
+
+    // Handlers treat keywords as resets, and preserve them after minification.
+    let mut stylesheet =
+      StyleSheet::parse(".foo { margin: initial; margin-left: 1px }", ParserOptions::default()).unwrap();
+    stylesheet.minify(MinifyOptions::default()).unwrap();
+    match &stylesheet.rules.0[0] {
+      CssRule::Style(style) => assert_eq!(
+        style.declarations.declarations[0],
+        Property::CSSWideKeyword(CSSWideKeywordProperty {
+          property_id: PropertyId::Margin,
+          keyword: CSSWideKeyword::Initial,
+        })
+      ),
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_declaration_importance() {
+    use crate::declaration::{Declaration, DeclarationBlock};
+    use crate::properties::PropertyId;
+
+    let mut block = DeclarationBlock::parse_string(
+      "color: red !important; width: 10px; color: green; height: 5px !important",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(block.is_important(&PropertyId::Color), Some(true));
+    assert_eq!(block.is_important(&PropertyId::Width), Some(false));
+    assert_eq!(block.is_important(&PropertyId::Margin), None);
+
+    // The `!important` declaration already applies, so the normal one is dropped.
+    assert!(block.set_important(&PropertyId::Color, true));
+    assert_eq!(
+      block.to_css_string(PrinterOptions::default()).unwrap(),
+      "width: 10px; color: red !important; height: 5px !important"
+    );
+
+    assert!(block.set_important(&PropertyId::Width, true));
+    assert!(block.set_important(&PropertyId::Color, false));
+    assert!(!block.set_important(&PropertyId::Margin, true));
+    assert_eq!(
+      block.to_css_string(PrinterOptions::default()).unwrap(),
+      "color: red; height: 5px !important; width: 10px !important"
+    );
+
+    let declarations = block.clone().into_iter().collect::<Vec<_>>();
+    assert_eq!(declarations.len(), 3);
+    assert_eq!(
+      declarations[0],
+      Declaration {
+        property: Property::parse_string("color".into(), "red", ParserOptions::default()).unwrap(),
+        important: false,
+      }
+    );
+    assert!(declarations[1].important && declarations[2].important);
+    assert_eq!(declarations.into_iter().collect::<DeclarationBlock>(), block);
+
+    // Longhands are resolved from shorthands, which are split apart when the importance changes.
+    let mut block =
+      DeclarationBlock::parse_string("margin: 1px 2px !important; padding-top: 3px", ParserOptions::default())
+        .unwrap();
+    assert_eq!(block.is_important(&PropertyId::MarginTop), Some(true));
+    assert!(block.set_important(&PropertyId::MarginTop, false));
+    assert_eq!(block.is_important(&PropertyId::MarginTop), Some(false));
+    assert_eq!(block.is_important(&PropertyId::MarginLeft), Some(true));
+    assert_eq!(
+      block.to_css_string(PrinterOptions::default()).unwrap(),
+      "padding-top: 3px; margin-top: 1px; margin-right: 2px !important; margin-bottom: 1px !important; margin-left: 2px !important"
+    );
+  }
+
+  #[test]
+  fn test_dimension_arithmetic() {
+    use crate::traits::TryOp;
+    use crate::values::angle::Angle;
+    use crate::values::length::{Length, LengthValue};
+    use crate::values::percentage::Percentage;
+
+    assert_eq!(Angle::Deg(90.0) + Angle::Deg(45.0), Angle::Deg(135.0));
+    assert_eq!(Angle::Turn(0.5) + Angle::Deg(90.0), Angle::Deg(270.0));
+    assert_eq!(Angle::Deg(90.0) - Angle::Deg(45.0), Angle::Deg(45.0));
+    assert_eq!(Angle::Rad(2.0) * 1.5, Angle::Rad(3.0));
+    assert_eq!(Angle::Deg(90.0) / 2.0, Angle::Deg(45.0));
+    assert_eq!(Angle::Deg(180.0).to_turns(), 0.5);
+    assert_eq!(Angle::Turn(0.25).to_turns(), 0.25);
+    assert_eq!(Angle::Deg(90.0).to_gradians(), 100.0);
+    assert_eq!(Angle::Grad(50.0).to_gradians(), 50.0);
+
+    assert_eq!(Percentage(0.5) + Percentage(0.25), Percentage(0.75));
+    assert_eq!(Percentage(0.5) - Percentage(0.25), Percentage(0.25));
+    assert_eq!(Percentage(0.5) * 2.0, Percentage(1.0));
+    assert_eq!(Percentage(0.5) / 2.0, Percentage(0.25));
+
+    assert_eq!(LengthValue::Px(10.0) * 1.25, LengthValue::Px(12.5));
+    assert_eq!(LengthValue::Em(3.0) / 2.0, LengthValue::Em(1.5));
+    assert_eq!(
+      LengthValue::Px(10.0).try_op(&LengthValue::In(1.0), |a, b| a + b),
+      Some(LengthValue::Px(106.0))
+    );
+    assert_eq!(LengthValue::Px(10.0).try_op(&LengthValue::Em(1.0), |a, b| a + b), None);
+
+    assert_eq!(Length::px(10.0) + Length::px(5.0), Length::px(15.0));
+    assert_eq!(Length::px(10.0) - Length::px(5.0), Length::px(5.0));
+    assert_eq!(Length::px(10.0) / 4.0, Length::px(2.5));
+    assert_eq!(
+      (Length::px(10.0) + Length::Value(LengthValue::Em(2.0)))
+        .to_css_string(PrinterOptions::default())
+        .unwrap(),
+      "calc(10px + 2em)"
+    );
+    assert_eq!(
+      (Length::px(10.0) - Length::Value(LengthValue::Em(2.0)))
+        .to_css_string(PrinterOptions::default())
+        .unwrap(),
+      "calc(10px - 2em)"
+    );
+    assert_eq!(
+      (Length::Value(LengthValue::Em(2.0)) * 1.25)
+        .to_css_string(PrinterOptions::default())
+        .unwrap(),
+      "2.5em"
+    );
+  }
+
+  #[test]
+  fn test_inline_variables() {
+    use crate::variables::InlineVariablesOptions;
+
+    fn inline_test<'i>(source: &'i str, expected: &str, options: InlineVariablesOptions<'i>) {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet.inline_variables(&options).unwrap();
+      stylesheet.minify(MinifyOptions::default()).unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    let root = InlineVariablesOptions {
+      root: true,
+      ..InlineVariablesOptions::default()
+    };
+
+    inline_test(
+      r#"
+      :root {
+        --color: #ff0000;
+        --size: 10px;
+      }
+
+      .foo {
+        color: var(--color);
+        width: calc(var(--size) * 2);
+      }
+
+      .bar {
+        border: var(--size) solid var(--color);
+      }
+    "#,
+      ".foo{color:red;width:calc(10px*2)}.bar{border:10px solid red}",
+      root.clone(),
+    );
+
+    // CSS-wide keywords apply to the custom property itself, so they are not inlined.
+    inline_test(
+      r#"
+      :root {
+        --a: initial;
+        --b: red;
+      }
+
+      .foo {
+        color: var(--a, var(--b));
+      }
+    "#,
+      ":root{--a:initial}.foo{color:var(--a,red)}",
+      root.clone(),
+    );
+
+    // Custom properties that are declared outside of `:root` are not static.
+    inline_test(
+      r#"
+      :root {
+        --a: red;
+        --b: blue;
+      }
+
+      .dark {
+        --b: green;
+      }
+
+      @media print {
+        :root {
+          --a: black;
+        }
+      }
+
+      .foo {
+        color: var(--a);
+        background: var(--b);
+      }
+    "#,
+      ":root{--b:blue}.dark{--b:green}@media print{:root{--a:black}}.foo{color:var(--a);background:var(--b)}",
+      root.clone(),
+    );
+
+    // Cyclic references and attr() are not inlined.
+    inline_test(
+      r#"
+      :root {
+        --a: var(--b);
+        --b: var(--a);
+        --c: attr(data-x);
+      }
+
+      .foo {
+        width: var(--a);
+        content: var(--c);
+      }
+    "#,
+      ":root{--a:var(--b);--b:var(--a);--c:attr(data-x)}.foo{width:var(--a);content:var(--c)}",
+      root.clone(),
+    );
+
+    // Provided values take precedence, and references between variables are resolved.
+    let mut values = HashMap::new();
+    values.insert("--spacing".into(), "4px");
+    inline_test(
       r#"
-      @keyframes foo {
-        from {
-          --custom: #ff0;
-          opacity: 0;
-        }
+      :root {
+        --spacing: 2px;
+        --gap: calc(var(--spacing) * 2);
+      }
 
-        to {
-          --custom: lch(50.998% 135.363 338);
-          opacity: 1;
-        }
+      .foo {
+        margin: var(--gap);
+        padding: var(--missing, var(--spacing));
       }
     "#,
-      indoc! {r#"
-      @keyframes foo {
-        from {
-          --custom: #ff0;
-          opacity: 0;
-        }
+      ".foo{margin:calc(4px*2);padding:var(--missing,4px)}",
+      InlineVariablesOptions {
+        values: values.clone(),
+        root: true,
+      },
+    );
 
-        to {
-          --custom: #ee00be;
-          opacity: 1;
-        }
+    // Without `root`, only the provided values are inlined.
+    inline_test(
+      r#"
+      :root {
+        --spacing: 2px;
+        --color: red;
       }
 
-      @supports (color: lab(0% 0 0)) {
-        @keyframes foo {
-          from {
-            --custom: #ff0;
-            opacity: 0;
-          }
-  
-          to {
-            --custom: lab(50.998% 125.506 -50.7078);
-            opacity: 1;
-          }
-        }
+      .foo {
+        margin: var(--spacing);
+        color: var(--color);
+      }
+    "#,
+      ":root{--color:red}.foo{margin:4px;color:var(--color)}",
+      InlineVariablesOptions { values, root: false },
+    );
+  }
+
+  #[test]
+  fn test_env() {
+    minify_test(
+      ".foo { padding-top: env(safe-area-inset-top) }",
+      ".foo{padding-top:env(safe-area-inset-top)}",
+    );
+    minify_test(
+      ".foo { padding-top: env(safe-area-inset-top, 0px) }",
+      ".foo{padding-top:env(safe-area-inset-top,0px)}",
+    );
+    minify_test(
+      ".foo { padding-top: env(SAFE-AREA-INSET-TOP, 0px) }",
+      ".foo{padding-top:env(safe-area-inset-top,0px)}",
+    );
+    minify_test(
+      ".foo { padding: env(safe-area-inset-top, 20px) env(safe-area-inset-right, 20px) }",
+      ".foo{padding:env(safe-area-inset-top,20px)env(safe-area-inset-right,20px)}",
+    );
+    minify_test(
+      ".foo { width: env(viewport-segment-width 0 1, 100vw) }",
+      ".foo{width:env(viewport-segment-width 0 1,100vw)}",
+    );
+    minify_test(
+      ".foo { color: env(--brand-color, #ff0000) }",
+      ".foo{color:env(--brand-color,red)}",
+    );
+    minify_test(".foo { color: env(unknown, red) }", ".foo{color:env(unknown,red)}");
+    minify_test(
+      ".foo { padding-top: calc(env(safe-area-inset-top, 0px) + 10px) }",
+      ".foo{padding-top:calc(env(safe-area-inset-top,0px) + 10px)}",
+    );
+    minify_test(
+      ".foo { padding-top: env(safe-area-inset-top, var(--inset, 0px)) }",
+      ".foo{padding-top:env(safe-area-inset-top,var(--inset,0px))}",
+    );
+    test(
+      ".foo { padding: env(safe-area-inset-top, 20px) env(safe-area-inset-right, 20px) }",
+      indoc! {r#"
+      .foo {
+        padding: env(safe-area-inset-top, 20px) env(safe-area-inset-right, 20px);
       }
     "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
     );
 
-    prefix_test(
+    let mut env = HashMap::new();
+    env.insert("safe-area-inset-top".to_string(), "10px".to_string());
+    env.insert("--brand-color".to_string(), "#00f".to_string());
+    env.insert("viewport-segment-width".to_string(), "50vw".to_string());
+    let stylesheet = StyleSheet::parse(
       r#"
-      @keyframes foo {
-        from {
-          text-decoration: var(--foo) lab(29.2345% 39.3825 20.0664);
-        }
+      .foo {
+        padding: env(safe-area-inset-top, 0px) env(safe-area-inset-right, 0px);
+        color: env(--brand-color);
+        width: env(viewport-segment-width 0 0, 100vw);
       }
     "#,
-      indoc! {r#"
-      @keyframes foo {
-        from {
-          text-decoration: var(--foo) #7d2329;
-        }
-      }
+      ParserOptions::default(),
+    )
+    .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        env: Some(&env),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{padding:10px env(safe-area-inset-right,0px);color:#00f;width:env(viewport-segment-width 0 0,100vw)}"
+    );
 
-      @supports (color: lab(0% 0 0)) {
-        @keyframes foo {
-          from {
-            text-decoration: var(--foo) lab(29.2345% 39.3825 20.0664);
-          }
-        }
+    let mut env = HashMap::new();
+    env.insert("--semicolon".to_string(), "1px; color: red".to_string());
+    env.insert("--paren".to_string(), "1px) 2px".to_string());
+    env.insert("--important".to_string(), "1px !important".to_string());
+    env.insert("--empty".to_string(), "  ".to_string());
+    env.insert("--color".to_string(), " rgb(255, 0, 0) ".to_string());
+    let stylesheet = StyleSheet::parse(
+      r#"
+      .foo {
+        width: env(--semicolon, 1px);
+        height: env(--paren, 2px);
+        margin: env(--important, 3px);
+        padding: env(--empty, 4px);
+        color: env(--color);
       }
-    "#},
-      Browsers {
-        chrome: Some(90 << 16),
-        ..Browsers::default()
-      },
+    "#,
+      ParserOptions::default(),
+    )
+    .unwrap();
+    let res = stylesheet
+      .to_css(PrinterOptions {
+        minify: true,
+        env: Some(&env),
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(
+      res.code,
+      ".foo{width:env(--semicolon,1px);height:env(--paren,2px);margin:env(--important,3px);padding:env(--empty,4px);color:red}"
     );
   }
 
@@ -16084,6 +19957,27 @@ mod tests {
         ..Browsers::default()
       }),
     );
+
+    let targets = Some(Browsers {
+      safari: Some(8 << 16),
+      ..Browsers::default()
+    });
+    let mut attr =
+      StyleAttribute::parse("margin-inline-start: 2px; block-size: 10px", ParserOptions::default()).unwrap();
+    attr.minify(MinifyOptions {
+      targets,
+      writing_mode: crate::properties::size::WritingMode::VerticalRl,
+      direction: Some(crate::properties::text::TextDirection::Rtl),
+      ..MinifyOptions::default()
+    });
+    let res = attr
+      .to_css(PrinterOptions {
+        targets,
+        minify: true,
+        ..PrinterOptions::default()
+      })
+      .unwrap();
+    assert_eq!(res.code, "width:10px;margin-right:2px");
   }
 
   #[test]
@@ -17709,6 +21603,121 @@ mod tests {
     assert_eq!(res.code, expected);
   }
 
+  #[test]
+  fn test_remove_unused_rules() {
+    fn unused_rules_test(source: &str, expected: &str, font_faces: bool, used_symbols: Vec<&str>) {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          remove_unused_rules: true,
+          remove_unused_font_faces: font_faces,
+          used_symbols: used_symbols.iter().map(|s| String::from(*s)).collect(),
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    unused_rules_test(
+      r#"
+      .foo { animation: 1s fade }
+      @keyframes fade { to { opacity: 1 } }
+      @keyframes unused { to { opacity: 1 } }
+    "#,
+      ".foo{animation:fade 1s}@keyframes fade{to{opacity:1}}",
+      false,
+      vec![],
+    );
+    unused_rules_test(
+      r#"
+      @keyframes fade { to { opacity: 1 } }
+      @keyframes slide { to { opacity: 1 } }
+      @media (min-width: 100px) {
+        .foo { animation-name: slide }
+      }
+    "#,
+      "@keyframes slide{to{opacity:1}}@media (min-width:100px){.foo{animation-name:slide}}",
+      false,
+      vec![],
+    );
+    unused_rules_test(
+      r#"
+      @keyframes fade { to { opacity: 1 } }
+      @keyframes slide { to { opacity: 1 } }
+      .foo { --animation: fade; animation: var(--animation) 1s }
+    "#,
+      "@keyframes fade{to{opacity:1}}.foo{--animation:fade;animation:var(--animation) 1s}",
+      false,
+      vec![],
+    );
+    unused_rules_test(
+      r#"
+      @keyframes fade { to { opacity: 1 } }
+      @keyframes slide { to { opacity: 1 } }
+    "#,
+      "@keyframes slide{to{opacity:1}}",
+      false,
+      vec!["slide"],
+    );
+    unused_rules_test(
+      r#"
+      @property --used { syntax: "<color>"; inherits: false; initial-value: red }
+      @property --declared { syntax: "<color>"; inherits: false; initial-value: red }
+      @property --transitioned { syntax: "<length>"; inherits: false; initial-value: 0px }
+      @property --unused { syntax: "<color>"; inherits: false; initial-value: red }
+      .foo { color: var(--used); transition: --transitioned 1s }
+      .bar { --declared: blue }
+    "#,
+      "@property --used{syntax:\"<color>\";inherits:false;initial-value:red}@property --declared{syntax:\"<color>\";inherits:false;initial-value:red}@property --transitioned{syntax:\"<length>\";inherits:false;initial-value:0}.foo{color:var(--used);transition:--transitioned 1s}.bar{--declared:blue}",
+      false,
+      vec![],
+    );
+    unused_rules_test(
+      r#"
+      @property --from-js { syntax: "*"; inherits: false }
+    "#,
+      "@property --from-js{syntax:\"*\";inherits:false}",
+      false,
+      vec!["--from-js"],
+    );
+    unused_rules_test(
+      r#"
+      @font-face { font-family: Foo; src: url(foo.woff) }
+      @font-face { font-family: "Open Sans"; src: url(open-sans.woff) }
+      @font-face { font-family: Unused; src: url(unused.woff) }
+      .foo { font-family: foo, serif }
+      .bar { font: 12px Open Sans }
+    "#,
+      "@font-face{font-family:Foo;src:url(foo.woff)}@font-face{font-family:Open Sans;src:url(open-sans.woff)}.foo{font-family:foo,serif}.bar{font:12px Open Sans}",
+      true,
+      vec![],
+    );
+    unused_rules_test(
+      r#"
+      @font-face { font-family: Foo; src: url(foo.woff) }
+      @font-face { font-family: Bar; src: url(bar.woff) }
+    "#,
+      "@font-face{font-family:Foo;src:url(foo.woff)}@font-face{font-family:Bar;src:url(bar.woff)}",
+      false,
+      vec![],
+    );
+    unused_rules_test(
+      r#"
+      @font-face { font-family: Foo; src: url(foo.woff) }
+      @font-face { font-family: Bar; src: url(bar.woff) }
+    "#,
+      "@font-face{font-family:Bar;src:url(bar.woff)}",
+      true,
+      vec!["bar"],
+    );
+  }
+
   #[test]
   fn test_svg() {
     minify_test(".foo { fill: yellow; }", ".foo{fill:#ff0}");
@@ -17737,6 +21746,138 @@ mod tests {
       ".foo{stroke-dasharray:4 1 2}",
     );
 
+    minify_test(".foo { image-rendering: pixelated }", ".foo{image-rendering:pixelated}");
+    minify_test(
+      ".foo { image-rendering: CRISP-EDGES }",
+      ".foo{image-rendering:crisp-edges}",
+    );
+    minify_test(
+      ".foo { image-rendering: optimizespeed }",
+      ".foo{image-rendering:optimizeSpeed}",
+    );
+    minify_test(
+      ".foo { image-rendering: -webkit-optimize-contrast }",
+      ".foo{image-rendering:-webkit-optimize-contrast}",
+    );
+    minify_test(
+      ".foo { image-rendering: -moz-crisp-edges }",
+      ".foo{image-rendering:-moz-crisp-edges}",
+    );
+    prefix_test(
+      ".foo { image-rendering: pixelated }",
+      indoc! { r#"
+        .foo {
+          image-rendering: -webkit-optimize-contrast;
+          image-rendering: pixelated;
+        }
+      "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { image-rendering: pixelated }",
+      indoc! { r#"
+        .foo {
+          image-rendering: -webkit-optimize-contrast;
+          image-rendering: -moz-crisp-edges;
+          image-rendering: pixelated;
+        }
+      "#},
+      Browsers {
+        chrome: Some(40 << 16),
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { image-rendering: pixelated }",
+      indoc! { r#"
+        .foo {
+          image-rendering: pixelated;
+        }
+      "#},
+      Browsers {
+        chrome: Some(41 << 16),
+        firefox: Some(93 << 16),
+        safari: Some(10 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { image-rendering: crisp-edges }",
+      indoc! { r#"
+        .foo {
+          image-rendering: -moz-crisp-edges;
+          image-rendering: crisp-edges;
+        }
+      "#},
+      Browsers {
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { image-rendering: -moz-crisp-edges }",
+      indoc! { r#"
+        .foo {
+          image-rendering: crisp-edges;
+        }
+      "#},
+      Browsers {
+        firefox: Some(65 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { image-rendering: -webkit-optimize-contrast }",
+      indoc! { r#"
+        .foo {
+          image-rendering: -webkit-optimize-contrast;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { image-rendering: pixelated }",
+      indoc! { r#"
+        .foo {
+          image-rendering: -webkit-optimize-contrast;
+          image-rendering: pixelated;
+        }
+      "#},
+      Browsers {
+        chrome: Some(40 << 16),
+        firefox: Some(93 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      r#"
+      .foo {
+        image-rendering: -webkit-optimize-contrast;
+        image-rendering: -moz-crisp-edges;
+        image-rendering: crisp-edges;
+      }
+    "#,
+      indoc! { r#"
+        .foo {
+          image-rendering: -webkit-optimize-contrast;
+          image-rendering: -moz-crisp-edges;
+          image-rendering: crisp-edges;
+        }
+      "#},
+      Browsers {
+        chrome: Some(100 << 16),
+        firefox: Some(60 << 16),
+        ..Browsers::default()
+      },
+    );
+
     minify_test(".foo { mask: url('foo.svg'); }", ".foo{mask:url(foo.svg)}");
     minify_test(
       ".foo { mask: url(masks.svg#star) luminance }",
@@ -17929,6 +22070,152 @@ mod tests {
       },
     );
 
+    prefix_test(
+      ".foo { clip-path: var(--clip); }",
+      indoc! { r#"
+        .foo {
+          -webkit-clip-path: var(--clip);
+          clip-path: var(--clip);
+        }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { clip-path: circle(50px); clip-path: var(--clip); clip-path: inset(10px); }",
+      indoc! { r#"
+        .foo {
+          -webkit-clip-path: circle(50px);
+          clip-path: circle(50px);
+          -webkit-clip-path: var(--clip);
+          clip-path: var(--clip);
+          -webkit-clip-path: inset(10px);
+          clip-path: inset(10px);
+        }
+      "#},
+      Browsers {
+        safari: Some(8 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(
+      ".foo { clip-path: path('M 0 0 L 100 0 L 50 100 Z'); }",
+      ".foo{clip-path:path(\"M 0 0 L 100 0 L 50 100 Z\")}",
+    );
+    minify_test(
+      ".foo { clip-path: path(nonzero, 'M 0 0 L 100 0 L 50 100 Z'); }",
+      ".foo{clip-path:path(\"M 0 0 L 100 0 L 50 100 Z\")}",
+    );
+    minify_test(
+      ".foo { clip-path: path(evenodd, 'M 0 0 L 100 0 L 50 100 Z') padding-box; }",
+      ".foo{clip-path:path(evenodd,\"M 0 0 L 100 0 L 50 100 Z\") padding-box}",
+    );
+
+    minify_test(
+      ".foo { clip-path: xywh(0 0 100% 50%); }",
+      ".foo{clip-path:inset(0 0 50%)}",
+    );
+    minify_test(
+      ".foo { clip-path: xywh(10px 20px 50px 50px); }",
+      ".foo{clip-path:xywh(10px 20px 50px 50px)}",
+    );
+    minify_test(
+      ".foo { clip-path: xywh(0 0 -10px 10px); }",
+      ".foo{clip-path:xywh(0 0 -10px 10px)}",
+    );
+    minify_test(
+      ".foo { clip-path: rect(25% 75% 75% 25%); }",
+      ".foo{clip-path:inset(25%)}",
+    );
+    minify_test(
+      ".foo { clip-path: rect(auto auto 50% auto round 5px); }",
+      ".foo{clip-path:inset(0 0 50% round 5px)}",
+    );
+    minify_test(
+      ".foo { clip-path: rect(10px 20px 30px 40px round 5px) border-box; }",
+      ".foo{clip-path:rect(10px 20px 30px 40px round 5px) border-box}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(nonzero from 0px 0px, line to 100% 0, hline by -10.50px, vline to 100%, curve to 50% 100% with 25% 75% / 0 50%, smooth by 10px 10px, arc to 0 0 of 10px 10px ccw small rotate 0deg, close); }",
+      ".foo{clip-path:shape(from 0 0,line to 100% 0,hline by -10.5px,vline to 100%,curve to 50% 100% with 25% 75%/0 50%,smooth by 10px 10px,arc to 0 0 of 10px,close)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(evenodd from 10px 10px, arc by 5px 5px large of 5px 10px cw rotate 45deg) border-box; }",
+      ".foo{clip-path:shape(evenodd from 10px 10px,arc by 5px 5px of 5px 10px cw large rotate 45deg) border-box}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0); }",
+      ".foo{clip-path:shape(from 0 0)}",
+    );
+    minify_test(
+      ".foo { clip-path: shape(from 0 0, arc to 10px 10px cw); }",
+      ".foo{clip-path:shape(from 0 0,arc to 10px 10px cw)}",
+    );
+    test(
+      ".foo { clip-path: shape(from 0 0, line by 10px 0, curve to 0 0 with 5px 5px/5px 10px, close); }",
+      indoc! {r#"
+        .foo {
+          clip-path: shape(from 0 0, line by 10px 0, curve to 0 0 with 5px 5px / 5px 10px, close);
+        }
+      "#},
+    );
+    test(
+      ".foo { clip-path: xywh(0 0 100% 50%); }",
+      indoc! {r#"
+        .foo {
+          clip-path: xywh(0 0 100% 50%);
+        }
+      "#},
+    );
+
+    minify_test(".foo { shape-outside: none; }", ".foo{shape-outside:none}");
+    minify_test(".foo { shape-outside: margin-box; }", ".foo{shape-outside:margin-box}");
+    minify_test(
+      ".foo { shape-outside: circle(50% at 50% 50%) margin-box; }",
+      ".foo{shape-outside:circle(50%)}",
+    );
+    minify_test(
+      ".foo { shape-outside: content-box polygon(nonzero, 0 0, 100% 0, 0 100%); }",
+      ".foo{shape-outside:polygon(0 0,100% 0,0 100%) content-box}",
+    );
+    minify_test(
+      ".foo { shape-outside: url('shape.png'); }",
+      ".foo{shape-outside:url(shape.png)}",
+    );
+    minify_test(
+      ".foo { shape-outside: linear-gradient(45deg, white 150px, red 150px); }",
+      ".foo{shape-outside:linear-gradient(45deg,#fff 150px,red 150px)}",
+    );
+
+    prefix_test(
+      ".foo { shape-outside: circle(50%); }",
+      indoc! { r#"
+        .foo {
+          -webkit-shape-outside: circle(50%);
+          shape-outside: circle(50%);
+        }
+      "#},
+      Browsers {
+        safari: Some(9 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    minify_test(".foo { offset-path: none; }", ".foo{offset-path:none}");
+    minify_test(
+      ".foo { offset-path: path('M 20 20 H 80'); }",
+      ".foo{offset-path:path(\"M 20 20 H 80\")}",
+    );
+    minify_test(
+      ".foo { offset-path: border-box circle(50px); }",
+      ".foo{offset-path:circle(50px)}",
+    );
+    minify_test(".foo { offset-path: url('#path'); }", ".foo{offset-path:url(#path)}");
+
     prefix_test(
       ".foo { fill: lch(50.998% 135.363 338) }",
       indoc! { r#"
@@ -18058,6 +22345,37 @@ mod tests {
       },
     );
 
+    prefix_test(
+      ".foo { mask-image: url(x.svg); mask-image: var(--mask); }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-image: url("x.svg");
+          mask-image: url("x.svg");
+          -webkit-mask-image: var(--mask);
+          mask-image: var(--mask);
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    prefix_test(
+      ".foo { mask-position: 10px 20px; mask-position-x: right; }",
+      indoc! { r#"
+        .foo {
+          -webkit-mask-position: 10px 20px;
+          mask-position: 10px 20px;
+          mask-position-x: right;
+        }
+      "#},
+      Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       ".foo { mask: linear-gradient(lch(56.208% 136.76 46.312), lch(51% 135.366 301.364)) 40px 20px }",
       indoc! { r#"
@@ -18372,6 +22690,27 @@ mod tests {
       },
     );
 
+    prefix_test(
+      r#"
+        .foo {
+          mask-border-source: url(foo.png);
+          mask-border-slice: var(--foo);
+        }
+      "#,
+      indoc! { r#"
+        .foo {
+          -webkit-mask-box-image-source: url("foo.png");
+          mask-border-source: url("foo.png");
+          -webkit-mask-box-image-slice: var(--foo);
+          mask-border-slice: var(--foo);
+        }
+    "#},
+      Browsers {
+        chrome: Some(90 << 16),
+        ..Browsers::default()
+      },
+    );
+
     prefix_test(
       r#"
         .foo {
@@ -18483,6 +22822,76 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_clip() {
+    minify_test(".foo { clip: auto }", ".foo{clip:auto}");
+    minify_test(
+      ".foo { clip: rect(10px, 20px, 30px, 40px) }",
+      ".foo{clip:rect(10px 20px 30px 40px)}",
+    );
+    minify_test(
+      ".foo { clip: rect(10px 20px 30px 40px) }",
+      ".foo{clip:rect(10px 20px 30px 40px)}",
+    );
+    minify_test(
+      ".foo { clip: rect(0px, auto, 1.50em, auto) }",
+      ".foo{clip:rect(0 auto 1.5em auto)}",
+    );
+    minify_test(
+      ".foo { clip: rect(0 0 0 0) }",
+      ".foo{clip:rect(0 0 0 0)}",
+    );
+    test(
+      ".foo { clip: rect(1px, 2px, 3px, 4px) }",
+      indoc! {r#"
+        .foo {
+          clip: rect(1px 2px 3px 4px);
+        }
+      "#},
+    );
+    prefix_test(
+      ".foo { clip: rect(1px 2px 3px 4px) }",
+      indoc! {r#"
+        .foo {
+          clip: rect(1px, 2px, 3px, 4px);
+        }
+      "#},
+      Browsers {
+        ie: Some(3 << 16),
+        ..Browsers::default()
+      },
+    );
+    prefix_test(
+      ".foo { clip: rect(1px, 2px, 3px, 4px) }",
+      indoc! {r#"
+        .foo {
+          clip: rect(1px 2px 3px 4px);
+        }
+      "#},
+      Browsers {
+        ie: Some(6 << 16),
+        ..Browsers::default()
+      },
+    );
+
+    for value in [
+      "rect(1px, 2px 3px, 4px)",
+      "rect(1px 2px 3px)",
+      "rect(1px, 2px, 3px, 4px, 5px)",
+      "rect(1px, 2px, 3px, 10%)",
+      "none",
+    ] {
+      assert!(
+        matches!(
+          Property::parse_string("clip".into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}",
+        value
+      );
+    }
+  }
+
   #[test]
   fn test_filter() {
     minify_test(
@@ -18501,6 +22910,23 @@ mod tests {
       ".foo { filter: contrast(175%) brightness(3%); }",
       ".foo{filter:contrast(175%)brightness(3%)}",
     );
+    minify_test(".foo { filter: brightness(1); }", ".foo{filter:brightness()}");
+    minify_test(".foo { filter: invert(1) sepia(100%) }", ".foo{filter:invert()sepia()}");
+    minify_test(".foo { filter: hue-rotate(0deg) }", ".foo{filter:hue-rotate()}");
+    minify_test(".foo { filter: saturate(0) }", ".foo{filter:saturate(0)}");
+    minify_test(".foo { filter: blur(-5px) }", ".foo{filter:blur(-5px)}");
+    minify_test(".foo { filter: brightness(-10%) }", ".foo{filter:brightness(-10%)}");
+
+    for value in ["blur(-5px)", "brightness(-10%)", "contrast(-1)", "grayscale(-50%)", "sepia(-1)"] {
+      assert!(
+        matches!(
+          Property::parse_string("filter".into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}",
+        value
+      );
+    }
 
     prefix_test(
       ".foo { filter: blur(5px) }",
@@ -18631,23 +23057,237 @@ mod tests {
         ..Browsers::default()
       },
     );
-  }
+  }
+
+  #[test]
+  fn test_blend_mode() {
+    minify_test(".foo { mix-blend-mode: multiply }", ".foo{mix-blend-mode:multiply}");
+    minify_test(".foo { mix-blend-mode: COLOR-DODGE }", ".foo{mix-blend-mode:color-dodge}");
+    minify_test(".foo { isolation: isolate }", ".foo{isolation:isolate}");
+    minify_test(".foo { isolation: auto }", ".foo{isolation:auto}");
+    minify_test(
+      ".foo { background-blend-mode: screen, luminosity }",
+      ".foo{background-blend-mode:screen,luminosity}",
+    );
+    test(
+      ".foo { background-blend-mode: normal, soft-light }",
+      indoc! {r#"
+        .foo {
+          background-blend-mode: normal, soft-light;
+        }
+      "#},
+    );
+
+    for (property, value) in [
+      ("mix-blend-mode", "add"),
+      ("mix-blend-mode", "multiply, screen"),
+      ("isolation", "none"),
+      ("background-blend-mode", "multiply screen"),
+      ("background-blend-mode", "burn"),
+    ] {
+      assert!(
+        matches!(
+          Property::parse_string(property.into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}: {}",
+        property,
+        value
+      );
+    }
+  }
+
+  #[test]
+  fn test_viewport() {
+    minify_test(
+      r#"
+    @viewport {
+      width: 100vw;
+    }"#,
+      "@viewport{width:100vw}",
+    );
+    minify_test(
+      r#"
+    @-ms-viewport {
+      width: device-width;
+    }"#,
+      "@-ms-viewport{width:device-width}",
+    );
+  }
+
+  #[test]
+  fn test_draft_properties() {
+    draft_properties_test(
+      ".foo { field-sizing: content; interpolate-size: allow-keywords }",
+      ".foo{field-sizing:content;interpolate-size:allow-keywords}",
+    );
+    draft_properties_test(".foo { field-sizing: FIXED }", ".foo{field-sizing:fixed}");
+    draft_properties_test(
+      ".foo { interpolate-size: numeric-only }",
+      ".foo{interpolate-size:numeric-only}",
+    );
+    draft_properties_test(
+      ".foo { transition-behavior: allow-discrete }",
+      ".foo{transition-behavior:allow-discrete}",
+    );
+    draft_properties_test(
+      ".foo { transition: opacity 1s; transition-behavior: allow-discrete }",
+      ".foo{transition:opacity 1s allow-discrete}",
+    );
+    draft_properties_test(
+      ".foo { transition-behavior: allow-discrete; transition: opacity 1s }",
+      ".foo{transition:opacity 1s}",
+    );
+    draft_properties_test(
+      r#"
+      .foo {
+        transition-property: opacity, display;
+        transition-duration: 1s;
+        transition-delay: 0s;
+        transition-timing-function: ease;
+        transition-behavior: normal, allow-discrete;
+      }
+    "#,
+      ".foo{transition:opacity 1s,display 1s allow-discrete}",
+    );
+    draft_properties_test(
+      ".foo { transition-behavior: allow-discrete; transition-duration: var(--duration) }",
+      ".foo{transition-behavior:allow-discrete;transition-duration:var(--duration)}",
+    );
+    draft_properties_test(
+      ".foo { animation-timeline: --foo, auto, NONE }",
+      ".foo{animation-timeline:--foo,auto,none}",
+    );
+    draft_properties_test(
+      ".foo { animation-timeline: scroll(), scroll(block nearest), scroll(x root) }",
+      ".foo{animation-timeline:scroll(),scroll(),scroll(root x)}",
+    );
+    draft_properties_test(
+      ".foo { animation-timeline: view(), view(block auto), view(10px inline), view(20% 10px) }",
+      ".foo{animation-timeline:view(),view(),view(inline 10px),view(20% 10px)}",
+    );
+    draft_properties_test(
+      ".foo { animation: foo 1s; animation-timeline: --foo }",
+      ".foo{animation:foo 1s;animation-timeline:--foo}",
+    );
+    draft_properties_test(
+      ".foo { animation-timeline: --foo; animation: foo 1s }",
+      ".foo{animation:foo 1s}",
+    );
+    minify_test(
+      ".foo { animation-composition: REPLACE, add, accumulate }",
+      ".foo{animation-composition:replace,add,accumulate}",
+    );
+    minify_test(
+      ".foo { animation: foo 1s; animation-composition: add }",
+      ".foo{animation:foo 1s;animation-composition:add}",
+    );
+    minify_test(
+      ".foo { animation-composition: add; animation: foo 1s }",
+      ".foo{animation:foo 1s}",
+    );
+    draft_properties_test(
+      ".foo { animation-range-start: entry 0%; animation-range-end: exit 100% }",
+      ".foo{animation-range:entry exit}",
+    );
+    draft_properties_test(
+      ".foo { animation-range-start: entry; animation-range-end: entry }",
+      ".foo{animation-range:entry}",
+    );
+    draft_properties_test(
+      ".foo { animation-range: cover 10% cover 90%, 20% normal }",
+      ".foo{animation-range:cover 10% cover 90%,20%}",
+    );
+    draft_properties_test(
+      ".foo { animation-range: contain 10%; animation-range-end: normal }",
+      ".foo{animation-range:contain 10% normal}",
+    );
+    draft_properties_test(
+      ".foo { animation-range-start: entry-crossing 10px, normal }",
+      ".foo{animation-range-start:entry-crossing 10px,normal}",
+    );
+    draft_properties_test(
+      ".foo { animation-range: entry, exit; animation-range-end: normal }",
+      ".foo{animation-range-start:entry,exit;animation-range-end:normal}",
+    );
+    draft_properties_test(
+      ".foo { scroll-timeline-name: --foo, --bar; scroll-timeline-axis: block, x }",
+      ".foo{scroll-timeline:--foo,--bar x}",
+    );
+    draft_properties_test(
+      ".foo { scroll-timeline: --foo inline; scroll-timeline-axis: y }",
+      ".foo{scroll-timeline:--foo y}",
+    );
+    draft_properties_test(
+      ".foo { scroll-timeline-name: --foo, --bar; scroll-timeline-axis: x }",
+      ".foo{scroll-timeline-name:--foo,--bar;scroll-timeline-axis:x}",
+    );
+    draft_properties_test(".foo { scroll-timeline: none block }", ".foo{scroll-timeline:none}");
+    draft_properties_test(
+      ".foo { scroll-timeline-name: --foo; scroll-behavior: smooth; scroll-timeline-axis: x }",
+      ".foo{scroll-behavior:smooth;scroll-timeline:--foo x}",
+    );
+    draft_properties_test(
+      ".foo { view-timeline-name: --foo; view-timeline-axis: inline; view-timeline-inset: auto 20% }",
+      ".foo{view-timeline:--foo inline auto 20%}",
+    );
+    draft_properties_test(
+      ".foo { view-timeline: --foo 10px 10px x }",
+      ".foo{view-timeline:--foo x 10px}",
+    );
+    draft_properties_test(
+      ".foo { view-timeline: --foo; view-timeline-inset: var(--inset) }",
+      ".foo{view-timeline:--foo;view-timeline-inset:var(--inset)}",
+    );
+    draft_properties_test(
+      ".foo { timeline-scope: --foo, --bar }",
+      ".foo{timeline-scope:--foo,--bar}",
+    );
+    draft_properties_test(".foo { timeline-scope: ALL }", ".foo{timeline-scope:all}");
 
-  #[test]
-  fn test_viewport() {
+    // Without the draft flag, these properties are preserved as unparsed values.
+    minify_test(".foo { field-sizing: content }", ".foo{field-sizing:content}");
     minify_test(
-      r#"
-    @viewport {
-      width: 100vw;
-    }"#,
-      "@viewport{width:100vw}",
+      ".foo { transition-behavior: allow-discrete }",
+      ".foo{transition-behavior:allow-discrete}",
     );
-    minify_test(
-      r#"
-    @-ms-viewport {
-      width: device-width;
-    }"#,
-      "@-ms-viewport{width:device-width}",
+    // Timeline names must be dashed idents.
+    for (property_id, value) in [
+      ("scroll-timeline-name", "foo"),
+      ("view-timeline", "foo block"),
+      ("animation-timeline", "foo"),
+      ("timeline-scope", "--foo, bar"),
+    ] {
+      let property = Property::parse_string(
+        property_id.into(),
+        value,
+        ParserOptions {
+          draft_properties: true,
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      assert!(matches!(property, Property::Unparsed(..)));
+    }
+    let property = Property::parse_string(
+      crate::properties::PropertyId::FieldSizing,
+      "content",
+      ParserOptions::default(),
+    )
+    .unwrap();
+    assert!(matches!(property, Property::Unparsed(..)));
+    let property = Property::parse_string(
+      crate::properties::PropertyId::FieldSizing,
+      "content",
+      ParserOptions {
+        draft_properties: true,
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(
+      property,
+      Property::FieldSizing(crate::properties::ui::FieldSizing::Content)
     );
   }
 
@@ -19509,6 +24149,351 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_merge() {
+    fn merge_test(a: &str, b: &str, expected: &str) {
+      let mut stylesheet = StyleSheet::parse(
+        a,
+        ParserOptions {
+          filename: "a.css".into(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      let other = StyleSheet::parse(
+        b,
+        ParserOptions {
+          filename: "b.css".into(),
+          ..ParserOptions::default()
+        },
+      )
+      .unwrap();
+      stylesheet.merge(other);
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    merge_test(
+      ".foo { color: red }",
+      ".bar { color: green }",
+      ".foo{color:red}.bar{color:green}",
+    );
+    merge_test(
+      "@import 'a.css'; .foo { color: red }",
+      "@import 'b.css'; .bar { color: green }",
+      "@import \"a.css\";@import \"b.css\";.foo{color:red}.bar{color:green}",
+    );
+    merge_test(
+      "@import 'a.css'; .foo { color: red }",
+      "@import 'a.css'; @import 'b.css'; .bar { color: green }",
+      "@import \"a.css\";@import \"b.css\";.foo{color:red}.bar{color:green}",
+    );
+    merge_test(
+      "@import 'a.css' print; .foo { color: red }",
+      "@import 'a.css'; .bar { color: green }",
+      "@import \"a.css\" print;@import \"a.css\";.foo{color:red}.bar{color:green}",
+    );
+    merge_test(
+      "@namespace svg url(http://www.w3.org/2000/svg); svg|a { color: red }",
+      "@namespace svg url(http://www.w3.org/2000/svg); @namespace html url(http://www.w3.org/1999/xhtml); html|a { color: green }",
+      "@namespace svg \"http://www.w3.org/2000/svg\";@namespace html \"http://www.w3.org/1999/xhtml\";svg|a{color:red}html|a{color:green}",
+    );
+    merge_test(
+      "@layer foo { .foo { color: red } } @layer bar { .bar { color: red } }",
+      "@import 'b.css' layer(baz); .baz { color: green }",
+      "@layer foo,bar;@import \"b.css\" layer(baz);@layer foo{.foo{color:red}}@layer bar{.bar{color:red}}.baz{color:green}",
+    );
+    merge_test(
+      "@layer foo, bar; @layer foo { .foo { color: red } }",
+      "@layer baz; @import 'b.css' layer(baz); .baz { color: green }",
+      "@layer foo,bar;@layer baz;@import \"b.css\" layer(baz);@layer foo{.foo{color:red}}.baz{color:green}",
+    );
+    merge_test(
+      "@layer foo { .foo { color: red } }",
+      "@layer bar; .bar { color: green }",
+      "@layer foo{.foo{color:red}}@layer bar;.bar{color:green}",
+    );
+
+    let mut stylesheet = StyleSheet::parse(
+      ".foo { color: red }",
+      ParserOptions {
+        filename: "a.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    let other = StyleSheet::parse(
+      "@media print { .bar { color: green } }",
+      ParserOptions {
+        filename: "b.css".into(),
+        ..ParserOptions::default()
+      },
+    )
+    .unwrap();
+    stylesheet.merge(other);
+    assert_eq!(stylesheet.sources, vec!["a.css".to_string(), "b.css".to_string()]);
+    match &stylesheet.rules.0[1] {
+      CssRule::Media(media) => {
+        assert_eq!(media.loc.source_index, 1);
+        match &media.rules.0[0] {
+          CssRule::Style(style) => assert_eq!(style.loc.source_index, 1),
+          _ => unreachable!(),
+        }
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_nest_rules() {
+    fn nest_test(source: &str, expected: &str, targets: Option<Browsers>) {
+      let mut stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      stylesheet
+        .minify(MinifyOptions {
+          targets,
+          nest_rules: true,
+          ..MinifyOptions::default()
+        })
+        .unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          targets,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    nest_test(
+      ".foo { color: red } .foo .bar { color: green } .foo > .baz { color: blue }",
+      ".foo{color:red;& .bar{color:green}&>.baz{color:#00f}}",
+      None,
+    );
+    nest_test(
+      ".container .foo { color: red } .container + .bar { color: green }",
+      ".container{& .foo{color:red}&+.bar{color:green}}",
+      None,
+    );
+    nest_test(
+      ".a .b { color: red } .a .c { color: green }",
+      ".a .b{color:red}.a .c{color:green}",
+      None,
+    );
+    nest_test(
+      ".foo { color: red } .bar { color: green } .foo .baz { color: blue }",
+      ".foo{color:red}.bar{color:green}.foo .baz{color:#00f}",
+      None,
+    );
+    nest_test(
+      ".foo { color: red } .foo .bar, .baz { color: green }",
+      ".foo{color:red}.foo .bar,.baz{color:green}",
+      None,
+    );
+    nest_test(
+      "@media print { .foo { color: red } .foo .bar .baz { color: green } }",
+      "@media print{.foo{color:red;& .bar .baz{color:green}}}",
+      None,
+    );
+    nest_test(
+      ".foo { color: red } .foo .bar { color: green }",
+      ".foo{color:red}.foo .bar{color:green}",
+      Some(Browsers {
+        chrome: Some(95 << 16),
+        ..Browsers::default()
+      }),
+    );
+  }
+
+  #[test]
+  fn test_ascii_only() {
+    fn ascii_test(source: &str, expected: &str) {
+      let stylesheet = StyleSheet::parse(&source, ParserOptions::default()).unwrap();
+      let res = stylesheet
+        .to_css(PrinterOptions {
+          minify: true,
+          ascii_only: true,
+          ..PrinterOptions::default()
+        })
+        .unwrap();
+      assert_eq!(res.code, expected);
+    }
+
+    ascii_test(".café { color: red }", ".caf\\e9 {color:red}");
+    ascii_test(".café .foo { color: red }", ".caf\\e9  .foo{color:red}");
+    ascii_test(".foo { content: \"→\" }", ".foo{content:\"\\2192 \"}");
+    ascii_test(".foo { content: \"a→b\" }", ".foo{content:\"a\\2192 b\"}");
+    ascii_test(".foo { content: \"→x\" }", ".foo{content:\"\\2192x\"}");
+    ascii_test(".foo { font-family: Ünï }", ".foo{font-family:\\dcn\\ef }");
+    ascii_test(".foo { --bär: 1 }", ".foo{--b\\e4r:1}");
+    ascii_test(".foo { --x: café }", ".foo{--x:caf\\e9 }");
+    ascii_test(".foo { --x: \"→\" }", ".foo{--x:\"\\2192 \"}");
+    ascii_test("[title=\"a é\"] { color: red }", "[title=\"a \\e9 \"]{color:red}");
+    ascii_test(
+      ".foo { background: url(café.png) }",
+      ".foo{background:url(caf\\e9 .png)}",
+    );
+    ascii_test(
+      "@keyframes é { from { color: red } }",
+      "@keyframes \\e9 {from{color:red}}",
+    );
+    minify_test(".café { color: red }", ".café{color:red}");
+  }
+
+  #[test]
+  fn test_stream() {
+    use crate::stream::{parse_declarations, parse_stylesheet, ParseHandler};
+
+    #[derive(Default)]
+    struct Handler {
+      events: Vec<String>,
+    }
+
+    impl<'i> ParseHandler<'i> for Handler {
+      fn enter_rule(&mut self, rule: &CssRule<'i>) {
+        let name = match rule {
+          CssRule::Style(..) => "style",
+          CssRule::Media(..) => "media",
+          CssRule::Supports(..) => "supports",
+          CssRule::Keyframes(..) => "keyframes",
+          CssRule::Import(..) => "import",
+          _ => "other",
+        };
+        self.events.push(format!("enter {}", name));
+      }
+
+      fn exit_rule(&mut self, _rule: &CssRule<'i>) {
+        self.events.push("exit".into());
+      }
+
+      fn declaration(&mut self, property: &Property<'i>, important: bool) {
+        let mut s = property.to_css_string(important, PrinterOptions::default()).unwrap();
+        s.retain(|c| c != ' ');
+        self.events.push(s);
+      }
+    }
+
+    let mut handler = Handler::default();
+    parse_stylesheet(
+      r#"
+      @import "foo.css";
+      .foo { color: red; width: 10px !important }
+      @media print {
+        .bar { color: green }
+      }
+      @supports (display: grid) {
+        @media (min-width: 10px) {
+          .baz { display: grid }
+        }
+        .qux { color: blue }
+      }
+      @keyframes test {
+        from { opacity: 0 }
+        to { opacity: 1 }
+      }
+    "#,
+      ParserOptions::default(),
+      &mut handler,
+    )
+    .unwrap();
+    assert_eq!(
+      handler.events,
+      vec![
+        "enter import",
+        "exit",
+        "enter style",
+        "color:red",
+        "width:10px!important",
+        "exit",
+        "enter media",
+        "enter style",
+        "color:green",
+        "exit",
+        "exit",
+        "enter supports",
+        "enter media",
+        "enter style",
+        "display:grid",
+        "exit",
+        "exit",
+        "enter style",
+        "color:blue",
+        "exit",
+        "exit",
+        "enter keyframes",
+        "opacity:0",
+        "opacity:1",
+        "exit",
+      ]
+    );
+
+    let mut handler = Handler::default();
+    parse_declarations(
+      "color: red !important; width: 10px; height: 20px",
+      ParserOptions::default(),
+      &mut handler,
+    )
+    .unwrap();
+    assert_eq!(handler.events, vec!["color:red!important", "width:10px", "height:20px"]);
+
+    let mut handler = Handler::default();
+    let res = parse_stylesheet(
+      ".foo { color: red } .bar { color: green",
+      ParserOptions::default(),
+      &mut handler,
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+      handler.events,
+      vec!["enter style", "color:red", "exit", "enter style", "color:green", "exit"]
+    );
+
+    let mut handler = Handler::default();
+    let res = parse_stylesheet(
+      ".foo { color: red } @import 'foo.css';",
+      ParserOptions::default(),
+      &mut handler,
+    );
+    assert!(res.is_err());
+  }
+
+  #[test]
+  fn test_property_id() {
+    use crate::properties::PropertyId;
+    use crate::vendor_prefix::VendorPrefix;
+
+    assert_eq!(PropertyId::from("color"), PropertyId::Color);
+    assert_eq!(PropertyId::from("COLOR"), PropertyId::Color);
+    assert_eq!(PropertyId::from("all"), PropertyId::All);
+    assert_eq!(
+      PropertyId::from("transition"),
+      PropertyId::Transition(VendorPrefix::None)
+    );
+    assert_eq!(
+      PropertyId::from("-webkit-Transition"),
+      PropertyId::Transition(VendorPrefix::WebKit)
+    );
+    assert_eq!(
+      PropertyId::from("-webkit-box-ordinal-group"),
+      PropertyId::BoxOrdinalGroup(VendorPrefix::WebKit)
+    );
+    assert_eq!(
+      PropertyId::from("box-ordinal-group"),
+      PropertyId::Custom("box-ordinal-group".into())
+    );
+    assert_eq!(
+      PropertyId::from("-webkit-color"),
+      PropertyId::Custom("-webkit-color".into())
+    );
+    assert_eq!(PropertyId::from("--color"), PropertyId::Custom("--color".into()));
+    assert_eq!(PropertyId::from("unknown"), PropertyId::Custom("unknown".into()));
+  }
+
   #[test]
   fn test_property() {
     minify_test(
@@ -19708,6 +24693,83 @@ mod tests {
     "#,
       "@property --property-name{syntax:\"<color>+\";inherits:false;initial-value:#ff0 #00f}",
     );
+
+    minify_test(
+      r#"
+      @property --size {
+        syntax: '<length>';
+        inherits: false;
+        initial-value: 0px;
+      }
+
+      .foo {
+        --size: calc(10px + 5px);
+        --other: calc(10px + 5px);
+      }
+
+      .bar {
+        --size: 0px;
+      }
+
+      .baz {
+        --size: red;
+      }
+
+      .qux {
+        --size: var(--other);
+      }
+    "#,
+      "@property --size{syntax:\"<length>\";inherits:false;initial-value:0}.foo{--size:15px;--other:calc(10px + 5px)}.bar{--size:0px}.baz{--size:red}.qux{--size:var(--other)}",
+    );
+
+    minify_test(
+      r#"
+      @property --sizes {
+        syntax: '<length-percentage>+';
+        inherits: false;
+        initial-value: 0px;
+      }
+
+      @property --colors {
+        syntax: '<color># | none';
+        inherits: false;
+        initial-value: none;
+      }
+
+      .foo {
+        --sizes: calc(1px + 1px)   50.0%;
+        --colors: yellow,   rgb(0, 0, 255);
+      }
+
+      @media (min-width: 100px) {
+        .foo {
+          --colors: none;
+        }
+      }
+    "#,
+      "@property --sizes{syntax:\"<length-percentage>+\";inherits:false;initial-value:0}@property --colors{syntax:\"<color>#|none\";inherits:false;initial-value:none}.foo{--sizes:2px 50%;--colors:#ff0,#00f}@media (min-width:100px){.foo{--colors:none}}",
+    );
+
+    minify_test(
+      r#"
+      @property --size {
+        syntax: '<length>';
+        inherits: false;
+        initial-value: 0px;
+      }
+
+      @keyframes grow {
+        from {
+          --size: calc(0px + 0px);
+        }
+
+        to {
+          --size: calc(10px + 5px);
+        }
+      }
+    "#,
+      "@property --size{syntax:\"<length>\";inherits:false;initial-value:0}@keyframes grow{0%{--size:0px}to{--size:15px}}",
+    );
   }
 
   #[test]
@@ -20127,6 +25189,92 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_content_visibility() {
+    minify_test(".foo { content-visibility: auto }", ".foo{content-visibility:auto}");
+    minify_test(".foo { content-visibility: hidden }", ".foo{content-visibility:hidden}");
+    minify_test(".foo { content-visibility: VISIBLE }", ".foo{content-visibility:visible}");
+    assert!(matches!(
+      Property::parse_string("content-visibility".into(), "auto hidden", ParserOptions::default()),
+      Ok(Property::Unparsed(..))
+    ));
+  }
+
+  #[test]
+  fn test_contain_intrinsic_size() {
+    minify_test(".foo { contain-intrinsic-size: 10px }", ".foo{contain-intrinsic-size:10px}");
+    minify_test(".foo { contain-intrinsic-size: 10px 10px }", ".foo{contain-intrinsic-size:10px}");
+    minify_test(".foo { contain-intrinsic-size: none }", ".foo{contain-intrinsic-size:none}");
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 10px auto 10px }",
+      ".foo{contain-intrinsic-size:auto 10px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto 10px 20px }",
+      ".foo{contain-intrinsic-size:auto 10px 20px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: auto none }",
+      ".foo{contain-intrinsic-size:auto none}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: auto 0px }",
+      ".foo{contain-intrinsic-width:auto 0}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-inline-size: auto 300px }",
+      ".foo{contain-intrinsic-inline-size:auto 300px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: 10px; contain-intrinsic-height: 10px }",
+      ".foo{contain-intrinsic-size:10px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: 10px; contain-intrinsic-height: auto 20px }",
+      ".foo{contain-intrinsic-size:10px auto 20px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-size: 10px; contain-intrinsic-height: 20px }",
+      ".foo{contain-intrinsic-size:10px 20px}",
+    );
+    minify_test(
+      ".foo { contain-intrinsic-width: 10px; contain-intrinsic-inline-size: 20px; contain-intrinsic-height: 30px }",
+      ".foo{contain-intrinsic-width:10px;contain-intrinsic-inline-size:20px;contain-intrinsic-height:30px}",
+    );
+    for value in ["auto", "-10px", "auto auto 10px", "10px 20px 30px", "none auto"] {
+      assert!(
+        matches!(
+          Property::parse_string("contain-intrinsic-size".into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}",
+        value
+      );
+    }
+  }
+
+  #[test]
+  fn test_contain() {
+    minify_test(".foo { contain: none }", ".foo{contain:none}");
+    minify_test(".foo { contain: strict }", ".foo{contain:strict}");
+    minify_test(".foo { contain: content }", ".foo{contain:content}");
+    minify_test(".foo { contain: paint layout }", ".foo{contain:layout paint}");
+    minify_test(".foo { contain: inline-size style }", ".foo{contain:inline-size style}");
+    minify_test(".foo { contain: style paint layout }", ".foo{contain:content}");
+    minify_test(".foo { contain: size paint style layout }", ".foo{contain:strict}");
+    minify_test(".foo { contain: inline-size layout style paint }", ".foo{contain:inline-size layout style paint}");
+    for value in ["paint paint", "size inline-size", "strict paint", "layout content", "none size"] {
+      assert!(
+        matches!(
+          Property::parse_string("contain".into(), value, ParserOptions::default()),
+          Ok(Property::Unparsed(..))
+        ),
+        "{}",
+        value
+      );
+    }
+  }
+
   #[test]
   fn test_unknown_at_rules() {
     minify_test("@foo;", "@foo;");
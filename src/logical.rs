@@ -24,4 +24,5 @@ pub enum LogicalGroup {
   Size,
   MinSize,
   MaxSize,
+  ContainIntrinsicSize,
 }
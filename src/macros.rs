@@ -103,16 +103,24 @@ macro_rules! enum_property {
       }
     }
 
-    impl ToCss for $name {
-      fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError> where W: std::fmt::Write {
+    impl $name {
+      /// Returns the CSS keyword for the value.
+      #[allow(dead_code)]
+      pub fn as_str(&self) -> &'static str {
         use $name::*;
         match self {
           $(
-            $id => dest.write_str($str),
+            $id => $str,
           )+
         }
       }
     }
+
+    impl ToCss for $name {
+      fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError> where W: std::fmt::Write {
+        dest.write_str(self.as_str())
+      }
+    }
   };
 }
 
@@ -702,6 +710,51 @@ macro_rules! define_list_shorthand {
 pub(crate) use define_list_shorthand;
 
 macro_rules! rect_shorthand {
+  (
+    $(#[$meta: meta])*
+    $vis:vis struct $name: ident<$l: lifetime, $t: ty> {
+      $top: ident,
+      $right: ident,
+      $bottom: ident,
+      $left: ident
+    }
+  ) => {
+    define_shorthand! {
+      $(#[$meta])*
+      pub struct $name<$l> {
+        /// The top value.
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        top: $top($t),
+        /// The right value.
+        right: $right($t),
+        /// The bottom value.
+        bottom: $bottom($t),
+        /// The left value.
+        left: $left($t),
+      }
+    }
+
+    impl<$l> Parse<$l> for $name<$l> {
+      fn parse<'t>(input: &mut Parser<$l, 't>) -> Result<Self, ParseError<$l, ParserError<$l>>> {
+        let rect = Rect::parse(input)?;
+        Ok(Self {
+          top: rect.0,
+          right: rect.1,
+          bottom: rect.2,
+          left: rect.3,
+        })
+      }
+    }
+
+    impl<$l> ToCss for $name<$l> {
+      fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+      where
+        W: std::fmt::Write,
+      {
+        Rect::new(&self.top, &self.right, &self.bottom, &self.left).to_css(dest)
+      }
+    }
+  };
   (
     $(#[$meta: meta])*
     $vis:vis struct $name: ident<$t: ty> {
@@ -751,6 +804,45 @@ macro_rules! rect_shorthand {
 pub(crate) use rect_shorthand;
 
 macro_rules! size_shorthand {
+  (
+    $(#[$outer:meta])*
+    $vis:vis struct $name: ident<$l: lifetime, $t: ty> {
+      $(#[$a_meta: meta])*
+      $a_key: ident: $a_prop: ident,
+      $(#[$b_meta: meta])*
+      $b_key: ident: $b_prop: ident,
+    }
+  ) => {
+    define_shorthand! {
+      $(#[$outer])*
+      $vis struct $name<$l> {
+        $(#[$a_meta])*
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        $a_key: $a_prop($t),
+        $(#[$b_meta])*
+        $b_key: $b_prop($t),
+      }
+    }
+
+    impl<$l> Parse<$l> for $name<$l> {
+      fn parse<'t>(input: &mut Parser<$l, 't>) -> Result<Self, ParseError<$l, ParserError<$l>>> {
+        let size = Size2D::parse(input)?;
+        Ok(Self {
+          $a_key: size.0,
+          $b_key: size.1,
+        })
+      }
+    }
+
+    impl<$l> ToCss for $name<$l> {
+      fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+      where
+        W: std::fmt::Write,
+      {
+        Size2D(&self.$a_key, &self.$b_key).to_css(dest)
+      }
+    }
+  };
   (
     $(#[$outer:meta])*
     $vis:vis struct $name: ident<$t: ty> {
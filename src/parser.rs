@@ -26,6 +26,7 @@ use crate::rules::{
 };
 use crate::selector::{SelectorParser, Selectors};
 use crate::traits::Parse;
+use crate::values::color::ContrastAlgorithm;
 use crate::values::ident::{CustomIdent, DashedIdent};
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
@@ -43,6 +44,10 @@ pub struct ParserOptions<'o, 'i> {
   pub nesting: bool,
   /// Whether to enable the [custom media](https://drafts.csswg.org/mediaqueries-5/#custom-mq) draft syntax.
   pub custom_media: bool,
+  /// Whether to enable typed parsing of draft properties such as `field-sizing`, `interpolate-size`,
+  /// `transition-behavior`, and the scroll-driven animation properties. Otherwise, these are preserved
+  /// as unparsed token lists.
+  pub draft_properties: bool,
   /// Whether the enable [CSS modules](https://github.com/css-modules/css-modules).
   pub css_modules: Option<crate::css_modules::Config<'o>>,
   /// The source index to assign to all parsed rules. Impacts the source map when
@@ -50,6 +55,12 @@ pub struct ParserOptions<'o, 'i> {
   pub source_index: u32,
   /// Whether to ignore invalid rules and declarations rather than erroring.
   pub error_recovery: bool,
+  /// The algorithm used to compute the winning color of the `color-contrast()` and `contrast-color()`
+  /// functions when all of their arguments are literal colors.
+  pub contrast_algorithm: ContrastAlgorithm,
+  /// Whether to preserve the `color-contrast()` and `contrast-color()` functions as written rather
+  /// than computing the winning color when all of their arguments are literal colors.
+  pub preserve_contrast_functions: bool,
   /// A list that will be appended to when a warning occurs.
   pub warnings: Option<Arc<RwLock<Vec<Error<ParserError<'i>>>>>>,
 }
@@ -99,6 +110,12 @@ impl<'a, 'o, 'b, 'i> TopLevelRuleParser<'a, 'o, 'i> {
       options: &self.options,
     }
   }
+
+  /// Returns a parser for the contents of a top-level at-rule block.
+  pub(crate) fn block_parser(&mut self) -> NestedRuleParser<'_, 'o, 'i> {
+    self.state = State::Body;
+    self.nested()
+  }
 }
 
 /// A rule prelude for at-rule with block.
@@ -320,10 +337,10 @@ impl<'a, 'o, 'i> QualifiedRuleParser<'i> for TopLevelRuleParser<'a, 'o, 'i> {
 }
 
 #[derive(Clone)]
-struct NestedRuleParser<'a, 'o, 'i> {
+pub(crate) struct NestedRuleParser<'a, 'o, 'i> {
   default_namespace: &'a Option<CowArcStr<'i>>,
   namespace_prefixes: &'a HashMap<CowArcStr<'i>, CowArcStr<'i>>,
-  options: &'a ParserOptions<'o, 'i>,
+  pub(crate) options: &'a ParserOptions<'o, 'i>,
 }
 
 impl<'a, 'o, 'b, 'i> NestedRuleParser<'a, 'o, 'i> {
@@ -356,7 +373,7 @@ impl<'a, 'o, 'b, 'i> NestedRuleParser<'a, 'o, 'i> {
     Ok(CssRuleList(rules))
   }
 
-  fn loc(&self, start: &ParserState) -> Location {
+  pub(crate) fn loc(&self, start: &ParserState) -> Location {
     let loc = start.source_location();
     Location {
       source_index: self.options.source_index,
@@ -401,7 +418,11 @@ impl<'a, 'o, 'b, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
         return Ok(AtRulePrelude::FontPaletteValues(name))
       },
       "counter-style" => {
-        let name = CustomIdent::parse(input)?;
+        // https://drafts.csswg.org/css-counter-styles-3/#the-counter-style-rule
+        let name = CustomIdent::parse_except(
+          input,
+          &["none", "decimal", "disc", "square", "circle", "disclosure-open", "disclosure-closed"],
+        )?;
         Ok(AtRulePrelude::CounterStyle(name))
       },
       "viewport" | "-ms-viewport" => {
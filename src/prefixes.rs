@@ -93,6 +93,7 @@ pub enum Feature {
   GridTemplateAreas,
   GridTemplateColumns,
   GridTemplateRows,
+  HyphenateCharacter,
   Hyphens,
   ImageRendering,
   ImageSet,
@@ -973,6 +974,43 @@ impl Feature {
           }
         }
       }
+      Feature::HyphenateCharacter => {
+        if let Some(version) = browsers.android {
+          if version >= 2424832 && version <= 6881280 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.chrome {
+          if version >= 393216 && version <= 6881280 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.edge {
+          if version >= 5177344 && version <= 6881280 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.ios_saf {
+          if version >= 327680 && version <= 1050112 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.opera {
+          if version >= 983040 && version <= 5963776 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.safari {
+          if version >= 327936 && version <= 1050112 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+        if let Some(version) = browsers.samsung {
+          if version >= 65536 && version <= 1245184 {
+            prefixes |= VendorPrefix::WebKit;
+          }
+        }
+      }
       Feature::Hyphens => {
         if let Some(version) = browsers.edge {
           if version >= 786432 && version <= 1179648 {
@@ -1893,11 +1931,6 @@ impl Feature {
             prefixes |= VendorPrefix::WebKit;
           }
         }
-        if let Some(version) = browsers.ie {
-          if version >= 328960 && version <= 720896 {
-            prefixes |= VendorPrefix::Ms;
-          }
-        }
         if let Some(version) = browsers.ios_saf {
           if version >= 327680 && version <= 656128 {
             prefixes |= VendorPrefix::WebKit;
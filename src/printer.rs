@@ -6,8 +6,9 @@ use crate::error::{Error, ErrorLocation, PrinterError, PrinterErrorKind};
 use crate::rules::Location;
 use crate::targets::Browsers;
 use crate::vendor_prefix::VendorPrefix;
-use cssparser::{serialize_identifier, serialize_name};
+use cssparser::{serialize_identifier, serialize_name, serialize_string};
 use parcel_sourcemap::{OriginalLocation, SourceMap};
+use std::collections::HashMap;
 
 /// Options that control how CSS is serialized to a string.
 #[derive(Default)]
@@ -29,6 +30,19 @@ pub struct PrinterOptions<'a> {
   /// A mapping of pseudo classes to replace with class names that can be applied
   /// from JavaScript. Useful for polyfills, for example.
   pub pseudo_classes: Option<PseudoClasses<'a>>,
+  /// Values to substitute for `env()` references, keyed by environment variable name.
+  /// References with indices are not substituted.
+  pub env: Option<&'a HashMap<String, String>>,
+  /// Whether to escape all non-ASCII characters in identifiers, strings, and URLs,
+  /// for environments that require 7-bit output. This applies to everything written by
+  /// the printer, including selectors, rule preludes, and custom property values.
+  pub ascii_only: bool,
+  /// Whether to pad the columns of `grid-template-areas` strings so that they line up
+  /// across rows. Only applies when not minifying.
+  pub align_grid_areas: bool,
+  /// Whether to print the keyword form of values that have an equivalent keyword,
+  /// e.g. `font-weight: bold` rather than `700`. Only applies when not minifying.
+  pub prefer_keywords: bool,
 }
 
 /// A mapping of user action pseudo classes to replace with class names.
@@ -74,6 +88,10 @@ pub struct Printer<'a, 'b, 'c, W> {
   pub(crate) css_module: Option<CssModule<'a, 'b, 'c>>,
   pub(crate) dependencies: Option<Vec<Dependency>>,
   pub(crate) pseudo_classes: Option<PseudoClasses<'a>>,
+  pub(crate) env: Option<&'a HashMap<String, String>>,
+  ascii_only: bool,
+  pub(crate) align_grid_areas: bool,
+  pub(crate) prefer_keywords: bool,
 }
 
 impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
@@ -102,6 +120,24 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
         None
       },
       pseudo_classes: options.pseudo_classes,
+      env: options.env,
+      ascii_only: options.ascii_only,
+      align_grid_areas: options.align_grid_areas,
+      prefer_keywords: options.prefer_keywords,
+    }
+  }
+
+  /// Returns options for a temporary printer that serializes values the same way as this one,
+  /// e.g. to compare the length of alternative serializations.
+  pub(crate) fn scratch_options(&self) -> PrinterOptions<'a> {
+    PrinterOptions {
+      minify: self.minify,
+      targets: self.targets,
+      env: self.env,
+      ascii_only: self.ascii_only,
+      align_grid_areas: self.align_grid_areas,
+      prefer_keywords: self.prefer_keywords,
+      ..PrinterOptions::default()
     }
   }
 
@@ -123,8 +159,7 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
   /// NOTE: Is is assumed that the string does not contain any newline characters.
   /// If such a string is written, it will break source maps.
   pub fn write_str(&mut self, s: &str) -> Result<(), PrinterError> {
-    self.col += s.len() as u32;
-    self.dest.write_str(s)?;
+    std::fmt::Write::write_str(self, s)?;
     Ok(())
   }
 
@@ -133,6 +168,8 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     if c == '\n' {
       self.line += 1;
       self.col = 0;
+    } else if self.ascii_only && !c.is_ascii() {
+      return self.write_str(c.encode_utf8(&mut [0; 4]));
     } else {
       self.col += 1;
     }
@@ -224,24 +261,24 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
   /// is added, and the mapping is added to the CSS module.
   pub fn write_ident(&mut self, ident: &str) -> Result<(), PrinterError> {
     if let Some(css_module) = &mut self.css_module {
-      let dest = &mut self.dest;
+      let mut id = String::new();
       let mut first = true;
       css_module.config.pattern.write(
         &css_module.hashes[self.loc.source_index as usize],
         &css_module.sources[self.loc.source_index as usize],
         ident,
         |s| {
-          self.col += s.len() as u32;
           if first {
             first = false;
-            serialize_identifier(s, dest)
+            serialize_identifier(s, &mut id)
           } else {
-            serialize_name(s, dest)
+            serialize_name(s, &mut id)
           }
         },
       )?;
 
       css_module.add_local(&ident, &ident, self.loc.source_index);
+      self.write_str(&id)?;
     } else {
       serialize_identifier(ident, self)?;
     }
@@ -254,20 +291,18 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
 
     match &mut self.css_module {
       Some(css_module) if css_module.config.dashed_idents => {
-        let dest = &mut self.dest;
+        let mut name = String::new();
         css_module.config.pattern.write(
           &css_module.hashes[self.loc.source_index as usize],
           &css_module.sources[self.loc.source_index as usize],
           &ident[2..],
-          |s| {
-            self.col += s.len() as u32;
-            serialize_name(s, dest)
-          },
+          |s| serialize_name(s, &mut name),
         )?;
 
         if is_declaration {
           css_module.add_dashed(ident, self.loc.source_index);
         }
+        self.write_str(&name)?;
       }
       _ => {
         serialize_name(&ident[2..], self)?;
@@ -277,6 +312,60 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
     Ok(())
   }
 
+  /// Writes a value that may be serialized as either an identifier or a quoted string,
+  /// choosing whichever is shorter. If `words` is true, spaces separate a sequence of
+  /// identifiers rather than being escaped, as in font family names.
+  ///
+  /// Callers must check that the value is not reserved in the context where it is used
+  /// (e.g. a CSS-wide keyword), and write such values as strings instead.
+  pub fn write_ident_or_string(&mut self, value: &str, words: bool) -> Result<(), PrinterError> {
+    if !value.is_empty() && !(words && value.split(' ').any(str::is_empty)) {
+      let mut id = String::new();
+      if words {
+        for (i, word) in value.split(' ').enumerate() {
+          if i > 0 {
+            id.push(' ');
+          }
+          serialize_identifier(word, &mut id)?;
+        }
+      } else {
+        serialize_identifier(value, &mut id)?;
+      }
+
+      if id.len() < value.len() + 2 {
+        return self.write_str(&id);
+      }
+    }
+
+    serialize_string(value, self)?;
+    Ok(())
+  }
+
+  /// Writes a string, replacing non-ASCII characters with hex escapes.
+  fn write_ascii_escaped(&mut self, s: &str) -> std::fmt::Result {
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+      if c.is_ascii() {
+        self.col += 1;
+        self.dest.write_char(c)?;
+        continue;
+      }
+
+      let mut escaped = format!("\\{:x}", c as u32);
+      // A space terminates the escape if it could otherwise continue into the next character.
+      // The end of this chunk is unknown, so a space is always written there.
+      if chars
+        .peek()
+        .map_or(true, |next| next.is_ascii_hexdigit() || next.is_ascii_whitespace())
+      {
+        escaped.push(' ');
+      }
+      self.col += escaped.len() as u32;
+      self.dest.write_str(&escaped)?;
+    }
+    Ok(())
+  }
+
   /// Returns an error of the given kind at the provided location in the current source file.
   pub fn error(&self, kind: PrinterErrorKind, loc: crate::dependencies::Location) -> Error<PrinterErrorKind> {
     Error {
@@ -292,6 +381,10 @@ impl<'a, 'b, 'c, W: std::fmt::Write + Sized> Printer<'a, 'b, 'c, W> {
 
 impl<'a, 'b, 'c, W: std::fmt::Write + Sized> std::fmt::Write for Printer<'a, 'b, 'c, W> {
   fn write_str(&mut self, s: &str) -> std::fmt::Result {
+    if self.ascii_only && !s.is_ascii() {
+      return self.write_ascii_escaped(s);
+    }
+
     self.col += s.len() as u32;
     self.dest.write_str(s)
   }
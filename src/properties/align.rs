@@ -105,6 +105,17 @@ enum_property! {
   }
 }
 
+impl ContentPosition {
+  /// Returns the flexbox equivalent of the `start` and `end` keywords.
+  fn to_flex(&self) -> ContentPosition {
+    match self {
+      ContentPosition::Start => ContentPosition::FlexStart,
+      ContentPosition::End => ContentPosition::FlexEnd,
+      pos => *pos,
+    }
+  }
+}
+
 /// A value for the [align-content](https://www.w3.org/TR/css-align-3/#propdef-align-content) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -325,6 +336,17 @@ enum_property! {
   }
 }
 
+impl SelfPosition {
+  /// Returns the flexbox equivalent of the `start` and `end` keywords.
+  fn to_flex(&self) -> SelfPosition {
+    match self {
+      SelfPosition::Start => SelfPosition::FlexStart,
+      SelfPosition::End => SelfPosition::FlexEnd,
+      pos => *pos,
+    }
+  }
+}
+
 /// A value for the [align-self](https://www.w3.org/TR/css-align-3/#align-self-property) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -1014,15 +1036,16 @@ impl<'i> PropertyHandler<'i> for AlignHandler {
         property!(align_items, &val.align, &VendorPrefix::None);
         self.justify_items = Some(val.justify.clone());
       }
-      RowGap(val) => {
+      // The legacy grid-gap properties are aliases of the gap properties.
+      RowGap(val) | GridRowGap(val) => {
         self.row_gap = Some(val.clone());
         self.has_any = true;
       }
-      ColumnGap(val) => {
+      ColumnGap(val) | GridColumnGap(val) => {
         self.column_gap = Some(val.clone());
         self.has_any = true;
       }
-      Gap(val) => {
+      Gap(val) | GridGap(val) => {
         self.row_gap = Some(val.row.clone());
         self.column_gap = Some(val.column.clone());
         self.has_any = true;
@@ -1065,6 +1088,25 @@ impl AlignHandler {
     let flex_align = std::mem::take(&mut self.flex_align);
     let flex_item_align = std::mem::take(&mut self.flex_item_align);
 
+    // Browsers that don't support `start` and `end` within flexbox layouts support `flex-start` and `flex-end`,
+    // which behave the same as `start` and `end` in other layouts.
+    if let Some(targets) = self.targets {
+      if !compat::Feature::FlexStartEnd.is_compatible(targets) {
+        if let Some((AlignContent::ContentPosition(_, pos), _)) = &mut align_content {
+          *pos = pos.to_flex();
+        }
+        if let Some((JustifyContent::ContentPosition(_, pos), _)) = &mut justify_content {
+          *pos = pos.to_flex();
+        }
+        if let Some((AlignSelf::SelfPosition(_, pos), _)) = &mut align_self {
+          *pos = pos.to_flex();
+        }
+        if let Some((AlignItems::SelfPosition(_, pos), _)) = &mut align_items {
+          *pos = pos.to_flex();
+        }
+      }
+    }
+
     // Gets prefixes for standard properties.
     macro_rules! prefixes {
       ($prop: ident) => {{
@@ -1229,17 +1271,33 @@ impl AlignHandler {
     standard_property!(AlignItems, align_items);
     unprefixed_property!(JustifyItems, justify_items);
 
+    // Browsers that only implemented gaps for grid layout support the legacy grid-gap names.
+    let legacy_gap = match self.targets {
+      Some(targets) => !compat::Feature::Gap.is_compatible(targets),
+      None => false,
+    };
+
     if row_gap.is_some() && column_gap.is_some() {
-      dest.push(Property::Gap(Gap {
+      let gap = Gap {
         row: row_gap.unwrap(),
         column: column_gap.unwrap(),
-      }))
+      };
+      if legacy_gap {
+        dest.push(Property::GridGap(gap.clone()))
+      }
+      dest.push(Property::Gap(gap))
     } else {
       if let Some(gap) = row_gap {
+        if legacy_gap {
+          dest.push(Property::GridRowGap(gap.clone()))
+        }
         dest.push(Property::RowGap(gap))
       }
 
       if let Some(gap) = column_gap {
+        if legacy_gap {
+          dest.push(Property::GridColumnGap(gap.clone()))
+        }
         dest.push(Property::ColumnGap(gap))
       }
     }
@@ -1266,7 +1324,10 @@ fn is_align_property(property_id: &PropertyId) -> bool {
     | PropertyId::PlaceItems
     | PropertyId::RowGap
     | PropertyId::ColumnGap
-    | PropertyId::Gap => true,
+    | PropertyId::Gap
+    | PropertyId::GridRowGap
+    | PropertyId::GridColumnGap
+    | PropertyId::GridGap => true,
     _ => false,
   }
 }
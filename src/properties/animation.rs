@@ -9,8 +9,11 @@ use crate::printer::Printer;
 use crate::properties::{Property, PropertyId, VendorPrefix};
 use crate::targets::Browsers;
 use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, Zero};
+use crate::values::ident::{is_reserved_keyword, CustomIdent, DashedIdent};
+use crate::values::length::{LengthPercentage, LengthPercentageOrAuto};
 use crate::values::number::CSSNumber;
-use crate::values::{easing::EasingFunction, ident::CustomIdent, time::Time};
+use crate::values::percentage::Percentage;
+use crate::values::{easing::EasingFunction, time::Time};
 use cssparser::*;
 use itertools::izip;
 use smallvec::SmallVec;
@@ -36,13 +39,12 @@ impl<'i> Parse<'i> for AnimationName<'i> {
       return Ok(AnimationName::None);
     }
 
-    let location = input.current_source_location();
-    let name = match *input.next()? {
-      Token::Ident(ref s) => s.into(),
-      Token::QuotedString(ref s) => s.into(),
-      ref t => return Err(location.new_unexpected_token_error(t.clone())),
-    };
-    Ok(AnimationName::Ident(CustomIdent(name)))
+    if let Ok(ident) = input.try_parse(CustomIdent::parse) {
+      return Ok(AnimationName::Ident(ident));
+    }
+
+    let name = input.expect_string()?;
+    Ok(AnimationName::Ident(CustomIdent(name.into())))
   }
 }
 
@@ -54,6 +56,12 @@ impl<'i> ToCss for AnimationName<'i> {
     match self {
       AnimationName::None => dest.write_str("none"),
       AnimationName::Ident(s) => {
+        // CSS-wide keywords and `none` must remain quoted.
+        if s.0.eq_ignore_ascii_case("none") || is_reserved_keyword(&s.0) {
+          serialize_string(&s.0, dest)?;
+          return Ok(());
+        }
+
         if let Some(css_module) = &mut dest.css_module {
           css_module.reference(&s.0, dest.loc.source_index)
         }
@@ -216,56 +224,697 @@ impl<'i> ToCss for Animation<'i> {
   where
     W: std::fmt::Write,
   {
-    self.name.to_css(dest)?;
-    match &self.name {
-      AnimationName::None => return Ok(()),
-      AnimationName::Ident(name) => {
-        if !self.duration.is_zero() || !self.delay.is_zero() {
-          dest.write_char(' ')?;
-          self.duration.to_css(dest)?;
-        }
+    let name = match &self.name {
+      AnimationName::None => return dest.write_str("none"),
+      AnimationName::Ident(name) => name,
+    };
 
-        if (self.timing_function != EasingFunction::Ease
-          && self.timing_function != EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0))
-          || EasingFunction::is_ident(&name.0)
-        {
-          dest.write_char(' ')?;
-          self.timing_function.to_css(dest)?;
-        }
+    // Names that match a keyword of another longhand are written after that keyword.
+    // Otherwise, the name would be parsed as the keyword when reading the value back in.
+    let name_last = EasingFunction::is_ident(&name.0)
+      || name.0 == "infinite"
+      || AnimationDirection::parse_string(&name.0).is_ok()
+      || AnimationFillMode::parse_string(&name.0).is_ok()
+      || AnimationPlayState::parse_string(&name.0).is_ok();
 
-        if !self.delay.is_zero() {
+    let mut needs_space = false;
+    macro_rules! space {
+      () => {
+        if needs_space {
           dest.write_char(' ')?;
-          self.delay.to_css(dest)?;
         }
+        needs_space = true;
+      };
+    }
 
-        if self.iteration_count != AnimationIterationCount::Number(1.0) || name.0 == "infinite" {
-          dest.write_char(' ')?;
-          self.iteration_count.to_css(dest)?;
-        }
+    if !name_last {
+      space!();
+      self.name.to_css(dest)?;
+    }
 
-        if self.direction != AnimationDirection::Normal || AnimationDirection::parse_string(&name.0).is_ok() {
-          dest.write_char(' ')?;
-          self.direction.to_css(dest)?;
+    if !self.duration.is_zero() || !self.delay.is_zero() {
+      space!();
+      self.duration.to_css(dest)?;
+    }
+
+    if (self.timing_function != EasingFunction::Ease
+      && self.timing_function != EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0))
+      || EasingFunction::is_ident(&name.0)
+    {
+      space!();
+      self.timing_function.to_css(dest)?;
+    }
+
+    if !self.delay.is_zero() {
+      space!();
+      self.delay.to_css(dest)?;
+    }
+
+    if self.iteration_count != AnimationIterationCount::Number(1.0) || name.0 == "infinite" {
+      space!();
+      self.iteration_count.to_css(dest)?;
+    }
+
+    if self.direction != AnimationDirection::Normal || AnimationDirection::parse_string(&name.0).is_ok() {
+      space!();
+      self.direction.to_css(dest)?;
+    }
+
+    if self.fill_mode != AnimationFillMode::None || AnimationFillMode::parse_string(&name.0).is_ok() {
+      space!();
+      self.fill_mode.to_css(dest)?;
+    }
+
+    if self.play_state != AnimationPlayState::Running || AnimationPlayState::parse_string(&name.0).is_ok() {
+      space!();
+      self.play_state.to_css(dest)?;
+    }
+
+    if name_last {
+      space!();
+      self.name.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// A list of animations.
+pub type AnimationList<'i> = SmallVec<[Animation<'i>; 1]>;
+
+/// A value for the [animation-timeline](https://drafts.csswg.org/css-animations-2/#animation-timeline) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum AnimationTimeline<'i> {
+  /// The animation is associated with the default document timeline.
+  Auto,
+  /// The animation is not associated with a timeline.
+  None,
+  /// A named scroll or view progress timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+  /// An anonymous scroll progress timeline.
+  Scroll(ScrollFunction),
+  /// An anonymous view progress timeline.
+  View(ViewFunction),
+}
+
+impl<'i> Parse<'i> for AnimationTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(AnimationTimeline::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(AnimationTimeline::None);
+    }
+
+    if let Ok(name) = input.try_parse(DashedIdent::parse) {
+      return Ok(AnimationTimeline::DashedIdent(name));
+    }
+
+    let location = input.current_source_location();
+    let f = input.expect_function()?.clone();
+    match_ignore_ascii_case! { &f,
+      "scroll" => Ok(AnimationTimeline::Scroll(input.parse_nested_block(ScrollFunction::parse)?)),
+      "view" => Ok(AnimationTimeline::View(input.parse_nested_block(ViewFunction::parse)?)),
+      _ => Err(location.new_unexpected_token_error(Token::Function(f.clone())))
+    }
+  }
+}
+
+impl<'i> ToCss for AnimationTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationTimeline::Auto => dest.write_str("auto"),
+      AnimationTimeline::None => dest.write_str("none"),
+      AnimationTimeline::DashedIdent(name) => name.to_css(dest),
+      AnimationTimeline::Scroll(scroll) => scroll.to_css(dest),
+      AnimationTimeline::View(view) => view.to_css(dest),
+    }
+  }
+}
+
+/// The [scroll()](https://drafts.csswg.org/scroll-animations-1/#scroll-notation) function,
+/// as used in the `animation-timeline` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollFunction {
+  /// The scroll container whose scroll position drives the timeline.
+  pub scroller: Scroller,
+  /// The axis of the scroll container.
+  pub axis: ScrollAxis,
+}
+
+impl<'i> Parse<'i> for ScrollFunction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut scroller = None;
+    let mut axis = None;
+    loop {
+      if scroller.is_none() {
+        scroller = input.try_parse(Scroller::parse).ok();
+        if scroller.is_some() {
+          continue;
         }
+      }
 
-        if self.fill_mode != AnimationFillMode::None || AnimationFillMode::parse_string(&name.0).is_ok() {
-          dest.write_char(' ')?;
-          self.fill_mode.to_css(dest)?;
+      if axis.is_none() {
+        axis = input.try_parse(ScrollAxis::parse).ok();
+        if axis.is_some() {
+          continue;
         }
+      }
+
+      break;
+    }
+
+    Ok(ScrollFunction {
+      scroller: scroller.unwrap_or_default(),
+      axis: axis.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for ScrollFunction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("scroll(")?;
+
+    let mut needs_space = false;
+    if self.scroller != Scroller::default() {
+      self.scroller.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.axis != ScrollAxis::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.axis.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
 
-        if self.play_state != AnimationPlayState::Running || AnimationPlayState::parse_string(&name.0).is_ok() {
+enum_property! {
+  /// A [`<scroller>`](https://drafts.csswg.org/scroll-animations-1/#typedef-scroller) value,
+  /// as used in the `scroll()` function.
+  pub enum Scroller {
+    /// The root element of the document.
+    "root": Root,
+    /// The nearest ancestor scroll container.
+    "nearest": Nearest,
+    /// The element itself.
+    "self": SelfElement,
+  }
+}
+
+impl Default for Scroller {
+  fn default() -> Self {
+    Scroller::Nearest
+  }
+}
+
+enum_property! {
+  /// An [`<axis>`](https://drafts.csswg.org/scroll-animations-1/#typedef-axis) value, as used in
+  /// the `scroll-timeline-axis` and `view-timeline-axis` properties, and the `scroll()` and `view()` functions.
+  pub enum ScrollAxis {
+    /// The block axis of the scroll container.
+    "block": Block,
+    /// The inline axis of the scroll container.
+    "inline": Inline,
+    /// The horizontal axis of the scroll container.
+    "x": X,
+    /// The vertical axis of the scroll container.
+    "y": Y,
+  }
+}
+
+impl Default for ScrollAxis {
+  fn default() -> Self {
+    ScrollAxis::Block
+  }
+}
+
+/// The [view()](https://drafts.csswg.org/scroll-animations-1/#view-notation) function,
+/// as used in the `animation-timeline` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewFunction {
+  /// The axis of the nearest ancestor scroll container.
+  pub axis: ScrollAxis,
+  /// An adjustment of the view progress visibility range.
+  pub inset: ViewTimelineInset,
+}
+
+impl<'i> Parse<'i> for ViewFunction {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let (axis, inset) = parse_view_timeline_options(input)?;
+    Ok(ViewFunction { axis, inset })
+  }
+}
+
+impl ToCss for ViewFunction {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("view(")?;
+
+    let mut needs_space = false;
+    if self.axis != ScrollAxis::default() {
+      self.axis.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.inset != ViewTimelineInset::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.inset.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+fn parse_view_timeline_options<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<(ScrollAxis, ViewTimelineInset), ParseError<'i, ParserError<'i>>> {
+  let mut axis = None;
+  let mut inset = None;
+  loop {
+    if axis.is_none() {
+      axis = input.try_parse(ScrollAxis::parse).ok();
+      if axis.is_some() {
+        continue;
+      }
+    }
+
+    if inset.is_none() {
+      inset = input.try_parse(ViewTimelineInset::parse).ok();
+      if inset.is_some() {
+        continue;
+      }
+    }
+
+    break;
+  }
+
+  Ok((axis.unwrap_or_default(), inset.unwrap_or_default()))
+}
+
+/// A value for the [view-timeline-inset](https://drafts.csswg.org/scroll-animations-1/#view-timeline-inset) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewTimelineInset {
+  /// The inset from the start of the scrollport.
+  pub start: LengthPercentageOrAuto,
+  /// The inset from the end of the scrollport.
+  pub end: LengthPercentageOrAuto,
+}
+
+impl Default for ViewTimelineInset {
+  fn default() -> Self {
+    ViewTimelineInset {
+      start: LengthPercentageOrAuto::Auto,
+      end: LengthPercentageOrAuto::Auto,
+    }
+  }
+}
+
+impl<'i> Parse<'i> for ViewTimelineInset {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let start = LengthPercentageOrAuto::parse(input)?;
+    let end = input.try_parse(LengthPercentageOrAuto::parse).unwrap_or_else(|_| start.clone());
+    Ok(ViewTimelineInset { start, end })
+  }
+}
+
+impl ToCss for ViewTimelineInset {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.start.to_css(dest)?;
+    if self.end != self.start {
+      dest.write_char(' ')?;
+      self.end.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [animation-composition](https://drafts.csswg.org/css-animations-2/#animation-composition) property.
+  pub enum AnimationComposition {
+    /// The result of compositing the effect value with the underlying value is simply the effect value.
+    Replace,
+    /// The effect value is added to the underlying value.
+    Add,
+    /// The effect value is accumulated onto the underlying value.
+    Accumulate,
+  }
+}
+
+enum_property! {
+  /// A [`<timeline-range-name>`](https://drafts.csswg.org/scroll-animations-1/#typedef-timeline-range-name) value,
+  /// as used in the `animation-range` properties.
+  pub enum TimelineRangeName {
+    /// The full range of the view progress timeline.
+    "cover": Cover,
+    /// The range during which the subject is fully contained within the scrollport.
+    "contain": Contain,
+    /// The range during which the subject is entering the scrollport.
+    "entry": Entry,
+    /// The range during which the subject is exiting the scrollport.
+    "exit": Exit,
+    /// The range during which the subject crosses the end edge of the scrollport.
+    "entry-crossing": EntryCrossing,
+    /// The range during which the subject crosses the start edge of the scrollport.
+    "exit-crossing": ExitCrossing,
+  }
+}
+
+/// The start or end of an [animation attachment range](https://drafts.csswg.org/scroll-animations-1/#animation-range),
+/// as used in the `animation-range-start` and `animation-range-end` properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum AnimationAttachmentRange {
+  /// The start or end of the animation's timeline.
+  Normal,
+  /// An offset from the start of the animation's timeline.
+  LengthPercentage {
+    /// The offset.
+    value: LengthPercentage,
+  },
+  /// An offset within a named timeline range.
+  TimelineRange {
+    /// The name of the timeline range.
+    name: TimelineRangeName,
+    /// The offset within the timeline range.
+    offset: LengthPercentage,
+  },
+}
+
+impl AnimationAttachmentRange {
+  fn parse<'i, 't>(
+    input: &mut Parser<'i, 't>,
+    default_offset: f32,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(AnimationAttachmentRange::Normal);
+    }
+
+    if let Ok(value) = input.try_parse(LengthPercentage::parse) {
+      return Ok(AnimationAttachmentRange::LengthPercentage { value });
+    }
+
+    let name = TimelineRangeName::parse(input)?;
+    let offset = input
+      .try_parse(LengthPercentage::parse)
+      .unwrap_or(LengthPercentage::Percentage(Percentage(default_offset)));
+    Ok(AnimationAttachmentRange::TimelineRange { name, offset })
+  }
+
+  fn to_css<W>(&self, dest: &mut Printer<W>, default_offset: f32) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnimationAttachmentRange::Normal => dest.write_str("normal"),
+      AnimationAttachmentRange::LengthPercentage { value } => value.to_css(dest),
+      AnimationAttachmentRange::TimelineRange { name, offset } => {
+        name.to_css(dest)?;
+        if *offset != LengthPercentage::Percentage(Percentage(default_offset)) {
           dest.write_char(' ')?;
-          self.play_state.to_css(dest)?;
+          offset.to_css(dest)?;
         }
+        Ok(())
       }
     }
+  }
+}
+
+/// A value for the [animation-range-start](https://drafts.csswg.org/scroll-animations-1/#animation-range-start) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationRangeStart(pub AnimationAttachmentRange);
+
+impl<'i> Parse<'i> for AnimationRangeStart {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // A timeline range name without an offset starts at 0%.
+    Ok(AnimationRangeStart(AnimationAttachmentRange::parse(input, 0.0)?))
+  }
+}
 
+impl ToCss for AnimationRangeStart {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest, 0.0)
+  }
+}
+
+/// A value for the [animation-range-end](https://drafts.csswg.org/scroll-animations-1/#animation-range-end) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationRangeEnd(pub AnimationAttachmentRange);
+
+impl<'i> Parse<'i> for AnimationRangeEnd {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    // A timeline range name without an offset ends at 100%.
+    Ok(AnimationRangeEnd(AnimationAttachmentRange::parse(input, 1.0)?))
+  }
+}
+
+impl ToCss for AnimationRangeEnd {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest, 1.0)
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [animation-range](https://drafts.csswg.org/scroll-animations-1/#animation-range) shorthand property.
+  pub struct AnimationRange {
+    /// The start of the animation's attachment range.
+    start: AnimationRangeStart(AnimationRangeStart),
+    /// The end of the animation's attachment range.
+    end: AnimationRangeEnd(AnimationRangeEnd),
+  }
+}
+
+impl AnimationRange {
+  /// Returns the end value that is implied when it is omitted from the shorthand.
+  fn default_end(start: &AnimationRangeStart) -> AnimationRangeEnd {
+    match &start.0 {
+      AnimationAttachmentRange::TimelineRange { name, .. } => {
+        AnimationRangeEnd(AnimationAttachmentRange::TimelineRange {
+          name: *name,
+          offset: LengthPercentage::Percentage(Percentage(1.0)),
+        })
+      }
+      _ => AnimationRangeEnd(AnimationAttachmentRange::Normal),
+    }
+  }
+}
+
+impl<'i> Parse<'i> for AnimationRange {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let start = AnimationRangeStart::parse(input)?;
+    let end = match input.try_parse(AnimationRangeEnd::parse) {
+      Ok(end) => end,
+      Err(_) => AnimationRange::default_end(&start),
+    };
+    Ok(AnimationRange { start, end })
+  }
+}
+
+impl ToCss for AnimationRange {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.start.to_css(dest)?;
+    if self.end != AnimationRange::default_end(&self.start) {
+      dest.write_char(' ')?;
+      self.end.to_css(dest)?;
+    }
     Ok(())
   }
 }
 
-/// A list of animations.
-pub type AnimationList<'i> = SmallVec<[Animation<'i>; 1]>;
+/// A timeline name, as used in the [scroll-timeline-name](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-name)
+/// and [view-timeline-name](https://drafts.csswg.org/scroll-animations-1/#view-timeline-name) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TimelineName<'i> {
+  /// No timeline is declared.
+  None,
+  /// The name of the timeline.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdent(DashedIdent<'i>),
+}
+
+impl<'i> Parse<'i> for TimelineName<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineName::None);
+    }
+
+    Ok(TimelineName::DashedIdent(DashedIdent::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for TimelineName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineName::None => dest.write_str("none"),
+      TimelineName::DashedIdent(name) => name.to_css(dest),
+    }
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [scroll-timeline](https://drafts.csswg.org/scroll-animations-1/#scroll-timeline-shorthand) shorthand property.
+  pub struct ScrollTimeline<'i> {
+    /// The name of the timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ScrollTimelineName(TimelineName<'i>),
+    /// The axis of the scroll container.
+    axis: ScrollTimelineAxis(ScrollAxis),
+  }
+}
+
+impl<'i> Parse<'i> for ScrollTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let axis = input.try_parse(ScrollAxis::parse).unwrap_or_default();
+    Ok(ScrollTimeline { name, axis })
+  }
+}
+
+impl<'i> ToCss for ScrollTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+define_list_shorthand! {
+  /// A value for the [view-timeline](https://drafts.csswg.org/scroll-animations-1/#view-timeline-shorthand) shorthand property.
+  pub struct ViewTimeline<'i> {
+    /// The name of the timeline.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: ViewTimelineName(TimelineName<'i>),
+    /// The axis of the nearest ancestor scroll container.
+    axis: ViewTimelineAxis(ScrollAxis),
+    /// An adjustment of the view progress visibility range.
+    inset: ViewTimelineInset(ViewTimelineInset),
+  }
+}
+
+impl<'i> Parse<'i> for ViewTimeline<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = TimelineName::parse(input)?;
+    let (axis, inset) = parse_view_timeline_options(input)?;
+    Ok(ViewTimeline { name, axis, inset })
+  }
+}
+
+impl<'i> ToCss for ViewTimeline<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.name.to_css(dest)?;
+    if self.axis != ScrollAxis::default() {
+      dest.write_char(' ')?;
+      self.axis.to_css(dest)?;
+    }
+    if self.inset != ViewTimelineInset::default() {
+      dest.write_char(' ')?;
+      self.inset.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [timeline-scope](https://drafts.csswg.org/scroll-animations-1/#timeline-scope) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TimelineScope<'i> {
+  /// No timeline names are in scope.
+  None,
+  /// All timeline names declared by descendants are in scope.
+  All,
+  /// A list of timeline names.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  DashedIdents(SmallVec<[DashedIdent<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for TimelineScope<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TimelineScope::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("all")).is_ok() {
+      return Ok(TimelineScope::All);
+    }
+
+    Ok(TimelineScope::DashedIdents(SmallVec::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for TimelineScope<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TimelineScope::None => dest.write_str("none"),
+      TimelineScope::All => dest.write_str("all"),
+      TimelineScope::DashedIdents(names) => names.to_css(dest),
+    }
+  }
+}
 
 #[derive(Default)]
 pub(crate) struct AnimationHandler<'i> {
@@ -278,6 +927,10 @@ pub(crate) struct AnimationHandler<'i> {
   play_states: Option<(SmallVec<[AnimationPlayState; 1]>, VendorPrefix)>,
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   fill_modes: Option<(SmallVec<[AnimationFillMode; 1]>, VendorPrefix)>,
+  timelines: Option<SmallVec<[AnimationTimeline<'i>; 1]>>,
+  compositions: Option<SmallVec<[AnimationComposition; 1]>>,
+  range_starts: Option<SmallVec<[AnimationRangeStart; 1]>>,
+  range_ends: Option<SmallVec<[AnimationRangeEnd; 1]>>,
   has_any: bool,
 }
 
@@ -368,6 +1021,36 @@ impl<'i> PropertyHandler<'i> for AnimationHandler<'i> {
         property!(play_states, &play_states, vp);
         property!(delays, &delays, vp);
         property!(fill_modes, &fill_modes, vp);
+
+        // The unprefixed shorthand resets animation-timeline, animation-composition,
+        // and animation-range to their initial values.
+        if vp.contains(VendorPrefix::None) {
+          self.timelines = None;
+          self.compositions = None;
+          self.range_starts = None;
+          self.range_ends = None;
+        }
+      }
+      AnimationTimeline(val) => {
+        self.timelines = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationComposition(val) => {
+        self.compositions = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRangeStart(val) => {
+        self.range_starts = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRangeEnd(val) => {
+        self.range_ends = Some(val.clone());
+        self.has_any = true;
+      }
+      AnimationRange(val) => {
+        self.range_starts = Some(val.iter().map(|r| r.start.clone()).collect());
+        self.range_ends = Some(val.iter().map(|r| r.end.clone()).collect());
+        self.has_any = true;
       }
       Unparsed(val) if is_animation_property(&val.property_id) => {
         self.flush(dest);
@@ -400,6 +1083,10 @@ impl<'i> AnimationHandler<'i> {
     let mut play_states = std::mem::take(&mut self.play_states);
     let mut delays = std::mem::take(&mut self.delays);
     let mut fill_modes = std::mem::take(&mut self.fill_modes);
+    let timelines = std::mem::take(&mut self.timelines);
+    let compositions = std::mem::take(&mut self.compositions);
+    let range_starts = std::mem::take(&mut self.range_starts);
+    let range_ends = std::mem::take(&mut self.range_ends);
 
     if let (
       Some((names, names_vp)),
@@ -506,6 +1193,30 @@ impl<'i> AnimationHandler<'i> {
     prop!(play_states, AnimationPlayState);
     prop!(delays, AnimationDelay);
     prop!(fill_modes, AnimationFillMode);
+
+    // Output the reset-only properties last so that they aren't reset by the shorthand.
+    if let Some(timelines) = timelines {
+      dest.push(Property::AnimationTimeline(timelines));
+    }
+
+    if let Some(compositions) = compositions {
+      dest.push(Property::AnimationComposition(compositions));
+    }
+
+    match (range_starts, range_ends) {
+      (Some(starts), Some(ends)) if starts.len() == ends.len() => {
+        let ranges = izip!(starts, ends).map(|(start, end)| AnimationRange { start, end }).collect();
+        dest.push(Property::AnimationRange(ranges));
+      }
+      (starts, ends) => {
+        if let Some(starts) = starts {
+          dest.push(Property::AnimationRangeStart(starts));
+        }
+        if let Some(ends) = ends {
+          dest.push(Property::AnimationRangeEnd(ends));
+        }
+      }
+    }
   }
 }
 
@@ -520,7 +1231,127 @@ fn is_animation_property(property_id: &PropertyId) -> bool {
     | PropertyId::AnimationPlayState(_)
     | PropertyId::AnimationDelay(_)
     | PropertyId::AnimationFillMode(_)
+    | PropertyId::AnimationTimeline
+    | PropertyId::AnimationComposition
+    | PropertyId::AnimationRangeStart
+    | PropertyId::AnimationRangeEnd
+    | PropertyId::AnimationRange
     | PropertyId::Animation(_) => true,
     _ => false,
   }
 }
+
+#[derive(Default)]
+pub(crate) struct TimelineHandler<'i> {
+  scroll_names: Option<SmallVec<[TimelineName<'i>; 1]>>,
+  scroll_axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  view_names: Option<SmallVec<[TimelineName<'i>; 1]>>,
+  view_axes: Option<SmallVec<[ScrollAxis; 1]>>,
+  view_insets: Option<SmallVec<[ViewTimelineInset; 1]>>,
+  has_any: bool,
+}
+
+impl<'i> PropertyHandler<'i> for TimelineHandler<'i> {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    _: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ScrollTimelineName(val) => self.scroll_names = Some(val.clone()),
+      Property::ScrollTimelineAxis(val) => self.scroll_axes = Some(val.clone()),
+      Property::ScrollTimeline(val) => {
+        self.scroll_names = Some(val.iter().map(|t| t.name.clone()).collect());
+        self.scroll_axes = Some(val.iter().map(|t| t.axis).collect());
+      }
+      Property::ViewTimelineName(val) => self.view_names = Some(val.clone()),
+      Property::ViewTimelineAxis(val) => self.view_axes = Some(val.clone()),
+      Property::ViewTimelineInset(val) => self.view_insets = Some(val.clone()),
+      Property::ViewTimeline(val) => {
+        self.view_names = Some(val.iter().map(|t| t.name.clone()).collect());
+        self.view_axes = Some(val.iter().map(|t| t.axis).collect());
+        self.view_insets = Some(val.iter().map(|t| t.inset.clone()).collect());
+      }
+      Property::Unparsed(val) if is_timeline_property(&val.property_id) => {
+        self.flush(dest);
+        dest.push(property.clone());
+        return true;
+      }
+      _ => return false,
+    }
+
+    self.has_any = true;
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest);
+  }
+}
+
+impl<'i> TimelineHandler<'i> {
+  fn flush(&mut self, dest: &mut DeclarationList<'i>) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let scroll_names = std::mem::take(&mut self.scroll_names);
+    let scroll_axes = std::mem::take(&mut self.scroll_axes);
+    let view_names = std::mem::take(&mut self.view_names);
+    let view_axes = std::mem::take(&mut self.view_axes);
+    let view_insets = std::mem::take(&mut self.view_insets);
+
+    // Only use shorthand syntax if the number of timelines matches on all properties.
+    match (scroll_names, scroll_axes) {
+      (Some(names), Some(axes)) if names.len() == axes.len() => {
+        let timelines = izip!(names, axes).map(|(name, axis)| ScrollTimeline { name, axis }).collect();
+        dest.push(Property::ScrollTimeline(timelines));
+      }
+      (names, axes) => {
+        if let Some(names) = names {
+          dest.push(Property::ScrollTimelineName(names));
+        }
+        if let Some(axes) = axes {
+          dest.push(Property::ScrollTimelineAxis(axes));
+        }
+      }
+    }
+
+    match (view_names, view_axes, view_insets) {
+      (Some(names), Some(axes), Some(insets)) if names.len() == axes.len() && names.len() == insets.len() => {
+        let timelines = izip!(names, axes, insets)
+          .map(|(name, axis, inset)| ViewTimeline { name, axis, inset })
+          .collect();
+        dest.push(Property::ViewTimeline(timelines));
+      }
+      (names, axes, insets) => {
+        if let Some(names) = names {
+          dest.push(Property::ViewTimelineName(names));
+        }
+        if let Some(axes) = axes {
+          dest.push(Property::ViewTimelineAxis(axes));
+        }
+        if let Some(insets) = insets {
+          dest.push(Property::ViewTimelineInset(insets));
+        }
+      }
+    }
+  }
+}
+
+#[inline]
+fn is_timeline_property(property_id: &PropertyId) -> bool {
+  match property_id {
+    PropertyId::ScrollTimelineName
+    | PropertyId::ScrollTimelineAxis
+    | PropertyId::ScrollTimeline
+    | PropertyId::ViewTimelineName
+    | PropertyId::ViewTimelineAxis
+    | PropertyId::ViewTimelineInset
+    | PropertyId::ViewTimeline => true,
+    _ => false,
+  }
+}
@@ -28,7 +28,7 @@ enum_property! {
     Stretch,
     /// The image is tiled (repeated) to fill the area.
     Repeat,
-     /// The image is scaled so that it repeats an even number of times.
+    /// The image is scaled so that it repeats an even number of times.
     Round,
     /// The image is repeated so that it fits, and then spaced apart evenly.
     Space,
@@ -54,8 +54,8 @@ impl<'i> Parse<'i> for BorderRadius {
     Ok(BorderRadius {
       top_left: Size2D(widths.0, heights.0),
       top_right: Size2D(widths.1, heights.1),
-      bottom_left: Size2D(widths.2, heights.2),
-      bottom_right: Size2D(widths.3, heights.3),
+      bottom_right: Size2D(widths.2, heights.2),
+      bottom_left: Size2D(widths.3, heights.3),
     })
   }
 }
@@ -68,14 +68,14 @@ impl ToCss for BorderRadius {
     let widths = Rect::new(
       &self.top_left.0,
       &self.top_right.0,
-      &self.bottom_left.0,
       &self.bottom_right.0,
+      &self.bottom_left.0,
     );
     let heights = Rect::new(
       &self.top_left.1,
       &self.top_right.1,
-      &self.bottom_left.1,
       &self.bottom_right.1,
+      &self.bottom_left.1,
     );
 
     widths.to_css(dest)?;
@@ -8,7 +8,7 @@ use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::Property;
 use crate::targets::Browsers;
-use crate::traits::{Parse, PropertyHandler, ToCss, Zero};
+use crate::traits::{Parse, PropertyHandler, ToCss, TrySign, Zero};
 use crate::values::color::{ColorFallbackKind, CssColor};
 use crate::values::length::Length;
 use crate::vendor_prefix::VendorPrefix;
@@ -51,7 +51,11 @@ impl<'i> Parse<'i> for BoxShadow {
         let value = input.try_parse::<_, _, ParseError<ParserError<'i>>>(|input| {
           let horizontal = Length::parse(input)?;
           let vertical = Length::parse(input)?;
+          let location = input.current_source_location();
           let blur = input.try_parse(Length::parse).unwrap_or(Length::zero());
+          if blur.try_sign().map_or(false, |sign| sign < 0.0) {
+            return Err(location.new_custom_error(ParserError::InvalidValue));
+          }
           let spread = input.try_parse(Length::parse).unwrap_or(Length::zero());
           Ok((horizontal, vertical, blur, spread))
         });
@@ -155,7 +159,7 @@ impl<'i> PropertyHandler<'i> for BoxShadowHandler {
       Property::Unparsed(unparsed) if matches!(unparsed.property_id, PropertyId::BoxShadow(_)) => {
         self.finalize(dest, context);
 
-        let mut unparsed = unparsed.clone();
+        let mut unparsed = unparsed.get_prefixed(self.targets, Feature::BoxShadow);
         context.add_unparsed_fallbacks(&mut unparsed);
         dest.push(Property::Unparsed(unparsed))
       }
@@ -0,0 +1,441 @@
+//! CSS properties related to multi-column layout.
+
+use super::border::{BorderSideWidth, GenericBorder, LineStyle};
+use super::{Property, PropertyId};
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
+use crate::error::{ParserError, PrinterError};
+use crate::macros::{define_shorthand, enum_property, impl_shorthand};
+use crate::prefixes::Feature;
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, TrySign};
+use crate::values::color::CssColor;
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+use crate::vendor_prefix::VendorPrefix;
+use cssparser::*;
+
+/// A value for the [column-width](https://drafts.csswg.org/css-multicol/#cw) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ColumnWidth {
+  /// The column width is determined by other properties.
+  Auto,
+  /// The optimal column width.
+  Length(Length),
+}
+
+impl Default for ColumnWidth {
+  fn default() -> ColumnWidth {
+    ColumnWidth::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ColumnWidth {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnWidth::Auto);
+    }
+
+    let location = input.current_source_location();
+    let length = Length::parse(input)?;
+    if length.try_sign().map_or(false, |sign| sign < 0.0) {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ColumnWidth::Length(length))
+  }
+}
+
+impl ToCss for ColumnWidth {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnWidth::Auto => dest.write_str("auto"),
+      ColumnWidth::Length(length) => length.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [column-count](https://drafts.csswg.org/css-multicol/#cc) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ColumnCount {
+  /// The number of columns is determined by other properties.
+  Auto,
+  /// The optimal number of columns.
+  Integer(CSSInteger),
+}
+
+impl Default for ColumnCount {
+  fn default() -> ColumnCount {
+    ColumnCount::Auto
+  }
+}
+
+impl<'i> Parse<'i> for ColumnCount {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ColumnCount::Auto);
+    }
+
+    let location = input.current_source_location();
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ColumnCount::Integer(count))
+  }
+}
+
+impl ToCss for ColumnCount {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColumnCount::Auto => dest.write_str("auto"),
+      ColumnCount::Integer(count) => count.to_css(dest),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [columns](https://drafts.csswg.org/css-multicol/#columns) shorthand property.
+  pub struct Columns(VendorPrefix) {
+    /// The column width.
+    width: ColumnWidth(ColumnWidth, VendorPrefix),
+    /// The column count.
+    count: ColumnCount(ColumnCount, VendorPrefix),
+  }
+}
+
+impl<'i> Parse<'i> for Columns {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut width = None;
+    let mut count = None;
+    let mut autos = 0;
+    for _ in 0..2 {
+      // `auto` is ambiguous, and applies to whichever value is not otherwise specified.
+      if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+        autos += 1;
+        continue;
+      }
+
+      // Unitless numbers are parsed as lengths, so the count must be parsed first.
+      if count.is_none() {
+        if let Ok(value) = input.try_parse(ColumnCount::parse) {
+          count = Some(value);
+          continue;
+        }
+      }
+
+      if width.is_none() {
+        if let Ok(value) = input.try_parse(ColumnWidth::parse) {
+          width = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if autos == 0 && width.is_none() && count.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(Columns {
+      width: width.unwrap_or_default(),
+      count: count.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for Columns {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match (&self.width, &self.count) {
+      (ColumnWidth::Auto, ColumnCount::Auto) => dest.write_str("auto"),
+      (width, ColumnCount::Auto) => width.to_css(dest),
+      (ColumnWidth::Auto, count) => count.to_css(dest),
+      (width, count) => {
+        width.to_css(dest)?;
+        dest.write_char(' ')?;
+        count.to_css(dest)
+      }
+    }
+  }
+}
+
+/// A value for the [column-rule](https://drafts.csswg.org/css-multicol/#column-rule) shorthand property.
+pub type ColumnRule = GenericBorder<LineStyle, 12>;
+
+impl_shorthand! {
+  ColumnRule(ColumnRule, VendorPrefix) {
+    width: [ColumnRuleWidth(VendorPrefix)],
+    style: [ColumnRuleStyle(VendorPrefix)],
+    color: [ColumnRuleColor(VendorPrefix)],
+  }
+}
+
+enum_property! {
+  /// A value for the [column-span](https://drafts.csswg.org/css-multicol/#column-span) property.
+  pub enum ColumnSpan {
+    /// The element does not span multiple columns.
+    "none": None,
+    /// The element spans across all columns.
+    "all": All,
+  }
+}
+
+enum_property! {
+  /// A value for the [column-fill](https://drafts.csswg.org/css-multicol/#cf) property.
+  pub enum ColumnFill {
+    /// Columns are filled sequentially.
+    "auto": Auto,
+    /// Content is balanced equally between columns, in the last fragment only.
+    "balance": Balance,
+    /// Content is balanced equally between columns, in all fragments.
+    "balance-all": BalanceAll,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ColumnsHandler {
+  targets: Option<Browsers>,
+  width: Option<(ColumnWidth, VendorPrefix)>,
+  count: Option<(ColumnCount, VendorPrefix)>,
+  rule_width: Option<(BorderSideWidth, VendorPrefix)>,
+  rule_style: Option<(LineStyle, VendorPrefix)>,
+  rule_color: Option<(CssColor, VendorPrefix)>,
+  span: Option<(ColumnSpan, VendorPrefix)>,
+  fill: Option<(ColumnFill, VendorPrefix)>,
+  has_any: bool,
+}
+
+impl ColumnsHandler {
+  pub fn new(targets: Option<Browsers>) -> ColumnsHandler {
+    ColumnsHandler {
+      targets,
+      ..ColumnsHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for ColumnsHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    _: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    macro_rules! maybe_flush {
+      ($prop: ident, $val: expr, $vp: ident) => {{
+        // If two vendor prefixes for the same property have different
+        // values, we need to flush what we have immediately to preserve order.
+        if let Some((val, prefixes)) = &self.$prop {
+          if val != $val && !prefixes.contains(*$vp) {
+            self.flush(dest);
+          }
+        }
+      }};
+    }
+
+    macro_rules! property {
+      ($prop: ident, $val: expr, $vp: ident) => {{
+        maybe_flush!($prop, $val, $vp);
+
+        // Otherwise, update the value and add the prefix.
+        if let Some((val, prefixes)) = &mut self.$prop {
+          *val = $val.clone();
+          *prefixes |= *$vp;
+        } else {
+          self.$prop = Some(($val.clone(), *$vp));
+          self.has_any = true;
+        }
+      }};
+    }
+
+    match property {
+      Property::ColumnWidth(val, vp) => property!(width, val, vp),
+      Property::ColumnCount(val, vp) => property!(count, val, vp),
+      Property::Columns(val, vp) => {
+        maybe_flush!(width, &val.width, vp);
+        maybe_flush!(count, &val.count, vp);
+        property!(width, &val.width, vp);
+        property!(count, &val.count, vp);
+      }
+      Property::ColumnRuleWidth(val, vp) => property!(rule_width, val, vp),
+      Property::ColumnRuleStyle(val, vp) => property!(rule_style, val, vp),
+      Property::ColumnRuleColor(val, vp) => property!(rule_color, val, vp),
+      Property::ColumnRule(val, vp) => {
+        maybe_flush!(rule_width, &val.width, vp);
+        maybe_flush!(rule_style, &val.style, vp);
+        maybe_flush!(rule_color, &val.color, vp);
+        property!(rule_width, &val.width, vp);
+        property!(rule_style, &val.style, vp);
+        property!(rule_color, &val.color, vp);
+      }
+      Property::ColumnSpan(val, vp) => property!(span, val, vp),
+      Property::ColumnFill(val, vp) => property!(fill, val, vp),
+      Property::Unparsed(val) if is_columns_property(&val.property_id) => {
+        self.flush(dest);
+
+        // Even if we weren't able to parse the value (e.g. due to var() references),
+        // we can still add vendor prefixes to the property itself.
+        let feature = match &val.property_id {
+          PropertyId::ColumnWidth(_) => Some(Feature::ColumnWidth),
+          PropertyId::ColumnCount(_) => Some(Feature::ColumnCount),
+          PropertyId::Columns(_) => Some(Feature::Columns),
+          PropertyId::ColumnRuleWidth(_) => Some(Feature::ColumnRuleWidth),
+          PropertyId::ColumnRuleStyle(_) => Some(Feature::ColumnRuleStyle),
+          PropertyId::ColumnRuleColor(_) => Some(Feature::ColumnRuleColor),
+          PropertyId::ColumnRule(_) => Some(Feature::ColumnRule),
+          PropertyId::ColumnSpan(_) => Some(Feature::ColumnSpan),
+          PropertyId::ColumnFill(_) => Some(Feature::ColumnFill),
+          _ => None,
+        };
+
+        match (feature, self.targets) {
+          (Some(feature), Some(targets)) if val.property_id.prefix().contains(VendorPrefix::None) => {
+            let mut prefix = feature.prefixes_for(targets);
+            if matches!(val.property_id, PropertyId::ColumnSpan(_)) {
+              prefix.remove(VendorPrefix::Moz);
+            }
+            dest.push(Property::Unparsed(
+              val.with_property_id(val.property_id.with_prefix(prefix)),
+            ))
+          }
+          _ => dest.push(property.clone()),
+        }
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest);
+  }
+}
+
+impl ColumnsHandler {
+  fn flush(&mut self, dest: &mut DeclarationList) {
+    if !self.has_any {
+      return;
+    }
+
+    self.has_any = false;
+
+    let mut width = std::mem::take(&mut self.width);
+    let mut count = std::mem::take(&mut self.count);
+    let mut rule_width = std::mem::take(&mut self.rule_width);
+    let mut rule_style = std::mem::take(&mut self.rule_style);
+    let mut rule_color = std::mem::take(&mut self.rule_color);
+    let span = std::mem::take(&mut self.span);
+    let fill = std::mem::take(&mut self.fill);
+
+    macro_rules! prefix {
+      ($prefix: expr, $feature: ident) => {{
+        let mut prefix = $prefix;
+        if prefix.contains(VendorPrefix::None) {
+          if let Some(targets) = self.targets {
+            prefix = Feature::$feature.prefixes_for(targets);
+          }
+        }
+        prefix
+      }};
+    }
+
+    macro_rules! single_property {
+      ($prop: ident, $key: ident) => {
+        if let Some((val, prefix)) = $key {
+          if !prefix.is_empty() {
+            dest.push(Property::$prop(val, prefix!(prefix, $prop)))
+          }
+        }
+      };
+    }
+
+    if let (Some((width, width_prefix)), Some((count, count_prefix))) = (&mut width, &mut count) {
+      let intersection = *width_prefix & *count_prefix;
+      if !intersection.is_empty() {
+        dest.push(Property::Columns(
+          Columns {
+            width: width.clone(),
+            count: count.clone(),
+          },
+          prefix!(intersection, Columns),
+        ));
+        width_prefix.remove(intersection);
+        count_prefix.remove(intersection);
+      }
+    }
+
+    single_property!(ColumnWidth, width);
+    single_property!(ColumnCount, count);
+
+    if let (Some((rule_width, width_prefix)), Some((rule_style, style_prefix)), Some((rule_color, color_prefix))) =
+      (&mut rule_width, &mut rule_style, &mut rule_color)
+    {
+      let intersection = *width_prefix & *style_prefix & *color_prefix;
+      if !intersection.is_empty() {
+        dest.push(Property::ColumnRule(
+          ColumnRule {
+            width: rule_width.clone(),
+            style: rule_style.clone(),
+            color: rule_color.clone(),
+          },
+          prefix!(intersection, ColumnRule),
+        ));
+        width_prefix.remove(intersection);
+        style_prefix.remove(intersection);
+        color_prefix.remove(intersection);
+      }
+    }
+
+    single_property!(ColumnRuleWidth, rule_width);
+    single_property!(ColumnRuleStyle, rule_style);
+    single_property!(ColumnRuleColor, rule_color);
+    if let Some((span, prefix)) = span {
+      let mut prefix = prefix!(prefix, ColumnSpan);
+      // Firefox never implemented a prefixed column-span.
+      prefix.remove(VendorPrefix::Moz);
+      if !prefix.is_empty() {
+        dest.push(Property::ColumnSpan(span, prefix))
+      }
+    }
+    single_property!(ColumnFill, fill);
+  }
+}
+
+#[inline]
+fn is_columns_property(property_id: &PropertyId) -> bool {
+  match property_id {
+    PropertyId::ColumnWidth(_)
+    | PropertyId::ColumnCount(_)
+    | PropertyId::Columns(_)
+    | PropertyId::ColumnRuleWidth(_)
+    | PropertyId::ColumnRuleStyle(_)
+    | PropertyId::ColumnRuleColor(_)
+    | PropertyId::ColumnRule(_)
+    | PropertyId::ColumnSpan(_)
+    | PropertyId::ColumnFill(_) => true,
+    _ => false,
+  }
+}
@@ -10,14 +10,206 @@ use crate::{
   context::PropertyHandlerContext,
   declaration::{DeclarationBlock, DeclarationList},
   error::{ParserError, PrinterError},
-  macros::{define_shorthand, shorthand_handler},
+  macros::{define_shorthand, enum_property, shorthand_handler},
   printer::Printer,
   properties::{Property, PropertyId},
   rules::container::ContainerName as ContainerIdent,
   targets::Browsers,
-  traits::{Parse, PropertyHandler, Shorthand, ToCss},
+  traits::{Parse, PropertyHandler, Shorthand, ToCss, TrySign},
+  values::length::Length,
 };
 
+bitflags! {
+  /// A value for the [contain](https://drafts.csswg.org/css-contain-2/#contain-property) property.
+  /// Indicates that an element and its contents are independent of the rest of the document tree.
+  ///
+  /// An empty set of flags represents `none`. The `strict` and `content` keywords are shorthands
+  /// for combinations of the other flags, and are used when serializing where possible.
+  #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+  pub struct Contain: u8 {
+    /// Size containment in both axes.
+    const Size       = 0b00000001;
+    /// Size containment in the inline axis.
+    const InlineSize = 0b00000010;
+    /// Layout containment.
+    const Layout     = 0b00000100;
+    /// Style containment.
+    const Style      = 0b00001000;
+    /// Paint containment.
+    const Paint      = 0b00010000;
+    /// Equivalent to `size layout style paint`.
+    const Strict = Self::Size.bits | Self::Layout.bits | Self::Style.bits | Self::Paint.bits;
+    /// Equivalent to `layout style paint`.
+    const Content = Self::Layout.bits | Self::Style.bits | Self::Paint.bits;
+  }
+}
+
+impl<'i> Parse<'i> for Contain {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut flags = Contain::empty();
+    while let Ok(ident) = input.try_parse(|input| input.expect_ident_cloned()) {
+      let location = input.current_source_location();
+      let flag = match_ignore_ascii_case! { &ident,
+        // The single keywords cannot be combined with others.
+        "none" if flags.is_empty() => return Ok(Contain::empty()),
+        "strict" if flags.is_empty() => return Ok(Contain::Strict),
+        "content" if flags.is_empty() => return Ok(Contain::Content),
+        "size" => Contain::Size,
+        "inline-size" => Contain::InlineSize,
+        "layout" => Contain::Layout,
+        "style" => Contain::Style,
+        "paint" => Contain::Paint,
+        _ => return Err(location.new_unexpected_token_error(
+          cssparser::Token::Ident(ident.clone())
+        ))
+      };
+      // Each keyword may only appear once, and size and inline-size are mutually exclusive.
+      let size = Contain::Size | Contain::InlineSize;
+      if flags.intersects(flag) || (flags.intersects(size) && flag.intersects(size)) {
+        return Err(location.new_unexpected_token_error(cssparser::Token::Ident(ident.clone())));
+      }
+      flags |= flag;
+    }
+
+    if flags.is_empty() {
+      return Err(input.new_error_for_next_token());
+    } else {
+      return Ok(flags);
+    }
+  }
+}
+
+impl ToCss for Contain {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.is_empty() {
+      return dest.write_str("none");
+    }
+
+    if *self == Contain::Strict {
+      return dest.write_str("strict");
+    }
+
+    if *self == Contain::Content {
+      return dest.write_str("content");
+    }
+
+    let mut first = true;
+    macro_rules! flag {
+      ($flag: ident, $name: literal) => {
+        if self.contains(Contain::$flag) {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($name)?;
+        }
+      };
+    }
+
+    flag!(Size, "size");
+    flag!(InlineSize, "inline-size");
+    flag!(Layout, "layout");
+    flag!(Style, "style");
+    flag!(Paint, "paint");
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [content-visibility](https://drafts.csswg.org/css-contain-2/#content-visibility) property.
+  pub enum ContentVisibility {
+    /// The element's contents are rendered normally.
+    "visible": Visible,
+    /// The element skips its contents, which are not accessible to user agent features.
+    "hidden": Hidden,
+    /// The element skips its contents while they are not relevant to the user.
+    "auto": Auto,
+  }
+}
+
+/// A value for the [contain-intrinsic-width](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override),
+/// `contain-intrinsic-height`, `contain-intrinsic-block-size`, and `contain-intrinsic-inline-size` properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContainIntrinsicLength {
+  /// Whether the last remembered size of the element is used, if any.
+  pub auto: bool,
+  /// The explicit intrinsic size of the element, or `None` if there is none.
+  pub length: Option<Length>,
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicLength {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let auto = input.try_parse(|input| input.expect_ident_matching("auto")).is_ok();
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ContainIntrinsicLength { auto, length: None });
+    }
+
+    let location = input.current_source_location();
+    let length = Length::parse(input)?;
+    if length.try_sign().map_or(false, |sign| sign < 0.0) {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(ContainIntrinsicLength {
+      auto,
+      length: Some(length),
+    })
+  }
+}
+
+impl ToCss for ContainIntrinsicLength {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto ")?;
+    }
+
+    match &self.length {
+      Some(length) => length.to_css(dest),
+      None => dest.write_str("none"),
+    }
+  }
+}
+
+define_shorthand! {
+  /// A value for the [contain-intrinsic-size](https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override) shorthand property.
+  pub struct ContainIntrinsicSize {
+    /// The intrinsic width.
+    width: ContainIntrinsicWidth(ContainIntrinsicLength),
+    /// The intrinsic height.
+    height: ContainIntrinsicHeight(ContainIntrinsicLength),
+  }
+}
+
+impl<'i> Parse<'i> for ContainIntrinsicSize {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let width = ContainIntrinsicLength::parse(input)?;
+    let height = input.try_parse(ContainIntrinsicLength::parse).unwrap_or_else(|_| width.clone());
+    Ok(ContainIntrinsicSize { width, height })
+  }
+}
+
+impl ToCss for ContainIntrinsicSize {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.width.to_css(dest)?;
+    if self.height != self.width {
+      dest.write_char(' ')?;
+      self.height.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 bitflags! {
   /// A value for the [container-type](https://drafts.csswg.org/css-contain-3/#container-type) property.
   /// Establishes the element as a query container for the purpose of container queries.
@@ -189,3 +381,66 @@ shorthand_handler!(ContainerHandler -> Container<'i> {
   name: ContainerName(ContainerNameList<'i>),
   container_type: ContainerType(ContainerType),
 });
+
+#[derive(Default)]
+pub(crate) struct ContainIntrinsicSizeHandler {
+  width: Option<ContainIntrinsicLength>,
+  height: Option<ContainIntrinsicLength>,
+}
+
+impl<'i> PropertyHandler<'i> for ContainIntrinsicSizeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ContainIntrinsicWidth(val) => self.width = Some(val.clone()),
+      Property::ContainIntrinsicHeight(val) => self.height = Some(val.clone()),
+      Property::ContainIntrinsicSize(val) => {
+        self.width = Some(val.width.clone());
+        self.height = Some(val.height.clone());
+      }
+      // Logical properties are not part of the shorthand, but may override the physical ones,
+      // so the order of declarations must be preserved.
+      Property::ContainIntrinsicBlockSize(..) | Property::ContainIntrinsicInlineSize(..) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::ContainIntrinsicWidth
+            | PropertyId::ContainIntrinsicHeight
+            | PropertyId::ContainIntrinsicSize
+            | PropertyId::ContainIntrinsicBlockSize
+            | PropertyId::ContainIntrinsicInlineSize
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    match (std::mem::take(&mut self.width), std::mem::take(&mut self.height)) {
+      (Some(width), Some(height)) => {
+        dest.push(Property::ContainIntrinsicSize(ContainIntrinsicSize { width, height }))
+      }
+      (width, height) => {
+        if let Some(width) = width {
+          dest.push(Property::ContainIntrinsicWidth(width));
+        }
+
+        if let Some(height) = height {
+          dest.push(Property::ContainIntrinsicHeight(height));
+        }
+      }
+    }
+  }
+}
@@ -2,20 +2,24 @@
 
 use crate::compat;
 use crate::error::{ParserError, PrinterError, PrinterErrorKind};
+use crate::macros::enum_property;
 use crate::prefixes::Feature;
-use crate::printer::Printer;
+use crate::printer::{Printer, PrinterOptions};
 use crate::properties::PropertyId;
 use crate::rules::supports::SupportsCondition;
 use crate::stylesheet::ParserOptions;
 use crate::targets::Browsers;
 use crate::traits::{Parse, ParseWithOptions, ToCss};
 use crate::values::color::{
-  parse_hsl_hwb_components, parse_rgb_components, ColorFallbackKind, ComponentParser, CssColor,
+  parse_contrast_function, parse_hsl_hwb_components, parse_rgb_components, ColorFallbackKind, ComponentParser,
+  CssColor,
 };
-use crate::values::ident::DashedIdentReference;
-use crate::values::length::serialize_dimension;
+use crate::values::ident::{CustomIdent, DashedIdentReference};
+use crate::values::length::{serialize_dimension, LengthValue};
+use crate::values::number::CSSInteger;
 use crate::values::percentage::Percentage;
 use crate::values::string::CowArcStr;
+use crate::values::syntax::SyntaxString;
 use crate::values::url::Url;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
@@ -39,10 +43,36 @@ impl<'i> CustomProperty<'i> {
     options: &ParserOptions,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let value = input.parse_until_before(Delimiter::Bang | Delimiter::Semicolon, |input| {
-      TokenList::parse(input, options, 0)
+      TokenList::parse_with_whitespace(input, options, 0, true)
     })?;
     Ok(CustomProperty { name, value })
   }
+
+  /// Validates the value against a syntax registered for the property via `@property`, and replaces
+  /// it with its normalized form if it matches, e.g. simplifying `calc()` expressions. Values that
+  /// do not match, including those that contain `var()` references, are left untouched, since they
+  /// are only invalid at computed value time.
+  pub(crate) fn apply_syntax(&mut self, syntax: &SyntaxString) {
+    if *syntax == SyntaxString::Universal {
+      return;
+    }
+
+    let mut css = String::new();
+    {
+      let mut printer = Printer::new(&mut css, PrinterOptions::default());
+      if self.value.to_css(&mut printer, true).is_err() {
+        return;
+      }
+    }
+
+    let mut input = ParserInput::new(&css);
+    let mut parser = Parser::new(&mut input);
+    if let Ok(value) = parser.parse_entirely(|input| syntax.parse_value(input)) {
+      if let Some(tokens) = value.to_token_list() {
+        self.value = tokens;
+      }
+    }
+  }
 }
 
 /// A known property with an unparsed value.
@@ -92,6 +122,71 @@ impl<'i> UnparsedProperty<'i> {
   }
 }
 
+impl<'i> UnparsedProperty<'i> {
+  /// Returns the CSS-wide keyword that the property is set to, if its value consists of only a keyword.
+  pub(crate) fn as_css_wide_keyword(&self) -> Option<CSSWideKeywordProperty<'i>> {
+    match &self.value.0[..] {
+      [TokenOrValue::Token(Token::Ident(ident))] => {
+        let keyword = CSSWideKeyword::parse_string(&**ident).ok()?;
+        Some(CSSWideKeywordProperty {
+          property_id: self.property_id.clone(),
+          keyword,
+        })
+      }
+      _ => None,
+    }
+  }
+}
+
+enum_property! {
+  /// A [CSS-wide keyword](https://drafts.csswg.org/css-cascade-5/#defaulting-keywords).
+  pub enum CSSWideKeyword {
+    /// The property's initial value.
+    "initial": Initial,
+    /// The property's computed value on the parent element.
+    "inherit": Inherit,
+    /// Either inherit or initial depending on whether the property is inherited.
+    "unset": Unset,
+    /// Rolls back the cascade to the cascaded value of the earlier origin.
+    "revert": Revert,
+    /// Rolls back the cascade to the value of the previous cascade layer.
+    "revert-layer": RevertLayer,
+  }
+}
+
+/// A property set to a [CSS-wide keyword](https://drafts.csswg.org/css-cascade-5/#defaulting-keywords).
+///
+/// Any property other than custom properties may be set to a CSS-wide keyword, regardless
+/// of the values it otherwise accepts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CSSWideKeywordProperty<'i> {
+  /// The id of the property.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub property_id: PropertyId<'i>,
+  /// The keyword the property is set to.
+  pub keyword: CSSWideKeyword,
+}
+
+impl<'i> CSSWideKeywordProperty<'i> {
+  /// Returns a new CSSWideKeywordProperty with the same keyword and the given property id.
+  pub fn with_property_id(&self, property_id: PropertyId<'i>) -> CSSWideKeywordProperty<'i> {
+    CSSWideKeywordProperty {
+      property_id,
+      keyword: self.keyword,
+    }
+  }
+
+  /// Converts the property to an equivalent unparsed property, which property handlers
+  /// treat as resetting any previous declarations of the property.
+  pub(crate) fn to_unparsed(&self) -> UnparsedProperty<'i> {
+    UnparsedProperty {
+      property_id: self.property_id.clone(),
+      value: TokenList(vec![TokenOrValue::Token(Token::Ident(self.keyword.as_str().into()))]),
+    }
+  }
+}
+
 /// A raw list of CSS tokens, with embedded parsed values.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -116,6 +211,10 @@ pub enum TokenOrValue<'i> {
   Url(Url<'i>),
   /// A CSS variable reference.
   Var(Variable<'i>),
+  /// An attribute reference, i.e. the `attr()` function.
+  Attr(Attr<'i>),
+  /// An environment variable reference, i.e. the `env()` function.
+  Env(EnvironmentVariable<'i>),
 }
 
 impl<'i> From<Token<'i>> for TokenOrValue<'i> {
@@ -136,9 +235,20 @@ impl<'i> TokenList<'i> {
     input: &mut Parser<'i, 't>,
     options: &ParserOptions,
     depth: usize,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    TokenList::parse_with_whitespace(input, options, depth, false)
+  }
+
+  /// Parses a token list. Whitespace at the start and end of nested blocks is preserved
+  /// within custom property values, where it may be significant to consumers of the value.
+  fn parse_with_whitespace<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+    depth: usize,
+    is_custom_property: bool,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let mut tokens = vec![];
-    TokenList::parse_into(input, &mut tokens, options, depth)?;
+    TokenList::parse_into(input, &mut tokens, options, depth, is_custom_property)?;
 
     // Slice off leading and trailing whitespace if there are at least two tokens.
     // If there is only one token, we must preserve it. e.g. `--foo: ;` is valid.
@@ -161,6 +271,7 @@ impl<'i> TokenList<'i> {
     tokens: &mut Vec<TokenOrValue<'i>>,
     options: &ParserOptions,
     depth: usize,
+    is_custom_property: bool,
   ) -> Result<(), ParseError<'i, ParserError<'i>>> {
     if depth > 500 {
       return Err(input.new_custom_error(ParserError::MaximumNestingDepth));
@@ -174,7 +285,7 @@ impl<'i> TokenList<'i> {
         Ok(&cssparser::Token::WhiteSpace(..)) | Ok(&cssparser::Token::Comment(..)) => {
           // Skip whitespace if the last token was a delimeter.
           // Otherwise, replace all whitespace and comments with a single space character.
-          if !last_is_delim {
+          if !last_is_delim && !last_is_whitespace {
             tokens.push(Token::WhiteSpace(" ").into());
             last_is_whitespace = true;
           }
@@ -186,26 +297,59 @@ impl<'i> TokenList<'i> {
             tokens.push(TokenOrValue::Color(color));
             last_is_delim = false;
             last_is_whitespace = false;
+          } else if let Ok(color) = input.try_parse(|input| {
+            if options.preserve_contrast_functions {
+              return Err(input.new_custom_error(ParserError::InvalidValue));
+            }
+            parse_contrast_function(&f, input, options.contrast_algorithm)
+          }) {
+            // Contrast functions with non-literal arguments are preserved as tokens below.
+            tokens.push(TokenOrValue::Color(color));
+            last_is_delim = false;
+            last_is_whitespace = false;
           } else if let Ok(color) = input.try_parse(|input| UnresolvedColor::parse(&f, input, options)) {
             tokens.push(TokenOrValue::UnresolvedColor(color));
             last_is_delim = true;
             last_is_whitespace = false;
-          } else if f == "url" {
+          } else if f.eq_ignore_ascii_case("url") {
             input.reset(&state);
             tokens.push(TokenOrValue::Url(Url::parse(input)?));
             last_is_delim = false;
             last_is_whitespace = false;
-          } else if f == "var" {
+          } else if let Ok(attr) = input.try_parse(|input| {
+            if !f.eq_ignore_ascii_case("attr") {
+              return Err(input.new_custom_error(ParserError::InvalidValue));
+            }
+            input.parse_nested_block(|input| Attr::parse(input, options, depth + 1, is_custom_property))
+          }) {
+            tokens.push(TokenOrValue::Attr(attr));
+            last_is_delim = true;
+            last_is_whitespace = false;
+          } else if f.eq_ignore_ascii_case("var") {
             let var = input.parse_nested_block(|input| {
-              let var = Variable::parse(input, options, depth + 1)?;
+              let var = Variable::parse(input, options, depth + 1, is_custom_property)?;
               Ok(TokenOrValue::Var(var))
             })?;
             tokens.push(var);
             last_is_delim = true;
             last_is_whitespace = false;
+          } else if f.eq_ignore_ascii_case("env") {
+            let env = input.parse_nested_block(|input| {
+              let env = EnvironmentVariable::parse(input, options, depth + 1, is_custom_property)?;
+              Ok(TokenOrValue::Env(env))
+            })?;
+            tokens.push(env);
+            last_is_delim = true;
+            last_is_whitespace = false;
           } else {
             tokens.push(Token::Function(f).into());
-            input.parse_nested_block(|input| TokenList::parse_into(input, tokens, options, depth + 1))?;
+            let start = tokens.len();
+            input.parse_nested_block(|input| {
+              TokenList::parse_into(input, tokens, options, depth + 1, is_custom_property)
+            })?;
+            if !is_custom_property {
+              trim_block_whitespace(tokens, start);
+            }
             tokens.push(Token::CloseParenthesis.into());
             last_is_delim = true; // Whitespace is not required after any of these chars.
             last_is_whitespace = false;
@@ -237,7 +381,13 @@ impl<'i> TokenList<'i> {
             _ => unreachable!(),
           };
 
-          input.parse_nested_block(|input| TokenList::parse_into(input, tokens, options, depth + 1))?;
+          let start = tokens.len();
+          input.parse_nested_block(|input| {
+            TokenList::parse_into(input, tokens, options, depth + 1, is_custom_property)
+          })?;
+          if !is_custom_property {
+            trim_block_whitespace(tokens, start);
+          }
 
           tokens.push(closing_delimiter.into());
           last_is_delim = true; // Whitespace is not required after any of these chars.
@@ -265,6 +415,16 @@ impl<'i> TokenList<'i> {
   }
 }
 
+/// Removes whitespace at the start and end of the contents of a block, which begin at `start`.
+fn trim_block_whitespace(tokens: &mut Vec<TokenOrValue>, start: usize) {
+  if tokens.len() > start && tokens.last().map_or(false, |token| token.is_whitespace()) {
+    tokens.pop();
+  }
+  if tokens.get(start).map_or(false, |token| token.is_whitespace()) {
+    tokens.remove(start);
+  }
+}
+
 #[inline]
 fn try_parse_color_token<'i, 't>(
   f: &CowArcStr<'i>,
@@ -320,19 +480,16 @@ impl<'i> TokenList<'i> {
         }
         TokenOrValue::Var(var) => {
           var.to_css(dest, is_custom_property)?;
-          if !dest.minify
-            && i != self.0.len() - 1
-            && !matches!(
-              self.0[i + 1],
-              TokenOrValue::Token(Token::Comma) | TokenOrValue::Token(Token::CloseParenthesis)
-            )
-          {
-            // Whitespace is removed during parsing, so add it back if we aren't minifying.
-            dest.write_char(' ')?;
-            true
-          } else {
-            false
-          }
+          self.write_whitespace_if_needed(i, dest, false)?
+        }
+        TokenOrValue::Env(env) => {
+          // Substituted values may end with an identifier or number, so whitespace is always required.
+          let substituted = env.to_css(dest, is_custom_property)?;
+          self.write_whitespace_if_needed(i, dest, substituted)?
+        }
+        TokenOrValue::Attr(attr) => {
+          attr.to_css(dest, is_custom_property)?;
+          false
         }
         TokenOrValue::Token(token) => {
           match token {
@@ -383,6 +540,31 @@ impl<'i> TokenList<'i> {
 
     Ok(())
   }
+
+  #[inline]
+  fn write_whitespace_if_needed<W>(
+    &self,
+    i: usize,
+    dest: &mut Printer<W>,
+    required: bool,
+  ) -> Result<bool, PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if (required || !dest.minify)
+      && i != self.0.len() - 1
+      && !matches!(
+        self.0[i + 1],
+        TokenOrValue::Token(Token::Comma) | TokenOrValue::Token(Token::CloseParenthesis)
+      )
+    {
+      // Whitespace is removed during parsing, so add it back if we aren't minifying.
+      dest.write_char(' ')?;
+      Ok(true)
+    } else {
+      Ok(false)
+    }
+  }
 }
 
 /// A raw CSS token.
@@ -767,11 +949,18 @@ impl<'i> Variable<'i> {
     input: &mut Parser<'i, 't>,
     options: &ParserOptions,
     depth: usize,
+    is_custom_property: bool,
   ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let name = DashedIdentReference::parse_with_options(input, options)?;
 
     let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
-      Some(TokenList::parse(input, options, depth)?)
+      let mut fallback = TokenList::parse_with_whitespace(input, options, depth, is_custom_property)?;
+      // Unlike a custom property value, a fallback consisting only of whitespace is equivalent to
+      // an empty fallback, so the whitespace need not be preserved.
+      if fallback.0.iter().all(|token| token.is_whitespace()) {
+        fallback.0.clear();
+      }
+      Some(fallback)
     } else {
       None
     };
@@ -793,6 +982,374 @@ impl<'i> Variable<'i> {
   }
 }
 
+/// A CSS [attr()](https://drafts.csswg.org/css-values-5/#attr-notation) function,
+/// which references the value of an attribute on the element.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attr<'i> {
+  /// The namespace prefix of the attribute, if any.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub namespace: Option<CowArcStr<'i>>,
+  /// The attribute name.
+  pub name: CowArcStr<'i>,
+  /// The type the attribute value is parsed as.
+  pub attr_type: Option<AttrType<'i>>,
+  /// A fallback value in case the attribute is missing or invalid.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+impl<'i> Attr<'i> {
+  fn parse<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+    depth: usize,
+    is_custom_property: bool,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let first: CowArcStr<'i> = input.expect_ident()?.into();
+    let (namespace, name) = if input.try_parse(|input| input.expect_delim('|')).is_ok() {
+      (Some(first), input.expect_ident()?.into())
+    } else {
+      (None, first)
+    };
+
+    let attr_type = input.try_parse(AttrType::parse).ok();
+    let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+      let fallback = TokenList::parse_with_whitespace(input, options, depth, is_custom_property)?;
+      Some(fallback)
+    } else {
+      None
+    };
+
+    Ok(Attr {
+      namespace,
+      name,
+      attr_type,
+      fallback,
+    })
+  }
+
+  fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("attr(")?;
+    if let Some(namespace) = &self.namespace {
+      serialize_identifier(namespace, dest)?;
+      dest.write_char('|')?;
+    }
+    serialize_identifier(&self.name, dest)?;
+    if let Some(attr_type) = &self.attr_type {
+      dest.write_char(' ')?;
+      attr_type.to_css(dest)?;
+    }
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest, is_custom_property)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+/// A CSS [env()](https://drafts.csswg.org/css-env-1/#env-function) function,
+/// which references an environment variable defined by the user agent.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvironmentVariable<'i> {
+  /// The environment variable name.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: EnvironmentVariableName<'i>,
+  /// Optional indices into the dimensions of the environment variable.
+  pub indices: Vec<CSSInteger>,
+  /// A fallback value in case the variable is not defined.
+  pub fallback: Option<TokenList<'i>>,
+}
+
+impl<'i> EnvironmentVariable<'i> {
+  fn parse<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+    depth: usize,
+    is_custom_property: bool,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let name = EnvironmentVariableName::parse_with_options(input, options)?;
+    let mut indices = Vec::new();
+    while let Ok(index) = input.try_parse(CSSInteger::parse) {
+      indices.push(index);
+    }
+
+    let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+      let fallback = TokenList::parse_with_whitespace(input, options, depth, is_custom_property)?;
+      Some(fallback)
+    } else {
+      None
+    };
+
+    Ok(EnvironmentVariable {
+      name,
+      indices,
+      fallback,
+    })
+  }
+
+  /// Writes the variable, or its substituted value if one was provided. Returns whether the value was substituted.
+  fn to_css<W>(&self, dest: &mut Printer<W>, is_custom_property: bool) -> Result<bool, PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.indices.is_empty() {
+      if let Some(value) = dest.env.and_then(|env| env.get(self.name.name())) {
+        // Values that are not valid within a declaration are ignored, and the reference is preserved.
+        if let Some(tokens) = parse_env_value(value) {
+          tokens.to_css(dest, is_custom_property)?;
+          return Ok(true);
+        }
+      }
+    }
+
+    dest.write_str("env(")?;
+    self.name.to_css(dest)?;
+    for index in &self.indices {
+      dest.write_char(' ')?;
+      index.to_css(dest)?;
+    }
+
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest, is_custom_property)?;
+    }
+    dest.write_char(')')?;
+    Ok(false)
+  }
+}
+
+/// Parses a value to substitute for an `env()` reference. Returns `None` if the value is empty,
+/// or would change the meaning of the surrounding declaration, e.g. by closing a block or function.
+fn parse_env_value(value: &str) -> Option<TokenList<'_>> {
+  fn is_valid<'i, 't>(input: &mut Parser<'i, 't>) -> bool {
+    while let Ok(token) = input.next_including_whitespace() {
+      match token {
+        cssparser::Token::BadUrl(..)
+        | cssparser::Token::BadString(..)
+        | cssparser::Token::CloseParenthesis
+        | cssparser::Token::CloseSquareBracket
+        | cssparser::Token::CloseCurlyBracket
+        | cssparser::Token::CurlyBracketBlock
+        | cssparser::Token::Semicolon
+        | cssparser::Token::Delim('!') => return false,
+        cssparser::Token::Function(..)
+        | cssparser::Token::ParenthesisBlock
+        | cssparser::Token::SquareBracketBlock => {
+          let res = input.parse_nested_block(|input| {
+            if is_valid(input) {
+              Ok(())
+            } else {
+              Err(input.new_custom_error::<(), ()>(()))
+            }
+          });
+          if res.is_err() {
+            return false;
+          }
+        }
+        _ => {}
+      }
+    }
+
+    true
+  }
+
+  let mut input = ParserInput::new(value);
+  let mut parser = Parser::new(&mut input);
+  if !is_valid(&mut parser) {
+    return None;
+  }
+
+  // Blocks that are not closed in the value are closed when the token list is printed.
+  let mut input = ParserInput::new(value);
+  let mut parser = Parser::new(&mut input);
+  let tokens = TokenList::parse(&mut parser, &ParserOptions::default(), 0).ok()?;
+  if tokens.0.iter().all(|token| token.is_whitespace()) {
+    return None;
+  }
+
+  Some(tokens)
+}
+
+/// The name of an [environment variable](EnvironmentVariable).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "lowercase")
+)]
+pub enum EnvironmentVariableName<'i> {
+  /// A UA-defined environment variable.
+  UA(UAEnvironmentVariable),
+  /// A custom author-defined environment variable.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Custom(DashedIdentReference<'i>),
+  /// An unknown environment variable.
+  Unknown(CustomIdent<'i>),
+}
+
+impl<'i> EnvironmentVariableName<'i> {
+  /// Returns the name of the environment variable.
+  pub fn name(&self) -> &str {
+    match self {
+      EnvironmentVariableName::UA(ua) => ua.as_str(),
+      EnvironmentVariableName::Custom(custom) => &custom.ident.0,
+      EnvironmentVariableName::Unknown(ident) => &ident.0,
+    }
+  }
+}
+
+impl<'i> ParseWithOptions<'i> for EnvironmentVariableName<'i> {
+  fn parse_with_options<'t>(
+    input: &mut Parser<'i, 't>,
+    options: &ParserOptions,
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(ua) = input.try_parse(UAEnvironmentVariable::parse) {
+      return Ok(EnvironmentVariableName::UA(ua));
+    }
+
+    if let Ok(dashed) = input.try_parse(|input| DashedIdentReference::parse_with_options(input, options)) {
+      return Ok(EnvironmentVariableName::Custom(dashed));
+    }
+
+    let ident = CustomIdent::parse(input)?;
+    Ok(EnvironmentVariableName::Unknown(ident))
+  }
+}
+
+impl<'i> ToCss for EnvironmentVariableName<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      EnvironmentVariableName::UA(ua) => ua.to_css(dest),
+      EnvironmentVariableName::Custom(custom) => custom.to_css(dest),
+      EnvironmentVariableName::Unknown(ident) => ident.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A UA-defined environment variable name.
+  pub enum UAEnvironmentVariable {
+    /// The safe area inset from the top of the viewport.
+    "safe-area-inset-top": SafeAreaInsetTop,
+    /// The safe area inset from the right of the viewport.
+    "safe-area-inset-right": SafeAreaInsetRight,
+    /// The safe area inset from the bottom of the viewport.
+    "safe-area-inset-bottom": SafeAreaInsetBottom,
+    /// The safe area inset from the left of the viewport.
+    "safe-area-inset-left": SafeAreaInsetLeft,
+    /// The viewport segment width.
+    "viewport-segment-width": ViewportSegmentWidth,
+    /// The viewport segment height.
+    "viewport-segment-height": ViewportSegmentHeight,
+    /// The top position of the viewport segment.
+    "viewport-segment-top": ViewportSegmentTop,
+    /// The left position of the viewport segment.
+    "viewport-segment-left": ViewportSegmentLeft,
+    /// The bottom position of the viewport segment.
+    "viewport-segment-bottom": ViewportSegmentBottom,
+    /// The right position of the viewport segment.
+    "viewport-segment-right": ViewportSegmentRight,
+    /// The x position of the title bar area.
+    "titlebar-area-x": TitlebarAreaX,
+    /// The y position of the title bar area.
+    "titlebar-area-y": TitlebarAreaY,
+    /// The width of the title bar area.
+    "titlebar-area-width": TitlebarAreaWidth,
+    /// The height of the title bar area.
+    "titlebar-area-height": TitlebarAreaHeight,
+    /// The top inset of the virtual keyboard.
+    "keyboard-inset-top": KeyboardInsetTop,
+    /// The right inset of the virtual keyboard.
+    "keyboard-inset-right": KeyboardInsetRight,
+    /// The bottom inset of the virtual keyboard.
+    "keyboard-inset-bottom": KeyboardInsetBottom,
+    /// The left inset of the virtual keyboard.
+    "keyboard-inset-left": KeyboardInsetLeft,
+    /// The width of the virtual keyboard.
+    "keyboard-inset-width": KeyboardInsetWidth,
+    /// The height of the virtual keyboard.
+    "keyboard-inset-height": KeyboardInsetHeight,
+  }
+}
+
+/// The type of an [attr()](Attr) function, which determines how the attribute value is parsed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum AttrType<'i> {
+  /// A `type()` function, containing a syntax definition.
+  Type(SyntaxString),
+  /// The `raw-string` keyword.
+  RawString,
+  /// An attribute unit such as `px` or `%`, or a legacy type keyword such as `string` or `number`.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Unit(CowArcStr<'i>),
+}
+
+impl<'i> Parse<'i> for AttrType<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_function_matching("type")).is_ok() {
+      return input.parse_nested_block(|input| {
+        let start = input.position();
+        while input.next().is_ok() {}
+        SyntaxString::parse_string(input.slice_from(start))
+          .map(AttrType::Type)
+          .map_err(|_| input.new_custom_error(ParserError::InvalidValue))
+      });
+    }
+
+    let location = input.current_source_location();
+    match input.next()? {
+      cssparser::Token::Ident(ident) if ident.eq_ignore_ascii_case("raw-string") => Ok(AttrType::RawString),
+      cssparser::Token::Ident(ident) if is_attr_unit(ident) => Ok(AttrType::Unit(ident.into())),
+      cssparser::Token::Delim('%') => Ok(AttrType::Unit("%".into())),
+      t => Err(location.new_unexpected_token_error(t.clone())),
+    }
+  }
+}
+
+/// Returns whether the given ident is a CSS dimension unit, or a legacy `attr()` type keyword.
+fn is_attr_unit(ident: &str) -> bool {
+  if LengthValue::is_unit(ident) {
+    return true;
+  }
+
+  match_ignore_ascii_case! { ident,
+    // Angle, time, frequency, resolution, and flex units.
+    "deg" | "grad" | "rad" | "turn" | "s" | "ms" | "hz" | "khz" | "dpi" | "dpcm" | "dppx" | "x" | "fr" => true,
+    // Type keywords from earlier versions of the spec.
+    "string" | "color" | "url" | "integer" | "number" | "length" | "angle" | "time" | "frequency" => true,
+    _ => false
+  }
+}
+
+impl<'i> ToCss for AttrType<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AttrType::Type(syntax) => {
+        dest.write_str("type(")?;
+        syntax.to_css_unquoted(dest)?;
+        dest.write_char(')')
+      }
+      AttrType::RawString => dest.write_str("raw-string"),
+      AttrType::Unit(unit) => dest.write_str(unit),
+    }
+  }
+}
+
 /// A color value with an unresolved alpha value (e.g. a variable).
 /// These can be converted from the modern slash syntax to older comma syntax.
 /// This can only be done when the only unresolved component is the alpha
@@ -1,9 +1,10 @@
 //! CSS properties related to filters and effects.
 
 use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::targets::Browsers;
-use crate::traits::{FallbackValues, Parse, ToCss, Zero};
+use crate::traits::{FallbackValues, Parse, ToCss, TrySign, Zero};
 use crate::values::color::ColorFallbackKind;
 use crate::values::{angle::Angle, color::CssColor, length::Length, percentage::NumberOrPercentage, url::Url};
 use cssparser::*;
@@ -53,22 +54,27 @@ impl<'i> Parse<'i> for Filter<'i> {
     match_ignore_ascii_case! { &function,
       "blur" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::zero())))
+          let location = input.current_source_location();
+          let radius = input.try_parse(Length::parse).unwrap_or(Length::zero());
+          if radius.try_sign().map_or(false, |sign| sign < 0.0) {
+            return Err(location.new_custom_error(ParserError::InvalidValue));
+          }
+          Ok(Filter::Blur(radius))
         })
       },
       "brightness" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Brightness(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Brightness(parse_amount(input)?))
         })
       },
       "contrast" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Contrast(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Contrast(parse_amount(input)?))
         })
       },
       "grayscale" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Grayscale(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Grayscale(parse_amount(input)?))
         })
       },
       "hue-rotate" => {
@@ -78,22 +84,22 @@ impl<'i> Parse<'i> for Filter<'i> {
       },
       "invert" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Invert(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Invert(parse_amount(input)?))
         })
       },
       "opacity" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Opacity(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Opacity(parse_amount(input)?))
         })
       },
       "saturate" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Saturate(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Saturate(parse_amount(input)?))
         })
       },
       "sepia" => {
         input.parse_nested_block(|input| {
-          Ok(Filter::Sepia(input.try_parse(NumberOrPercentage::parse).unwrap_or(NumberOrPercentage::Number(1.0))))
+          Ok(Filter::Sepia(parse_amount(input)?))
         })
       },
       "drop-shadow" => {
@@ -108,6 +114,22 @@ impl<'i> Parse<'i> for Filter<'i> {
   }
 }
 
+/// Parses the amount argument of a filter function, which defaults to 1 and may not be negative.
+fn parse_amount<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<NumberOrPercentage, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let amount = input
+    .try_parse(NumberOrPercentage::parse)
+    .unwrap_or(NumberOrPercentage::Number(1.0));
+  let value: f32 = (&amount).into();
+  if value < 0.0 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+
+  Ok(amount)
+}
+
 impl<'i> ToCss for Filter<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -382,3 +404,52 @@ impl<'i> FallbackValues for FilterList<'i> {
     res
   }
 }
+
+enum_property! {
+  /// A [`<blend-mode>`](https://drafts.fxtf.org/compositing-1/#ltblendmodegt) value,
+  /// as used in the `mix-blend-mode` and `background-blend-mode` properties.
+  pub enum BlendMode {
+    /// The source color is used without blending.
+    "normal": Normal,
+    /// The source and backdrop colors are multiplied.
+    "multiply": Multiply,
+    /// The complements of the source and backdrop colors are multiplied.
+    "screen": Screen,
+    /// Multiplies or screens the colors, depending on the backdrop color.
+    "overlay": Overlay,
+    /// The darker of the source and backdrop colors is used.
+    "darken": Darken,
+    /// The lighter of the source and backdrop colors is used.
+    "lighten": Lighten,
+    /// Brightens the backdrop color to reflect the source color.
+    "color-dodge": ColorDodge,
+    /// Darkens the backdrop color to reflect the source color.
+    "color-burn": ColorBurn,
+    /// Multiplies or screens the colors, depending on the source color.
+    "hard-light": HardLight,
+    /// Darkens or lightens the colors, depending on the source color.
+    "soft-light": SoftLight,
+    /// Subtracts the darker of the two colors from the lighter color.
+    "difference": Difference,
+    /// Similar to `difference`, but with lower contrast.
+    "exclusion": Exclusion,
+    /// Uses the hue of the source color with the saturation and luminosity of the backdrop color.
+    "hue": Hue,
+    /// Uses the saturation of the source color with the hue and luminosity of the backdrop color.
+    "saturation": Saturation,
+    /// Uses the hue and saturation of the source color with the luminosity of the backdrop color.
+    "color": Color,
+    /// Uses the luminosity of the source color with the hue and saturation of the backdrop color.
+    "luminosity": Luminosity,
+  }
+}
+
+enum_property! {
+  /// A value for the [isolation](https://drafts.fxtf.org/compositing-1/#isolation) property.
+  pub enum Isolation {
+    /// A new stacking context is only created if required by other properties.
+    "auto": Auto,
+    /// A new stacking context is created, isolating the element from its backdrop.
+    "isolate": Isolate,
+  }
+}
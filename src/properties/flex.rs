@@ -595,7 +595,37 @@ impl<'i> PropertyHandler<'i> for FlexHandler {
       FlexOrder(val, vp) => property!(flex_order, val, vp),
       Unparsed(val) if is_flex_property(&val.property_id) => {
         self.flush(dest);
-        dest.push(property.clone()) // TODO: prefix?
+
+        // Even if we weren't able to parse the value (e.g. due to var() references),
+        // we can still add vendor prefixes to the property itself. Only the prefixes
+        // for the final spec are added, because legacy values are not equivalent.
+        let feature = match &val.property_id {
+          PropertyId::FlexDirection(_) => Some(Feature::FlexDirection),
+          PropertyId::FlexWrap(_) => Some(Feature::FlexWrap),
+          PropertyId::FlexFlow(_) => Some(Feature::FlexFlow),
+          PropertyId::FlexGrow(_) => Some(Feature::FlexGrow),
+          PropertyId::FlexShrink(_) => Some(Feature::FlexShrink),
+          PropertyId::FlexBasis(_) => Some(Feature::FlexBasis),
+          PropertyId::Flex(_) => Some(Feature::Flex),
+          PropertyId::Order(_) => Some(Feature::Order),
+          _ => None,
+        };
+
+        match (feature, self.targets) {
+          (Some(feature), Some(targets)) if val.property_id.prefix().contains(VendorPrefix::None) => {
+            let mut prefix = feature.prefixes_for(targets);
+            // Firefox only implemented the 2009 spec prefixed, and the 2012 spec uses
+            // different property names for everything except `flex` itself.
+            prefix.remove(VendorPrefix::Moz);
+            if !matches!(val.property_id, PropertyId::Flex(_)) {
+              prefix.remove(VendorPrefix::Ms);
+            }
+            dest.push(Property::Unparsed(
+              val.with_property_id(val.property_id.with_prefix(prefix)),
+            ))
+          }
+          _ => dest.push(property.clone()),
+        }
       }
       _ => return false,
     }
@@ -8,11 +8,14 @@ use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::*;
+use crate::prefixes;
 use crate::printer::Printer;
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
-use crate::values::number::CSSNumber;
+use crate::traits::{KeywordEquivalent, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::ident::CustomIdent;
+use crate::values::number::{CSSInteger, CSSNumber};
 use crate::values::string::CowArcStr;
 use crate::values::{angle::Angle, length::LengthPercentage, percentage::Percentage};
+use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
 /// A value for the [font-weight](https://www.w3.org/TR/css-fonts-4/#font-weight-prop) property.
@@ -117,13 +120,47 @@ impl ToCss for AbsoluteFontWeight {
   where
     W: std::fmt::Write,
   {
-    use AbsoluteFontWeight::*;
+    self.to_css_normalized(dest)
+  }
+}
+
+enum_property! {
+  /// A keyword for an [absolute font weight](https://www.w3.org/TR/css-fonts-4/#font-weight-absolute-values).
+  ///
+  /// See [AbsoluteFontWeight](AbsoluteFontWeight).
+  pub enum FontWeightKeyword {
+    /// Same as `400`.
+    "normal": Normal,
+    /// Same as `700`.
+    "bold": Bold,
+  }
+}
+
+impl KeywordEquivalent for AbsoluteFontWeight {
+  type Keyword = FontWeightKeyword;
+  type Numeric = CSSNumber;
+
+  fn to_keyword(&self) -> Option<FontWeightKeyword> {
+    match self {
+      AbsoluteFontWeight::Normal => Some(FontWeightKeyword::Normal),
+      AbsoluteFontWeight::Bold => Some(FontWeightKeyword::Bold),
+      AbsoluteFontWeight::Weight(val) if *val == 400.0 => Some(FontWeightKeyword::Normal),
+      AbsoluteFontWeight::Weight(val) if *val == 700.0 => Some(FontWeightKeyword::Bold),
+      AbsoluteFontWeight::Weight(..) => None,
+    }
+  }
+
+  fn to_numeric(&self) -> CSSNumber {
     match self {
-      Weight(val) => val.to_css(dest),
-      Normal => dest.write_str(if dest.minify { "400" } else { "normal" }),
-      Bold => dest.write_str(if dest.minify { "700" } else { "bold" }),
+      AbsoluteFontWeight::Weight(val) => *val,
+      AbsoluteFontWeight::Normal => 400.0,
+      AbsoluteFontWeight::Bold => 700.0,
     }
   }
+
+  fn is_keyword(&self) -> bool {
+    !matches!(self, AbsoluteFontWeight::Weight(..))
+  }
 }
 
 enum_property! {
@@ -233,6 +270,26 @@ impl Default for FontStretchKeyword {
   }
 }
 
+impl FontStretchKeyword {
+  fn from_percentage(percentage: &Percentage) -> Option<FontStretchKeyword> {
+    use FontStretchKeyword::*;
+    [
+      UltraCondensed,
+      ExtraCondensed,
+      Condensed,
+      SemiCondensed,
+      Normal,
+      SemiExpanded,
+      Expanded,
+      ExtraExpanded,
+      UltraExpanded,
+    ]
+    .iter()
+    .find(|keyword| Into::<Percentage>::into(*keyword) == *percentage)
+    .copied()
+  }
+}
+
 impl Into<Percentage> for &FontStretchKeyword {
   fn into(self) -> Percentage {
     use FontStretchKeyword::*;
@@ -296,16 +353,28 @@ impl ToCss for FontStretch {
   where
     W: std::fmt::Write,
   {
-    if dest.minify {
-      let percentage: Percentage = self.into();
-      return percentage.to_css(dest);
-    }
+    self.to_css_normalized(dest)
+  }
+}
+
+impl KeywordEquivalent for FontStretch {
+  type Keyword = FontStretchKeyword;
+  type Numeric = Percentage;
 
+  fn to_keyword(&self) -> Option<FontStretchKeyword> {
     match self {
-      FontStretch::Percentage(val) => val.to_css(dest),
-      FontStretch::Keyword(val) => val.to_css(dest),
+      FontStretch::Keyword(keyword) => Some(*keyword),
+      FontStretch::Percentage(val) => FontStretchKeyword::from_percentage(val),
     }
   }
+
+  fn to_numeric(&self) -> Percentage {
+    self.into()
+  }
+
+  fn is_keyword(&self) -> bool {
+    matches!(self, FontStretch::Keyword(..))
+  }
 }
 
 enum_property! {
@@ -406,23 +475,12 @@ impl<'i> ToCss for FontFamily<'i> {
         // Generic family names such as sans-serif must be quoted if parsed as a string.
         // CSS wide keywords, as well as "default", must also be quoted.
         // https://www.w3.org/TR/css-fonts-4/#family-name-syntax
-        if !val.is_empty() && !GenericFontFamily::parse_string(val).is_ok() {
-          let mut id = String::new();
-          let mut first = true;
-          for slice in val.split(' ') {
-            if first {
-              first = false;
-            } else {
-              id.push(' ');
-            }
-            serialize_identifier(slice, &mut id)?;
-          }
-          if id.len() < val.len() + 2 {
-            return dest.write_str(&id);
-          }
+        if GenericFontFamily::parse_string(val).is_ok() {
+          serialize_string(&val, dest)?;
+          Ok(())
+        } else {
+          dest.write_ident_or_string(val, true)
         }
-        serialize_string(&val, dest)?;
-        Ok(())
       }
     }
   }
@@ -528,6 +586,683 @@ impl FontVariantCaps {
   }
 }
 
+enum_property! {
+  /// A [`<common-lig-values>`](https://www.w3.org/TR/css-fonts-4/#common-lig-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum CommonLigatures {
+    /// Enables display of common ligatures.
+    "common-ligatures": Common,
+    /// Disables display of common ligatures.
+    "no-common-ligatures": NoCommon,
+  }
+}
+
+enum_property! {
+  /// A [`<discretionary-lig-values>`](https://www.w3.org/TR/css-fonts-4/#discretionary-lig-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum DiscretionaryLigatures {
+    /// Enables display of discretionary ligatures.
+    "discretionary-ligatures": Discretionary,
+    /// Disables display of discretionary ligatures.
+    "no-discretionary-ligatures": NoDiscretionary,
+  }
+}
+
+enum_property! {
+  /// A [`<historical-lig-values>`](https://www.w3.org/TR/css-fonts-4/#historical-lig-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum HistoricalLigatures {
+    /// Enables display of historical ligatures.
+    "historical-ligatures": Historical,
+    /// Disables display of historical ligatures.
+    "no-historical-ligatures": NoHistorical,
+  }
+}
+
+enum_property! {
+  /// A [`<contextual-alt-values>`](https://www.w3.org/TR/css-fonts-4/#contextual-alt-values) value,
+  /// as used in the `font-variant-ligatures` property.
+  pub enum ContextualAlternates {
+    /// Enables display of contextual alternates.
+    "contextual": Contextual,
+    /// Disables display of contextual alternates.
+    "no-contextual": NoContextual,
+  }
+}
+
+/// A set of ligature values for the `font-variant-ligatures` property.
+///
+/// See [FontVariantLigatures](FontVariantLigatures).
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LigatureValues {
+  /// Whether common ligatures are enabled.
+  pub common: Option<CommonLigatures>,
+  /// Whether discretionary ligatures are enabled.
+  pub discretionary: Option<DiscretionaryLigatures>,
+  /// Whether historical ligatures are enabled.
+  pub historical: Option<HistoricalLigatures>,
+  /// Whether contextual alternates are enabled.
+  pub contextual: Option<ContextualAlternates>,
+}
+
+impl LigatureValues {
+  fn parse_value<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.common.is_none() {
+      if let Ok(value) = input.try_parse(CommonLigatures::parse) {
+        self.common = Some(value);
+        return Ok(());
+      }
+    }
+
+    if self.discretionary.is_none() {
+      if let Ok(value) = input.try_parse(DiscretionaryLigatures::parse) {
+        self.discretionary = Some(value);
+        return Ok(());
+      }
+    }
+
+    if self.historical.is_none() {
+      if let Ok(value) = input.try_parse(HistoricalLigatures::parse) {
+        self.historical = Some(value);
+        return Ok(());
+      }
+    }
+
+    if self.contextual.is_none() {
+      if let Ok(value) = input.try_parse(ContextualAlternates::parse) {
+        self.contextual = Some(value);
+        return Ok(());
+      }
+    }
+
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+impl ToCss for LigatureValues {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! component {
+      ($key: ident) => {
+        if let Some(value) = &self.$key {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          value.to_css(dest)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    component!(common);
+    component!(discretionary);
+    component!(historical);
+    component!(contextual);
+    if !needs_space {
+      dest.write_str("normal")?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-variant-ligatures](https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontVariantLigatures {
+  /// Ligatures and contextual forms are enabled as determined by the font.
+  Normal,
+  /// All ligatures and contextual forms are disabled.
+  None,
+  /// An explicit set of ligature values.
+  Values(LigatureValues),
+}
+
+impl Default for FontVariantLigatures {
+  fn default() -> FontVariantLigatures {
+    FontVariantLigatures::Normal
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantLigatures {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariantLigatures::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariantLigatures::None);
+    }
+
+    let mut values = LigatureValues::default();
+    values.parse_value(input)?;
+    while input.try_parse(|input| values.parse_value(input)).is_ok() {}
+    Ok(FontVariantLigatures::Values(values))
+  }
+}
+
+impl ToCss for FontVariantLigatures {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontVariantLigatures::Normal => dest.write_str("normal"),
+      FontVariantLigatures::None => dest.write_str("none"),
+      FontVariantLigatures::Values(values) => values.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-figure-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-figure-values) value,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericFigure {
+    /// Enables display of lining numerals.
+    "lining-nums": LiningNums,
+    /// Enables display of old-style numerals.
+    "oldstyle-nums": OldstyleNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-spacing-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-spacing-values) value,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericSpacing {
+    /// Enables display of proportional numerals.
+    "proportional-nums": ProportionalNums,
+    /// Enables display of tabular numerals.
+    "tabular-nums": TabularNums,
+  }
+}
+
+enum_property! {
+  /// A [`<numeric-fraction-values>`](https://www.w3.org/TR/css-fonts-4/#numeric-fraction-values) value,
+  /// as used in the `font-variant-numeric` property.
+  pub enum NumericFraction {
+    /// Enables display of diagonal fractions.
+    "diagonal-fractions": DiagonalFractions,
+    /// Enables display of stacked fractions.
+    "stacked-fractions": StackedFractions,
+  }
+}
+
+/// A value for the [font-variant-numeric](https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop) property.
+///
+/// If no values are set, this represents the `normal` keyword.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantNumeric {
+  /// The style of numerals.
+  pub figure: Option<NumericFigure>,
+  /// The spacing of numerals.
+  pub spacing: Option<NumericSpacing>,
+  /// The style of fractions.
+  pub fraction: Option<NumericFraction>,
+  /// Whether ordinal forms are used.
+  pub ordinal: bool,
+  /// Whether a slashed zero is used.
+  pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+  fn parse_value<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.figure.is_none() {
+      if let Ok(value) = input.try_parse(NumericFigure::parse) {
+        self.figure = Some(value);
+        return Ok(());
+      }
+    }
+
+    if self.spacing.is_none() {
+      if let Ok(value) = input.try_parse(NumericSpacing::parse) {
+        self.spacing = Some(value);
+        return Ok(());
+      }
+    }
+
+    if self.fraction.is_none() {
+      if let Ok(value) = input.try_parse(NumericFraction::parse) {
+        self.fraction = Some(value);
+        return Ok(());
+      }
+    }
+
+    if !self.ordinal && input.try_parse(|input| input.expect_ident_matching("ordinal")).is_ok() {
+      self.ordinal = true;
+      return Ok(());
+    }
+
+    if !self.slashed_zero && input.try_parse(|input| input.expect_ident_matching("slashed-zero")).is_ok() {
+      self.slashed_zero = true;
+      return Ok(());
+    }
+
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantNumeric {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontVariantNumeric::default();
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(value);
+    }
+
+    value.parse_value(input)?;
+    while input.try_parse(|input| value.parse_value(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantNumeric {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! component {
+      ($write: expr) => {{
+        if needs_space {
+          dest.write_char(' ')?;
+        }
+        $write?;
+        needs_space = true;
+      }};
+    }
+
+    if let Some(figure) = &self.figure {
+      component!(figure.to_css(dest));
+    }
+    if let Some(spacing) = &self.spacing {
+      component!(spacing.to_css(dest));
+    }
+    if let Some(fraction) = &self.fraction {
+      component!(fraction.to_css(dest));
+    }
+    if self.ordinal {
+      component!(dest.write_str("ordinal"));
+    }
+    if self.slashed_zero {
+      component!(dest.write_str("slashed-zero"));
+    }
+    if !needs_space {
+      dest.write_str("normal")?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-variant-values>`](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) value,
+  /// as used in the `font-variant-east-asian` property.
+  pub enum EastAsianVariant {
+    /// JIS X 0208:1978 glyph forms.
+    "jis78": Jis78,
+    /// JIS X 0208:1983 glyph forms.
+    "jis83": Jis83,
+    /// JIS X 0208:1990 glyph forms.
+    "jis90": Jis90,
+    /// JIS2004 glyph forms.
+    "jis04": Jis04,
+    /// Simplified Chinese glyph forms.
+    "simplified": Simplified,
+    /// Traditional Chinese glyph forms.
+    "traditional": Traditional,
+  }
+}
+
+enum_property! {
+  /// An [`<east-asian-width-values>`](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) value,
+  /// as used in the `font-variant-east-asian` property.
+  pub enum EastAsianWidth {
+    /// Glyphs of roughly equal width, the width of an ideograph.
+    "full-width": FullWidth,
+    /// Proportionally spaced glyphs.
+    "proportional-width": ProportionalWidth,
+  }
+}
+
+/// A value for the [font-variant-east-asian](https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop) property.
+///
+/// If no values are set, this represents the `normal` keyword.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantEastAsian {
+  /// The glyph forms to use.
+  pub variant: Option<EastAsianVariant>,
+  /// The width of glyphs.
+  pub width: Option<EastAsianWidth>,
+  /// Whether ruby variant glyphs are used.
+  pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+  fn parse_value<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if self.variant.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianVariant::parse) {
+        self.variant = Some(value);
+        return Ok(());
+      }
+    }
+
+    if self.width.is_none() {
+      if let Ok(value) = input.try_parse(EastAsianWidth::parse) {
+        self.width = Some(value);
+        return Ok(());
+      }
+    }
+
+    if !self.ruby && input.try_parse(|input| input.expect_ident_matching("ruby")).is_ok() {
+      self.ruby = true;
+      return Ok(());
+    }
+
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantEastAsian {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontVariantEastAsian::default();
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(value);
+    }
+
+    value.parse_value(input)?;
+    while input.try_parse(|input| value.parse_value(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl ToCss for FontVariantEastAsian {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    if let Some(variant) = &self.variant {
+      variant.to_css(dest)?;
+      needs_space = true;
+    }
+    if let Some(width) = &self.width {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      width.to_css(dest)?;
+      needs_space = true;
+    }
+    if self.ruby {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      dest.write_str("ruby")?;
+      needs_space = true;
+    }
+    if !needs_space {
+      dest.write_str("normal")?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-variant-alternates](https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop) property.
+///
+/// The functional values refer to feature values defined by `@font-feature-values` rules.
+/// If no values are set, this represents the `normal` keyword.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariantAlternates<'i> {
+  /// The `stylistic()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub stylistic: Option<CustomIdent<'i>>,
+  /// Whether historical forms are used.
+  pub historical_forms: bool,
+  /// The `styleset()` function.
+  pub styleset: Option<Vec<CustomIdent<'i>>>,
+  /// The `character-variant()` function.
+  pub character_variant: Option<Vec<CustomIdent<'i>>>,
+  /// The `swash()` function.
+  pub swash: Option<CustomIdent<'i>>,
+  /// The `ornaments()` function.
+  pub ornaments: Option<CustomIdent<'i>>,
+  /// The `annotation()` function.
+  pub annotation: Option<CustomIdent<'i>>,
+}
+
+impl<'i> FontVariantAlternates<'i> {
+  fn parse_value<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, ParserError<'i>>> {
+    if !self.historical_forms && input.try_parse(|input| input.expect_ident_matching("historical-forms")).is_ok() {
+      self.historical_forms = true;
+      return Ok(());
+    }
+
+    macro_rules! function {
+      ($key: ident, $name: literal, $parse: expr) => {
+        if self.$key.is_none() {
+          let value = input.try_parse(|input| {
+            input.expect_function_matching($name)?;
+            input.parse_nested_block($parse)
+          });
+          if let Ok(value) = value {
+            self.$key = Some(value);
+            return Ok(());
+          }
+        }
+      };
+    }
+
+    function!(stylistic, "stylistic", CustomIdent::parse);
+    function!(styleset, "styleset", |input| input
+      .parse_comma_separated(CustomIdent::parse));
+    function!(character_variant, "character-variant", |input| input
+      .parse_comma_separated(CustomIdent::parse));
+    function!(swash, "swash", CustomIdent::parse);
+    function!(ornaments, "ornaments", CustomIdent::parse);
+    function!(annotation, "annotation", CustomIdent::parse);
+
+    Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+impl<'i> Parse<'i> for FontVariantAlternates<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut value = FontVariantAlternates::default();
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(value);
+    }
+
+    value.parse_value(input)?;
+    while input.try_parse(|input| value.parse_value(input)).is_ok() {}
+    Ok(value)
+  }
+}
+
+impl<'i> ToCss for FontVariantAlternates<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    macro_rules! function {
+      ($key: ident, $name: literal) => {
+        if let Some(value) = &self.$key {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($name)?;
+          dest.write_char('(')?;
+          value.to_css(dest)?;
+          dest.write_char(')')?;
+          needs_space = true;
+        }
+      };
+    }
+
+    macro_rules! list_function {
+      ($key: ident, $name: literal) => {
+        if let Some(values) = &self.$key {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          dest.write_str($name)?;
+          dest.write_char('(')?;
+          for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+              dest.delim(',', false)?;
+            }
+            value.to_css(dest)?;
+          }
+          dest.write_char(')')?;
+          needs_space = true;
+        }
+      };
+    }
+
+    function!(stylistic, "stylistic");
+    if self.historical_forms {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      dest.write_str("historical-forms")?;
+      needs_space = true;
+    }
+    list_function!(styleset, "styleset");
+    list_function!(character_variant, "character-variant");
+    function!(swash, "swash");
+    function!(ornaments, "ornaments");
+    function!(annotation, "annotation");
+    if !needs_space {
+      dest.write_str("normal")?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [font-variant-position](https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop) property.
+  pub enum FontVariantPosition {
+    /// No subscript or superscript variant glyphs are used.
+    "normal": Normal,
+    /// Subscript variant glyphs are used.
+    "sub": Sub,
+    /// Superscript variant glyphs are used.
+    "super": Super,
+  }
+}
+
+impl Default for FontVariantPosition {
+  fn default() -> FontVariantPosition {
+    FontVariantPosition::Normal
+  }
+}
+
+/// A [`<feature-tag-value>`](https://www.w3.org/TR/css-fonts-4/#feature-tag-value),
+/// as used in the `font-feature-settings` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureTagValue<'i> {
+  /// The four character OpenType feature tag.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub tag: CowArcStr<'i>,
+  /// The value of the feature. Boolean features are disabled by `0` and enabled by `1`.
+  pub value: CSSInteger,
+}
+
+impl<'i> Parse<'i> for FeatureTagValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let tag = input.expect_string_cloned()?;
+    // Tags must be exactly four characters in the printable ASCII range.
+    if tag.len() != 4 || !tag.bytes().all(|b| (0x20..=0x7e).contains(&b)) {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let value = if let Ok(value) = input.try_parse(CSSInteger::parse) {
+      if value < 0 {
+        return Err(location.new_custom_error(ParserError::InvalidValue));
+      }
+      value
+    } else if input.try_parse(|input| input.expect_ident_matching("off")).is_ok() {
+      0
+    } else {
+      let _ = input.try_parse(|input| input.expect_ident_matching("on"));
+      1
+    };
+
+    Ok(FeatureTagValue { tag: tag.into(), value })
+  }
+}
+
+impl<'i> ToCss for FeatureTagValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    serialize_string(&self.tag, dest)?;
+    // The `on` and `off` keywords are serialized as the shorter `1` and `0`, and `1` is the default.
+    if self.value != 1 {
+      dest.write_char(' ')?;
+      self.value.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [font-feature-settings](https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum FontFeatureSettings<'i> {
+  /// No features are changed from their defaults.
+  Normal,
+  /// A list of features to enable or disable.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(Vec<FeatureTagValue<'i>>),
+}
+
+impl<'i> Parse<'i> for FontFeatureSettings<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontFeatureSettings::Normal);
+    }
+
+    Ok(FontFeatureSettings::Features(
+      input.parse_comma_separated(FeatureTagValue::parse)?,
+    ))
+  }
+}
+
+impl<'i> ToCss for FontFeatureSettings<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      FontFeatureSettings::Normal => dest.write_str("normal"),
+      FontFeatureSettings::Features(features) => {
+        for (i, feature) in features.iter().enumerate() {
+          if i > 0 {
+            dest.delim(',', false)?;
+          }
+          feature.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
 /// A value for the [line-height](https://www.w3.org/TR/2020/WD-css-inline-3-20200827/#propdef-line-height) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -780,6 +1515,184 @@ impl<'i> ToCss for Font<'i> {
   }
 }
 
+/// A value for the [font-variant](https://www.w3.org/TR/css-fonts-4/#font-variant-prop) shorthand property.
+///
+/// If `ligatures` is `none`, then all other properties must be `normal`.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontVariant<'i> {
+  /// The ligatures and contextual forms to use.
+  pub ligatures: FontVariantLigatures,
+  /// How the text should be capitalized.
+  pub caps: FontVariantCaps,
+  /// The alternate glyphs to use.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub alternates: FontVariantAlternates<'i>,
+  /// The glyphs to use for numbers, fractions, and ordinals.
+  pub numeric: FontVariantNumeric,
+  /// The glyphs to use for East Asian text.
+  pub east_asian: FontVariantEastAsian,
+  /// Whether subscript or superscript glyphs are used.
+  pub position: FontVariantPosition,
+}
+
+impl<'i> Parse<'i> for FontVariant<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(FontVariant::default());
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(FontVariant {
+        ligatures: FontVariantLigatures::None,
+        ..FontVariant::default()
+      });
+    }
+
+    // The values of each longhand may be interleaved, but the `normal` and `none` keywords may not be combined with others.
+    let mut ligatures = LigatureValues::default();
+    let mut caps = None;
+    let mut alternates = FontVariantAlternates::default();
+    let mut numeric = FontVariantNumeric::default();
+    let mut east_asian = FontVariantEastAsian::default();
+    let mut position = None;
+    let mut any = false;
+
+    loop {
+      if input.try_parse(|input| ligatures.parse_value(input)).is_ok()
+        || input.try_parse(|input| alternates.parse_value(input)).is_ok()
+        || input.try_parse(|input| numeric.parse_value(input)).is_ok()
+        || input.try_parse(|input| east_asian.parse_value(input)).is_ok()
+      {
+        any = true;
+        continue;
+      }
+
+      if caps.is_none() {
+        if let Ok(value) = input.try_parse(parse_non_normal::<FontVariantCaps>) {
+          caps = Some(value);
+          any = true;
+          continue;
+        }
+      }
+
+      if position.is_none() {
+        if let Ok(value) = input.try_parse(parse_non_normal::<FontVariantPosition>) {
+          position = Some(value);
+          any = true;
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if !any {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(FontVariant {
+      ligatures: if ligatures == LigatureValues::default() {
+        FontVariantLigatures::Normal
+      } else {
+        FontVariantLigatures::Values(ligatures)
+      },
+      caps: caps.unwrap_or_default(),
+      alternates,
+      numeric,
+      east_asian,
+      position: position.unwrap_or_default(),
+    })
+  }
+}
+
+fn parse_non_normal<'i, 't, T: Parse<'i> + Default + PartialEq>(
+  input: &mut Parser<'i, 't>,
+) -> Result<T, ParseError<'i, ParserError<'i>>> {
+  let value = T::parse(input)?;
+  if value == T::default() {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(value)
+}
+
+impl<'i> ToCss for FontVariant<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.ligatures == FontVariantLigatures::None {
+      return dest.write_str("none");
+    }
+
+    let mut needs_space = false;
+    macro_rules! component {
+      ($key: ident) => {
+        if self.$key != Default::default() {
+          if needs_space {
+            dest.write_char(' ')?;
+          }
+          self.$key.to_css(dest)?;
+          needs_space = true;
+        }
+      };
+    }
+
+    component!(ligatures);
+    component!(caps);
+    component!(alternates);
+    component!(numeric);
+    component!(east_asian);
+    component!(position);
+    if !needs_space {
+      dest.write_str("normal")?;
+    }
+    Ok(())
+  }
+}
+
+impl<'i> FontVariant<'i> {
+  #[inline]
+  fn is_valid(
+    ligatures: &FontVariantLigatures,
+    caps: &FontVariantCaps,
+    alternates: &FontVariantAlternates,
+    numeric: &FontVariantNumeric,
+    east_asian: &FontVariantEastAsian,
+    position: &FontVariantPosition,
+  ) -> bool {
+    // The `none` keyword for ligatures cannot be combined with the values of other longhands.
+    *ligatures != FontVariantLigatures::None
+      || (*caps == FontVariantCaps::default()
+        && *alternates == FontVariantAlternates::default()
+        && *numeric == FontVariantNumeric::default()
+        && *east_asian == FontVariantEastAsian::default()
+        && *position == FontVariantPosition::default())
+  }
+}
+
+impl_shorthand! {
+  FontVariant(FontVariant<'i>) {
+    ligatures: [FontVariantLigatures],
+    caps: [FontVariantCaps],
+    alternates: [FontVariantAlternates],
+    numeric: [FontVariantNumeric],
+    east_asian: [FontVariantEastAsian],
+    position: [FontVariantPosition],
+  }
+
+  fn is_valid(shorthand) {
+    FontVariant::is_valid(
+      &shorthand.ligatures,
+      &shorthand.caps,
+      &shorthand.alternates,
+      &shorthand.numeric,
+      &shorthand.east_asian,
+      &shorthand.position,
+    )
+  }
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct FontHandler<'i> {
   family: Option<Vec<FontFamily<'i>>>,
@@ -789,6 +1702,12 @@ pub(crate) struct FontHandler<'i> {
   stretch: Option<FontStretch>,
   line_height: Option<LineHeight>,
   variant_caps: Option<FontVariantCaps>,
+  variant_ligatures: Option<FontVariantLigatures>,
+  variant_alternates: Option<FontVariantAlternates<'i>>,
+  variant_numeric: Option<FontVariantNumeric>,
+  variant_east_asian: Option<FontVariantEastAsian>,
+  variant_position: Option<FontVariantPosition>,
+  feature_settings: Option<(FontFeatureSettings<'i>, VendorPrefix)>,
   has_any: bool,
 }
 
@@ -815,7 +1734,35 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
       FontWeight(val) => property!(weight, val),
       FontStretch(val) => property!(stretch, val),
       FontVariantCaps(val) => property!(variant_caps, val),
+      FontVariantLigatures(val) => property!(variant_ligatures, val),
+      FontVariantAlternates(val) => property!(variant_alternates, val),
+      FontVariantNumeric(val) => property!(variant_numeric, val),
+      FontVariantEastAsian(val) => property!(variant_east_asian, val),
+      FontVariantPosition(val) => property!(variant_position, val),
+      FontVariant(val) => {
+        self.variant_ligatures = Some(val.ligatures.clone());
+        self.variant_caps = Some(val.caps.clone());
+        self.variant_alternates = Some(val.alternates.clone());
+        self.variant_numeric = Some(val.numeric.clone());
+        self.variant_east_asian = Some(val.east_asian.clone());
+        self.variant_position = Some(val.position.clone());
+        self.has_any = true;
+      }
       LineHeight(val) => property!(line_height, val),
+      FontFeatureSettings(val, prefix) => {
+        if let Some((cur, prefixes)) = &mut self.feature_settings {
+          if cur != val && !prefixes.contains(*prefix) {
+            self.finalize(dest, context);
+            self.feature_settings = Some((val.clone(), *prefix));
+          } else {
+            *cur = val.clone();
+            *prefixes |= *prefix;
+          }
+        } else {
+          self.feature_settings = Some((val.clone(), *prefix));
+        }
+        self.has_any = true;
+      }
       Font(val) => {
         self.family = Some(val.family.clone());
         self.size = Some(val.size.clone());
@@ -824,6 +1771,14 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.stretch = Some(val.stretch.clone());
         self.line_height = Some(val.line_height.clone());
         self.variant_caps = Some(val.variant_caps.clone());
+        // The font shorthand also resets the other font-variant longhands.
+        self.variant_ligatures = Some(FontVariantLigatures::default());
+        self.variant_alternates = Some(FontVariantAlternates::default());
+        self.variant_numeric = Some(FontVariantNumeric::default());
+        self.variant_east_asian = Some(FontVariantEastAsian::default());
+        self.variant_position = Some(FontVariantPosition::default());
+        // The font shorthand resets font-feature-settings as well, so earlier values have no effect.
+        self.feature_settings = None;
         self.has_any = true;
         // TODO: reset other properties
       }
@@ -831,6 +1786,12 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         self.finalize(dest, context);
         dest.push(property.clone());
       }
+      Unparsed(val) if matches!(val.property_id, PropertyId::FontFeatureSettings(_)) => {
+        self.finalize(dest, context);
+        let mut unparsed = val.get_prefixed(context.targets, prefixes::Feature::FontFeatureSettings);
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
       _ => return false,
     }
 
@@ -853,7 +1814,13 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
     let weight = std::mem::take(&mut self.weight);
     let stretch = std::mem::take(&mut self.stretch);
     let line_height = std::mem::take(&mut self.line_height);
-    let variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut variant_caps = std::mem::take(&mut self.variant_caps);
+    let mut variant_ligatures = std::mem::take(&mut self.variant_ligatures);
+    let mut variant_alternates = std::mem::take(&mut self.variant_alternates);
+    let mut variant_numeric = std::mem::take(&mut self.variant_numeric);
+    let mut variant_east_asian = std::mem::take(&mut self.variant_east_asian);
+    let mut variant_position = std::mem::take(&mut self.variant_position);
+    let feature_settings = std::mem::take(&mut self.feature_settings);
 
     if let Some(family) = &mut family {
       if family.len() > 1 {
@@ -886,11 +1853,31 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         },
       }));
 
-      // The `font` property only accepts CSS 2.1 values for font-variant caps.
-      // If we have a CSS 3+ value, we need to add a separate property.
-      if !caps.is_css2() {
-        decls.push(Property::FontVariantCaps(variant_caps.unwrap()))
+      // The `font` property only accepts CSS 2.1 values for font-variant caps, and resets
+      // the other font-variant longhands. Any other values need to be added separately.
+      variant_caps = if caps.is_css2() { None } else { Some(caps) };
+      macro_rules! reset_default {
+        ($key: ident) => {
+          if $key.as_ref().map_or(false, |v| *v == Default::default()) {
+            $key = None;
+          }
+        };
       }
+      reset_default!(variant_ligatures);
+      reset_default!(variant_alternates);
+      reset_default!(variant_numeric);
+      reset_default!(variant_east_asian);
+      reset_default!(variant_position);
+
+      push_font_variant(
+        decls,
+        variant_ligatures,
+        variant_caps,
+        variant_alternates,
+        variant_numeric,
+        variant_east_asian,
+        variant_position,
+      );
     } else {
       if let Some(val) = family {
         decls.push(Property::FontFamily(val))
@@ -904,9 +1891,15 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         decls.push(Property::FontStyle(val))
       }
 
-      if let Some(val) = variant_caps {
-        decls.push(Property::FontVariantCaps(val))
-      }
+      push_font_variant(
+        decls,
+        variant_ligatures,
+        variant_caps,
+        variant_alternates,
+        variant_numeric,
+        variant_east_asian,
+        variant_position,
+      );
 
       if let Some(val) = weight {
         decls.push(Property::FontWeight(val))
@@ -920,6 +1913,76 @@ impl<'i> PropertyHandler<'i> for FontHandler<'i> {
         decls.push(Property::LineHeight(val))
       }
     }
+
+    if let Some((val, mut prefix)) = feature_settings {
+      if prefix.contains(VendorPrefix::None) {
+        if let Some(targets) = context.targets {
+          prefix = prefixes::Feature::FontFeatureSettings.prefixes_for(targets);
+        }
+      }
+
+      decls.push(Property::FontFeatureSettings(val, prefix))
+    }
+  }
+}
+
+/// Adds the font-variant longhands to the declaration list, collapsing them into the
+/// `font-variant` shorthand if possible.
+fn push_font_variant<'i>(
+  decls: &mut DeclarationList<'i>,
+  ligatures: Option<FontVariantLigatures>,
+  caps: Option<FontVariantCaps>,
+  alternates: Option<FontVariantAlternates<'i>>,
+  numeric: Option<FontVariantNumeric>,
+  east_asian: Option<FontVariantEastAsian>,
+  position: Option<FontVariantPosition>,
+) {
+  if let (Some(ligatures), Some(caps), Some(alternates), Some(numeric), Some(east_asian), Some(position)) =
+    (&ligatures, &caps, &alternates, &numeric, &east_asian, &position)
+  {
+    // The `none` keyword cannot be combined with other values in the shorthand,
+    // so it is added as a separate longhand after the other values.
+    let is_valid = FontVariant::is_valid(ligatures, caps, alternates, numeric, east_asian, position);
+    decls.push(Property::FontVariant(FontVariant {
+      ligatures: if is_valid {
+        ligatures.clone()
+      } else {
+        FontVariantLigatures::Normal
+      },
+      caps: caps.clone(),
+      alternates: alternates.clone(),
+      numeric: numeric.clone(),
+      east_asian: east_asian.clone(),
+      position: position.clone(),
+    }));
+    if !is_valid {
+      decls.push(Property::FontVariantLigatures(FontVariantLigatures::None));
+    }
+    return;
+  }
+
+  if let Some(val) = ligatures {
+    decls.push(Property::FontVariantLigatures(val))
+  }
+
+  if let Some(val) = caps {
+    decls.push(Property::FontVariantCaps(val))
+  }
+
+  if let Some(val) = alternates {
+    decls.push(Property::FontVariantAlternates(val))
+  }
+
+  if let Some(val) = numeric {
+    decls.push(Property::FontVariantNumeric(val))
+  }
+
+  if let Some(val) = east_asian {
+    decls.push(Property::FontVariantEastAsian(val))
+  }
+
+  if let Some(val) = position {
+    decls.push(Property::FontVariantPosition(val))
   }
 }
 
@@ -970,6 +2033,12 @@ fn is_font_property(property_id: &PropertyId) -> bool {
     | PropertyId::FontWeight
     | PropertyId::FontStretch
     | PropertyId::FontVariantCaps
+    | PropertyId::FontVariantLigatures
+    | PropertyId::FontVariantAlternates
+    | PropertyId::FontVariantNumeric
+    | PropertyId::FontVariantEastAsian
+    | PropertyId::FontVariantPosition
+    | PropertyId::FontVariant
     | PropertyId::LineHeight
     | PropertyId::Font => true,
     _ => false,
@@ -0,0 +1,250 @@
+//! CSS properties related to fragmentation, i.e. breaking content across pages and columns.
+
+use super::{Property, PropertyId};
+use crate::compat;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::DeclarationList;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::targets::Browsers;
+use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::values::number::CSSInteger;
+use cssparser::*;
+
+enum_property! {
+  /// A value for the [break-before](https://drafts.csswg.org/css-break-3/#propdef-break-before)
+  /// and [break-after](https://drafts.csswg.org/css-break-3/#propdef-break-after) properties.
+  pub enum BreakBetween {
+    /// Neither force nor forbid a break.
+    "auto": Auto,
+    /// Avoid a break.
+    "avoid": Avoid,
+    /// Avoid a page break.
+    "avoid-page": AvoidPage,
+    /// Force a page break.
+    "page": Page,
+    /// Force one or two page breaks so that the next page is formatted as a left page.
+    "left": Left,
+    /// Force one or two page breaks so that the next page is formatted as a right page.
+    "right": Right,
+    /// Force one or two page breaks so that the next page is formatted as a recto page.
+    "recto": Recto,
+    /// Force one or two page breaks so that the next page is formatted as a verso page.
+    "verso": Verso,
+    /// Avoid a column break.
+    "avoid-column": AvoidColumn,
+    /// Force a column break.
+    "column": Column,
+    /// Avoid a region break.
+    "avoid-region": AvoidRegion,
+    /// Force a region break.
+    "region": Region,
+  }
+}
+
+enum_property! {
+  /// A value for the [break-inside](https://drafts.csswg.org/css-break-3/#propdef-break-inside) property.
+  pub enum BreakInside {
+    /// Neither force nor forbid a break within the box.
+    "auto": Auto,
+    /// Avoid a break within the box.
+    "avoid": Avoid,
+    /// Avoid a page break within the box.
+    "avoid-page": AvoidPage,
+    /// Avoid a column break within the box.
+    "avoid-column": AvoidColumn,
+    /// Avoid a region break within the box.
+    "avoid-region": AvoidRegion,
+  }
+}
+
+enum_property! {
+  /// A value for the legacy [page-break-before](https://drafts.csswg.org/css-break-3/#page-break-properties)
+  /// and `page-break-after` properties. These are aliases of `break-before` and `break-after`.
+  pub enum PageBreak {
+    /// Neither force nor forbid a page break.
+    "auto": Auto,
+    /// Force a page break.
+    "always": Always,
+    /// Avoid a page break.
+    "avoid": Avoid,
+    /// Force one or two page breaks so that the next page is formatted as a left page.
+    "left": Left,
+    /// Force one or two page breaks so that the next page is formatted as a right page.
+    "right": Right,
+  }
+}
+
+enum_property! {
+  /// A value for the legacy [page-break-inside](https://drafts.csswg.org/css-break-3/#page-break-properties)
+  /// property. This is an alias of `break-inside`.
+  pub enum PageBreakInside {
+    /// Neither force nor forbid a page break within the box.
+    "auto": Auto,
+    /// Avoid a page break within the box.
+    "avoid": Avoid,
+  }
+}
+
+impl From<PageBreak> for BreakBetween {
+  fn from(value: PageBreak) -> BreakBetween {
+    match value {
+      PageBreak::Auto => BreakBetween::Auto,
+      PageBreak::Always => BreakBetween::Page,
+      PageBreak::Avoid => BreakBetween::Avoid,
+      PageBreak::Left => BreakBetween::Left,
+      PageBreak::Right => BreakBetween::Right,
+    }
+  }
+}
+
+impl BreakBetween {
+  /// Returns the equivalent value for the legacy `page-break-*` properties, if any.
+  pub fn to_page_break(&self) -> Option<PageBreak> {
+    match self {
+      BreakBetween::Auto => Some(PageBreak::Auto),
+      BreakBetween::Page => Some(PageBreak::Always),
+      BreakBetween::Avoid => Some(PageBreak::Avoid),
+      BreakBetween::Left => Some(PageBreak::Left),
+      BreakBetween::Right => Some(PageBreak::Right),
+      _ => None,
+    }
+  }
+}
+
+impl From<PageBreakInside> for BreakInside {
+  fn from(value: PageBreakInside) -> BreakInside {
+    match value {
+      PageBreakInside::Auto => BreakInside::Auto,
+      PageBreakInside::Avoid => BreakInside::Avoid,
+    }
+  }
+}
+
+impl BreakInside {
+  /// Returns the equivalent value for the legacy `page-break-inside` property, if any.
+  pub fn to_page_break(&self) -> Option<PageBreakInside> {
+    match self {
+      BreakInside::Auto => Some(PageBreakInside::Auto),
+      BreakInside::Avoid => Some(PageBreakInside::Avoid),
+      _ => None,
+    }
+  }
+}
+
+/// A value for the [orphans](https://drafts.csswg.org/css-break-3/#propdef-orphans)
+/// and [widows](https://drafts.csswg.org/css-break-3/#propdef-widows) properties.
+///
+/// This is the minimum number of lines to keep together, and must be a positive integer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineCount(pub CSSInteger);
+
+impl<'i> Parse<'i> for LineCount {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let count = CSSInteger::parse(input)?;
+    if count < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(LineCount(count))
+  }
+}
+
+impl ToCss for LineCount {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.0.to_css(dest)
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct BreakHandler {
+  targets: Option<Browsers>,
+  before: Option<BreakBetween>,
+  after: Option<BreakBetween>,
+  inside: Option<BreakInside>,
+}
+
+impl BreakHandler {
+  pub fn new(targets: Option<Browsers>) -> BreakHandler {
+    BreakHandler {
+      targets,
+      ..BreakHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for BreakHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::BreakBefore(val) => self.before = Some(*val),
+      Property::BreakAfter(val) => self.after = Some(*val),
+      Property::BreakInside(val) => self.inside = Some(*val),
+      // The legacy properties are aliases, and are converted to their modern equivalents.
+      Property::PageBreakBefore(val) => self.before = Some((*val).into()),
+      Property::PageBreakAfter(val) => self.after = Some((*val).into()),
+      Property::PageBreakInside(val) => self.inside = Some((*val).into()),
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::BreakBefore
+            | PropertyId::BreakAfter
+            | PropertyId::BreakInside
+            | PropertyId::PageBreakBefore
+            | PropertyId::PageBreakAfter
+            | PropertyId::PageBreakInside
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    let needs_legacy = match self.targets {
+      Some(targets) => !compat::Feature::BreakProperties.is_compatible(targets),
+      None => false,
+    };
+
+    if let Some(before) = std::mem::take(&mut self.before) {
+      if needs_legacy {
+        if let Some(legacy) = before.to_page_break() {
+          dest.push(Property::PageBreakBefore(legacy));
+        }
+      }
+      dest.push(Property::BreakBefore(before));
+    }
+
+    if let Some(after) = std::mem::take(&mut self.after) {
+      if needs_legacy {
+        if let Some(legacy) = after.to_page_break() {
+          dest.push(Property::PageBreakAfter(legacy));
+        }
+      }
+      dest.push(Property::BreakAfter(after));
+    }
+
+    if let Some(inside) = std::mem::take(&mut self.inside) {
+      if needs_legacy {
+        if let Some(legacy) = inside.to_page_break() {
+          dest.push(Property::PageBreakInside(legacy));
+        }
+      }
+      dest.push(Property::BreakInside(inside));
+    }
+  }
+}
@@ -349,13 +349,21 @@ impl ToCss for RepeatCount {
   }
 }
 
+/// Parses a line name, which may not be `span` or `auto`.
+#[inline]
+fn parse_line_name<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+  CustomIdent::parse_except(input, &["span", "auto"])
+}
+
 fn parse_line_names<'i, 't>(
   input: &mut Parser<'i, 't>,
 ) -> Result<CustomIdentList<'i>, ParseError<'i, ParserError<'i>>> {
   input.expect_square_bracket_block()?;
   input.parse_nested_block(|input| {
     let mut values = SmallVec::new();
-    while let Ok(ident) = input.try_parse(CustomIdent::parse) {
+    while let Ok(ident) = input.try_parse(parse_line_name) {
       values.push(ident)
     }
     Ok(values)
@@ -572,17 +580,60 @@ impl<'i> Parse<'i> for GridTemplateAreas {
       if row == 0 {
         columns = parsed_columns;
       } else if parsed_columns != columns {
-        return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+        return Err(input.new_custom_error(ParserError::InvalidGridTemplateAreasRow));
       }
 
       row += 1;
     }
 
+    // At least one string is required.
+    if row == 0 {
+      return Err(input.new_error_for_next_token());
+    }
+
+    Self::validate_areas(&tokens, columns)
+      .map_err(|name| input.new_custom_error(ParserError::InvalidGridArea(name.into())))?;
     Ok(GridTemplateAreas::Areas { columns, areas: tokens })
   }
 }
 
 impl GridTemplateAreas {
+  /// Checks that each named grid area forms a single filled-in rectangle,
+  /// returning the name of the first area that does not.
+  fn validate_areas(tokens: &[Option<String>], columns: u32) -> Result<(), String> {
+    let columns = columns as usize;
+    let mut bounds: Vec<(&str, usize, usize, usize, usize, usize)> = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+      let name = match token {
+        Some(name) => name.as_str(),
+        None => continue,
+      };
+
+      let (row, column) = (i / columns, i % columns);
+      if let Some((_, min_row, max_row, min_column, max_column, count)) =
+        bounds.iter_mut().find(|(n, ..)| *n == name)
+      {
+        *min_row = (*min_row).min(row);
+        *max_row = (*max_row).max(row);
+        *min_column = (*min_column).min(column);
+        *max_column = (*max_column).max(column);
+        *count += 1;
+      } else {
+        bounds.push((name, row, row, column, column, 1));
+      }
+    }
+
+    // The cells of an area are all within its bounding box, so the area is a rectangle
+    // exactly when it has as many cells as the bounding box.
+    for (name, min_row, max_row, min_column, max_column, count) in bounds {
+      if (max_row - min_row + 1) * (max_column - min_column + 1) != count {
+        return Err(name.into());
+      }
+    }
+
+    Ok(())
+  }
+
   fn parse_string(string: &str, tokens: &mut Vec<Option<String>>) -> Result<u32, ()> {
     let mut string = string;
     let mut column = 0;
@@ -676,22 +727,33 @@ impl GridTemplateAreas {
       _ => unreachable!(),
     };
 
+    let widths = self.column_widths(dest);
+
     dest.write_char('"')?;
 
     let mut last_was_null = false;
     for i in 0..columns {
+      let width = widths.as_ref().map_or(1, |widths| widths[i as usize]);
       if let Some(token) = next {
         if let Some(string) = token {
           if i > 0 && (!last_was_null || !dest.minify) {
             dest.write_char(' ')?;
           }
           write_ident(string, dest)?;
+          if widths.is_some() && i + 1 < columns {
+            for _ in ident_width(string)..width {
+              dest.write_char(' ')?;
+            }
+          }
           last_was_null = false;
         } else {
           if i > 0 && (last_was_null || !dest.minify) {
             dest.write_char(' ')?;
           }
-          dest.write_char('.')?;
+          // A sequence of dots is a single null cell token, so it can fill the column.
+          for _ in 0..width {
+            dest.write_char('.')?;
+          }
           last_was_null = true;
         }
       }
@@ -701,6 +763,37 @@ impl GridTemplateAreas {
 
     dest.write_char('"')
   }
+
+  /// Returns the width of each column when the `align_grid_areas` printer option is enabled,
+  /// or `None` if the rows should not be aligned.
+  fn column_widths<W>(&self, dest: &Printer<W>) -> Option<Vec<usize>> {
+    let (columns, areas) = match self {
+      GridTemplateAreas::Areas { columns, areas } => (*columns as usize, areas),
+      GridTemplateAreas::None => return None,
+    };
+
+    // CSS module names are not known until they are written, so they cannot be aligned.
+    if dest.minify || !dest.align_grid_areas || dest.css_module.is_some() || columns == 0 {
+      return None;
+    }
+
+    let mut widths = vec![1; columns];
+    for (i, token) in areas.iter().enumerate() {
+      if let Some(name) = token {
+        let width = &mut widths[i % columns];
+        *width = (*width).max(ident_width(name));
+      }
+    }
+
+    Some(widths)
+  }
+}
+
+/// Returns the number of characters in the serialized form of an identifier.
+fn ident_width(name: &str) -> usize {
+  let mut s = String::new();
+  let _ = serialize_identifier(name, &mut s);
+  s.chars().count()
 }
 
 /// A value for the [grid-template](https://drafts.csswg.org/css-grid-2/#explicit-grid-shorthand) shorthand property.
@@ -752,7 +845,7 @@ impl<'i> Parse<'i> for GridTemplate<'i> {
         if row == 0 {
           columns = parsed_columns;
         } else if parsed_columns != columns {
-          return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+          return Err(input.new_custom_error(ParserError::InvalidGridTemplateAreasRow));
         }
 
         row += 1;
@@ -768,6 +861,9 @@ impl<'i> Parse<'i> for GridTemplate<'i> {
     }
 
     if !tokens.is_empty() {
+      GridTemplateAreas::validate_areas(&tokens, columns)
+        .map_err(|name| input.new_custom_error(ParserError::InvalidGridArea(name.into())))?;
+
       if line_names.len() == items.len() {
         line_names.push(Default::default());
       }
@@ -1245,16 +1341,17 @@ impl<'i> Parse<'i> for GridLine<'i> {
     if input.try_parse(|input| input.expect_ident_matching("span")).is_ok() {
       // TODO: is calc() supported here??
       let (line_number, ident) = if let Ok(line_number) = input.try_parse(CSSInteger::parse) {
-        let ident = input.try_parse(CustomIdent::parse).ok();
+        let ident = input.try_parse(parse_line_name).ok();
         (line_number, ident)
-      } else if let Ok(ident) = input.try_parse(CustomIdent::parse) {
+      } else if let Ok(ident) = input.try_parse(parse_line_name) {
         let line_number = input.try_parse(CSSInteger::parse).unwrap_or(1);
         (line_number, Some(ident))
       } else {
         return Err(input.new_custom_error(ParserError::InvalidDeclaration));
       };
 
-      if line_number == 0 {
+      // Negative integers and zero are invalid for spans.
+      if line_number <= 0 {
         return Err(input.new_custom_error(ParserError::InvalidDeclaration));
       }
 
@@ -1265,11 +1362,11 @@ impl<'i> Parse<'i> for GridLine<'i> {
       if line_number == 0 {
         return Err(input.new_custom_error(ParserError::InvalidDeclaration));
       }
-      let ident = input.try_parse(CustomIdent::parse).ok();
+      let ident = input.try_parse(parse_line_name).ok();
       return Ok(GridLine::Line(line_number, ident));
     }
 
-    let ident = CustomIdent::parse(input)?;
+    let ident = parse_line_name(input)?;
     if let Ok(line_number) = input.try_parse(CSSInteger::parse) {
       if line_number == 0 {
         return Err(input.new_custom_error(ParserError::InvalidDeclaration));
@@ -4,13 +4,15 @@ use super::{Property, PropertyId};
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthand_property};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler};
 use crate::printer::Printer;
 use crate::targets::Browsers;
 use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::number::CSSInteger;
 use crate::values::string::CowArcStr;
 use crate::values::{ident::CustomIdent, image::Image};
 use cssparser::*;
+use smallvec::SmallVec;
 
 /// A value for the [list-style-type](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#text-markers) property.
 #[derive(Debug, Clone, PartialEq)]
@@ -297,7 +299,528 @@ enum_property! {
   }
 }
 
-shorthand_property! {
+/// A [`<counter>`](https://www.w3.org/TR/css-lists-3/#typedef-counter) value,
+/// i.e. the `counter()` or `counters()` function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum Counter<'i> {
+  /// The [`counter()`](https://www.w3.org/TR/css-lists-3/#funcdef-counter) function,
+  /// which represents the innermost counter with the given name.
+  Counter {
+    /// The name of the counter.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: CustomIdent<'i>,
+    /// The counter style used to represent the counter value.
+    style: CounterStyle<'i>,
+  },
+  /// The [`counters()`](https://www.w3.org/TR/css-lists-3/#funcdef-counters) function,
+  /// which represents all counters with the given name, joined by a separator.
+  Counters {
+    /// The name of the counter.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    name: CustomIdent<'i>,
+    /// The string used to separate the values of nested counters.
+    separator: CowArcStr<'i>,
+    /// The counter style used to represent the counter values.
+    style: CounterStyle<'i>,
+  },
+}
+
+impl<'i> Parse<'i> for Counter<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let f = input.expect_function()?.clone();
+    match_ignore_ascii_case! { &*f,
+      "counter" => input.parse_nested_block(|input| {
+        let name = parse_counter_name(input)?;
+        let style = parse_counter_style(input)?;
+        Ok(Counter::Counter { name, style })
+      }),
+      "counters" => input.parse_nested_block(|input| {
+        let name = parse_counter_name(input)?;
+        input.expect_comma()?;
+        let separator = input.expect_string_cloned()?.into();
+        let style = parse_counter_style(input)?;
+        Ok(Counter::Counters { name, separator, style })
+      }),
+      _ => Err(location.new_unexpected_token_error(Token::Function(f.clone())))
+    }
+  }
+}
+
+fn parse_counter_name<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CustomIdent<'i>, ParseError<'i, ParserError<'i>>> {
+  // `none` is not a valid counter name, in addition to the CSS-wide keywords.
+  let location = input.current_source_location();
+  let name = CustomIdent::parse(input)?;
+  if name.0.eq_ignore_ascii_case("none") {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(name)
+}
+
+fn parse_counter_style<'i, 't>(
+  input: &mut Parser<'i, 't>,
+) -> Result<CounterStyle<'i>, ParseError<'i, ParserError<'i>>> {
+  if input.try_parse(|input| input.expect_comma()).is_ok() {
+    CounterStyle::parse(input)
+  } else {
+    Ok(CounterStyle::Predefined(PredefinedCounterStyle::Decimal))
+  }
+}
+
+impl<'i> ToCss for Counter<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let style = match self {
+      Counter::Counter { name, style } => {
+        dest.write_str("counter(")?;
+        name.to_css(dest)?;
+        style
+      }
+      Counter::Counters { name, separator, style } => {
+        dest.write_str("counters(")?;
+        name.to_css(dest)?;
+        dest.delim(',', false)?;
+        serialize_string(&separator, dest)?;
+        style
+      }
+    };
+
+    // The decimal style is the default, so it can be omitted.
+    if *style != CounterStyle::Predefined(PredefinedCounterStyle::Decimal) {
+      dest.delim(',', false)?;
+      style.to_css(dest)?;
+    }
+
+    dest.write_char(')')
+  }
+}
+
+/// A counter name and value, as used in the [counter-reset](CounterReset), [counter-increment](CounterIncrement),
+/// and [counter-set](CounterSet) properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CounterValue<'i> {
+  /// The name of the counter.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub name: CustomIdent<'i>,
+  /// Whether this is a [reversed counter](https://www.w3.org/TR/css-lists-3/#reversed-counter),
+  /// i.e. `reversed(name)`. Only valid in `counter-reset`.
+  pub reversed: bool,
+  /// The value of the counter, or `None` for the default value of the property.
+  pub value: Option<CSSInteger>,
+}
+
+fn parse_counter_values<'i, 't>(
+  input: &mut Parser<'i, 't>,
+  default: CSSInteger,
+  allow_reversed: bool,
+) -> Result<SmallVec<[CounterValue<'i>; 1]>, ParseError<'i, ParserError<'i>>> {
+  let mut counters: SmallVec<[CounterValue<'i>; 1]> = SmallVec::new();
+  loop {
+    let reversed = allow_reversed && input.try_parse(|input| input.expect_function_matching("reversed")).is_ok();
+    let name = if reversed {
+      input.parse_nested_block(parse_counter_name)?
+    } else if counters.is_empty() {
+      parse_counter_name(input)?
+    } else if let Ok(name) = input.try_parse(parse_counter_name) {
+      name
+    } else {
+      break;
+    };
+
+    // The default value can be omitted, except for reversed counters where
+    // the default is computed from the number of list items instead.
+    let value = input
+      .try_parse(CSSInteger::parse)
+      .ok()
+      .filter(|value| reversed || *value != default);
+    counters.push(CounterValue { name, reversed, value });
+  }
+
+  Ok(counters)
+}
+
+fn write_counter_values<W>(
+  counters: &SmallVec<[CounterValue; 1]>,
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  for counter in counters {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+
+    if counter.reversed {
+      dest.write_str("reversed(")?;
+      counter.name.to_css(dest)?;
+      dest.write_char(')')?;
+    } else {
+      counter.name.to_css(dest)?;
+    }
+
+    if let Some(value) = counter.value {
+      dest.write_char(' ')?;
+      value.to_css(dest)?;
+    }
+  }
+  Ok(())
+}
+
+macro_rules! define_counter_property {
+  (
+    $(#[$outer:meta])*
+    $name: ident, default: $default: literal, reversed: $reversed: literal
+  ) => {
+    $(#[$outer])*
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(
+      feature = "serde",
+      derive(serde::Serialize, serde::Deserialize),
+      serde(tag = "type", content = "value", rename_all = "kebab-case")
+    )]
+    pub enum $name<'i> {
+      /// No counters are affected.
+      None,
+      /// A list of counters.
+      #[cfg_attr(feature = "serde", serde(borrow))]
+      Counters(SmallVec<[CounterValue<'i>; 1]>),
+    }
+
+    impl<'i> Parse<'i> for $name<'i> {
+      fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+          return Ok($name::None);
+        }
+
+        Ok($name::Counters(parse_counter_values(input, $default, $reversed)?))
+      }
+    }
+
+    impl<'i> ToCss for $name<'i> {
+      fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+      where
+        W: std::fmt::Write,
+      {
+        match self {
+          $name::None => dest.write_str("none"),
+          $name::Counters(counters) => write_counter_values(counters, dest),
+        }
+      }
+    }
+  };
+}
+
+define_counter_property! {
+  /// A value for the [counter-reset](https://www.w3.org/TR/css-lists-3/#counter-reset) property.
+  CounterReset, default: 0, reversed: true
+}
+
+define_counter_property! {
+  /// A value for the [counter-increment](https://www.w3.org/TR/css-lists-3/#increment-set) property.
+  CounterIncrement, default: 1, reversed: false
+}
+
+define_counter_property! {
+  /// A value for the [counter-set](https://www.w3.org/TR/css-lists-3/#increment-set) property.
+  CounterSet, default: 0, reversed: false
+}
+
+enum_property! {
+  /// A [`<quote>`](https://www.w3.org/TR/css-content-3/#typedef-quote) value, as used in the `content` property.
+  pub enum Quote {
+    /// An opening quote.
+    "open-quote": OpenQuote,
+    /// A closing quote.
+    "close-quote": CloseQuote,
+    /// Increments the quote nesting level without inserting a quote.
+    "no-open-quote": NoOpenQuote,
+    /// Decrements the quote nesting level without inserting a quote.
+    "no-close-quote": NoCloseQuote,
+  }
+}
+
+/// An item within the value of the [content](Content) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ContentItem<'i> {
+  /// A string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CowArcStr<'i>),
+  /// An image.
+  Image(Image<'i>),
+  /// A `counter()` or `counters()` function.
+  Counter(Counter<'i>),
+  /// A quote.
+  Quote(Quote),
+}
+
+impl<'i> Parse<'i> for ContentItem<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(counter) = input.try_parse(Counter::parse) {
+      return Ok(ContentItem::Counter(counter));
+    }
+
+    if let Ok(quote) = input.try_parse(Quote::parse) {
+      return Ok(ContentItem::Quote(quote));
+    }
+
+    if let Ok(s) = input.try_parse(|input| input.expect_string_cloned()) {
+      return Ok(ContentItem::String(s.into()));
+    }
+
+    let image = Image::parse(input)?;
+    if image == Image::None {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+    Ok(ContentItem::Image(image))
+  }
+}
+
+impl<'i> ToCss for ContentItem<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ContentItem::String(s) => {
+        serialize_string(&s, dest)?;
+        Ok(())
+      }
+      ContentItem::Image(image) => image.to_css(dest),
+      ContentItem::Counter(counter) => counter.to_css(dest),
+      ContentItem::Quote(quote) => quote.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [content](https://www.w3.org/TR/css-content-3/#content-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum Content<'i> {
+  /// The `normal` keyword.
+  Normal,
+  /// The `none` keyword.
+  None,
+  /// A list of content items.
+  List {
+    /// The items to generate.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    items: Vec<ContentItem<'i>>,
+    /// Alternative text for the generated content, consisting of strings and counters.
+    alt: Option<Vec<ContentItem<'i>>>,
+  },
+}
+
+impl Default for Content<'_> {
+  fn default() -> Self {
+    Content::Normal
+  }
+}
+
+impl<'i> Parse<'i> for Content<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(Content::Normal);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Content::None);
+    }
+
+    let mut items = Vec::new();
+    while let Ok(item) = input.try_parse(ContentItem::parse) {
+      items.push(item);
+    }
+
+    if items.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let alt = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+      let mut alt = Vec::new();
+      while let Ok(item) = input.try_parse(|input| {
+        let location = input.current_source_location();
+        match ContentItem::parse(input)? {
+          item @ ContentItem::String(..) | item @ ContentItem::Counter(..) => Ok(item),
+          _ => Err(location.new_custom_error(ParserError::InvalidValue)),
+        }
+      }) {
+        alt.push(item);
+      }
+
+      if alt.is_empty() {
+        return Err(input.new_custom_error(ParserError::InvalidValue));
+      }
+
+      Some(alt)
+    } else {
+      None
+    };
+
+    Ok(Content::List { items, alt })
+  }
+}
+
+impl<'i> ToCss for Content<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Content::Normal => dest.write_str("normal"),
+      Content::None => dest.write_str("none"),
+      Content::List { items, alt } => {
+        write_content_items(items, dest)?;
+        if let Some(alt) = alt {
+          dest.delim('/', true)?;
+          write_content_items(alt, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A pair of opening and closing quotes, as used in the [quotes](Quotes) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuotePair<'i> {
+  /// The opening quote.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub open: CowArcStr<'i>,
+  /// The closing quote.
+  pub close: CowArcStr<'i>,
+}
+
+/// A value for the [quotes](https://www.w3.org/TR/css-content-3/#quotes) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum Quotes<'i> {
+  /// Appropriate quote marks are chosen automatically based on the content language.
+  Auto,
+  /// The `open-quote` and `close-quote` values of the content property produce no quotes.
+  None,
+  /// Pairs of opening and closing quotes, one for each level of nesting.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Pairs(SmallVec<[QuotePair<'i>; 2]>),
+}
+
+impl<'i> Parse<'i> for Quotes<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(Quotes::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(Quotes::None);
+    }
+
+    let mut pairs: SmallVec<[QuotePair<'i>; 2]> = SmallVec::new();
+    loop {
+      let open = if pairs.is_empty() {
+        input.expect_string_cloned()?
+      } else if let Ok(open) = input.try_parse(|input| input.expect_string_cloned()) {
+        open
+      } else {
+        break;
+      };
+
+      // Quotes must be specified in pairs.
+      let close = input.expect_string_cloned()?;
+      pairs.push(QuotePair {
+        open: open.into(),
+        close: close.into(),
+      });
+    }
+
+    Ok(Quotes::Pairs(pairs))
+  }
+}
+
+impl<'i> ToCss for Quotes<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Quotes::Auto => dest.write_str("auto"),
+      Quotes::None => dest.write_str("none"),
+      Quotes::Pairs(pairs) => {
+        let mut first = true;
+        for pair in pairs {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          serialize_string(&pair.open, dest)?;
+          dest.write_char(' ')?;
+          serialize_string(&pair.close, dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+fn write_content_items<W>(items: &Vec<ContentItem>, dest: &mut Printer<W>) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+{
+  let mut first = true;
+  let mut iter = items.iter().peekable();
+  while let Some(item) = iter.next() {
+    if first {
+      first = false;
+    } else {
+      dest.write_char(' ')?;
+    }
+
+    // Adjacent strings are concatenated, so they can be combined into a single string.
+    if let ContentItem::String(s) = item {
+      if let Some(ContentItem::String(..)) = iter.peek() {
+        let mut s = s.to_string();
+        while let Some(ContentItem::String(next)) = iter.peek() {
+          s.push_str(next);
+          iter.next();
+        }
+        serialize_string(&s, dest)?;
+        continue;
+      }
+    }
+
+    item.to_css(dest)?;
+  }
+  Ok(())
+}
+
+define_shorthand! {
   /// A value for the [list-style](https://www.w3.org/TR/2020/WD-css-lists-3-20201117/#list-style-property) shorthand property.
   pub struct ListStyle<'i> {
     /// The list style type.
@@ -310,6 +833,99 @@ shorthand_property! {
   }
 }
 
+impl<'i> Parse<'i> for ListStyle<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut list_style_type = None;
+    let mut image = None;
+    let mut position = None;
+    let mut nones = 0;
+
+    loop {
+      // `none` is valid for both the type and the image, so it is resolved once all other values are known.
+      if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+        nones += 1;
+        continue;
+      }
+
+      // Parse the position before the type so that it is not treated as a counter style name.
+      if position.is_none() {
+        if let Ok(val) = input.try_parse(ListStylePosition::parse) {
+          position = Some(val);
+          continue;
+        }
+      }
+
+      if image.is_none() {
+        if let Ok(val) = input.try_parse(Image::parse) {
+          image = Some(val);
+          continue;
+        }
+      }
+
+      if list_style_type.is_none() {
+        if let Ok(val) = input.try_parse(ListStyleType::parse) {
+          list_style_type = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    match (nones, &list_style_type, &image) {
+      (0, _, _) => {}
+      (1, None, _) => list_style_type = Some(ListStyleType::None),
+      (1, Some(_), None) => image = Some(Image::None),
+      (2, None, None) => {
+        list_style_type = Some(ListStyleType::None);
+        image = Some(Image::None);
+      }
+      _ => return Err(input.new_custom_error(ParserError::InvalidValue)),
+    }
+
+    Ok(ListStyle {
+      list_style_type: list_style_type.unwrap_or_default(),
+      image: image.unwrap_or_default(),
+      position: position.unwrap_or_default(),
+    })
+  }
+}
+
+impl<'i> ToCss for ListStyle<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let mut needs_space = false;
+    if self.list_style_type != ListStyleType::default() {
+      self.list_style_type.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.image != Image::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.image.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if self.position != ListStylePosition::default() {
+      if needs_space {
+        dest.write_char(' ')?;
+      }
+      self.position.to_css(dest)?;
+      needs_space = true;
+    }
+
+    if !needs_space {
+      self.list_style_type.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
 impl<'i> FallbackValues for ListStyle<'i> {
   fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
     self
@@ -5,7 +5,7 @@ use crate::error::{ParserError, PrinterError};
 use crate::logical::PropertyCategory;
 use crate::macros::{define_shorthand, rect_shorthand, size_shorthand};
 use crate::printer::Printer;
-use crate::properties::{Property, PropertyId};
+use crate::properties::{position::InsetValue, Property, PropertyId};
 use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::{length::LengthPercentageOrAuto, rect::Rect, size::Size2D};
 use cssparser::*;
@@ -52,7 +52,7 @@ rect_shorthand! {
 
 rect_shorthand! {
   /// A value for the [inset](https://drafts.csswg.org/css-logical/#propdef-inset) shorthand property.
-  pub struct Inset<LengthPercentageOrAuto> {
+  pub struct Inset<'i, InsetValue<'i>> {
     Top,
     Right,
     Bottom,
@@ -142,7 +142,7 @@ size_shorthand! {
 
 size_shorthand! {
   /// A value for the [inset-block](https://drafts.csswg.org/css-logical/#propdef-inset-block) shorthand property.
-  pub struct InsetBlock<LengthPercentageOrAuto> {
+  pub struct InsetBlock<'i, InsetValue<'i>> {
      /// The block start value.
     block_start: InsetBlockStart,
     /// The block end value.
@@ -152,7 +152,7 @@ size_shorthand! {
 
 size_shorthand! {
   /// A value for the [inset-inline](https://drafts.csswg.org/css-logical/#propdef-inset-inline) shorthand property.
-  pub struct InsetInline<LengthPercentageOrAuto> {
+  pub struct InsetInline<'i, InsetValue<'i>> {
     /// The inline start value.
     inline_start: InsetInlineStart,
     /// The inline end value.
@@ -161,13 +161,13 @@ size_shorthand! {
 }
 
 macro_rules! side_handler {
-  ($name: ident, $top: ident, $bottom: ident, $left: ident, $right: ident, $block_start: ident, $block_end: ident, $inline_start: ident, $inline_end: ident, $shorthand: ident, $block_shorthand: ident, $inline_shorthand: ident, $logical_shorthand: literal $(, $feature: ident, $shorthand_feature: ident)?) => {
+  ($name: ident, $value: ty, $top: ident, $bottom: ident, $left: ident, $right: ident, $block_start: ident, $block_end: ident, $inline_start: ident, $inline_end: ident, $shorthand: ident, $block_shorthand: ident, $inline_shorthand: ident, $logical_shorthand: literal $(, $feature: ident, $shorthand_feature: ident)?) => {
     #[derive(Debug, Default)]
     pub(crate) struct $name<'i> {
-      top: Option<LengthPercentageOrAuto>,
-      bottom: Option<LengthPercentageOrAuto>,
-      left: Option<LengthPercentageOrAuto>,
-      right: Option<LengthPercentageOrAuto>,
+      top: Option<$value>,
+      bottom: Option<$value>,
+      left: Option<$value>,
+      right: Option<$value>,
       block_start: Option<Property<'i>>,
       block_end: Option<Property<'i>>,
       inline_start: Option<Property<'i>>,
@@ -380,6 +380,7 @@ macro_rules! side_handler {
 
 side_handler!(
   MarginHandler,
+  LengthPercentageOrAuto,
   MarginTop,
   MarginBottom,
   MarginLeft,
@@ -398,6 +399,7 @@ side_handler!(
 
 side_handler!(
   PaddingHandler,
+  LengthPercentageOrAuto,
   PaddingTop,
   PaddingBottom,
   PaddingLeft,
@@ -416,6 +418,7 @@ side_handler!(
 
 side_handler!(
   ScrollMarginHandler,
+  LengthPercentageOrAuto,
   ScrollMarginTop,
   ScrollMarginBottom,
   ScrollMarginLeft,
@@ -432,6 +435,7 @@ side_handler!(
 
 side_handler!(
   ScrollPaddingHandler,
+  LengthPercentageOrAuto,
   ScrollPaddingTop,
   ScrollPaddingBottom,
   ScrollPaddingLeft,
@@ -448,6 +452,7 @@ side_handler!(
 
 side_handler!(
   InsetHandler,
+  InsetValue<'i>,
   Top,
   Bottom,
   Left,
@@ -3,6 +3,7 @@
 use super::background::{BackgroundRepeat, BackgroundSize};
 use super::border_image::{BorderImage, BorderImageRepeat, BorderImageSideWidth, BorderImageSlice};
 use super::PropertyId;
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
@@ -13,7 +14,7 @@ use crate::properties::Property;
 use crate::targets::Browsers;
 use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
 use crate::values::image::ImageFallback;
-use crate::values::length::LengthOrNumber;
+use crate::values::length::{Length, LengthOrNumber};
 use crate::values::rect::Rect;
 use crate::values::{image::Image, position::Position, shape::BasicShape, url::Url};
 use crate::vendor_prefix::VendorPrefix;
@@ -385,7 +386,7 @@ pub enum ClipPath<'i> {
   #[cfg_attr(feature = "serde", serde(borrow))]
   Url(Url<'i>),
   /// A basic shape, positioned according to the reference box.
-  Shape(Box<BasicShape>, GeometryBox),
+  Shape(Box<BasicShape<'i>>, GeometryBox),
   /// A reference box.
   Box(GeometryBox),
 }
@@ -434,6 +435,147 @@ impl<'i> ToCss for ClipPath<'i> {
   }
 }
 
+/// A value for the deprecated [clip](https://www.w3.org/TR/css-masking-1/#clip-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum Clip {
+  /// The element is not clipped.
+  Auto,
+  /// The element is clipped to a rectangle.
+  Rect(ClipRect),
+}
+
+impl<'i> Parse<'i> for Clip {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(Clip::Auto);
+    }
+
+    Ok(Clip::Rect(ClipRect::parse(input)?))
+  }
+}
+
+impl ToCss for Clip {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      Clip::Auto => dest.write_str("auto"),
+      Clip::Rect(rect) => rect.to_css(dest),
+    }
+  }
+}
+
+/// A `rect()` function, as used in the [clip](https://www.w3.org/TR/css-masking-1/#clip-property) property.
+///
+/// Offsets are measured from the top left corner of the element's border box.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClipRect {
+  /// The offset of the top edge.
+  pub top: ClipRectOffset,
+  /// The offset of the right edge.
+  pub right: ClipRectOffset,
+  /// The offset of the bottom edge.
+  pub bottom: ClipRectOffset,
+  /// The offset of the left edge.
+  pub left: ClipRectOffset,
+}
+
+impl<'i> Parse<'i> for ClipRect {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("rect")?;
+    input.parse_nested_block(|input| {
+      // The offsets may either be separated by commas or by whitespace, but not a mix of both.
+      let top = ClipRectOffset::parse(input)?;
+      let commas = input.try_parse(|input| input.expect_comma()).is_ok();
+      let right = ClipRectOffset::parse(input)?;
+      if commas {
+        input.expect_comma()?;
+      }
+      let bottom = ClipRectOffset::parse(input)?;
+      if commas {
+        input.expect_comma()?;
+      }
+      let left = ClipRectOffset::parse(input)?;
+      Ok(ClipRect {
+        top,
+        right,
+        bottom,
+        left,
+      })
+    })
+  }
+}
+
+impl ToCss for ClipRect {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    // The whitespace separated form is no longer than the comma separated form,
+    // so prefer it unless some browser targets do not support it.
+    let whitespace = if let Some(targets) = dest.targets {
+      compat::Feature::ClipRectWhitespace.is_compatible(targets)
+    } else {
+      true
+    };
+
+    dest.write_str("rect(")?;
+    self.top.to_css(dest)?;
+    for offset in [&self.right, &self.bottom, &self.left] {
+      if whitespace {
+        dest.write_char(' ')?;
+      } else {
+        dest.delim(',', false)?;
+      }
+      offset.to_css(dest)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+/// An offset of an edge within a [ClipRect](ClipRect).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ClipRectOffset {
+  /// The edge is placed at the corresponding edge of the border box.
+  Auto,
+  /// An explicit offset.
+  Length(Length),
+}
+
+impl<'i> Parse<'i> for ClipRectOffset {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ClipRectOffset::Auto);
+    }
+
+    Ok(ClipRectOffset::Length(Length::parse(input)?))
+  }
+}
+
+impl ToCss for ClipRectOffset {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ClipRectOffset::Auto => dest.write_str("auto"),
+      ClipRectOffset::Length(length) => length.to_css(dest),
+    }
+  }
+}
+
 enum_property! {
   /// A value for the [mask-border-mode](https://www.w3.org/TR/css-masking-1/#the-mask-border-mode) property.
   pub enum MaskBorderMode {
@@ -660,7 +802,13 @@ impl<'i> PropertyHandler<'i> for MaskHandler<'i> {
         property!(clips, &clips, prefix);
         property!(origins, &origins, prefix);
       }
+      Property::MaskPositionX(..) | Property::MaskPositionY(..) => {
+        // These are WebKit-only, so flush so they remain ordered with the other mask properties.
+        self.flush_mask(dest, context);
+        dest.push(property.clone());
+      }
       Property::Unparsed(val) if is_mask_property(&val.property_id) => {
+        self.flush_mask(dest, context);
         let mut unparsed = val.get_prefixed(context.targets, Feature::Mask);
         context.add_unparsed_fallbacks(&mut unparsed);
         dest.push(Property::Unparsed(unparsed));
@@ -684,6 +832,8 @@ impl<'i> PropertyHandler<'i> for MaskHandler<'i> {
         border_shorthand!(val, VendorPrefix::WebKit);
       }
       Property::Unparsed(val) if is_mask_border_property(&val.property_id) => {
+        self.flush_mask_border(dest, context);
+
         // Add vendor prefixes and expand color fallbacks.
         let mut val = val.clone();
         let mut prefix = val.property_id.prefix();
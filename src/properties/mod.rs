@@ -87,6 +87,9 @@
 //! If a known property is unable to be parsed, e.g. it contains `var()` references, then it is represented by the
 //! [UnparsedProperty](custom::UnparsedProperty) struct, and the `Property::Unparsed` variant. The value is stored
 //! as a raw [TokenList](custom::TokenList), with a [PropertyId](PropertyId) as the name.
+//!
+//! Properties set to a CSS-wide keyword such as `inherit` are represented by the
+//! [CSSWideKeywordProperty](custom::CSSWideKeywordProperty) struct, and the `Property::CSSWideKeyword` variant.
 
 #![deny(missing_docs)]
 
@@ -97,6 +100,7 @@ pub mod border;
 pub mod border_image;
 pub mod border_radius;
 pub mod box_shadow;
+pub mod columns;
 pub mod contain;
 pub mod css_modules;
 pub mod custom;
@@ -104,6 +108,7 @@ pub mod display;
 pub mod effects;
 pub mod flex;
 pub mod font;
+pub mod fragmentation;
 #[cfg(feature = "grid")]
 pub mod grid;
 pub mod list;
@@ -113,6 +118,7 @@ pub mod outline;
 pub mod overflow;
 pub mod position;
 pub(crate) mod prefix_handler;
+pub mod shape;
 pub mod size;
 pub mod svg;
 pub mod text;
@@ -143,6 +149,7 @@ use border::*;
 use border_image::*;
 use border_radius::*;
 use box_shadow::*;
+use columns::*;
 use contain::*;
 use css_modules::*;
 use cssparser::*;
@@ -151,6 +158,7 @@ use display::*;
 use effects::*;
 use flex::*;
 use font::*;
+use fragmentation::*;
 #[cfg(feature = "grid")]
 use grid::*;
 use list::*;
@@ -158,6 +166,7 @@ use margin_padding::*;
 use masking::*;
 use outline::*;
 use overflow::*;
+use shape::*;
 use size::*;
 use smallvec::{smallvec, SmallVec};
 use svg::*;
@@ -166,6 +175,25 @@ use transform::*;
 use transition::*;
 use ui::*;
 
+/// A parser option that enables parsing of a property when set.
+trait ParserFlag {
+  fn is_enabled(&self) -> bool;
+}
+
+impl ParserFlag for bool {
+  #[inline]
+  fn is_enabled(&self) -> bool {
+    *self
+  }
+}
+
+impl<T> ParserFlag for Option<T> {
+  #[inline]
+  fn is_enabled(&self) -> bool {
+    self.is_some()
+  }
+}
+
 macro_rules! define_properties {
   (
     $(
@@ -200,6 +228,42 @@ macro_rules! define_properties {
       };
     }
 
+    /// Returns the id for a lower case property name, if it is known and allows the given vendor prefix.
+    fn property_id_from_name(name: &str, prefix: VendorPrefix) -> Option<PropertyId<'static>> {
+      macro_rules! get_allowed_prefixes {
+        ($v: literal) => {
+          VendorPrefix::empty()
+        };
+        () => {
+          VendorPrefix::None
+        };
+      }
+
+      match name {
+        $(
+          $(#[$meta])*
+          $name => {
+            macro_rules! get_propertyid {
+              ($v: ty) => {
+                PropertyId::$property(prefix)
+              };
+              () => {
+                PropertyId::$property
+              };
+            }
+
+            let allowed_prefixes = get_allowed_prefixes!($($unprefixed)?) $(| VendorPrefix::$prefix)*;
+            if allowed_prefixes.contains(prefix) {
+              return Some(get_propertyid!($($vp)?))
+            }
+            None
+          },
+        )+
+        "all" if prefix == VendorPrefix::None => Some(PropertyId::All),
+        _ => None,
+      }
+    }
+
     impl<'i> From<CowArcStr<'i>> for PropertyId<'i> {
       fn from(name: CowArcStr<'i>) -> PropertyId<'i> {
         let name_ref = name.as_ref();
@@ -215,37 +279,24 @@ macro_rules! define_properties {
           (VendorPrefix::None, name_ref)
         };
 
-        macro_rules! get_allowed_prefixes {
-          ($v: literal) => {
-            VendorPrefix::empty()
-          };
-          () => {
-            VendorPrefix::None
-          };
-        }
-
-        match_ignore_ascii_case! { name_ref,
-          $(
-            $(#[$meta])*
-            $name => {
-              macro_rules! get_propertyid {
-                ($v: ty) => {
-                  PropertyId::$property(prefix)
-                };
-                () => {
-                  PropertyId::$property
-                };
-              }
+        // Lower case names into a stack buffer rather than allocating. Names longer than the
+        // buffer cannot match any known property.
+        let mut buf = [0u8; 64];
+        let property_id = if !name_ref.bytes().any(|b| b.is_ascii_uppercase()) {
+          property_id_from_name(name_ref, prefix)
+        } else if name_ref.len() <= buf.len() {
+          let buf = &mut buf[..name_ref.len()];
+          buf.copy_from_slice(name_ref.as_bytes());
+          buf.make_ascii_lowercase();
+          std::str::from_utf8(buf).ok().and_then(|name| property_id_from_name(name, prefix))
+        } else {
+          None
+        };
 
-              let allowed_prefixes = get_allowed_prefixes!($($unprefixed)?) $(| VendorPrefix::$prefix)*;
-              if allowed_prefixes.contains(prefix) {
-                return get_propertyid!($($vp)?)
-              }
-            },
-          )+
-          "all" => return PropertyId::All,
-          _ => {}
+        if let Some(property_id) = property_id {
+          return property_id;
         }
+
         PropertyId::Custom(name)
       }
     }
@@ -536,6 +587,9 @@ macro_rules! define_properties {
       /// An unparsed property.
       #[cfg_attr(feature = "serde", serde(borrow, rename = "unparsed"))]
       Unparsed(UnparsedProperty<'i>),
+      /// A property set to a CSS-wide keyword, e.g. `inherit`.
+      #[cfg_attr(feature = "serde", serde(borrow, rename = "css-wide-keyword"))]
+      CSSWideKeyword(CSSWideKeywordProperty<'i>),
       /// A custom or unknown property.
       #[cfg_attr(feature = "serde", serde(borrow, rename = "custom"))]
       Custom(CustomProperty<'i>),
@@ -543,16 +597,35 @@ macro_rules! define_properties {
 
     impl<'i> Property<'i> {
       /// Parses a CSS property by name.
-      pub fn parse<'t>(property_id: PropertyId<'i>, input: &mut Parser<'i, 't>, options: &ParserOptions) -> Result<Property<'i>, ParseError<'i, ParserError<'i>>> {
+      pub fn parse<'t>(property_id: PropertyId<'i>, input: &mut Parser<'i, 't>, options: &ParserOptions<'_, 'i>) -> Result<Property<'i>, ParseError<'i, ParserError<'i>>> {
         let state = input.state();
 
+        // Any property other than custom properties may be set to a CSS-wide keyword.
+        if !matches!(property_id, PropertyId::Custom(..)) {
+          if let Ok(keyword) = input.try_parse(CSSWideKeyword::parse) {
+            if input.expect_exhausted().is_ok() {
+              return Ok(Property::CSSWideKeyword(CSSWideKeywordProperty { property_id, keyword }))
+            }
+            input.reset(&state);
+          }
+        }
+
         match property_id {
           $(
             $(#[$meta])*
-            PropertyId::$property$((vp_name!($vp, prefix)))? $(if options.$condition.is_some())? => {
-              if let Ok(c) = <$type>::parse_with_options(input, options) {
-                if input.expect_exhausted().is_ok() {
-                  return Ok(Property::$property(c $(, vp_name!($vp, prefix))?))
+            PropertyId::$property$((vp_name!($vp, prefix)))? $(if options.$condition.is_enabled())? => {
+              match <$type>::parse_with_options(input, options) {
+                Ok(c) => {
+                  if input.expect_exhausted().is_ok() {
+                    return Ok(Property::$property(c $(, vp_name!($vp, prefix))?))
+                  }
+                }
+                Err(err) => {
+                  // Values that are well formed but violate a constraint of the property are
+                  // preserved as unparsed below, so report why they were not parsed.
+                  if matches!(&err.kind, ParseErrorKind::Custom(e) if e.is_constraint_violation()) {
+                    options.warn(err);
+                  }
                 }
               }
             },
@@ -579,12 +652,13 @@ macro_rules! define_properties {
             $property(_, $(vp_name!($vp, p))?) => PropertyId::$property$((*vp_name!($vp, p)))?,
           )+
           Unparsed(unparsed) => unparsed.property_id.clone(),
+          CSSWideKeyword(wide) => wide.property_id.clone(),
           Custom(custom) => PropertyId::Custom(custom.name.clone())
         }
       }
 
       /// Parses a CSS property from a string.
-      pub fn parse_string(property_id: PropertyId<'i>, input: &'i str, options: ParserOptions) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+      pub fn parse_string(property_id: PropertyId<'i>, input: &'i str, options: ParserOptions<'_, 'i>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
         let mut input = ParserInput::new(input);
         let mut parser = Parser::new(&mut input);
         Self::parse(property_id, &mut parser, &options)
@@ -604,6 +678,9 @@ macro_rules! define_properties {
           Unparsed(unparsed) => {
             unparsed.value.to_css(dest, false)
           }
+          CSSWideKeyword(wide) => {
+            wide.keyword.to_css(dest)
+          }
           Custom(custom) => {
             custom.value.to_css(dest, custom.name.starts_with("--"))
           }
@@ -661,6 +738,7 @@ macro_rules! define_properties {
             },
           )+
           Unparsed(unparsed) => (unparsed.property_id.name(), unparsed.property_id.prefix()),
+          CSSWideKeyword(wide) => (wide.property_id.name(), wide.property_id.prefix()),
           Custom(custom) => {
             // Ensure custom property names are escaped.
             let name = custom.name.as_ref();
@@ -772,38 +850,49 @@ define_properties! {
   "display": Display(Display),
   "visibility": Visibility(Visibility),
 
-  "width": Width(Size) [logical_group: Size, category: Physical],
-  "height": Height(Size) [logical_group: Size, category: Physical],
-  "min-width": MinWidth(Size) [logical_group: MinSize, category: Physical],
-  "min-height": MinHeight(Size) [logical_group: MinSize, category: Physical],
-  "max-width": MaxWidth(MaxSize) [logical_group: MaxSize, category: Physical],
-  "max-height": MaxHeight(MaxSize) [logical_group: MaxSize, category: Physical],
-  "block-size": BlockSize(Size) [logical_group: Size, category: Logical],
-  "inline-size": InlineSize(Size) [logical_group: Size, category: Logical],
-  "min-block-size": MinBlockSize(Size) [logical_group: MinSize, category: Logical],
-  "min-inline-size": MinInlineSize(Size) [logical_group: MinSize, category: Logical],
-  "max-block-size": MaxBlockSize(MaxSize) [logical_group: MaxSize, category: Logical],
-  "max-inline-size": MaxInlineSize(MaxSize) [logical_group: MaxSize, category: Logical],
+  "width": Width(Size<'i>) [logical_group: Size, category: Physical],
+  "height": Height(Size<'i>) [logical_group: Size, category: Physical],
+  "min-width": MinWidth(Size<'i>) [logical_group: MinSize, category: Physical],
+  "min-height": MinHeight(Size<'i>) [logical_group: MinSize, category: Physical],
+  "max-width": MaxWidth(MaxSize<'i>) [logical_group: MaxSize, category: Physical],
+  "max-height": MaxHeight(MaxSize<'i>) [logical_group: MaxSize, category: Physical],
+  "block-size": BlockSize(Size<'i>) [logical_group: Size, category: Logical],
+  "inline-size": InlineSize(Size<'i>) [logical_group: Size, category: Logical],
+  "min-block-size": MinBlockSize(Size<'i>) [logical_group: MinSize, category: Logical],
+  "min-inline-size": MinInlineSize(Size<'i>) [logical_group: MinSize, category: Logical],
+  "max-block-size": MaxBlockSize(MaxSize<'i>) [logical_group: MaxSize, category: Logical],
+  "max-inline-size": MaxInlineSize(MaxSize<'i>) [logical_group: MaxSize, category: Logical],
   "box-sizing": BoxSizing(BoxSizing, VendorPrefix) / WebKit / Moz,
+  "interpolate-size": InterpolateSize(InterpolateSize) if draft_properties,
+  "aspect-ratio": AspectRatio(AspectRatio),
 
   "overflow": Overflow(Overflow) shorthand: true,
   "overflow-x": OverflowX(OverflowKeyword),
   "overflow-y": OverflowY(OverflowKeyword),
-  "text-overflow": TextOverflow(TextOverflow, VendorPrefix) / O,
+  "overflow-clip-margin": OverflowClipMargin(OverflowClipMargin),
+  "scroll-behavior": ScrollBehavior(ScrollBehavior),
+  "text-overflow": TextOverflow(TextOverflow<'i>, VendorPrefix) / O,
+  "line-clamp": LineClamp(LineClamp, VendorPrefix) / WebKit,
+
+  // https://drafts.csswg.org/css-overscroll-1/
+  "overscroll-behavior": OverscrollBehavior(OverscrollBehavior) shorthand: true,
+  "overscroll-behavior-x": OverscrollBehaviorX(OverscrollBehaviorKeyword),
+  "overscroll-behavior-y": OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  "scroll-chaining": ScrollChaining(ScrollChaining, VendorPrefix) / Ms unprefixed: false,
 
   // https://www.w3.org/TR/2020/WD-css-position-3-20200519
   "position": Position(position::Position),
-  "top": Top(LengthPercentageOrAuto) [logical_group: Inset, category: Physical],
-  "bottom": Bottom(LengthPercentageOrAuto) [logical_group: Inset, category: Physical],
-  "left": Left(LengthPercentageOrAuto) [logical_group: Inset, category: Physical],
-  "right": Right(LengthPercentageOrAuto) [logical_group: Inset, category: Physical],
-  "inset-block-start": InsetBlockStart(LengthPercentageOrAuto) [logical_group: Inset, category: Logical],
-  "inset-block-end": InsetBlockEnd(LengthPercentageOrAuto) [logical_group: Inset, category: Logical],
-  "inset-inline-start": InsetInlineStart(LengthPercentageOrAuto) [logical_group: Inset, category: Logical],
-  "inset-inline-end": InsetInlineEnd(LengthPercentageOrAuto) [logical_group: Inset, category: Logical],
-  "inset-block": InsetBlock(InsetBlock) shorthand: true,
-  "inset-inline": InsetInline(InsetInline) shorthand: true,
-  "inset": Inset(Inset) shorthand: true,
+  "top": Top(position::InsetValue<'i>) [logical_group: Inset, category: Physical],
+  "bottom": Bottom(position::InsetValue<'i>) [logical_group: Inset, category: Physical],
+  "left": Left(position::InsetValue<'i>) [logical_group: Inset, category: Physical],
+  "right": Right(position::InsetValue<'i>) [logical_group: Inset, category: Physical],
+  "inset-block-start": InsetBlockStart(position::InsetValue<'i>) [logical_group: Inset, category: Logical],
+  "inset-block-end": InsetBlockEnd(position::InsetValue<'i>) [logical_group: Inset, category: Logical],
+  "inset-inline-start": InsetInlineStart(position::InsetValue<'i>) [logical_group: Inset, category: Logical],
+  "inset-inline-end": InsetInlineEnd(position::InsetValue<'i>) [logical_group: Inset, category: Logical],
+  "inset-block": InsetBlock(InsetBlock<'i>) shorthand: true,
+  "inset-inline": InsetInline(InsetInline<'i>) shorthand: true,
+  "inset": Inset(Inset<'i>) shorthand: true,
 
   "border-top-color": BorderTopColor(CssColor) [logical_group: BorderColor, category: Physical],
   "border-bottom-color": BorderBottomColor(CssColor) [logical_group: BorderColor, category: Physical],
@@ -877,6 +966,7 @@ define_properties! {
   "outline-color": OutlineColor(CssColor),
   "outline-style": OutlineStyle(OutlineStyle),
   "outline-width": OutlineWidth(BorderSideWidth),
+  "outline-offset": OutlineOffset(Length),
 
   // Flex properties: https://www.w3.org/TR/2018/CR-css-flexbox-1-20181119
   "flex-direction": FlexDirection(FlexDirection, VendorPrefix) / WebKit / Ms,
@@ -901,6 +991,30 @@ define_properties! {
   "row-gap": RowGap(GapValue),
   "column-gap": ColumnGap(GapValue),
   "gap": Gap(Gap) shorthand: true,
+  "grid-row-gap": GridRowGap(GapValue),
+  "grid-column-gap": GridColumnGap(GapValue),
+  "grid-gap": GridGap(Gap) shorthand: true,
+
+  // https://drafts.csswg.org/css-multicol/
+  "column-width": ColumnWidth(ColumnWidth, VendorPrefix) / WebKit / Moz,
+  "column-count": ColumnCount(ColumnCount, VendorPrefix) / WebKit / Moz,
+  "columns": Columns(Columns, VendorPrefix) / WebKit / Moz shorthand: true,
+  "column-rule-width": ColumnRuleWidth(BorderSideWidth, VendorPrefix) / WebKit / Moz,
+  "column-rule-style": ColumnRuleStyle(LineStyle, VendorPrefix) / WebKit / Moz,
+  "column-rule-color": ColumnRuleColor(CssColor, VendorPrefix) / WebKit / Moz,
+  "column-rule": ColumnRule(ColumnRule, VendorPrefix) / WebKit / Moz shorthand: true,
+  "column-span": ColumnSpan(ColumnSpan, VendorPrefix) / WebKit,
+  "column-fill": ColumnFill(ColumnFill, VendorPrefix) / WebKit / Moz,
+
+  // https://drafts.csswg.org/css-break-3/
+  "break-before": BreakBefore(BreakBetween),
+  "break-after": BreakAfter(BreakBetween),
+  "break-inside": BreakInside(BreakInside),
+  "page-break-before": PageBreakBefore(PageBreak),
+  "page-break-after": PageBreakAfter(PageBreak),
+  "page-break-inside": PageBreakInside(PageBreakInside),
+  "orphans": Orphans(LineCount),
+  "widows": Widows(LineCount),
 
   // Old flex (2009): https://www.w3.org/TR/2009/WD-css3-flexbox-20090723/
   "box-orient": BoxOrient(BoxOrient, VendorPrefix) / WebKit / Moz unprefixed: false,
@@ -1009,16 +1123,24 @@ define_properties! {
   "font-family": FontFamily(Vec<FontFamily<'i>>),
   "font-style": FontStyle(FontStyle),
   "font-variant-caps": FontVariantCaps(FontVariantCaps),
+  "font-variant-ligatures": FontVariantLigatures(FontVariantLigatures),
+  "font-variant-alternates": FontVariantAlternates(FontVariantAlternates<'i>),
+  "font-variant-numeric": FontVariantNumeric(FontVariantNumeric),
+  "font-variant-east-asian": FontVariantEastAsian(FontVariantEastAsian),
+  "font-variant-position": FontVariantPosition(FontVariantPosition),
+  "font-variant": FontVariant(FontVariant<'i>) shorthand: true,
   "line-height": LineHeight(LineHeight),
   "font": Font(Font<'i>) shorthand: true,
   "vertical-align": VerticalAlign(VerticalAlign),
   "font-palette": FontPalette(DashedIdentReference<'i>),
+  "font-feature-settings": FontFeatureSettings(FontFeatureSettings<'i>, VendorPrefix) / WebKit / Moz,
 
   "transition-property": TransitionProperty(SmallVec<[PropertyId<'i>; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-duration": TransitionDuration(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-delay": TransitionDelay(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition-timing-function": TransitionTimingFunction(SmallVec<[EasingFunction; 1]>, VendorPrefix) / WebKit / Moz / Ms,
   "transition": Transition(SmallVec<[Transition<'i>; 1]>, VendorPrefix) / WebKit / Moz / Ms shorthand: true,
+  "transition-behavior": TransitionBehavior(SmallVec<[TransitionBehavior; 1]>) if draft_properties,
 
   "animation-name": AnimationName(AnimationNameList<'i>, VendorPrefix) / WebKit / Moz / O,
   "animation-duration": AnimationDuration(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / O,
@@ -1029,10 +1151,25 @@ define_properties! {
   "animation-delay": AnimationDelay(SmallVec<[Time; 1]>, VendorPrefix) / WebKit / Moz / O,
   "animation-fill-mode": AnimationFillMode(SmallVec<[AnimationFillMode; 1]>, VendorPrefix) / WebKit / Moz / O,
   "animation": Animation(AnimationList<'i>, VendorPrefix) / WebKit / Moz / O shorthand: true,
+  "animation-timeline": AnimationTimeline(SmallVec<[AnimationTimeline<'i>; 1]>) if draft_properties,
+  "animation-composition": AnimationComposition(SmallVec<[AnimationComposition; 1]>),
+  "animation-range-start": AnimationRangeStart(SmallVec<[AnimationRangeStart; 1]>) if draft_properties,
+  "animation-range-end": AnimationRangeEnd(SmallVec<[AnimationRangeEnd; 1]>) if draft_properties,
+  "animation-range": AnimationRange(SmallVec<[AnimationRange; 1]>) shorthand: true if draft_properties,
+
+  // https://drafts.csswg.org/scroll-animations-1/
+  "scroll-timeline-name": ScrollTimelineName(SmallVec<[TimelineName<'i>; 1]>) if draft_properties,
+  "scroll-timeline-axis": ScrollTimelineAxis(SmallVec<[ScrollAxis; 1]>) if draft_properties,
+  "scroll-timeline": ScrollTimeline(SmallVec<[ScrollTimeline<'i>; 1]>) shorthand: true if draft_properties,
+  "view-timeline-name": ViewTimelineName(SmallVec<[TimelineName<'i>; 1]>) if draft_properties,
+  "view-timeline-axis": ViewTimelineAxis(SmallVec<[ScrollAxis; 1]>) if draft_properties,
+  "view-timeline-inset": ViewTimelineInset(SmallVec<[ViewTimelineInset; 1]>) if draft_properties,
+  "view-timeline": ViewTimeline(SmallVec<[ViewTimeline<'i>; 1]>) shorthand: true if draft_properties,
+  "timeline-scope": TimelineScope(TimelineScope<'i>) if draft_properties,
 
   // https://drafts.csswg.org/css-transforms-2/
   "transform": Transform(TransformList, VendorPrefix) / WebKit / Moz / Ms / O,
-  "transform-origin": TransformOrigin(Position, VendorPrefix) / WebKit / Moz / Ms / O, // TODO: handle z offset syntax
+  "transform-origin": TransformOrigin(TransformOrigin, VendorPrefix) / WebKit / Moz / Ms / O,
   "transform-style": TransformStyle(TransformStyle, VendorPrefix) / WebKit / Moz,
   "transform-box": TransformBox(TransformBox),
   "backface-visibility": BackfaceVisibility(BackfaceVisibility, VendorPrefix) / WebKit / Moz,
@@ -1045,10 +1182,16 @@ define_properties! {
   // https://www.w3.org/TR/2021/CRD-css-text-3-20210422
   "text-transform": TextTransform(TextTransform),
   "white-space": WhiteSpace(WhiteSpace),
+  "white-space-collapse": WhiteSpaceCollapse(WhiteSpaceCollapse),
+  "text-wrap-mode": TextWrapMode(TextWrapMode),
+  "text-wrap-style": TextWrapStyle(TextWrapStyle),
+  "text-wrap": TextWrap(TextWrap) shorthand: true,
   "tab-size": TabSize(LengthOrNumber, VendorPrefix) / Moz / O,
   "word-break": WordBreak(WordBreak),
   "line-break": LineBreak(LineBreak),
   "hyphens": Hyphens(Hyphens, VendorPrefix) / WebKit / Moz / Ms,
+  "hyphenate-character": HyphenateCharacter(HyphenateCharacter<'i>, VendorPrefix) / WebKit,
+  "hyphenate-limit-chars": HyphenateLimitChars(HyphenateLimitChars),
   "overflow-wrap": OverflowWrap(OverflowWrap),
   "word-wrap": WordWrap(OverflowWrap),
   "text-align": TextAlign(TextAlign),
@@ -1065,11 +1208,20 @@ define_properties! {
   "text-decoration-thickness": TextDecorationThickness(TextDecorationThickness),
   "text-decoration": TextDecoration(TextDecoration, VendorPrefix) / WebKit / Moz shorthand: true,
   "text-decoration-skip-ink": TextDecorationSkipInk(TextDecorationSkipInk, VendorPrefix) / WebKit,
+  "text-underline-offset": TextUnderlineOffset(TextUnderlineOffset),
+  "text-underline-position": TextUnderlinePosition(TextUnderlinePosition),
   "text-emphasis-style": TextEmphasisStyle(TextEmphasisStyle<'i>, VendorPrefix) / WebKit,
   "text-emphasis-color": TextEmphasisColor(CssColor, VendorPrefix) / WebKit,
   "text-emphasis": TextEmphasis(TextEmphasis<'i>, VendorPrefix) / WebKit shorthand: true,
   "text-emphasis-position": TextEmphasisPosition(TextEmphasisPosition, VendorPrefix) / WebKit,
   "text-shadow": TextShadow(SmallVec<[TextShadow; 1]>),
+  "text-stroke-width": WebKitTextStrokeWidth(BorderSideWidth, VendorPrefix) / WebKit unprefixed: false,
+  "text-stroke-color": WebKitTextStrokeColor(CssColor, VendorPrefix) / WebKit unprefixed: false,
+  "text-stroke": WebKitTextStroke(WebKitTextStroke, VendorPrefix) / WebKit unprefixed: false shorthand: true,
+  "text-fill-color": WebKitTextFillColor(CssColor, VendorPrefix) / WebKit unprefixed: false,
+  "writing-mode": WritingMode(WritingMode, VendorPrefix) / WebKit,
+  "text-orientation": TextOrientation(TextOrientation, VendorPrefix) / WebKit,
+  "text-combine-upright": TextCombineUpright(TextCombineUpright),
 
   // https://www.w3.org/TR/css-break-3/
   "box-decoration-break": BoxDecorationBreak(BoxDecorationBreak, VendorPrefix) / WebKit,
@@ -1081,15 +1233,34 @@ define_properties! {
   "caret-shape": CaretShape(CaretShape),
   "caret": Caret(Caret) shorthand: true,
   "user-select": UserSelect(UserSelect, VendorPrefix) / WebKit / Moz / Ms,
+  "pointer-events": PointerEvents(PointerEvents),
   "accent-color": AccentColor(ColorOrAuto),
   "appearance": Appearance(Appearance<'i>, VendorPrefix) / WebKit / Moz / Ms,
 
+  // https://drafts.csswg.org/css-color-adjust-1/
+  "color-scheme": ColorScheme(ColorScheme<'i>),
+  "forced-color-adjust": ForcedColorAdjust(ForcedColorAdjust),
+  "print-color-adjust": PrintColorAdjust(PrintColorAdjust, VendorPrefix) / WebKit,
+
+  // https://drafts.csswg.org/css-scrollbars-1/
+  "scrollbar-width": ScrollbarWidth(ScrollbarWidth),
+  "scrollbar-color": ScrollbarColor(ScrollbarColor),
+  "field-sizing": FieldSizing(FieldSizing) if draft_properties,
+
+  // https://www.w3.org/TR/css-will-change-1/
+  "will-change": WillChange(WillChange<'i>),
+
   // https://www.w3.org/TR/2020/WD-css-lists-3-20201117
   "list-style-type": ListStyleType(ListStyleType<'i>),
   "list-style-image": ListStyleImage(Image<'i>),
   "list-style-position": ListStylePosition(ListStylePosition),
   "list-style": ListStyle(ListStyle<'i>) shorthand: true,
   "marker-side": MarkerSide(MarkerSide),
+  "content": Content(Content<'i>),
+  "counter-reset": CounterReset(CounterReset<'i>),
+  "counter-increment": CounterIncrement(CounterIncrement<'i>),
+  "counter-set": CounterSet(CounterSet<'i>),
+  "quotes": Quotes(Quotes<'i>),
 
   // CSS modules
   "composes": Composes(Composes<'i>) if css_modules,
@@ -1117,9 +1288,13 @@ define_properties! {
   "text-rendering": TextRendering(TextRendering),
   "image-rendering": ImageRendering(ImageRendering),
 
+  // https://www.w3.org/TR/css-images-3/#the-object-position
+  "object-position": ObjectPosition(Position),
+
   // https://www.w3.org/TR/css-masking-1/
   "clip-path": ClipPath(ClipPath<'i>, VendorPrefix) / WebKit,
   "clip-rule": ClipRule(FillRule),
+  "clip": Clip(Clip),
   "mask-image": MaskImage(SmallVec<[Image<'i>; 1]>, VendorPrefix) / WebKit,
   "mask-mode": MaskMode(SmallVec<[MaskMode; 1]>),
   "mask-repeat": MaskRepeat(SmallVec<[BackgroundRepeat; 1]>, VendorPrefix) / WebKit,
@@ -1150,14 +1325,32 @@ define_properties! {
   "mask-box-image-outset": WebKitMaskBoxImageOutset(Rect<LengthOrNumber>, VendorPrefix) / WebKit unprefixed: false,
   "mask-box-image-repeat": WebKitMaskBoxImageRepeat(BorderImageRepeat, VendorPrefix) / WebKit unprefixed: false,
 
+  // https://www.w3.org/TR/css-shapes-1/
+  "shape-outside": ShapeOutside(ShapeOutside<'i>, VendorPrefix) / WebKit,
+
+  // https://drafts.fxtf.org/motion-1/
+  "offset-path": OffsetPath(OffsetPath<'i>),
+
   // https://drafts.fxtf.org/filter-effects-1/
   "filter": Filter(FilterList<'i>, VendorPrefix) / WebKit,
   "backdrop-filter": BackdropFilter(FilterList<'i>, VendorPrefix) / WebKit,
 
+  // https://drafts.fxtf.org/compositing-1/
+  "mix-blend-mode": MixBlendMode(BlendMode),
+  "isolation": Isolation(Isolation),
+  "background-blend-mode": BackgroundBlendMode(SmallVec<[BlendMode; 1]>),
+
   // https://drafts.csswg.org/css2/
   "z-index": ZIndex(position::ZIndex),
 
   // https://drafts.csswg.org/css-contain-3/
+  "contain": Contain(Contain),
+  "content-visibility": ContentVisibility(ContentVisibility),
+  "contain-intrinsic-width": ContainIntrinsicWidth(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Physical],
+  "contain-intrinsic-height": ContainIntrinsicHeight(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Physical],
+  "contain-intrinsic-block-size": ContainIntrinsicBlockSize(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Logical],
+  "contain-intrinsic-inline-size": ContainIntrinsicInlineSize(ContainIntrinsicLength) [logical_group: ContainIntrinsicSize, category: Logical],
+  "contain-intrinsic-size": ContainIntrinsicSize(ContainIntrinsicSize) shorthand: true,
   "container-type": ContainerType(ContainerType),
   "container-name": ContainerName(ContainerNameList<'i>),
   "container": Container(Container<'i>) shorthand: true,
@@ -1,30 +1,76 @@
 //! CSS properties related to overflow.
 
+use super::display::{Display, DisplayInside, DisplayOutside, DisplayPair};
+use super::flex::BoxOrient;
 use super::{Property, PropertyId};
 use crate::compat::Feature;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property};
+use crate::prefixes;
 use crate::printer::Printer;
 use crate::targets::Browsers;
-use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss};
+use crate::traits::{Parse, PropertyHandler, Shorthand, ToCss, TrySign, Zero};
+use crate::values::length::Length;
+use crate::values::number::CSSInteger;
+use crate::values::string::CowArcStr;
+use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
-enum_property! {
-  /// An [overflow](https://www.w3.org/TR/css-overflow-3/#overflow-properties) keyword
-  /// as used in the `overflow-x`, `overflow-y`, and `overflow` properties.
-  pub enum OverflowKeyword {
-    /// Overflowing content is visible.
-    Visible,
-    /// Overflowing content is hidden. Programmatic scrolling is allowed.
-    Hidden,
-    /// Overflowing content is clipped. Programmatic scrolling is not allowed.
-    Clip,
-    /// The element is scrollable.
-    Scroll,
-    /// Overflowing content scrolls if needed.
-    Auto,
+/// An [overflow](https://www.w3.org/TR/css-overflow-3/#overflow-properties) keyword
+/// as used in the `overflow-x`, `overflow-y`, and `overflow` properties.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "lowercase")
+)]
+pub enum OverflowKeyword {
+  /// Overflowing content is visible.
+  Visible,
+  /// Overflowing content is hidden. Programmatic scrolling is allowed.
+  Hidden,
+  /// Overflowing content is clipped. Programmatic scrolling is not allowed.
+  Clip,
+  /// The element is scrollable.
+  Scroll,
+  /// Overflowing content scrolls if needed.
+  Auto,
+}
+
+impl<'i> Parse<'i> for OverflowKeyword {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { &*ident,
+      "visible" => Ok(OverflowKeyword::Visible),
+      "hidden" => Ok(OverflowKeyword::Hidden),
+      "clip" => Ok(OverflowKeyword::Clip),
+      "scroll" => Ok(OverflowKeyword::Scroll),
+      "auto" => Ok(OverflowKeyword::Auto),
+      // `overlay` is a legacy alias of `auto`.
+      // https://drafts.csswg.org/css-overflow-3/#valdef-overflow-overlay
+      "overlay" => Ok(OverflowKeyword::Auto),
+      _ => Err(location.new_unexpected_token_error(
+        cssparser::Token::Ident(ident.clone())
+      ))
+    }
+  }
+}
+
+impl ToCss for OverflowKeyword {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str(match self {
+      OverflowKeyword::Visible => "visible",
+      OverflowKeyword::Hidden => "hidden",
+      OverflowKeyword::Clip => "clip",
+      OverflowKeyword::Scroll => "scroll",
+      OverflowKeyword::Auto => "auto",
+    })
   }
 }
 
@@ -61,12 +107,298 @@ impl ToCss for Overflow {
 }
 
 enum_property! {
-  /// A value for the [text-overflow](https://www.w3.org/TR/css-overflow-3/#text-overflow) property.
-  pub enum TextOverflow {
-    /// Overflowing text is clipped.
-    Clip,
-    /// Overflowing text is truncated with an ellipsis.
-    Ellipsis,
+  /// A [`<visual-box>`](https://drafts.csswg.org/css-box-4/#typedef-visual-box) value,
+  /// as used in the `overflow-clip-margin` property.
+  pub enum VisualBox {
+    /// The content box.
+    "content-box": ContentBox,
+    /// The padding box.
+    "padding-box": PaddingBox,
+    /// The border box.
+    "border-box": BorderBox,
+  }
+}
+
+/// A value for the [overflow-clip-margin](https://drafts.csswg.org/css-overflow-4/#overflow-clip-margin) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverflowClipMargin {
+  /// The box edge that the clip margin is measured from.
+  pub visual_box: VisualBox,
+  /// How far outside the box edge content may paint before being clipped.
+  pub margin: Length,
+}
+
+impl<'i> Parse<'i> for OverflowClipMargin {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut visual_box = None;
+    let mut margin = None;
+    loop {
+      if visual_box.is_none() {
+        if let Ok(val) = input.try_parse(VisualBox::parse) {
+          visual_box = Some(val);
+          continue;
+        }
+      }
+
+      if margin.is_none() {
+        let location = input.current_source_location();
+        if let Ok(val) = input.try_parse(Length::parse) {
+          if val.try_sign().map_or(false, |sign| sign < 0.0) {
+            return Err(location.new_custom_error(ParserError::InvalidValue));
+          }
+          margin = Some(val);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if visual_box.is_none() && margin.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(OverflowClipMargin {
+      visual_box: visual_box.unwrap_or(VisualBox::PaddingBox),
+      margin: margin.unwrap_or_else(Length::zero),
+    })
+  }
+}
+
+impl ToCss for OverflowClipMargin {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    // The box defaults to padding-box, and the margin defaults to zero.
+    if self.visual_box == VisualBox::PaddingBox {
+      return self.margin.to_css(dest);
+    }
+
+    self.visual_box.to_css(dest)?;
+    if !self.margin.is_zero() {
+      dest.write_char(' ')?;
+      self.margin.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the [scroll-behavior](https://drafts.csswg.org/css-overflow-3/#smooth-scrolling) property.
+  pub enum ScrollBehavior {
+    /// Scrolling happens instantly.
+    "auto": Auto,
+    /// Scrolling is animated using a user agent defined timing function.
+    "smooth": Smooth,
+  }
+}
+
+/// A value for one side of the [text-overflow](https://drafts.csswg.org/css-overflow-4/#text-overflow) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TextOverflowSide<'i> {
+  /// Overflowing text is clipped.
+  Clip,
+  /// Overflowing text is truncated with an ellipsis.
+  Ellipsis,
+  /// Overflowing text is truncated with the given string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CowArcStr<'i>),
+}
+
+impl<'i> Parse<'i> for TextOverflowSide<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(s) = input.try_parse(|input| input.expect_string_cloned()) {
+      return Ok(TextOverflowSide::String(s.into()));
+    }
+
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { &*ident,
+      "clip" => Ok(TextOverflowSide::Clip),
+      "ellipsis" => Ok(TextOverflowSide::Ellipsis),
+      _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+    }
+  }
+}
+
+impl<'i> ToCss for TextOverflowSide<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextOverflowSide::Clip => dest.write_str("clip"),
+      TextOverflowSide::Ellipsis => dest.write_str("ellipsis"),
+      TextOverflowSide::String(s) => {
+        serialize_string(&s, dest)?;
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A value for the [text-overflow](https://drafts.csswg.org/css-overflow-4/#text-overflow) property.
+///
+/// With a single value, only the end edge of the line is affected. With two values, the first
+/// applies to the line-left edge and the second to the line-right edge.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextOverflow<'i> {
+  /// The first value.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub first: TextOverflowSide<'i>,
+  /// The second value, if the two-value syntax is used.
+  pub second: Option<TextOverflowSide<'i>>,
+}
+
+impl<'i> TextOverflow<'i> {
+  /// Returns whether the value is supported by the legacy `-o-text-overflow` property,
+  /// which only accepts a single keyword.
+  pub(crate) fn is_legacy_compatible(&self) -> bool {
+    self.second.is_none() && !matches!(self.first, TextOverflowSide::String(..))
+  }
+}
+
+impl<'i> Parse<'i> for TextOverflow<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let first = TextOverflowSide::parse(input)?;
+    let second = input.try_parse(TextOverflowSide::parse).ok();
+    Ok(TextOverflow { first, second })
+  }
+}
+
+impl<'i> ToCss for TextOverflow<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.first.to_css(dest)?;
+    // Both sides are clipped by default, so `clip clip` is equivalent to `clip`.
+    if let Some(second) = &self.second {
+      if !(self.first == TextOverflowSide::Clip && *second == TextOverflowSide::Clip) {
+        dest.write_char(' ')?;
+        second.to_css(dest)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+/// A value for the [line-clamp](https://drafts.csswg.org/css-overflow-4/#line-clamp) property.
+///
+/// No browser supports the unprefixed property yet, so when compiling for targets it is
+/// lowered to the legacy `-webkit-line-clamp` pattern, which requires `display: -webkit-box`,
+/// `-webkit-box-orient: vertical`, and `overflow: hidden` to take effect.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum LineClamp {
+  /// Lines are not clamped.
+  None,
+  /// The maximum number of lines before content is truncated.
+  Integer(CSSInteger),
+}
+
+impl<'i> Parse<'i> for LineClamp {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(LineClamp::None);
+    }
+
+    let location = input.current_source_location();
+    let lines = CSSInteger::parse(input)?;
+    if lines < 1 {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(LineClamp::Integer(lines))
+  }
+}
+
+impl ToCss for LineClamp {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      LineClamp::None => dest.write_str("none"),
+      LineClamp::Integer(lines) => lines.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// An [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#overscroll-behavior-properties) keyword,
+  /// as used in the `overscroll-behavior-x`, `overscroll-behavior-y`, and `overscroll-behavior` properties.
+  pub enum OverscrollBehaviorKeyword {
+    /// Scroll chaining and default overscroll affordances are allowed.
+    "auto": Auto,
+    /// Scroll chaining to ancestor scroll containers is prevented.
+    "contain": Contain,
+    /// Scroll chaining and default overscroll affordances are prevented.
+    "none": None,
+  }
+}
+
+define_shorthand! {
+  /// A value for the [overscroll-behavior](https://drafts.csswg.org/css-overscroll-1/#propdef-overscroll-behavior) shorthand property.
+  pub struct OverscrollBehavior {
+    /// The overscroll behavior for the x direction.
+    x: OverscrollBehaviorX(OverscrollBehaviorKeyword),
+    /// The overscroll behavior for the y direction.
+    y: OverscrollBehaviorY(OverscrollBehaviorKeyword),
+  }
+}
+
+impl<'i> Parse<'i> for OverscrollBehavior {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = OverscrollBehaviorKeyword::parse(input)?;
+    let y = input.try_parse(OverscrollBehaviorKeyword::parse).unwrap_or(x);
+    Ok(OverscrollBehavior { x, y })
+  }
+}
+
+impl ToCss for OverscrollBehavior {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    if self.y != self.x {
+      dest.write_char(' ')?;
+      self.y.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+enum_property! {
+  /// A value for the legacy `-ms-scroll-chaining` property, supported by IE and
+  /// EdgeHTML in place of `overscroll-behavior`.
+  pub enum ScrollChaining {
+    /// Scrolling is chained to the parent element.
+    "chained": Chained,
+    /// Scrolling is not chained to the parent element.
+    "none": None,
+  }
+}
+
+impl From<OverscrollBehaviorKeyword> for ScrollChaining {
+  fn from(keyword: OverscrollBehaviorKeyword) -> ScrollChaining {
+    match keyword {
+      OverscrollBehaviorKeyword::Auto => ScrollChaining::Chained,
+      OverscrollBehaviorKeyword::Contain | OverscrollBehaviorKeyword::None => ScrollChaining::None,
+    }
   }
 }
 
@@ -75,6 +407,7 @@ pub(crate) struct OverflowHandler {
   targets: Option<Browsers>,
   x: Option<OverflowKeyword>,
   y: Option<OverflowKeyword>,
+  line_clamp: Option<LineClamp>,
 }
 
 impl OverflowHandler {
@@ -91,7 +424,7 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     &mut self,
     property: &Property<'i>,
     dest: &mut DeclarationList<'i>,
-    context: &mut PropertyHandlerContext<'i, '_>,
+    _: &mut PropertyHandlerContext<'i, '_>,
   ) -> bool {
     use Property::*;
 
@@ -102,13 +435,22 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
         self.x = Some(val.x);
         self.y = Some(val.y);
       }
+      // Values that use the newer syntax cannot be prefixed, so the prefix handler is bypassed.
+      Property::TextOverflow(val, prefix)
+        if !val.is_legacy_compatible() && prefix.contains(VendorPrefix::None) =>
+      {
+        dest.push(Property::TextOverflow(val.clone(), VendorPrefix::None))
+      }
+      Property::LineClamp(val, prefix) if self.targets.is_some() && *prefix == VendorPrefix::None => {
+        self.line_clamp = Some(val.clone());
+      }
       Unparsed(val)
         if matches!(
           val.property_id,
           PropertyId::OverflowX | PropertyId::OverflowY | PropertyId::Overflow
         ) =>
       {
-        self.finalize(dest, context);
+        self.flush(dest);
         dest.push(property.clone());
       }
       _ => return false,
@@ -117,7 +459,48 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     true
   }
 
-  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    // The legacy -webkit-line-clamp property only takes effect with a -webkit-box display and
+    // hidden overflow. These are only added when the rule does not declare its own values, which
+    // have already been flushed to the declaration list by the display and flex handlers.
+    if let Some(line_clamp) = std::mem::take(&mut self.line_clamp) {
+      if line_clamp != LineClamp::None {
+        let mut has_display = false;
+        let mut has_box_orient = false;
+        let mut has_overflow = self.x.is_some() || self.y.is_some();
+        for property in dest.iter() {
+          match property.property_id() {
+            PropertyId::Display => has_display = true,
+            PropertyId::BoxOrient(_) => has_box_orient = true,
+            PropertyId::Overflow | PropertyId::OverflowX | PropertyId::OverflowY => has_overflow = true,
+            _ => {}
+          }
+        }
+
+        if !has_display {
+          dest.push(Property::Display(Display::Pair(DisplayPair {
+            outside: DisplayOutside::Block,
+            inside: DisplayInside::Box(VendorPrefix::WebKit),
+            is_list_item: false,
+          })));
+        }
+        if !has_box_orient {
+          dest.push(Property::BoxOrient(BoxOrient::Vertical, VendorPrefix::WebKit));
+        }
+        if !has_overflow {
+          self.x = Some(OverflowKeyword::Hidden);
+          self.y = Some(OverflowKeyword::Hidden);
+        }
+      }
+      dest.push(Property::LineClamp(line_clamp, VendorPrefix::WebKit));
+    }
+
+    self.flush(dest);
+  }
+}
+
+impl OverflowHandler {
+  fn flush<'i>(&mut self, dest: &mut DeclarationList<'i>) {
     if self.x.is_none() && self.y.is_none() {
       return;
     }
@@ -145,3 +528,83 @@ impl<'i> PropertyHandler<'i> for OverflowHandler {
     }
   }
 }
+
+#[derive(Default)]
+pub(crate) struct OverscrollBehaviorHandler {
+  targets: Option<Browsers>,
+  x: Option<OverscrollBehaviorKeyword>,
+  y: Option<OverscrollBehaviorKeyword>,
+}
+
+impl OverscrollBehaviorHandler {
+  pub fn new(targets: Option<Browsers>) -> OverscrollBehaviorHandler {
+    OverscrollBehaviorHandler {
+      targets,
+      ..OverscrollBehaviorHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for OverscrollBehaviorHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    use Property::*;
+
+    match property {
+      OverscrollBehaviorX(val) => self.x = Some(*val),
+      OverscrollBehaviorY(val) => self.y = Some(*val),
+      OverscrollBehavior(val) => {
+        self.x = Some(val.x);
+        self.y = Some(val.y);
+      }
+      Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::OverscrollBehaviorX | PropertyId::OverscrollBehaviorY | PropertyId::OverscrollBehavior
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
+    let x = std::mem::take(&mut self.x);
+    let y = std::mem::take(&mut self.y);
+
+    match (x, y) {
+      (Some(x), Some(y)) => {
+        // -ms-scroll-chaining applies to both axes, so it can only be added if they agree.
+        if let Some(targets) = self.targets {
+          let chaining = ScrollChaining::from(x);
+          if chaining == ScrollChaining::from(y)
+            && prefixes::Feature::OverscrollBehavior
+              .prefixes_for(targets)
+              .contains(VendorPrefix::Ms)
+          {
+            dest.push(Property::ScrollChaining(chaining, VendorPrefix::Ms));
+          }
+        }
+
+        dest.push(Property::OverscrollBehavior(self::OverscrollBehavior { x, y }))
+      }
+      _ => {
+        if let Some(x) = x {
+          dest.push(Property::OverscrollBehaviorX(x))
+        }
+
+        if let Some(y) = y {
+          dest.push(Property::OverscrollBehaviorY(y))
+        }
+      }
+    }
+  }
+}
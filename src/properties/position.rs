@@ -8,6 +8,8 @@ use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::targets::Browsers;
 use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::values::anchor::AnchorFunction;
+use crate::values::length::LengthPercentage;
 use crate::values::number::CSSInteger;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
@@ -105,6 +107,51 @@ impl ToCss for ZIndex {
   }
 }
 
+/// A value for the [inset properties](https://drafts.csswg.org/css-position/#insets),
+/// e.g. `top` and `inset-inline-start`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum InsetValue<'i> {
+  /// The `auto` keyword.
+  Auto,
+  /// An explicit length or percentage.
+  LengthPercentage(LengthPercentage),
+  /// An `anchor()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Anchor(AnchorFunction<'i>),
+}
+
+impl<'i> Parse<'i> for InsetValue<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|i| i.expect_ident_matching("auto")).is_ok() {
+      return Ok(InsetValue::Auto);
+    }
+
+    if let Ok(anchor) = input.try_parse(AnchorFunction::parse) {
+      return Ok(InsetValue::Anchor(anchor));
+    }
+
+    Ok(InsetValue::LengthPercentage(LengthPercentage::parse(input)?))
+  }
+}
+
+impl<'i> ToCss for InsetValue<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      InsetValue::Auto => dest.write_str("auto"),
+      InsetValue::LengthPercentage(l) => l.to_css(dest),
+      InsetValue::Anchor(anchor) => anchor.to_css(dest),
+    }
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct PositionHandler {
   targets: Option<Browsers>,
@@ -30,7 +30,7 @@ macro_rules! define_prefixes {
     }
 
     impl<'i> PropertyHandler<'i> for PrefixHandler {
-      fn handle_property(&mut self, property: &Property<'i>, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext) -> bool {
+      fn handle_property(&mut self, property: &Property<'i>, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) -> bool {
         match property {
           $(
             Property::$name(val, prefix) => {
@@ -70,6 +70,25 @@ macro_rules! define_prefixes {
               dest.push(Property::$name(val.clone(), prefixes))
             }
           )+
+          Property::Unparsed(val) => {
+            let mut unparsed = match &val.property_id {
+              $(
+                PropertyId::$name(prefix) => {
+                  // Later declarations must not be merged into ones before the unparsed value.
+                  self.$name = None;
+                  if prefix.contains(VendorPrefix::None) {
+                    val.get_prefixed(self.targets, Feature::$name)
+                  } else {
+                    val.clone()
+                  }
+                }
+              )+
+              _ => return false
+            };
+
+            context.add_unparsed_fallbacks(&mut unparsed);
+            dest.push(Property::Unparsed(unparsed))
+          }
           _ => return false
         }
 
@@ -83,19 +102,19 @@ macro_rules! define_prefixes {
 
 define_prefixes! {
   TransformOrigin,
-  TransformStyle,
-  BackfaceVisibility,
-  Perspective,
   PerspectiveOrigin,
   BoxSizing,
   TabSize,
   Hyphens,
+  HyphenateCharacter,
   TextAlignLast,
   TextDecorationSkipInk,
   TextOverflow,
-  UserSelect,
+  WritingMode,
+  TextOrientation,
   Appearance,
   ClipPath,
+  ShapeOutside,
   BoxDecorationBreak,
 }
 
@@ -205,11 +224,8 @@ macro_rules! define_fallbacks {
 
 define_fallbacks! {
   Color,
-  TextShadow,
   Filter(prefix),
   BackdropFilter(prefix),
   Fill,
   Stroke,
-  CaretColor,
-  Caret,
 }
@@ -0,0 +1,156 @@
+//! CSS properties that use basic shapes, outside of clipping and masking.
+
+use super::masking::GeometryBox;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use crate::values::{image::Image, shape::BasicShape, url::Url};
+use cssparser::*;
+
+enum_property! {
+  /// A [`<shape-box>`](https://www.w3.org/TR/css-shapes-1/#typedef-shape-box) value
+  /// as used in the `shape-outside` property.
+  pub enum ShapeBox {
+    /// The shape is defined by the margin box.
+    "margin-box": MarginBox,
+    /// The shape is defined by the border box.
+    "border-box": BorderBox,
+    /// The shape is defined by the padding box.
+    "padding-box": PaddingBox,
+    /// The shape is defined by the content box.
+    "content-box": ContentBox,
+  }
+}
+
+impl Default for ShapeBox {
+  fn default() -> ShapeBox {
+    ShapeBox::MarginBox
+  }
+}
+
+/// A value for the [shape-outside](https://www.w3.org/TR/css-shapes-1/#shape-outside-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ShapeOutside<'i> {
+  /// The float area is unaffected.
+  None,
+  /// A basic shape, positioned according to the reference box.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Shape(Box<BasicShape<'i>>, ShapeBox),
+  /// A reference box.
+  Box(ShapeBox),
+  /// An image, whose alpha channel defines the shape.
+  Image(Image<'i>),
+}
+
+impl<'i> Parse<'i> for ShapeOutside<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(ShapeOutside::None);
+    }
+
+    if let Ok(shape) = input.try_parse(BasicShape::parse) {
+      let b = input.try_parse(ShapeBox::parse).unwrap_or_default();
+      return Ok(ShapeOutside::Shape(Box::new(shape), b));
+    }
+
+    if let Ok(b) = input.try_parse(ShapeBox::parse) {
+      if let Ok(shape) = input.try_parse(BasicShape::parse) {
+        return Ok(ShapeOutside::Shape(Box::new(shape), b));
+      }
+      return Ok(ShapeOutside::Box(b));
+    }
+
+    let image = Image::parse(input)?;
+    Ok(ShapeOutside::Image(image))
+  }
+}
+
+impl<'i> ToCss for ShapeOutside<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ShapeOutside::None => dest.write_str("none"),
+      ShapeOutside::Shape(shape, b) => {
+        shape.to_css(dest)?;
+        if *b != ShapeBox::default() {
+          dest.write_char(' ')?;
+          b.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeOutside::Box(b) => b.to_css(dest),
+      ShapeOutside::Image(image) => image.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [offset-path](https://drafts.fxtf.org/motion-1/#offset-path-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum OffsetPath<'i> {
+  /// No offset path.
+  None,
+  /// A url reference to an SVG shape element.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Url(Url<'i>),
+  /// A basic shape, positioned according to the reference box.
+  Shape(Box<BasicShape<'i>>, GeometryBox),
+  /// A reference box.
+  Box(GeometryBox),
+}
+
+impl<'i> Parse<'i> for OffsetPath<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(url) = input.try_parse(Url::parse) {
+      return Ok(OffsetPath::Url(url));
+    }
+
+    if let Ok(shape) = input.try_parse(BasicShape::parse) {
+      let b = input.try_parse(GeometryBox::parse).unwrap_or_default();
+      return Ok(OffsetPath::Shape(Box::new(shape), b));
+    }
+
+    if let Ok(b) = input.try_parse(GeometryBox::parse) {
+      if let Ok(shape) = input.try_parse(BasicShape::parse) {
+        return Ok(OffsetPath::Shape(Box::new(shape), b));
+      }
+      return Ok(OffsetPath::Box(b));
+    }
+
+    input.expect_ident_matching("none")?;
+    Ok(OffsetPath::None)
+  }
+}
+
+impl<'i> ToCss for OffsetPath<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      OffsetPath::None => dest.write_str("none"),
+      OffsetPath::Url(url) => url.to_css(dest),
+      OffsetPath::Shape(shape, b) => {
+        shape.to_css(dest)?;
+        if *b != GeometryBox::default() {
+          dest.write_char(' ')?;
+          b.to_css(dest)?;
+        }
+        Ok(())
+      }
+      OffsetPath::Box(b) => b.to_css(dest),
+    }
+  }
+}
@@ -8,7 +8,9 @@ use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
 use crate::traits::{Parse, PropertyHandler, ToCss};
+use crate::values::anchor::AnchorSizeFunction;
 use crate::values::length::LengthPercentage;
+use crate::values::ratio::Ratio;
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 
@@ -23,7 +25,7 @@ use cssparser::*;
   derive(serde::Serialize, serde::Deserialize),
   serde(tag = "type", content = "value", rename_all = "kebab-case")
 )]
-pub enum Size {
+pub enum Size<'i> {
   /// The `auto` keyword.
   Auto,
   /// An explicit length or percentage.
@@ -40,9 +42,12 @@ pub enum Size {
   Stretch(VendorPrefix),
   /// The `contain` keyword.
   Contain,
+  /// The `anchor-size()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  AnchorSize(AnchorSizeFunction<'i>),
 }
 
-impl<'i> Parse<'i> for Size {
+impl<'i> Parse<'i> for Size<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let res = input.try_parse(|input| {
       let ident = input.expect_ident()?;
@@ -73,12 +78,16 @@ impl<'i> Parse<'i> for Size {
       return Ok(Size::FitContentFunction(res));
     }
 
+    if let Ok(res) = input.try_parse(AnchorSizeFunction::parse) {
+      return Ok(Size::AnchorSize(res));
+    }
+
     let lp = input.try_parse(LengthPercentage::parse)?;
     Ok(Size::LengthPercentage(lp))
   }
 }
 
-impl ToCss for Size {
+impl<'i> ToCss for Size<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
@@ -110,6 +119,7 @@ impl ToCss for Size {
         l.to_css(dest)?;
         dest.write_str(")")
       }
+      AnchorSize(a) => a.to_css(dest),
       LengthPercentage(l) => l.to_css(dest),
     }
   }
@@ -124,7 +134,7 @@ impl ToCss for Size {
   derive(serde::Serialize, serde::Deserialize),
   serde(tag = "type", content = "value", rename_all = "kebab-case")
 )]
-pub enum MaxSize {
+pub enum MaxSize<'i> {
   /// The `none` keyword.
   None,
   /// An explicit length or percentage.
@@ -141,9 +151,12 @@ pub enum MaxSize {
   Stretch(VendorPrefix),
   /// The `contain` keyword.
   Contain,
+  /// The `anchor-size()` function.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  AnchorSize(AnchorSizeFunction<'i>),
 }
 
-impl<'i> Parse<'i> for MaxSize {
+impl<'i> Parse<'i> for MaxSize<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let res = input.try_parse(|input| {
       let ident = input.expect_ident()?;
@@ -174,12 +187,16 @@ impl<'i> Parse<'i> for MaxSize {
       return Ok(MaxSize::FitContentFunction(res));
     }
 
+    if let Ok(res) = input.try_parse(AnchorSizeFunction::parse) {
+      return Ok(MaxSize::AnchorSize(res));
+    }
+
     let lp = input.try_parse(LengthPercentage::parse)?;
     Ok(MaxSize::LengthPercentage(lp))
   }
 }
 
-impl ToCss for MaxSize {
+impl<'i> ToCss for MaxSize<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
@@ -211,6 +228,7 @@ impl ToCss for MaxSize {
         l.to_css(dest)?;
         dest.write_str(")")
       }
+      AnchorSize(a) => a.to_css(dest),
       LengthPercentage(l) => l.to_css(dest),
     }
   }
@@ -233,6 +251,136 @@ enum_property! {
   }
 }
 
+/// A value for the [writing-mode](https://drafts.csswg.org/css-writing-modes-4/#block-flow) property,
+/// which determines the physical dimensions that the logical sizing properties correspond to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "kebab-case")
+)]
+pub enum WritingMode {
+  /// Blocks flow from top to bottom, so the block size is the height.
+  HorizontalTb,
+  /// Blocks flow from right to left, so the block size is the width.
+  VerticalRl,
+  /// Blocks flow from left to right, so the block size is the width.
+  VerticalLr,
+  /// Like `vertical-rl`, but all text is typeset sideways.
+  SidewaysRl,
+  /// Blocks flow from left to right, and all text is typeset sideways with its top towards the left.
+  SidewaysLr,
+}
+
+impl<'i> Parse<'i> for WritingMode {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident()?;
+    match_ignore_ascii_case! { &*ident,
+      "horizontal-tb" => Ok(WritingMode::HorizontalTb),
+      "vertical-rl" => Ok(WritingMode::VerticalRl),
+      "vertical-lr" => Ok(WritingMode::VerticalLr),
+      "sideways-rl" => Ok(WritingMode::SidewaysRl),
+      "sideways-lr" => Ok(WritingMode::SidewaysLr),
+      // Legacy values from SVG 1.1, which are mapped to their modern equivalents.
+      // https://drafts.csswg.org/css-writing-modes-4/#svg-writing-mode
+      "lr" | "lr-tb" | "rl" | "rl-tb" => Ok(WritingMode::HorizontalTb),
+      "tb" | "tb-rl" => Ok(WritingMode::VerticalRl),
+      "tb-lr" => Ok(WritingMode::VerticalLr),
+      _ => Err(location.new_unexpected_token_error(
+        cssparser::Token::Ident(ident.clone())
+      ))
+    }
+  }
+}
+
+impl ToCss for WritingMode {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str(match self {
+      WritingMode::HorizontalTb => "horizontal-tb",
+      WritingMode::VerticalRl => "vertical-rl",
+      WritingMode::VerticalLr => "vertical-lr",
+      WritingMode::SidewaysRl => "sideways-rl",
+      WritingMode::SidewaysLr => "sideways-lr",
+    })
+  }
+}
+
+impl Default for WritingMode {
+  fn default() -> WritingMode {
+    WritingMode::HorizontalTb
+  }
+}
+
+impl WritingMode {
+  /// Returns whether the block axis is horizontal in this writing mode.
+  pub fn is_vertical(&self) -> bool {
+    !matches!(self, WritingMode::HorizontalTb)
+  }
+}
+
+enum_property! {
+  /// A value for the [interpolate-size](https://drafts.csswg.org/css-values-5/#interpolate-size) property.
+  pub enum InterpolateSize {
+    /// Only numeric sizes can be interpolated.
+    "numeric-only": NumericOnly,
+    /// Intrinsic size keywords such as `auto` and `fit-content` can also be interpolated.
+    "allow-keywords": AllowKeywords,
+  }
+}
+
+/// A value for the [aspect-ratio](https://drafts.csswg.org/css-sizing-4/#aspect-ratio) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspectRatio {
+  /// The `auto` keyword.
+  pub auto: bool,
+  /// A preferred aspect ratio for the box.
+  pub ratio: Option<Ratio>,
+}
+
+impl<'i> Parse<'i> for AspectRatio {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let mut auto = input.try_parse(|i| i.expect_ident_matching("auto"));
+    let ratio = input.try_parse(Ratio::parse);
+    if auto.is_err() {
+      auto = input.try_parse(|i| i.expect_ident_matching("auto"));
+    }
+    if auto.is_err() && ratio.is_err() {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
+    Ok(AspectRatio {
+      auto: auto.is_ok(),
+      ratio: ratio.ok(),
+    })
+  }
+}
+
+impl ToCss for AspectRatio {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.auto {
+      dest.write_str("auto")?;
+    }
+
+    if let Some(ratio) = &self.ratio {
+      if self.auto {
+        dest.write_char(' ')?;
+      }
+      ratio.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct SizeHandler;
 
@@ -272,12 +420,16 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
       }};
     }
 
+    let vertical = context.writing_mode.is_vertical();
+
     macro_rules! logical {
-      ($prop: ident, $val: ident, $physical: ident, $size: ident) => {
+      ($prop: ident, $val: ident, $horizontal: ident, $vertical: ident, $size: ident) => {
         if logical_supported {
           property!($prop, $val, $size);
+        } else if vertical {
+          property!($vertical, $val, $size);
         } else {
-          property!($physical, $val, $size);
+          property!($horizontal, $val, $size);
         }
       };
     }
@@ -289,20 +441,24 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
       Property::MinHeight(v) => property!(MinHeight, v, Size),
       Property::MaxWidth(v) => property!(MaxWidth, v, MaxSize),
       Property::MaxHeight(v) => property!(MaxHeight, v, MaxSize),
-      Property::BlockSize(size) => logical!(BlockSize, size, Height, Size),
-      Property::MinBlockSize(size) => logical!(MinBlockSize, size, MinHeight, Size),
-      Property::MaxBlockSize(size) => logical!(MaxBlockSize, size, MaxHeight, MaxSize),
-      Property::InlineSize(size) => logical!(InlineSize, size, Width, Size),
-      Property::MinInlineSize(size) => logical!(MinInlineSize, size, MinWidth, Size),
-      Property::MaxInlineSize(size) => logical!(MaxInlineSize, size, MaxWidth, MaxSize),
+      Property::BlockSize(size) => logical!(BlockSize, size, Height, Width, Size),
+      Property::MinBlockSize(size) => logical!(MinBlockSize, size, MinHeight, MinWidth, Size),
+      Property::MaxBlockSize(size) => logical!(MaxBlockSize, size, MaxHeight, MaxWidth, MaxSize),
+      Property::InlineSize(size) => logical!(InlineSize, size, Width, Height, Size),
+      Property::MinInlineSize(size) => logical!(MinInlineSize, size, MinWidth, MinHeight, Size),
+      Property::MaxInlineSize(size) => logical!(MaxInlineSize, size, MaxWidth, MaxHeight, MaxSize),
       Property::Unparsed(unparsed) => {
         macro_rules! logical_unparsed {
-          ($physical: ident) => {
+          ($horizontal: ident, $vertical: ident) => {
             if logical_supported {
               dest.push(property.clone());
+            } else if vertical {
+              dest.push(Property::Unparsed(
+                unparsed.with_property_id(PropertyId::$vertical),
+              ));
             } else {
               dest.push(Property::Unparsed(
-                unparsed.with_property_id(PropertyId::$physical),
+                unparsed.with_property_id(PropertyId::$horizontal),
               ));
             }
           };
@@ -317,12 +473,12 @@ impl<'i> PropertyHandler<'i> for SizeHandler {
           | PropertyId::MaxHeight => {
             dest.push(property.clone());
           }
-          PropertyId::BlockSize => logical_unparsed!(Height),
-          PropertyId::MinBlockSize => logical_unparsed!(MinHeight),
-          PropertyId::MaxBlockSize => logical_unparsed!(MaxHeight),
-          PropertyId::InlineSize => logical_unparsed!(Width),
-          PropertyId::MinInlineSize => logical_unparsed!(MinWidth),
-          PropertyId::MaxInlineSize => logical_unparsed!(MaxWidth),
+          PropertyId::BlockSize => logical_unparsed!(Height, Width),
+          PropertyId::MinBlockSize => logical_unparsed!(MinHeight, MinWidth),
+          PropertyId::MaxBlockSize => logical_unparsed!(MaxHeight, MaxWidth),
+          PropertyId::InlineSize => logical_unparsed!(Width, Height),
+          PropertyId::MinInlineSize => logical_unparsed!(MinWidth, MinHeight),
+          PropertyId::MaxInlineSize => logical_unparsed!(MaxWidth, MaxHeight),
           _ => return false,
         }
       }
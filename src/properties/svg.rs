@@ -1,10 +1,14 @@
 //! CSS properties used in SVG.
 
+use super::{Property, PropertyId};
+use crate::compat::Feature;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::DeclarationList;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::targets::Browsers;
-use crate::traits::{FallbackValues, Parse, ToCss};
+use crate::traits::{FallbackValues, Parse, PropertyHandler, ToCss};
 use crate::values::length::LengthPercentage;
 use crate::values::{color::CssColor, url::Url};
 use cssparser::*;
@@ -317,13 +321,126 @@ enum_property! {
 }
 
 enum_property! {
-  /// A value for the [image-rendering](https://www.w3.org/TR/SVG2/painting.html#ImageRendering) property.
+  /// A value for the [image-rendering](https://drafts.csswg.org/css-images-3/#the-image-rendering) property.
   pub enum ImageRendering {
     /// The UA can choose a tradeoff between speed and quality.
-    Auto,
-    /// The UA shall optimize speed over quality.
-    OptimizeSpeed,
-    /// The UA shall optimize quality over speed.
-    OptimizeQuality,
+    "auto": Auto,
+    /// The image is scaled with an algorithm that maximizes the appearance of the image.
+    "smooth": Smooth,
+    /// The same as `smooth`, but with a preference for higher-quality scaling.
+    "high-quality": HighQuality,
+    /// The image is scaled with an algorithm that preserves contrast and edges.
+    "crisp-edges": CrispEdges,
+    /// The image is scaled with the nearest neighbor algorithm or similar, so it appears to be made of large pixels.
+    "pixelated": Pixelated,
+    /// The UA shall optimize speed over quality. A legacy SVG value.
+    "optimizeSpeed": OptimizeSpeed,
+    /// The UA shall optimize quality over speed. A legacy SVG value.
+    "optimizeQuality": OptimizeQuality,
+    /// The legacy WebKit equivalent of `crisp-edges`.
+    "-webkit-optimize-contrast": WebKitOptimizeContrast,
+    /// The legacy Firefox equivalent of `crisp-edges`.
+    "-moz-crisp-edges": MozCrispEdges,
+  }
+}
+
+impl FallbackValues for ImageRendering {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    let feature = match self {
+      // Legacy values are converted to the standard value when all targets support it.
+      ImageRendering::WebKitOptimizeContrast | ImageRendering::MozCrispEdges => {
+        if Feature::ImageRenderingCrispEdges.is_compatible(targets) {
+          *self = ImageRendering::CrispEdges;
+        }
+        return Vec::new();
+      }
+      ImageRendering::CrispEdges => Feature::ImageRenderingCrispEdges,
+      ImageRendering::Pixelated => Feature::ImageRenderingPixelated,
+      _ => return Vec::new(),
+    };
+
+    if feature.is_compatible(targets) {
+      return Vec::new();
+    }
+
+    // Older browsers only support the vendor specific values, which are similar to `crisp-edges`.
+    // These are also the closest equivalent of `pixelated`. Only add them for targets that do not
+    // support the standard value.
+    macro_rules! unsupported {
+      ($($browser: ident),+) => {
+        $(
+          targets.$browser.is_some()
+            && !feature.is_compatible(Browsers {
+              $browser: targets.$browser,
+              ..Browsers::default()
+            })
+        )||+
+      };
+    }
+
+    let mut fallbacks = Vec::new();
+    if unsupported!(chrome, safari, ios_saf, android, opera, samsung) {
+      fallbacks.push(ImageRendering::WebKitOptimizeContrast);
+    }
+    if unsupported!(firefox) {
+      fallbacks.push(ImageRendering::MozCrispEdges);
+    }
+    fallbacks
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ImageRenderingHandler {
+  targets: Option<Browsers>,
+  values: Vec<ImageRendering>,
+}
+
+impl ImageRenderingHandler {
+  pub fn new(targets: Option<Browsers>) -> ImageRenderingHandler {
+    ImageRenderingHandler {
+      targets,
+      ..ImageRenderingHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for ImageRenderingHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ImageRendering(val) => {
+        let mut val = *val;
+        if let Some(targets) = self.targets {
+          let fallbacks = val.get_fallbacks(targets);
+          self.values.extend(fallbacks);
+        }
+        self.values.push(val);
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::ImageRendering) => {
+        self.finalize(dest, context);
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    // Generated fallbacks may duplicate legacy values that were already declared, e.g.
+    // `-webkit-optimize-contrast` followed by `crisp-edges`. Only the last occurrence of
+    // each value affects the cascade, so earlier duplicates are removed.
+    let values = std::mem::take(&mut self.values);
+    for (i, val) in values.iter().enumerate() {
+      if !values[i + 1..].contains(val) {
+        dest.push(Property::ImageRendering(*val));
+      }
+    }
   }
 }
@@ -2,11 +2,12 @@
 
 #![allow(non_upper_case_globals)]
 
+use super::border::BorderSideWidth;
 use super::{Property, PropertyId};
 use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
-use crate::error::{ParserError, PrinterError};
+use crate::error::{MinifyErrorKind, ParserError, PrinterError};
 use crate::macros::{define_shorthand, enum_property};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
@@ -15,6 +16,7 @@ use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss, Ze
 use crate::values::calc::{Calc, MathFunction};
 use crate::values::color::{ColorFallbackKind, CssColor};
 use crate::values::length::{Length, LengthPercentage, LengthValue};
+use crate::values::number::CSSInteger;
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
 use bitflags::bitflags;
@@ -110,16 +112,26 @@ impl<'i> Parse<'i> for TextTransform {
     loop {
       if case.is_none() {
         if let Ok(c) = input.try_parse(TextTransformCase::parse) {
+          // `none` cannot be combined with any other keyword.
+          if c == TextTransformCase::None && !other.is_empty() {
+            return Err(input.new_custom_error(ParserError::InvalidValue));
+          }
           case = Some(c);
           if c == TextTransformCase::None {
-            other = TextTransformOther::empty();
             break;
           }
           continue;
         }
       }
 
-      if let Ok(o) = input.try_parse(TextTransformOther::parse) {
+      // Each keyword may only appear once.
+      if let Ok(o) = input.try_parse(|input| {
+        let o = TextTransformOther::parse(input)?;
+        if other.contains(o) {
+          return Err(input.new_custom_error(ParserError::InvalidValue));
+        }
+        Ok(o)
+      }) {
         other |= o;
         continue;
       }
@@ -127,6 +139,10 @@ impl<'i> Parse<'i> for TextTransform {
       break;
     }
 
+    if case.is_none() && other.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
     Ok(TextTransform {
       case: case.unwrap_or_default(),
       other,
@@ -173,6 +189,153 @@ enum_property! {
   }
 }
 
+impl WhiteSpace {
+  /// Returns the values of the `white-space-collapse` and `text-wrap-mode` longhands
+  /// that this legacy keyword is equivalent to.
+  pub fn to_longhands(&self) -> (WhiteSpaceCollapse, TextWrapMode) {
+    match self {
+      WhiteSpace::Normal => (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap),
+      WhiteSpace::Pre => (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap),
+      WhiteSpace::NoWrap => (WhiteSpaceCollapse::Collapse, TextWrapMode::NoWrap),
+      WhiteSpace::PreWrap => (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap),
+      WhiteSpace::BreakSpaces => (WhiteSpaceCollapse::BreakSpaces, TextWrapMode::Wrap),
+      WhiteSpace::PreLine => (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap),
+    }
+  }
+
+  /// Returns the legacy keyword equivalent to the given `white-space-collapse`
+  /// and `text-wrap-mode` values, if any.
+  pub fn from_longhands(collapse: WhiteSpaceCollapse, mode: TextWrapMode) -> Option<WhiteSpace> {
+    match (collapse, mode) {
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::Wrap) => Some(WhiteSpace::Normal),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::NoWrap) => Some(WhiteSpace::Pre),
+      (WhiteSpaceCollapse::Collapse, TextWrapMode::NoWrap) => Some(WhiteSpace::NoWrap),
+      (WhiteSpaceCollapse::Preserve, TextWrapMode::Wrap) => Some(WhiteSpace::PreWrap),
+      (WhiteSpaceCollapse::BreakSpaces, TextWrapMode::Wrap) => Some(WhiteSpace::BreakSpaces),
+      (WhiteSpaceCollapse::PreserveBreaks, TextWrapMode::Wrap) => Some(WhiteSpace::PreLine),
+      _ => None,
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [white-space-collapse](https://drafts.csswg.org/css-text-4/#white-space-collapsing) property.
+  pub enum WhiteSpaceCollapse {
+    /// Sequences of white space are collapsed into a single character.
+    "collapse": Collapse,
+    /// White space is removed.
+    "discard": Discard,
+    /// White space is preserved.
+    "preserve": Preserve,
+    /// Segment breaks are preserved, but other white space is collapsed.
+    "preserve-breaks": PreserveBreaks,
+    /// Spaces and tabs are preserved, but segment breaks are converted to spaces.
+    "preserve-spaces": PreserveSpaces,
+    /// Like preserve, but preserved white space takes up space and allows wrapping.
+    "break-spaces": BreakSpaces,
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-mode](https://drafts.csswg.org/css-text-4/#text-wrap-mode) property.
+  pub enum TextWrapMode {
+    /// Lines may wrap at allowed soft wrap opportunities.
+    "wrap": Wrap,
+    /// Lines do not wrap.
+    "nowrap": NoWrap,
+  }
+}
+
+impl Default for TextWrapMode {
+  fn default() -> TextWrapMode {
+    TextWrapMode::Wrap
+  }
+}
+
+enum_property! {
+  /// A value for the [text-wrap-style](https://drafts.csswg.org/css-text-4/#text-wrap-style) property.
+  pub enum TextWrapStyle {
+    /// The UA chooses a wrapping method that is fast to compute.
+    "auto": Auto,
+    /// Line lengths are balanced across the block.
+    "balance": Balance,
+    /// Lines are not reflowed when content is edited.
+    "stable": Stable,
+    /// Slower algorithms are used to produce better layout, e.g. avoiding short last lines.
+    "pretty": Pretty,
+  }
+}
+
+impl Default for TextWrapStyle {
+  fn default() -> TextWrapStyle {
+    TextWrapStyle::Auto
+  }
+}
+
+define_shorthand! {
+  /// A value for the [text-wrap](https://drafts.csswg.org/css-text-4/#text-wrap) shorthand property.
+  pub struct TextWrap {
+    /// Whether lines may wrap.
+    mode: TextWrapMode(TextWrapMode),
+    /// How lines are wrapped.
+    style: TextWrapStyle(TextWrapStyle),
+  }
+}
+
+impl<'i> Parse<'i> for TextWrap {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut mode = None;
+    let mut style = None;
+
+    loop {
+      if mode.is_none() {
+        if let Ok(value) = input.try_parse(TextWrapMode::parse) {
+          mode = Some(value);
+          continue;
+        }
+      }
+
+      if style.is_none() {
+        if let Ok(value) = input.try_parse(TextWrapStyle::parse) {
+          style = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if mode.is_none() && style.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextWrap {
+      mode: mode.unwrap_or_default(),
+      style: style.unwrap_or_default(),
+    })
+  }
+}
+
+impl ToCss for TextWrap {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.mode != TextWrapMode::Wrap || self.style == TextWrapStyle::Auto {
+      self.mode.to_css(dest)?;
+      if self.style != TextWrapStyle::Auto {
+        dest.write_char(' ')?;
+      }
+    }
+
+    if self.style != TextWrapStyle::Auto {
+      self.style.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
 enum_property! {
   /// A value for the [word-break](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#word-break-property) property.
   pub enum WordBreak {
@@ -184,6 +347,10 @@ enum_property! {
     "break-all": BreakAll,
     /// Breaking is allowed if there is no otherwise acceptable break points in a line.
     "break-word": BreakWord,
+    /// Breaking is only allowed at explicit opportunities, e.g. spaces or `<wbr>`.
+    "manual": Manual,
+    /// Breaking is allowed at natural phrase boundaries determined by language analysis.
+    "auto-phrase": AutoPhrase,
   }
 }
 
@@ -214,6 +381,123 @@ enum_property! {
   }
 }
 
+/// A value for the [hyphenate-character](https://www.w3.org/TR/css-text-4/#hyphenate-character) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum HyphenateCharacter<'i> {
+  /// The UA chooses an appropriate string based on the content language.
+  Auto,
+  /// The given string is used as the hyphenation character.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  String(CowArcStr<'i>),
+}
+
+impl<'i> Parse<'i> for HyphenateCharacter<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(HyphenateCharacter::Auto);
+    }
+
+    let s = input.expect_string_cloned()?;
+    Ok(HyphenateCharacter::String(s.into()))
+  }
+}
+
+impl<'i> ToCss for HyphenateCharacter<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      HyphenateCharacter::Auto => dest.write_str("auto"),
+      HyphenateCharacter::String(s) => {
+        serialize_string(&s, dest)?;
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A limit in the [hyphenate-limit-chars](https://www.w3.org/TR/css-text-4/#hyphenate-char-limits) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum HyphenateLimit {
+  /// The UA chooses a value that adapts to the current layout.
+  Auto,
+  /// An explicit number of characters.
+  Integer(CSSInteger),
+}
+
+impl<'i> Parse<'i> for HyphenateLimit {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(HyphenateLimit::Auto);
+    }
+
+    Ok(HyphenateLimit::Integer(CSSInteger::parse(input)?))
+  }
+}
+
+impl ToCss for HyphenateLimit {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      HyphenateLimit::Auto => dest.write_str("auto"),
+      HyphenateLimit::Integer(i) => i.to_css(dest),
+    }
+  }
+}
+
+/// A value for the [hyphenate-limit-chars](https://www.w3.org/TR/css-text-4/#hyphenate-char-limits) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HyphenateLimitChars {
+  /// The minimum number of characters in a hyphenated word.
+  pub total: HyphenateLimit,
+  /// The minimum number of characters before the hyphen.
+  pub before: HyphenateLimit,
+  /// The minimum number of characters after the hyphen.
+  pub after: HyphenateLimit,
+}
+
+impl<'i> Parse<'i> for HyphenateLimitChars {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let total = HyphenateLimit::parse(input)?;
+    // If the second value is omitted it is auto, and if the third is omitted it is the same as the second.
+    let before = input.try_parse(HyphenateLimit::parse).unwrap_or(HyphenateLimit::Auto);
+    let after = input.try_parse(HyphenateLimit::parse).unwrap_or_else(|_| before.clone());
+    Ok(HyphenateLimitChars { total, before, after })
+  }
+}
+
+impl ToCss for HyphenateLimitChars {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.total.to_css(dest)?;
+    if self.before != HyphenateLimit::Auto || self.after != HyphenateLimit::Auto {
+      dest.write_char(' ')?;
+      self.before.to_css(dest)?;
+      if self.after != self.before {
+        dest.write_char(' ')?;
+        self.after.to_css(dest)?;
+      }
+    }
+    Ok(())
+  }
+}
+
 enum_property! {
   /// A value for the [overflow-wrap](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#overflow-wrap-property) property.
   pub enum OverflowWrap {
@@ -271,6 +555,88 @@ enum_property! {
   }
 }
 
+enum_property! {
+  /// An [inline base direction](https://drafts.csswg.org/css-writing-modes-4/#inline-base-direction), which
+  /// determines the physical sides that logical values such as `text-align: start` correspond to.
+  pub enum TextDirection {
+    /// Inline content flows from left to right.
+    Ltr,
+    /// Inline content flows from right to left.
+    Rtl,
+  }
+}
+
+enum_property! {
+  /// A value for the [text-orientation](https://drafts.csswg.org/css-writing-modes-4/#text-orientation) property.
+  pub enum TextOrientation {
+    /// Typographic character units in vertical scripts are typeset upright, and others sideways.
+    "mixed": Mixed,
+    /// All typographic character units are typeset upright.
+    "upright": Upright,
+    /// All text is typeset sideways, as if in a horizontal layout rotated 90° clockwise.
+    "sideways": Sideways,
+  }
+}
+
+/// A value for the [text-combine-upright](https://drafts.csswg.org/css-writing-modes-4/#text-combine-upright) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TextCombineUpright {
+  /// No special processing.
+  None,
+  /// All consecutive typographic character units are combined horizontally within a single character space.
+  All,
+  /// Sequences of up to the given number of consecutive ASCII digits (2 by default) are combined.
+  Digits(Option<CSSInteger>),
+}
+
+impl<'i> Parse<'i> for TextCombineUpright {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+      return Ok(TextCombineUpright::None);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("all")).is_ok() {
+      return Ok(TextCombineUpright::All);
+    }
+
+    input.expect_ident_matching("digits")?;
+    let location = input.current_source_location();
+    match input.try_parse(CSSInteger::parse) {
+      Ok(digits) if digits < 2 || digits > 4 => Err(location.new_custom_error(ParserError::InvalidValue)),
+      Ok(digits) => Ok(TextCombineUpright::Digits(Some(digits))),
+      Err(_) => Ok(TextCombineUpright::Digits(None)),
+    }
+  }
+}
+
+impl ToCss for TextCombineUpright {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextCombineUpright::None => dest.write_str("none"),
+      TextCombineUpright::All => dest.write_str("all"),
+      TextCombineUpright::Digits(digits) => {
+        dest.write_str("digits")?;
+        // The default is 2 digits.
+        match digits {
+          Some(digits) if *digits != 2 => {
+            dest.write_char(' ')?;
+            digits.to_css(dest)
+          }
+          _ => Ok(()),
+        }
+      }
+    }
+  }
+}
+
 enum_property! {
   /// A value for the [text-justify](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-justify-property) property.
   pub enum TextJustify {
@@ -323,23 +689,42 @@ impl ToCss for Spacing {
   }
 }
 
+enum_property! {
+  /// A keyword for the [text-indent](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-indent-property) property.
+  pub enum TextIndentKeyword {
+    /// Inverts which lines are affected.
+    "hanging": Hanging,
+    /// Affects the first line after each hard break.
+    "each-line": EachLine,
+  }
+}
+
 /// A value for the [text-indent](https://www.w3.org/TR/2021/CRD-css-text-3-20210422/#text-indent-property) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextIndent {
   /// The amount to indent.
   pub value: LengthPercentage,
-  /// Inverts which lines are affected.
-  pub hanging: bool,
-  /// Affects the first line after each hard break.
-  pub each_line: bool,
+  /// The `hanging` and `each-line` keywords, in the order they were specified.
+  pub keywords: SmallVec<[TextIndentKeyword; 2]>,
+}
+
+impl TextIndent {
+  /// Returns whether the `hanging` keyword is present.
+  pub fn hanging(&self) -> bool {
+    self.keywords.contains(&TextIndentKeyword::Hanging)
+  }
+
+  /// Returns whether the `each-line` keyword is present.
+  pub fn each_line(&self) -> bool {
+    self.keywords.contains(&TextIndentKeyword::EachLine)
+  }
 }
 
 impl<'i> Parse<'i> for TextIndent {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let mut value = None;
-    let mut hanging = false;
-    let mut each_line = false;
+    let mut keywords: SmallVec<[TextIndentKeyword; 2]> = SmallVec::new();
 
     loop {
       if value.is_none() {
@@ -349,29 +734,19 @@ impl<'i> Parse<'i> for TextIndent {
         }
       }
 
-      if !hanging {
-        if input.try_parse(|input| input.expect_ident_matching("hanging")).is_ok() {
-          hanging = true;
-          continue;
-        }
-      }
-
-      if !each_line {
-        if input.try_parse(|input| input.expect_ident_matching("each-line")).is_ok() {
-          each_line = true;
-          continue;
+      if let Ok(keyword) = input.try_parse(TextIndentKeyword::parse) {
+        if keywords.contains(&keyword) {
+          return Err(input.new_custom_error(ParserError::InvalidDeclaration));
         }
+        keywords.push(keyword);
+        continue;
       }
 
       break;
     }
 
     if let Some(value) = value {
-      Ok(TextIndent {
-        value,
-        hanging,
-        each_line,
-      })
+      Ok(TextIndent { value, keywords })
     } else {
       Err(input.new_custom_error(ParserError::InvalidDeclaration))
     }
@@ -384,11 +759,9 @@ impl ToCss for TextIndent {
     W: std::fmt::Write,
   {
     self.value.to_css(dest)?;
-    if self.hanging {
-      dest.write_str(" hanging")?;
-    }
-    if self.each_line {
-      dest.write_str(" each-line")?;
+    for keyword in &self.keywords {
+      dest.write_char(' ')?;
+      keyword.to_css(dest)?;
     }
     Ok(())
   }
@@ -571,6 +944,149 @@ impl ToCss for TextDecorationThickness {
   }
 }
 
+/// A value for the [text-underline-offset](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#underline-offset) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TextUnderlineOffset {
+  /// The UA chooses an appropriate offset for underlines.
+  Auto,
+  /// An explicit offset from the initial underline position.
+  LengthPercentage(LengthPercentage),
+}
+
+impl Default for TextUnderlineOffset {
+  fn default() -> TextUnderlineOffset {
+    TextUnderlineOffset::Auto
+  }
+}
+
+impl<'i> Parse<'i> for TextUnderlineOffset {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TextUnderlineOffset::Auto);
+    }
+
+    let lp = LengthPercentage::parse(input)?;
+    Ok(TextUnderlineOffset::LengthPercentage(lp))
+  }
+}
+
+impl ToCss for TextUnderlineOffset {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextUnderlineOffset::Auto => dest.write_str("auto"),
+      TextUnderlineOffset::LengthPercentage(lp) => lp.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A side keyword for the [text-underline-position](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-underline-position-property) property,
+  /// used in vertical typographic modes.
+  ///
+  /// See [TextUnderlinePosition](TextUnderlinePosition).
+  pub enum TextUnderlinePositionSide {
+    /// The underline is aligned with the left edge of the text.
+    Left,
+    /// The underline is aligned with the right edge of the text.
+    Right,
+  }
+}
+
+/// A value for the [text-underline-position](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-underline-position-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum TextUnderlinePosition {
+  /// The UA chooses an appropriate position for underlines.
+  Auto,
+  /// Use the underline position defined in the current font.
+  FromFont,
+  /// An explicit position. At least one of `under` or a side must be specified.
+  Position {
+    /// Whether the underline is positioned under the alphabetic baseline, below any descenders.
+    under: bool,
+    /// The side of the text the underline is placed on in vertical typographic modes.
+    side: Option<TextUnderlinePositionSide>,
+  },
+}
+
+impl Default for TextUnderlinePosition {
+  fn default() -> TextUnderlinePosition {
+    TextUnderlinePosition::Auto
+  }
+}
+
+impl<'i> Parse<'i> for TextUnderlinePosition {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(TextUnderlinePosition::Auto);
+    }
+
+    if input.try_parse(|input| input.expect_ident_matching("from-font")).is_ok() {
+      return Ok(TextUnderlinePosition::FromFont);
+    }
+
+    let mut under = false;
+    let mut side = None;
+    loop {
+      if !under && input.try_parse(|input| input.expect_ident_matching("under")).is_ok() {
+        under = true;
+        continue;
+      }
+
+      if side.is_none() {
+        if let Ok(s) = input.try_parse(TextUnderlinePositionSide::parse) {
+          side = Some(s);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if !under && side.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(TextUnderlinePosition::Position { under, side })
+  }
+}
+
+impl ToCss for TextUnderlinePosition {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      TextUnderlinePosition::Auto => dest.write_str("auto"),
+      TextUnderlinePosition::FromFont => dest.write_str("from-font"),
+      TextUnderlinePosition::Position { under, side } => {
+        if *under {
+          dest.write_str("under")?;
+        }
+        if let Some(side) = side {
+          if *under {
+            dest.write_char(' ')?;
+          }
+          side.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
 define_shorthand! {
   /// A value for the [text-decoration](https://www.w3.org/TR/2020/WD-css-text-decor-4-20200506/#text-decoration-property) shorthand property.
   pub struct TextDecoration(VendorPrefix) {
@@ -826,6 +1342,10 @@ impl<'i> Parse<'i> for TextEmphasis<'i> {
       break;
     }
 
+    if style.is_none() && color.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
     Ok(TextEmphasis {
       style: style.unwrap_or_default(),
       color: color.unwrap_or(CssColor::current_color()),
@@ -840,7 +1360,8 @@ impl<'i> ToCss for TextEmphasis<'i> {
   {
     self.style.to_css(dest)?;
 
-    if self.style != TextEmphasisStyle::None && self.color != CssColor::current_color() {
+    // The color is still set when the style is `none`, so it must be preserved.
+    if self.color != CssColor::current_color() {
       dest.write_char(' ')?;
       self.color.to_css(dest)?;
     }
@@ -949,6 +1470,9 @@ pub(crate) struct TextDecorationHandler<'i> {
   emphasis_style: Option<(TextEmphasisStyle<'i>, VendorPrefix)>,
   emphasis_color: Option<(CssColor, VendorPrefix)>,
   emphasis_position: Option<(TextEmphasisPosition, VendorPrefix)>,
+  stroke_width: Option<BorderSideWidth>,
+  stroke_color: Option<CssColor>,
+  overflow_wrap: Option<OverflowWrap>,
   has_any: bool,
 }
 
@@ -1023,6 +1547,54 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         property!(emphasis_color, &val.color, vp);
       }
       TextEmphasisPosition(val, vp) => property!(emphasis_position, val, vp),
+      WebKitTextStrokeWidth(val, _) => {
+        self.stroke_width = Some(val.clone());
+        self.has_any = true;
+      }
+      WebKitTextStrokeColor(val, _) => {
+        self.stroke_color = Some(val.clone());
+        self.has_any = true;
+      }
+      WebKitTextStroke(val, _) => {
+        self.stroke_width = Some(val.width.clone());
+        self.stroke_color = Some(val.color.clone());
+        self.has_any = true;
+      }
+      // word-wrap is a legacy alias of overflow-wrap.
+      OverflowWrap(val) | WordWrap(val) => {
+        self.overflow_wrap = Some(*val);
+        self.has_any = true;
+      }
+      Unparsed(val) if matches!(val.property_id, PropertyId::OverflowWrap | PropertyId::WordWrap) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      TextUnderlineOffset(..) | TextUnderlinePosition(..) => {
+        let feature = match property {
+          Property::TextUnderlinePosition(self::TextUnderlinePosition::Position { side: Some(..), .. }) => {
+            compat::Feature::TextUnderlinePositionSide
+          }
+          Property::TextUnderlinePosition(..) => compat::Feature::TextUnderlinePosition,
+          _ => compat::Feature::TextUnderlineOffset,
+        };
+
+        // There is no fallback for these, so let the user know the underline may render differently.
+        if !context.is_supported(feature) {
+          context.warn(MinifyErrorKind::UnsupportedByTargets {
+            property: property.property_id().name().to_owned(),
+          });
+        }
+        dest.push(property.clone())
+      }
+      WebKitTextFillColor(val, _) => {
+        let mut val = val.clone();
+        if let Some(targets) = self.targets {
+          for fallback in val.get_fallbacks(targets) {
+            dest.push(Property::WebKitTextFillColor(fallback, VendorPrefix::WebKit))
+          }
+        }
+        dest.push(Property::WebKitTextFillColor(val, VendorPrefix::WebKit))
+      }
       TextAlign(align) => {
         use super::text::*;
         macro_rules! logical {
@@ -1057,6 +1629,27 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         context.add_unparsed_fallbacks(&mut unparsed);
         dest.push(Property::Unparsed(unparsed))
       }
+      Unparsed(val) if is_text_stroke_property(&val.property_id) => {
+        self.finalize(dest, context);
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed))
+      }
+      TextShadow(val) => {
+        let mut val = val.clone();
+        dedupe_text_shadows(&mut val);
+        if let Some(targets) = self.targets {
+          for fallback in val.get_fallbacks(targets) {
+            dest.push(Property::TextShadow(fallback))
+          }
+        }
+        dest.push(Property::TextShadow(val))
+      }
+      Unparsed(val) if matches!(val.property_id, PropertyId::TextShadow) => {
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed))
+      }
       _ => return false,
     }
 
@@ -1077,6 +1670,9 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
     let mut emphasis_style = std::mem::take(&mut self.emphasis_style);
     let mut emphasis_color = std::mem::take(&mut self.emphasis_color);
     let emphasis_position = std::mem::take(&mut self.emphasis_position);
+    let overflow_wrap = std::mem::take(&mut self.overflow_wrap);
+    let stroke_width = std::mem::take(&mut self.stroke_width);
+    let stroke_color = std::mem::take(&mut self.stroke_color);
 
     if let (Some((line, line_vp)), Some(thickness_val), Some((style, style_vp)), Some((color, color_vp))) =
       (&mut line, &mut thickness, &mut style, &mut color)
@@ -1230,6 +1826,42 @@ impl<'i> PropertyHandler<'i> for TextDecorationHandler<'i> {
         dest.push(Property::TextEmphasisPosition(pos, prefix))
       }
     }
+
+    if let Some(overflow_wrap) = overflow_wrap {
+      if let Some(targets) = self.targets {
+        if !compat::Feature::OverflowWrap.is_compatible(targets) {
+          dest.push(Property::WordWrap(overflow_wrap));
+        }
+      }
+      dest.push(Property::OverflowWrap(overflow_wrap));
+    }
+
+    // -webkit-text-stroke has no unprefixed equivalent, so only color fallbacks are needed.
+    match (stroke_width, stroke_color) {
+      (Some(width), Some(color)) => {
+        let mut stroke = WebKitTextStroke { width, color };
+        if let Some(targets) = self.targets {
+          for fallback in stroke.get_fallbacks(targets) {
+            dest.push(Property::WebKitTextStroke(fallback, VendorPrefix::WebKit))
+          }
+        }
+        dest.push(Property::WebKitTextStroke(stroke, VendorPrefix::WebKit));
+      }
+      (width, color) => {
+        if let Some(width) = width {
+          dest.push(Property::WebKitTextStrokeWidth(width, VendorPrefix::WebKit))
+        }
+
+        if let Some(mut color) = color {
+          if let Some(targets) = self.targets {
+            for fallback in color.get_fallbacks(targets) {
+              dest.push(Property::WebKitTextStrokeColor(fallback, VendorPrefix::WebKit))
+            }
+          }
+          dest.push(Property::WebKitTextStrokeColor(color, VendorPrefix::WebKit))
+        }
+      }
+    }
   }
 }
 
@@ -1319,6 +1951,83 @@ impl ToCss for TextShadow {
   }
 }
 
+define_shorthand! {
+  /// A value for the [-webkit-text-stroke](https://compat.spec.whatwg.org/#the-webkit-text-stroke) shorthand property.
+  pub struct WebKitTextStroke(VendorPrefix) {
+    /// The width of the stroke.
+    width: WebKitTextStrokeWidth(BorderSideWidth, VendorPrefix),
+    /// The color of the stroke.
+    color: WebKitTextStrokeColor(CssColor, VendorPrefix),
+  }
+}
+
+impl<'i> Parse<'i> for WebKitTextStroke {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut width = None;
+    let mut color = None;
+
+    loop {
+      if width.is_none() {
+        if let Ok(value) = input.try_parse(BorderSideWidth::parse) {
+          width = Some(value);
+          continue;
+        }
+      }
+
+      if color.is_none() {
+        if let Ok(value) = input.try_parse(CssColor::parse) {
+          color = Some(value);
+          continue;
+        }
+      }
+
+      break;
+    }
+
+    if width.is_none() && color.is_none() {
+      return Err(input.new_custom_error(ParserError::InvalidDeclaration));
+    }
+
+    Ok(WebKitTextStroke {
+      // Unlike border widths, the initial stroke width is zero rather than `medium`.
+      width: width.unwrap_or(BorderSideWidth::Length(Length::zero())),
+      color: color.unwrap_or(CssColor::current_color()),
+    })
+  }
+}
+
+impl ToCss for WebKitTextStroke {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let has_color = self.color != CssColor::current_color();
+    if !has_color || self.width != BorderSideWidth::Length(Length::zero()) {
+      self.width.to_css(dest)?;
+      if has_color {
+        dest.write_char(' ')?;
+      }
+    }
+
+    if has_color {
+      self.color.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FallbackValues for WebKitTextStroke {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    self
+      .color
+      .get_fallbacks(targets)
+      .into_iter()
+      .map(|color| WebKitTextStroke { color, ..self.clone() })
+      .collect()
+  }
+}
+
 #[inline]
 fn is_text_decoration_property(property_id: &PropertyId) -> bool {
   match property_id {
@@ -1342,6 +2051,31 @@ fn is_text_emphasis_property(property_id: &PropertyId) -> bool {
   }
 }
 
+#[inline]
+fn is_text_stroke_property(property_id: &PropertyId) -> bool {
+  match property_id {
+    PropertyId::WebKitTextStrokeWidth(_)
+    | PropertyId::WebKitTextStrokeColor(_)
+    | PropertyId::WebKitTextStroke(_) => true,
+    _ => false,
+  }
+}
+
+/// Removes shadows that are identical to an earlier shadow in the list. Shadows are painted on top
+/// of each other, so this is only done for opaque shadows without a blur, which cannot stack visibly.
+fn dedupe_text_shadows(shadows: &mut SmallVec<[TextShadow; 1]>) {
+  let mut i = 1;
+  while i < shadows.len() {
+    let shadow = &shadows[i];
+    let is_opaque = matches!(&shadow.color, CssColor::RGBA(rgba) if rgba.alpha == 255);
+    if is_opaque && shadow.blur == Length::zero() && shadows[..i].contains(shadow) {
+      shadows.remove(i);
+    } else {
+      i += 1;
+    }
+  }
+}
+
 impl FallbackValues for SmallVec<[TextShadow; 1]> {
   fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
     let mut fallbacks = ColorFallbackKind::empty();
@@ -1381,3 +2115,77 @@ impl FallbackValues for SmallVec<[TextShadow; 1]> {
     res
   }
 }
+
+#[derive(Default)]
+pub(crate) struct WhiteSpaceHandler {
+  collapse: Option<WhiteSpaceCollapse>,
+  mode: Option<TextWrapMode>,
+  style: Option<TextWrapStyle>,
+}
+
+impl<'i> PropertyHandler<'i> for WhiteSpaceHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::WhiteSpace(val) => {
+        let (collapse, mode) = val.to_longhands();
+        self.collapse = Some(collapse);
+        self.mode = Some(mode);
+      }
+      Property::WhiteSpaceCollapse(val) => self.collapse = Some(*val),
+      Property::TextWrapMode(val) => self.mode = Some(*val),
+      Property::TextWrapStyle(val) => self.style = Some(*val),
+      Property::TextWrap(val) => {
+        self.mode = Some(val.mode);
+        self.style = Some(val.style);
+      }
+      Property::Unparsed(val)
+        if matches!(
+          val.property_id,
+          PropertyId::WhiteSpace
+            | PropertyId::WhiteSpaceCollapse
+            | PropertyId::TextWrapMode
+            | PropertyId::TextWrapStyle
+            | PropertyId::TextWrap
+        ) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    let collapse = std::mem::take(&mut self.collapse);
+    let mode = std::mem::take(&mut self.mode);
+    let style = std::mem::take(&mut self.style);
+
+    // The legacy white-space keywords are shorter and better supported than the longhands.
+    let legacy = match (collapse, mode) {
+      (Some(collapse), Some(mode)) => WhiteSpace::from_longhands(collapse, mode),
+      _ => None,
+    };
+
+    if let Some(legacy) = legacy {
+      dest.push(Property::WhiteSpace(legacy));
+    } else if let Some(collapse) = collapse {
+      dest.push(Property::WhiteSpaceCollapse(collapse));
+    }
+
+    match (mode, style) {
+      // The text-wrap shorthand is supported in more browsers than text-wrap-style,
+      // so use it even if white-space already includes the mode.
+      (Some(mode), Some(style)) => dest.push(Property::TextWrap(TextWrap { mode, style })),
+      (Some(mode), None) if legacy.is_none() => dest.push(Property::TextWrapMode(mode)),
+      (None, Some(style)) => dest.push(Property::TextWrapStyle(style)),
+      _ => {}
+    }
+  }
+}
@@ -1,9 +1,10 @@
 //! CSS properties related to 2D and 3D transforms.
 
 use super::{Property, PropertyId};
+use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::DeclarationList;
-use crate::error::{ParserError, PrinterError};
+use crate::error::{MinifyErrorKind, ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
@@ -13,7 +14,8 @@ use crate::traits::{Parse, PropertyHandler, ToCss, Zero};
 use crate::values::{
   angle::Angle,
   length::{Length, LengthPercentage},
-  percentage::NumberOrPercentage,
+  percentage::{NumberOrPercentage, Percentage},
+  position::{Position, PositionComponent},
 };
 use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
@@ -1039,7 +1041,8 @@ impl ToCss for Transform {
         dest.write_char(')')
       }
       Translate3d(x, y, z) => {
-        if dest.minify && !x.is_zero() && y.is_zero() && z.is_zero() {
+        if dest.minify && y.is_zero() && z.is_zero() {
+          // translate3d(x, 0, 0) => translate(x)
           dest.write_str("translate(")?;
           x.to_css(dest)?;
         } else if dest.minify && x.is_zero() && !y.is_zero() && z.is_zero() {
@@ -1154,15 +1157,16 @@ impl ToCss for Transform {
         dest.write_char(')')
       }
       Rotate3d(x, y, z, angle) => {
-        if dest.minify && *x == 1.0 && *y == 0.0 && *z == 0.0 {
+        // The direction vector is normalized, so only the sign of a single component matters.
+        if dest.minify && *x > 0.0 && *y == 0.0 && *z == 0.0 {
           // rotate3d(1, 0, 0, a) => rotateX(a)
           dest.write_str("rotateX(")?;
           angle.to_css(dest)?;
-        } else if dest.minify && *x == 0.0 && *y == 1.0 && *z == 0.0 {
+        } else if dest.minify && *x == 0.0 && *y > 0.0 && *z == 0.0 {
           // rotate3d(0, 1, 0, a) => rotateY(a)
           dest.write_str("rotateY(")?;
           angle.to_css(dest)?;
-        } else if dest.minify && *x == 0.0 && *y == 0.0 && *z == 1.0 {
+        } else if dest.minify && *x == 0.0 && *y == 0.0 && *z > 0.0 {
           // rotate3d(0, 0, 1, a) => rotate(a)
           dest.write_str("rotate(")?;
           angle.to_css(dest)?;
@@ -1384,6 +1388,85 @@ enum_property! {
   }
 }
 
+/// A value for the [transform-origin](https://drafts.csswg.org/css-transforms-1/#transform-origin-property) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransformOrigin {
+  /// The x and y position of the origin.
+  pub position: Position,
+  /// The z offset of the origin.
+  pub z: Length,
+}
+
+impl<'i> Parse<'i> for TransformOrigin {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let mut position = Position::parse(input)?;
+
+    // Side offsets are not valid in transform-origin, so a length following the
+    // keywords is the z offset, e.g. `right bottom 10px` or `top left 10px`.
+    let offset = match (&mut position.x, &mut position.y) {
+      (
+        PositionComponent::Side(_, None) | PositionComponent::Center,
+        PositionComponent::Side(_, offset @ Some(LengthPercentage::Dimension(_))),
+      )
+      | (
+        PositionComponent::Side(_, offset @ Some(LengthPercentage::Dimension(_))),
+        PositionComponent::Side(_, None) | PositionComponent::Center,
+      ) => offset.take(),
+      _ => None,
+    };
+
+    let z = match offset {
+      Some(LengthPercentage::Dimension(value)) => Length::Value(value),
+      _ => input.try_parse(Length::parse).unwrap_or(Length::zero()),
+    };
+
+    Ok(TransformOrigin { position, z })
+  }
+}
+
+impl ToCss for TransformOrigin {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.z.is_zero() {
+      return self.position.to_css(dest);
+    }
+
+    // With a z offset, both the x and y positions must be written.
+    match (
+      component_to_length_percentage(&self.position.x),
+      component_to_length_percentage(&self.position.y),
+    ) {
+      (Some(x), Some(y)) => {
+        x.to_css(dest)?;
+        dest.write_char(' ')?;
+        y.to_css(dest)?;
+      }
+      _ => {
+        self.position.x.to_css(dest)?;
+        dest.write_char(' ')?;
+        self.position.y.to_css(dest)?;
+      }
+    }
+
+    dest.write_char(' ')?;
+    self.z.to_css(dest)
+  }
+}
+
+fn component_to_length_percentage<S: Copy + Into<LengthPercentage>>(
+  component: &PositionComponent<S>,
+) -> Option<LengthPercentage> {
+  match component {
+    PositionComponent::Center => Some(LengthPercentage::Percentage(Percentage(0.5))),
+    PositionComponent::Length(lp) => Some(lp.clone()),
+    PositionComponent::Side(side, None) => Some((*side).into()),
+    PositionComponent::Side(_, Some(_)) => None,
+  }
+}
+
 /// A value for the [perspective](https://drafts.csswg.org/css-transforms-2/#perspective-property) property.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -1639,6 +1722,9 @@ pub(crate) struct TransformHandler {
   translate: Option<Translate>,
   rotate: Option<Rotate>,
   scale: Option<Scale>,
+  transform_style: Option<(TransformStyle, VendorPrefix)>,
+  backface_visibility: Option<(BackfaceVisibility, VendorPrefix)>,
+  perspective: Option<(Perspective, VendorPrefix)>,
   has_any: bool,
 }
 
@@ -1656,58 +1742,80 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
     &mut self,
     property: &Property<'i>,
     dest: &mut DeclarationList<'i>,
-    _: &mut PropertyHandlerContext<'i, '_>,
+    context: &mut PropertyHandlerContext<'i, '_>,
   ) -> bool {
     use Property::*;
 
-    macro_rules! individual_property {
-      ($prop: ident, $val: ident) => {
-        if let Some((transform, _)) = &mut self.transform {
-          transform.0.push($val.to_transform())
-        } else {
-          self.$prop = Some($val.clone());
-          self.has_any = true;
-        }
-      };
-    }
-
-    match property {
-      Transform(val, vp) => {
+    macro_rules! prefixed_property {
+      ($prop: ident, $val: ident, $vp: ident) => {{
         // If two vendor prefixes for the same property have different
         // values, we need to flush what we have immediately to preserve order.
-        if let Some((cur, prefixes)) = &self.transform {
-          if cur != val && !prefixes.contains(*vp) {
-            self.flush(dest);
+        if let Some((cur, prefixes)) = &self.$prop {
+          if cur != $val && !prefixes.contains(*$vp) {
+            self.flush(dest, context);
           }
         }
 
         // Otherwise, update the value and add the prefix.
-        if let Some((val, prefixes)) = &mut self.transform {
-          *val = val.clone();
-          *prefixes |= *vp;
+        if let Some((cur, prefixes)) = &mut self.$prop {
+          *cur = $val.clone();
+          *prefixes |= *$vp;
         } else {
-          self.transform = Some((val.clone(), *vp));
+          self.$prop = Some(($val.clone(), *$vp));
           self.has_any = true;
         }
+      }};
+    }
 
-        self.translate = None;
-        self.rotate = None;
-        self.scale = None;
-      }
+    macro_rules! individual_property {
+      ($prop: ident, $val: ident) => {{
+        self.$prop = Some($val.clone());
+        self.has_any = true;
+      }};
+    }
+
+    match property {
+      Transform(val, vp) => prefixed_property!(transform, val, vp),
       Translate(val) => individual_property!(translate, val),
       Rotate(val) => individual_property!(rotate, val),
       Scale(val) => individual_property!(scale, val),
+      TransformStyle(val, vp) => prefixed_property!(transform_style, val, vp),
+      BackfaceVisibility(val, vp) => prefixed_property!(backface_visibility, val, vp),
+      Perspective(val, vp) => prefixed_property!(perspective, val, vp),
       Unparsed(val)
         if matches!(
           val.property_id,
-          PropertyId::Transform(_) | PropertyId::Translate | PropertyId::Rotate | PropertyId::Scale
+          PropertyId::Transform(_)
+            | PropertyId::Translate
+            | PropertyId::Rotate
+            | PropertyId::Scale
+            | PropertyId::TransformStyle(_)
+            | PropertyId::BackfaceVisibility(_)
+            | PropertyId::Perspective(_)
         ) =>
       {
-        self.flush(dest);
-        let prop = if matches!(val.property_id, PropertyId::Transform(_)) {
-          Property::Unparsed(val.get_prefixed(self.targets, Feature::Transform))
-        } else {
-          property.clone()
+        let is_individual_transform = matches!(
+          val.property_id,
+          PropertyId::Translate | PropertyId::Rotate | PropertyId::Scale
+        );
+
+        // Unparsed individual transforms cannot be combined with the transform property.
+        // Pending individual transforms are reported when flushed below if they cannot be lowered.
+        if is_individual_transform && !self.supports_individual_transforms() {
+          context.warn(MinifyErrorKind::IndividualTransformLowering);
+        }
+
+        self.flush(dest, context);
+        let prop = match &val.property_id {
+          PropertyId::Transform(_) => Property::Unparsed(val.get_prefixed(self.targets, Feature::Transform)),
+          PropertyId::TransformStyle(_) => {
+            Property::Unparsed(val.get_prefixed(self.targets, Feature::TransformStyle))
+          }
+          PropertyId::BackfaceVisibility(_) => {
+            Property::Unparsed(val.get_prefixed(self.targets, Feature::BackfaceVisibility))
+          }
+          PropertyId::Perspective(_) => Property::Unparsed(val.get_prefixed(self.targets, Feature::Perspective)),
+          _ => property.clone(),
         };
         dest.push(prop)
       }
@@ -1717,34 +1825,62 @@ impl<'i> PropertyHandler<'i> for TransformHandler {
     true
   }
 
-  fn finalize(&mut self, dest: &mut DeclarationList, _: &mut PropertyHandlerContext<'i, '_>) {
-    self.flush(dest);
+  fn finalize(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext<'i, '_>) {
+    self.flush(dest, context);
   }
 }
 
 impl TransformHandler {
-  fn flush(&mut self, dest: &mut DeclarationList) {
+  fn flush(&mut self, dest: &mut DeclarationList, context: &mut PropertyHandlerContext) {
     if !self.has_any {
       return;
     }
 
     self.has_any = false;
 
-    let transform = std::mem::take(&mut self.transform);
-    let translate = std::mem::take(&mut self.translate);
-    let rotate = std::mem::take(&mut self.rotate);
-    let scale = std::mem::take(&mut self.scale);
+    let mut transform = std::mem::take(&mut self.transform);
+    let mut translate = std::mem::take(&mut self.translate);
+    let mut rotate = std::mem::take(&mut self.rotate);
+    let mut scale = std::mem::take(&mut self.scale);
+    let transform_style = std::mem::take(&mut self.transform_style);
+    let backface_visibility = std::mem::take(&mut self.backface_visibility);
+    let perspective = std::mem::take(&mut self.perspective);
+
+    // Individual transforms are applied before the transform property, in the order
+    // translate, rotate, scale. Prepend them to the transform list if unsupported. Without
+    // a transform in the same block, adding one would override transforms set by other rules.
+    if (translate.is_some() || rotate.is_some() || scale.is_some()) && !self.supports_individual_transforms() {
+      if let Some((list, _)) = &mut transform {
+        let mut transforms: Vec<Transform> = translate
+          .take()
+          .map(|translate| translate.to_transform())
+          .into_iter()
+          .chain(rotate.take().map(|rotate| rotate.to_transform()))
+          .chain(scale.take().map(|scale| scale.to_transform()))
+          .collect();
+        transforms.append(&mut list.0);
+        list.0 = transforms;
+      } else {
+        context.warn(MinifyErrorKind::IndividualTransformLowering);
+      }
+    }
 
-    if let Some((transform, prefix)) = transform {
-      let mut prefix = prefix;
-      if prefix.contains(VendorPrefix::None) {
-        if let Some(targets) = self.targets {
-          prefix = Feature::Transform.prefixes_for(targets)
+    macro_rules! prefixed_property {
+      ($prop: ident, $feature: ident) => {
+        if let Some((val, prefix)) = $prop {
+          let mut prefix = prefix;
+          if prefix.contains(VendorPrefix::None) {
+            if let Some(targets) = self.targets {
+              prefix = Feature::$feature.prefixes_for(targets)
+            }
+          }
+          dest.push(Property::$feature(val, prefix))
         }
-      }
-      dest.push(Property::Transform(transform, prefix))
+      };
     }
 
+    prefixed_property!(transform, Transform);
+
     if let Some(translate) = translate {
       dest.push(Property::Translate(translate))
     }
@@ -1756,5 +1892,15 @@ impl TransformHandler {
     if let Some(scale) = scale {
       dest.push(Property::Scale(scale))
     }
+
+    prefixed_property!(transform_style, TransformStyle);
+    prefixed_property!(backface_visibility, BackfaceVisibility);
+    prefixed_property!(perspective, Perspective);
+  }
+
+  fn supports_individual_transforms(&self) -> bool {
+    self.targets.map_or(true, |targets| {
+      compat::Feature::IndividualTransforms.is_compatible(targets)
+    })
   }
 }
@@ -5,7 +5,7 @@ use crate::compat;
 use crate::context::PropertyHandlerContext;
 use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::define_list_shorthand;
+use crate::macros::{define_list_shorthand, enum_property};
 use crate::prefixes::Feature;
 use crate::printer::Printer;
 use crate::properties::masking::get_webkit_mask_property;
@@ -29,6 +29,8 @@ define_list_shorthand! {
     delay: TransitionDelay(Time, VendorPrefix),
     /// The easing function for the transition.
     timing_function: TransitionTimingFunction(EasingFunction, VendorPrefix),
+    /// Whether transitions are started for discretely animated properties.
+    behavior: TransitionBehavior(TransitionBehavior),
   }
 }
 
@@ -38,6 +40,7 @@ impl<'i> Parse<'i> for Transition<'i> {
     let mut duration = None;
     let mut delay = None;
     let mut timing_function = None;
+    let mut behavior = None;
 
     loop {
       if duration.is_none() {
@@ -61,6 +64,14 @@ impl<'i> Parse<'i> for Transition<'i> {
         }
       }
 
+      // Parse the behavior before the property, so it isn't treated as an unknown property name.
+      if behavior.is_none() {
+        if let Ok(value) = input.try_parse(TransitionBehavior::parse) {
+          behavior = Some(value);
+          continue;
+        }
+      }
+
       if property.is_none() {
         if let Ok(value) = input.try_parse(PropertyId::parse) {
           property = Some(value);
@@ -76,6 +87,7 @@ impl<'i> Parse<'i> for Transition<'i> {
       duration: duration.unwrap_or(Time::Seconds(0.0)),
       delay: delay.unwrap_or(Time::Seconds(0.0)),
       timing_function: timing_function.unwrap_or(EasingFunction::Ease),
+      behavior: behavior.unwrap_or(TransitionBehavior::Normal),
     })
   }
 }
@@ -103,10 +115,25 @@ impl<'i> ToCss for Transition<'i> {
       self.delay.to_css(dest)?;
     }
 
+    if self.behavior != TransitionBehavior::Normal {
+      dest.write_char(' ')?;
+      self.behavior.to_css(dest)?;
+    }
+
     Ok(())
   }
 }
 
+enum_property! {
+  /// A value for the [transition-behavior](https://drafts.csswg.org/css-transitions-2/#transition-behavior-property) property.
+  pub enum TransitionBehavior {
+    /// Transitions are not started for discretely animated properties.
+    Normal,
+    /// Transitions are started for discretely animated properties.
+    "allow-discrete": AllowDiscrete,
+  }
+}
+
 #[derive(Default)]
 pub(crate) struct TransitionHandler<'i> {
   targets: Option<Browsers>,
@@ -114,6 +141,7 @@ pub(crate) struct TransitionHandler<'i> {
   durations: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   delays: Option<(SmallVec<[Time; 1]>, VendorPrefix)>,
   timing_functions: Option<(SmallVec<[EasingFunction; 1]>, VendorPrefix)>,
+  behaviors: Option<SmallVec<[TransitionBehavior; 1]>>,
   has_any: bool,
 }
 
@@ -199,6 +227,19 @@ impl<'i> PropertyHandler<'i> for TransitionHandler<'i> {
         property!(TransitionDuration, durations, &durations, vp);
         property!(TransitionDelay, delays, &delays, vp);
         property!(TransitionTimingFunction, timing_functions, &timing_functions, vp);
+
+        let behaviors: SmallVec<[self::TransitionBehavior; 1]> = val.iter().map(|b| b.behavior).collect();
+        if behaviors.iter().any(|b| *b != self::TransitionBehavior::Normal) {
+          self.behaviors = Some(behaviors);
+          self.has_any = true;
+        } else if vp.contains(VendorPrefix::None) {
+          // The unprefixed shorthand resets transition-behavior to its initial value.
+          self.behaviors = None;
+        }
+      }
+      TransitionBehavior(val) => {
+        self.behaviors = Some(val.clone());
+        self.has_any = true;
       }
       Unparsed(val) if is_transition_property(&val.property_id) => {
         self.flush(dest, context);
@@ -227,9 +268,27 @@ impl<'i> TransitionHandler<'i> {
     let mut durations = std::mem::take(&mut self.durations);
     let mut delays = std::mem::take(&mut self.delays);
     let mut timing_functions = std::mem::take(&mut self.timing_functions);
+    let mut behaviors = std::mem::take(&mut self.behaviors);
 
     let rtl_properties = if let Some((properties, _)) = &mut properties {
-      expand_properties(properties, self.targets, context)
+      let (rtl_properties, counts) = expand_properties(properties, self.targets, context);
+
+      // Repeat the values of the other longhands for each property that was expanded
+      // into multiple properties, so that they remain associated with the same property.
+      if let Some((durations, _)) = &mut durations {
+        repeat_values(durations, &counts);
+      }
+      if let Some((delays, _)) = &mut delays {
+        repeat_values(delays, &counts);
+      }
+      if let Some((timing_functions, _)) = &mut timing_functions {
+        repeat_values(timing_functions, &counts);
+      }
+      if let Some(behaviors) = &mut behaviors {
+        repeat_values(behaviors, &counts);
+      }
+
+      rtl_properties
     } else {
       None
     };
@@ -246,6 +305,18 @@ impl<'i> TransitionHandler<'i> {
       // prefixes will be handled by outputing individual properties below.
       let intersection = *property_prefixes & *duration_prefixes & *delay_prefixes & *timing_prefixes;
       if !intersection.is_empty() {
+        // Browsers without support for transition-behavior reject the entire shorthand if it
+        // includes allow-discrete, so only include it for supported targets. Otherwise, it is
+        // output as a separate longhand below, which only affects discrete transitions.
+        let shorthand_behaviors = if intersection == VendorPrefix::None
+          && self.targets.map_or(true, |targets| {
+            compat::Feature::TransitionBehavior.is_compatible(targets)
+          }) {
+          behaviors.take()
+        } else {
+          None
+        };
+
         macro_rules! get_transitions {
           ($properties: ident) => {{
             // transition-property determines the number of transitions. The values of other
@@ -254,15 +325,18 @@ impl<'i> TransitionHandler<'i> {
             let mut durations_iter = durations.iter().cycle().cloned();
             let mut delays_iter = delays.iter().cycle().cloned();
             let mut timing_iter = timing_functions.iter().cycle().cloned();
+            let mut behavior_iter = shorthand_behaviors.iter().flatten().cycle().cloned();
             for property_id in $properties {
               let duration = durations_iter.next().unwrap_or(Time::Seconds(0.0));
               let delay = delays_iter.next().unwrap_or(Time::Seconds(0.0));
               let timing_function = timing_iter.next().unwrap_or(EasingFunction::Ease);
+              let behavior = behavior_iter.next().unwrap_or(TransitionBehavior::Normal);
               let transition = Transition {
                 property: property_id.clone(),
                 duration,
                 delay,
                 timing_function,
+                behavior,
               };
 
               // Expand vendor prefixes into multiple transitions.
@@ -301,6 +375,27 @@ impl<'i> TransitionHandler<'i> {
       }
     }
 
+    // Vendor prefixed property names are printed as separate items in transition-property,
+    // so the values of the other longhands must be repeated for them as well.
+    if let Some((properties, _)) = &properties {
+      let counts: SmallVec<[usize; 1]> = properties
+        .iter()
+        .map(|property_id| property_id.prefix().bits().count_ones().max(1) as usize)
+        .collect();
+      if let Some((durations, _)) = &mut durations {
+        repeat_values(durations, &counts);
+      }
+      if let Some((delays, _)) = &mut delays {
+        repeat_values(delays, &counts);
+      }
+      if let Some((timing_functions, _)) = &mut timing_functions {
+        repeat_values(timing_functions, &counts);
+      }
+      if let Some(behaviors) = &mut behaviors {
+        repeat_values(behaviors, &counts);
+      }
+    }
+
     if let Some((properties, prefix)) = properties {
       if !prefix.is_empty() {
         if let Some(rtl_properties) = rtl_properties {
@@ -332,6 +427,11 @@ impl<'i> TransitionHandler<'i> {
       }
     }
 
+    // Output transition-behavior last so that it isn't reset by the shorthand.
+    if let Some(behaviors) = behaviors {
+      dest.push(Property::TransitionBehavior(behaviors));
+    }
+
     self.reset();
   }
 
@@ -340,7 +440,27 @@ impl<'i> TransitionHandler<'i> {
     self.durations = None;
     self.delays = None;
     self.timing_functions = None;
+    self.behaviors = None;
+  }
+}
+
+/// Repeats each value in a list the given number of times. Values are cycled
+/// if the list is shorter than the list of counts.
+fn repeat_values<T: Clone>(values: &mut SmallVec<[T; 1]>, counts: &[usize]) {
+  // A single value applies to all properties, so it doesn't need to be repeated.
+  if values.len() <= 1 || counts.iter().all(|count| *count == 1) {
+    return;
   }
+
+  let mut repeated = SmallVec::with_capacity(counts.iter().sum());
+  for (i, count) in counts.iter().enumerate() {
+    let value = &values[i % values.len()];
+    for _ in 0..*count {
+      repeated.push(value.clone());
+    }
+  }
+
+  *values = repeated;
 }
 
 #[inline]
@@ -350,6 +470,7 @@ fn is_transition_property(property_id: &PropertyId) -> bool {
     | PropertyId::TransitionDuration(_)
     | PropertyId::TransitionDelay(_)
     | PropertyId::TransitionTimingFunction(_)
+    | PropertyId::TransitionBehavior
     | PropertyId::Transition(_) => true,
     _ => false,
   }
@@ -359,8 +480,10 @@ fn expand_properties<'i>(
   properties: &mut SmallVec<[PropertyId<'i>; 1]>,
   targets: Option<Browsers>,
   context: &mut PropertyHandlerContext,
-) -> Option<SmallVec<[PropertyId<'i>; 1]>> {
+) -> (Option<SmallVec<[PropertyId<'i>; 1]>>, SmallVec<[usize; 1]>) {
   let mut rtl_properties: Option<SmallVec<[PropertyId; 1]>> = None;
+  // The number of properties that each of the original properties expanded to.
+  let mut counts = SmallVec::new();
   let mut i = 0;
 
   macro_rules! replace {
@@ -381,6 +504,7 @@ fn expand_properties<'i>(
           replace!(rtl_properties, props);
         }
         i += props.len();
+        counts.push(props.len());
       }
       LogicalPropertyId::Inline(feature, ltr, rtl) if !context.is_supported(feature) => {
         // Clone properties to create RTL version only when needed.
@@ -394,18 +518,22 @@ fn expand_properties<'i>(
         }
 
         i += ltr.len();
+        counts.push(ltr.len());
       }
       _ => {
         // Expand vendor prefixes for targets.
         properties[i].set_prefixes_for_targets(targets);
 
         // Expand mask properties, which use different vendor-prefixed names.
+        let mut count = 1;
         if let (Some(targets), Some(property_id)) = (targets, get_webkit_mask_property(&properties[i])) {
           if Feature::MaskBorder.prefixes_for(targets).contains(VendorPrefix::WebKit) {
             properties.insert(i, property_id);
             i += 1;
+            count += 1;
           }
         }
+        counts.push(count);
 
         if let Some(rtl_properties) = &mut rtl_properties {
           rtl_properties[i].set_prefixes_for_targets(targets);
@@ -421,7 +549,7 @@ fn expand_properties<'i>(
     }
   }
 
-  rtl_properties
+  (rtl_properties, counts)
 }
 
 enum LogicalPropertyId {
@@ -1,16 +1,25 @@
 //! CSS properties related to user interface.
 
-use crate::declaration::DeclarationBlock;
+use super::display::{Display, DisplayKeyword};
+use super::size::{Size, WritingMode};
+use crate::compat::Feature;
+use crate::context::PropertyHandlerContext;
+use crate::declaration::{DeclarationBlock, DeclarationList};
 use crate::error::{ParserError, PrinterError};
-use crate::macros::{define_shorthand, enum_property, shorthand_property};
+use crate::macros::{define_shorthand, enum_property, shorthand_handler, shorthand_property};
+use crate::prefixes;
 use crate::printer::Printer;
 use crate::properties::{Property, PropertyId};
+use crate::selector::WebKitScrollbarPseudoElement;
 use crate::targets::Browsers;
-use crate::traits::{FallbackValues, Parse, Shorthand, ToCss};
-use crate::values::color::CssColor;
+use crate::traits::{FallbackValues, Parse, PropertyHandler, Shorthand, ToCss};
+use crate::values::color::{ColorFallbackKind, CssColor};
+use crate::values::ident::{CustomIdent, CustomIdentList};
+use crate::values::image::Image;
+use crate::values::length::{LengthPercentage, LengthValue};
 use crate::values::number::CSSNumber;
 use crate::values::string::CowArcStr;
-use crate::values::url::Url;
+use crate::vendor_prefix::VendorPrefix;
 use cssparser::*;
 use smallvec::SmallVec;
 
@@ -32,22 +41,85 @@ enum_property! {
   }
 }
 
+impl Resize {
+  /// Returns the physical value equivalent to a logical value in the given writing mode, if any.
+  fn to_physical(&self, writing_mode: WritingMode) -> Option<Resize> {
+    let vertical = writing_mode.is_vertical();
+    match self {
+      Resize::Block if vertical => Some(Resize::Horizontal),
+      Resize::Block => Some(Resize::Vertical),
+      Resize::Inline if vertical => Some(Resize::Vertical),
+      Resize::Inline => Some(Resize::Horizontal),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ResizeHandler {
+  targets: Option<Browsers>,
+}
+
+impl ResizeHandler {
+  pub fn new(targets: Option<Browsers>) -> ResizeHandler {
+    ResizeHandler { targets }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for ResizeHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::Resize(val) => {
+        // Older browsers only support the physical values, so add a fallback based on the writing mode.
+        if let Some(targets) = self.targets {
+          if !Feature::LogicalResize.is_compatible(targets) {
+            if let Some(fallback) = val.to_physical(context.writing_mode) {
+              dest.push(Property::Resize(fallback));
+            }
+          }
+        }
+        dest.push(property.clone());
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::Resize) => {
+        let mut unparsed = val.clone();
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, _: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {}
+}
+
 /// A [cursor image](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#cursor) value, used in the `cursor` property.
 ///
 /// See [Cursor](Cursor).
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CursorImage<'i> {
-  /// A url to the cursor image.
+  /// The cursor image, either a `url()` or an `image-set()`.
   #[cfg_attr(feature = "serde", serde(borrow))]
-  pub url: Url<'i>,
+  pub image: Image<'i>,
   /// The location in the image where the mouse pointer appears.
   pub hotspot: Option<(CSSNumber, CSSNumber)>,
 }
 
 impl<'i> Parse<'i> for CursorImage<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let url = Url::parse(input)?;
+    let location = input.current_source_location();
+    let image = Image::parse(input)?;
+    if !matches!(image, Image::Url(..) | Image::ImageSet(..)) {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
+    }
+
     let hotspot = if let Ok(x) = input.try_parse(CSSNumber::parse) {
       let y = CSSNumber::parse(input)?;
       Some((x, y))
@@ -55,7 +127,7 @@ impl<'i> Parse<'i> for CursorImage<'i> {
       None
     };
 
-    Ok(CursorImage { url, hotspot })
+    Ok(CursorImage { image, hotspot })
   }
 }
 
@@ -64,7 +136,7 @@ impl<'i> ToCss for CursorImage<'i> {
   where
     W: std::fmt::Write,
   {
-    self.url.to_css(dest)?;
+    self.image.to_css(dest)?;
 
     if let Some((x, y)) = self.hotspot {
       dest.write_char(' ')?;
@@ -264,19 +336,151 @@ impl FallbackValues for Caret {
   }
 }
 
+shorthand_handler!(CaretHandler -> Caret fallbacks: true {
+  color: CaretColor(ColorOrAuto, fallback: true),
+  shape: CaretShape(CaretShape),
+});
+
 enum_property! {
   /// A value for the [user-select](https://www.w3.org/TR/2021/WD-css-ui-4-20210316/#content-selection) property.
   pub enum UserSelect {
     /// The UA determines whether text is selectable.
-    Auto,
+    "auto": Auto,
     /// Text is selectable.
-    Text,
+    "text": Text,
     /// Text is not selectable.
-    None,
+    "none": None,
     /// Text selection is contained to the element.
-    Contain,
+    "contain": Contain,
     /// Only the entire element is selectable.
-    All,
+    "all": All,
+    /// The legacy Firefox equivalent of `none`, which allows descendants to re-enable selection.
+    "-moz-none": MozNone,
+  }
+}
+
+enum_property! {
+  /// A value for the [pointer-events](https://drafts.csswg.org/css-ui-4/#pointer-events-control) property.
+  ///
+  /// In addition to `auto` and `none`, this includes the values defined for SVG elements
+  /// in [SVG 2](https://svgwg.org/svg2-draft/interact.html#PointerEventsProperty).
+  pub enum PointerEvents {
+    /// The element may be the target of pointer events.
+    "auto": Auto,
+    /// The element is never the target of pointer events.
+    "none": None,
+    /// The element may be the target when visible and over a painted fill or stroke.
+    "visiblePainted": VisiblePainted,
+    /// The element may be the target when visible and over its fill.
+    "visibleFill": VisibleFill,
+    /// The element may be the target when visible and over its stroke.
+    "visibleStroke": VisibleStroke,
+    /// The element may be the target when visible and over its fill or stroke.
+    "visible": Visible,
+    /// The element may be the target when over a painted fill or stroke.
+    "painted": Painted,
+    /// The element may be the target when over its fill.
+    "fill": Fill,
+    /// The element may be the target when over its stroke.
+    "stroke": Stroke,
+    /// The element may be the target when over its fill or stroke.
+    "all": All,
+    /// The element may be the target when over its bounding box.
+    "bounding-box": BoundingBox,
+  }
+}
+
+/// Firefox versions before 21 did not allow descendants of a `-moz-user-select: none` element
+/// to re-enable selection, and required `-moz-none` instead.
+const FIREFOX_USER_SELECT_NONE: u32 = 21 << 16;
+
+#[derive(Default)]
+pub(crate) struct UserSelectHandler {
+  targets: Option<Browsers>,
+  value: Option<(UserSelect, VendorPrefix)>,
+}
+
+impl UserSelectHandler {
+  pub fn new(targets: Option<Browsers>) -> UserSelectHandler {
+    UserSelectHandler {
+      targets,
+      ..UserSelectHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for UserSelectHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::UserSelect(UserSelect::MozNone, prefix) if *prefix != VendorPrefix::Moz => {
+        // `-moz-none` is only valid with the `-moz-` prefix, so other declarations are left untouched.
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      Property::UserSelect(val, prefix) => {
+        // When targets are known, `-moz-none` is re-added for older Firefox versions as needed.
+        let val = if *val == UserSelect::MozNone && self.targets.is_some() {
+          UserSelect::None
+        } else {
+          *val
+        };
+        if let Some((cur, prefixes)) = &mut self.value {
+          if *cur != val && !prefixes.contains(*prefix) {
+            self.finalize(dest, context);
+            self.value = Some((val, *prefix));
+          } else {
+            *cur = val;
+            *prefixes |= *prefix;
+          }
+        } else {
+          self.value = Some((val, *prefix));
+        }
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::UserSelect(_)) => {
+        self.finalize(dest, context);
+        let mut unparsed = val.get_prefixed(self.targets, prefixes::Feature::UserSelect);
+        context.add_unparsed_fallbacks(&mut unparsed);
+        dest.push(Property::Unparsed(unparsed));
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some((val, mut prefix)) = std::mem::take(&mut self.value) {
+      if prefix.contains(VendorPrefix::None) {
+        if let Some(targets) = self.targets {
+          prefix = prefixes::Feature::UserSelect.prefixes_for(targets);
+        }
+      }
+
+      let needs_moz_none = val == UserSelect::None
+        && prefix.contains(VendorPrefix::Moz)
+        && self
+          .targets
+          .and_then(|targets| targets.firefox)
+          .map_or(false, |version| version < FIREFOX_USER_SELECT_NONE);
+
+      if needs_moz_none {
+        if prefix.contains(VendorPrefix::WebKit) {
+          dest.push(Property::UserSelect(val, VendorPrefix::WebKit));
+        }
+        dest.push(Property::UserSelect(UserSelect::MozNone, VendorPrefix::Moz));
+        prefix.remove(VendorPrefix::WebKit | VendorPrefix::Moz);
+        if prefix.is_empty() {
+          return;
+        }
+      }
+
+      dest.push(Property::UserSelect(val, prefix));
+    }
   }
 }
 
@@ -328,7 +532,15 @@ impl<'i> Parse<'i> for Appearance<'i> {
       "slider-horizontal" => Ok(Appearance::SliderHorizontal),
       "square-button" => Ok(Appearance::SquareButton),
       "textarea" => Ok(Appearance::Textarea),
-      _ => Ok(Appearance::NonStandard(ident.into()))
+      _ => {
+        // Keywords are ASCII case-insensitive, so normalize non-standard values to allow
+        // prefixed and unprefixed declarations to be merged.
+        if ident.bytes().any(|b| b.is_ascii_uppercase()) {
+          Ok(Appearance::NonStandard(ident.to_ascii_lowercase().into()))
+        } else {
+          Ok(Appearance::NonStandard(ident.into()))
+        }
+      }
     }
   }
 }
@@ -359,3 +571,502 @@ impl<'i> ToCss for Appearance<'i> {
     }
   }
 }
+
+/// A value for the [will-change](https://www.w3.org/TR/css-will-change-1/#will-change) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum WillChange<'i> {
+  /// No particular changes are expected.
+  Auto,
+  /// A list of features that are expected to change.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Features(SmallVec<[WillChangeFeature<'i>; 1]>),
+}
+
+impl<'i> Parse<'i> for WillChange<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(WillChange::Auto);
+    }
+
+    let features = input.parse_comma_separated(WillChangeFeature::parse)?;
+    Ok(WillChange::Features(features.into()))
+  }
+}
+
+impl<'i> ToCss for WillChange<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      WillChange::Auto => dest.write_str("auto"),
+      WillChange::Features(features) => {
+        let mut first = true;
+        for feature in features {
+          if first {
+            first = false;
+          } else {
+            dest.delim(',', false)?;
+          }
+          feature.to_css(dest)?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// A feature that is expected to change, as used in the [will-change](WillChange) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum WillChangeFeature<'i> {
+  /// The scroll position of the element is expected to change.
+  ScrollPosition,
+  /// The contents of the element are expected to change.
+  Contents,
+  /// The value of a property is expected to change. Custom property names are accepted, but unknown
+  /// property names are rejected, so the declaration is preserved unparsed with a warning.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Property(PropertyId<'i>),
+}
+
+impl<'i> Parse<'i> for WillChangeFeature<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident_cloned()?;
+    match_ignore_ascii_case! { &*ident,
+      "scroll-position" => Ok(WillChangeFeature::ScrollPosition),
+      "contents" => Ok(WillChangeFeature::Contents),
+      // These keywords are excluded from the property names, e.g. `will-change: all` is invalid.
+      "will-change" | "none" | "all" | "auto" => {
+        Err(location.new_custom_error(ParserError::InvalidWillChangeFeature(ident.into())))
+      },
+      _ => {
+        let property_id = PropertyId::from(CowArcStr::from(ident.clone()));
+        if matches!(property_id, PropertyId::Custom(..)) && !ident.starts_with("--") {
+          return Err(location.new_custom_error(ParserError::InvalidWillChangeFeature(ident.into())))
+        }
+        Ok(WillChangeFeature::Property(property_id))
+      }
+    }
+  }
+}
+
+impl<'i> ToCss for WillChangeFeature<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      WillChangeFeature::ScrollPosition => dest.write_str("scroll-position"),
+      WillChangeFeature::Contents => dest.write_str("contents"),
+      WillChangeFeature::Property(property_id) => property_id.to_css(dest),
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [field-sizing](https://drafts.csswg.org/css-ui-4/#field-sizing) property.
+  pub enum FieldSizing {
+    /// The element has a fixed default size.
+    Fixed,
+    /// The element is sized to fit its contents.
+    Content,
+  }
+}
+
+enum_property! {
+  /// A value for the [scrollbar-width](https://drafts.csswg.org/css-scrollbars-1/#scrollbar-width) property.
+  pub enum ScrollbarWidth {
+    /// The platform's default scrollbar width.
+    Auto,
+    /// A thin scrollbar, if the platform provides one.
+    Thin,
+    /// No scrollbar is shown, but the element is still scrollable.
+    None,
+  }
+}
+
+/// A value for the [scrollbar-color](https://drafts.csswg.org/css-scrollbars-1/#scrollbar-color) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum ScrollbarColor {
+  /// The platform's default scrollbar colors.
+  Auto,
+  /// Explicit scrollbar colors.
+  Color {
+    /// The color of the scrollbar thumb.
+    thumb: CssColor,
+    /// The color of the scrollbar track.
+    track: CssColor,
+  },
+}
+
+impl<'i> Parse<'i> for ScrollbarColor {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("auto")).is_ok() {
+      return Ok(ScrollbarColor::Auto);
+    }
+
+    let thumb = CssColor::parse(input)?;
+    let track = CssColor::parse(input)?;
+    Ok(ScrollbarColor::Color { thumb, track })
+  }
+}
+
+impl ToCss for ScrollbarColor {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ScrollbarColor::Auto => dest.write_str("auto"),
+      ScrollbarColor::Color { thumb, track } => {
+        thumb.to_css(dest)?;
+        dest.write_char(' ')?;
+        track.to_css(dest)
+      }
+    }
+  }
+}
+
+impl FallbackValues for ScrollbarColor {
+  fn get_fallbacks(&mut self, targets: Browsers) -> Vec<Self> {
+    match self {
+      ScrollbarColor::Color { thumb, track } => {
+        // Each fallback must include both colors, so generate the fallbacks required by either of them.
+        let fallbacks = thumb.get_necessary_fallbacks(targets) | track.get_necessary_fallbacks(targets);
+        let get_fallback = |color: &CssColor, kind: ColorFallbackKind| {
+          if matches!(color, CssColor::CurrentColor) {
+            color.clone()
+          } else {
+            color.get_fallback(kind)
+          }
+        };
+
+        let mut res = Vec::new();
+        for kind in [ColorFallbackKind::RGB, ColorFallbackKind::P3] {
+          if fallbacks.contains(kind) {
+            res.push(ScrollbarColor::Color {
+              thumb: get_fallback(thumb, kind),
+              track: get_fallback(track, kind),
+            });
+          }
+        }
+
+        if fallbacks.contains(ColorFallbackKind::LAB) {
+          *thumb = get_fallback(thumb, ColorFallbackKind::LAB);
+          *track = get_fallback(track, ColorFallbackKind::LAB);
+        }
+
+        res
+      }
+      ScrollbarColor::Auto => Vec::new(),
+    }
+  }
+}
+
+/// The width used for `::-webkit-scrollbar` when converting `scrollbar-width: thin`.
+const THIN_SCROLLBAR_WIDTH: f32 = 8.0;
+
+/// Returns the subset of the given targets that support styling scrollbars via `::-webkit-scrollbar`.
+/// Firefox, Internet Explorer, and EdgeHTML based versions of Edge do not support it.
+fn webkit_scrollbar_targets(targets: Browsers) -> Option<Browsers> {
+  let targets = Browsers {
+    android: targets.android,
+    chrome: targets.chrome,
+    edge: targets.edge.filter(|version| *version >= 79 << 16),
+    ios_saf: targets.ios_saf,
+    opera: targets.opera,
+    safari: targets.safari,
+    samsung: targets.samsung,
+    ..Browsers::default()
+  };
+
+  if targets.android.is_some()
+    || targets.chrome.is_some()
+    || targets.edge.is_some()
+    || targets.ios_saf.is_some()
+    || targets.opera.is_some()
+    || targets.safari.is_some()
+    || targets.samsung.is_some()
+  {
+    Some(targets)
+  } else {
+    None
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ScrollbarHandler {
+  targets: Option<Browsers>,
+  width: Option<ScrollbarWidth>,
+  color: Option<ScrollbarColor>,
+}
+
+impl ScrollbarHandler {
+  pub fn new(targets: Option<Browsers>) -> ScrollbarHandler {
+    ScrollbarHandler {
+      targets,
+      ..ScrollbarHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for ScrollbarHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::ScrollbarWidth(val) => self.width = Some(*val),
+      Property::ScrollbarColor(val) => self.color = Some(val.clone()),
+      Property::Unparsed(val)
+        if matches!(val.property_id, PropertyId::ScrollbarWidth | PropertyId::ScrollbarColor) =>
+      {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, context: &mut PropertyHandlerContext<'i, '_>) {
+    let width = std::mem::take(&mut self.width);
+    let color = std::mem::take(&mut self.color);
+
+    if let Some(width) = width {
+      // Older Chromium and Safari versions only support styling scrollbars via ::-webkit-scrollbar.
+      if let Some(targets) = self.targets.and_then(webkit_scrollbar_targets) {
+        if context.webkit_scrollbar_rules && !Feature::ScrollbarWidth.is_compatible(targets) {
+          match width {
+            ScrollbarWidth::None => context.add_scrollbar_property(
+              WebKitScrollbarPseudoElement::Scrollbar,
+              Property::Display(Display::Keyword(DisplayKeyword::None)),
+            ),
+            ScrollbarWidth::Thin => {
+              let size =
+                Size::LengthPercentage(LengthPercentage::Dimension(LengthValue::Px(THIN_SCROLLBAR_WIDTH)));
+              context
+                .add_scrollbar_property(WebKitScrollbarPseudoElement::Scrollbar, Property::Width(size.clone()));
+              context.add_scrollbar_property(WebKitScrollbarPseudoElement::Scrollbar, Property::Height(size));
+            }
+            ScrollbarWidth::Auto => {}
+          }
+        }
+      }
+
+      dest.push(Property::ScrollbarWidth(width));
+    }
+
+    if let Some(mut color) = color {
+      if let Some(targets) = self.targets {
+        let webkit_targets = webkit_scrollbar_targets(targets);
+        if context.webkit_scrollbar_rules
+          && matches!(webkit_targets, Some(targets) if !Feature::ScrollbarColor.is_compatible(targets))
+        {
+          if let ScrollbarColor::Color { thumb, track } = &color {
+            context.add_scrollbar_property(
+              WebKitScrollbarPseudoElement::Thumb,
+              Property::BackgroundColor(thumb.clone()),
+            );
+            context.add_scrollbar_property(
+              WebKitScrollbarPseudoElement::Track,
+              Property::BackgroundColor(track.clone()),
+            );
+          }
+        }
+
+        for fallback in color.get_fallbacks(targets) {
+          dest.push(Property::ScrollbarColor(fallback));
+        }
+      }
+
+      dest.push(Property::ScrollbarColor(color));
+    }
+  }
+}
+
+/// A value for the [color-scheme](https://drafts.csswg.org/css-color-adjust/#color-scheme-prop) property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum ColorScheme<'i> {
+  /// The element does not opt into any color schemes.
+  Normal,
+  /// The element supports the given color schemes.
+  Schemes {
+    /// The supported color schemes, in order of preference, including the `light` and `dark` keywords.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    schemes: CustomIdentList<'i>,
+    /// Whether the user agent is forbidden from overriding the color scheme of the element.
+    only: bool,
+  },
+}
+
+impl<'i> Parse<'i> for ColorScheme<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+      return Ok(ColorScheme::Normal);
+    }
+
+    // The `only` keyword may appear either before or after the list of schemes, but not within it.
+    let mut only = input.try_parse(|input| input.expect_ident_matching("only")).is_ok();
+    let mut schemes = CustomIdentList::new();
+    loop {
+      if let Ok(ident) = input.try_parse(|input| CustomIdent::parse_except(input, &["normal", "only"])) {
+        // Normalize the case of the keywords defined by the spec. Other names are case-sensitive.
+        let ident = if ident.0.eq_ignore_ascii_case("light") {
+          CustomIdent("light".into())
+        } else if ident.0.eq_ignore_ascii_case("dark") {
+          CustomIdent("dark".into())
+        } else {
+          ident
+        };
+        schemes.push(ident);
+        continue;
+      }
+
+      break;
+    }
+
+    if schemes.is_empty() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    if !only {
+      only = input.try_parse(|input| input.expect_ident_matching("only")).is_ok();
+    }
+
+    Ok(ColorScheme::Schemes { schemes, only })
+  }
+}
+
+impl<'i> ToCss for ColorScheme<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ColorScheme::Normal => dest.write_str("normal"),
+      ColorScheme::Schemes { schemes, only } => {
+        let mut first = true;
+        for scheme in schemes {
+          if first {
+            first = false;
+          } else {
+            dest.write_char(' ')?;
+          }
+          scheme.to_css(dest)?;
+        }
+
+        if *only {
+          dest.write_str(" only")?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+enum_property! {
+  /// A value for the [forced-color-adjust](https://drafts.csswg.org/css-color-adjust/#forced-color-adjust-prop) property.
+  pub enum ForcedColorAdjust {
+    /// The element's colors are affected by forced colors mode.
+    "auto": Auto,
+    /// The element's colors are not affected by forced colors mode.
+    "none": None,
+    /// The element's colors are not adjusted, but the `color` property inherits the adjusted parent color.
+    "preserve-parent-color": PreserveParentColor,
+  }
+}
+
+enum_property! {
+  /// A value for the [print-color-adjust](https://drafts.csswg.org/css-color-adjust/#print-color-adjust) property.
+  pub enum PrintColorAdjust {
+    /// The user agent may adjust the element's styles to save ink when printing.
+    Economy,
+    /// The element's styles are preserved when printing.
+    Exact,
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct PrintColorAdjustHandler {
+  targets: Option<Browsers>,
+  value: Option<(PrintColorAdjust, VendorPrefix)>,
+}
+
+impl PrintColorAdjustHandler {
+  pub fn new(targets: Option<Browsers>) -> PrintColorAdjustHandler {
+    PrintColorAdjustHandler {
+      targets,
+      ..PrintColorAdjustHandler::default()
+    }
+  }
+}
+
+impl<'i> PropertyHandler<'i> for PrintColorAdjustHandler {
+  fn handle_property(
+    &mut self,
+    property: &Property<'i>,
+    dest: &mut DeclarationList<'i>,
+    context: &mut PropertyHandlerContext<'i, '_>,
+  ) -> bool {
+    match property {
+      Property::PrintColorAdjust(val, prefix) => {
+        if let Some((cur, prefixes)) = &mut self.value {
+          if cur != val && !prefixes.contains(*prefix) {
+            self.finalize(dest, context);
+            self.value = Some((*val, *prefix));
+          } else {
+            *cur = *val;
+            *prefixes |= *prefix;
+          }
+        } else {
+          self.value = Some((*val, *prefix));
+        }
+      }
+      Property::Unparsed(val) if matches!(val.property_id, PropertyId::PrintColorAdjust(_)) => {
+        self.finalize(dest, context);
+        dest.push(property.clone());
+      }
+      _ => return false,
+    }
+
+    true
+  }
+
+  fn finalize(&mut self, dest: &mut DeclarationList<'i>, _: &mut PropertyHandlerContext<'i, '_>) {
+    if let Some((val, mut prefix)) = std::mem::take(&mut self.value) {
+      if prefix.contains(VendorPrefix::None) {
+        if let Some(targets) = self.targets {
+          // Firefox supported the unprefixed `color-adjust` property rather than a prefixed alias.
+          prefix = prefixes::Feature::PrintColorAdjust.prefixes_for(targets) - VendorPrefix::Moz;
+        }
+      }
+
+      dest.push(Property::PrintColorAdjust(val, prefix));
+    }
+  }
+}
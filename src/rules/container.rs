@@ -33,11 +33,8 @@ pub struct ContainerName<'i>(#[cfg_attr(feature = "serde", serde(borrow))] pub C
 
 impl<'i> Parse<'i> for ContainerName<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let ident = CustomIdent::parse(input)?;
-    match_ignore_ascii_case! { &*ident.0,
-      "none" | "and" | "not" | "or" => Err(input.new_unexpected_token_error(Token::Ident(ident.0.as_ref().to_owned().into()))),
-      _ => Ok(ContainerName(ident))
-    }
+    let ident = CustomIdent::parse_except(input, &["none", "and", "not", "or"])?;
+    Ok(ContainerName(ident))
   }
 }
 
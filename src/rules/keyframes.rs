@@ -13,7 +13,7 @@ use crate::properties::Property;
 use crate::targets::Browsers;
 use crate::traits::{Parse, ToCss};
 use crate::values::color::ColorFallbackKind;
-use crate::values::ident::CustomIdent;
+use crate::values::ident::{is_reserved_keyword, CustomIdent};
 use crate::values::percentage::Percentage;
 use crate::values::string::CowArcStr;
 use crate::vendor_prefix::VendorPrefix;
@@ -53,13 +53,10 @@ impl<'i> Parse<'i> for KeyframesName<'i> {
     match input.next()?.clone() {
       Token::Ident(ref s) => {
         // CSS-wide keywords without quotes throws an error.
-        match_ignore_ascii_case! { &*s,
-          "none" | "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
-            Err(input.new_unexpected_token_error(Token::Ident(s.clone())))
-          },
-          _ => {
-            Ok(KeyframesName::Ident(CustomIdent(s.into())))
-          }
+        if s.eq_ignore_ascii_case("none") || is_reserved_keyword(s) {
+          Err(input.new_unexpected_token_error(Token::Ident(s.clone())))
+        } else {
+          Ok(KeyframesName::Ident(CustomIdent(s.into())))
         }
       }
 
@@ -80,13 +77,12 @@ impl<'i> ToCss for KeyframesName<'i> {
       }
       KeyframesName::Custom(s) => {
         // CSS-wide keywords and `none` cannot remove quotes.
-        match_ignore_ascii_case! { &*s,
-          "none" | "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => {
-            serialize_string(&s, dest)?;
-          },
-          _ => {
-            dest.write_ident(s.as_ref())?;
-          }
+        if s.eq_ignore_ascii_case("none") || is_reserved_keyword(s) {
+          serialize_string(&s, dest)?;
+        } else if dest.css_module.is_some() {
+          dest.write_ident(s.as_ref())?;
+        } else {
+          dest.write_ident_or_string(s, false)?;
         }
       }
     }
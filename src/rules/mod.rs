@@ -50,9 +50,11 @@ pub mod namespace;
 pub mod nesting;
 pub mod page;
 pub mod property;
+mod renest;
 pub mod style;
 pub mod supports;
 pub mod unknown;
+pub(crate) mod unused;
 pub mod viewport;
 
 use self::font_palette_values::FontPaletteValuesRule;
@@ -65,6 +67,7 @@ use crate::error::{MinifyError, ParserError, PrinterError};
 use crate::parser::TopLevelRuleParser;
 use crate::prefixes::Feature;
 use crate::printer::Printer;
+use crate::properties::font::FontFamily;
 use crate::rules::keyframes::KeyframesName;
 use crate::selector::{downlevel_selectors, get_prefix, is_equivalent};
 use crate::stylesheet::ParserOptions;
@@ -77,7 +80,7 @@ use counter_style::CounterStyleRule;
 use cssparser::{parse_one_rule, ParseError, Parser, ParserInput};
 use custom_media::CustomMediaRule;
 use document::MozDocumentRule;
-use font_face::FontFaceRule;
+use font_face::{FontFaceProperty, FontFaceRule};
 use import::ImportRule;
 use keyframes::KeyframesRule;
 use media::MediaRule;
@@ -89,6 +92,7 @@ use std::collections::{HashMap, HashSet};
 use style::StyleRule;
 use supports::SupportsRule;
 use unknown::UnknownAtRule;
+use unused::ReferencedNames;
 use viewport::ViewportRule;
 
 pub(crate) trait ToCssWithContext<'a, 'i> {
@@ -247,6 +251,7 @@ pub(crate) struct MinifyContext<'a, 'i> {
   pub unused_symbols: &'a HashSet<String>,
   pub custom_media: Option<HashMap<CowArcStr<'i>, CustomMediaRule<'i>>>,
   pub css_modules: bool,
+  pub referenced_names: Option<ReferencedNames>,
 }
 
 impl<'i> CssRuleList<'i> {
@@ -260,12 +265,18 @@ impl<'i> CssRuleList<'i> {
     for mut rule in self.0.drain(..) {
       match &mut rule {
         CssRule::Keyframes(keyframes) => {
-          if context.unused_symbols.contains(match &keyframes.name {
+          let name = match &keyframes.name {
             KeyframesName::Ident(ident) => ident.0.as_ref(),
             KeyframesName::Custom(string) => string.as_ref(),
-          }) {
+          };
+          if context.unused_symbols.contains(name) {
             continue;
           }
+          if let Some(referenced_names) = &context.referenced_names {
+            if !referenced_names.is_keyframes_used(name) {
+              continue;
+            }
+          }
           keyframes.minify(context);
 
           macro_rules! set_prefix {
@@ -396,6 +407,7 @@ impl<'i> CssRuleList<'i> {
 
           let supports = context.handler_context.get_supports_rules(&style);
           let logical = context.handler_context.get_logical_rules(&style);
+          let scrollbar = context.handler_context.get_scrollbar_rules(&style);
           if !merged && !style.is_empty() {
             rules.push(rule);
           }
@@ -406,6 +418,7 @@ impl<'i> CssRuleList<'i> {
             rules.extend(logical.0)
           }
 
+          rules.extend(scrollbar);
           rules.extend(supports);
           continue;
         }
@@ -437,6 +450,24 @@ impl<'i> CssRuleList<'i> {
           if context.unused_symbols.contains(property.name.0.as_ref()) {
             continue;
           }
+          if let Some(referenced_names) = &context.referenced_names {
+            if !referenced_names.is_property_used(property.name.0.as_ref()) {
+              continue;
+            }
+          }
+        }
+        CssRule::FontFace(font_face) => {
+          if let Some(referenced_names) = &context.referenced_names {
+            let is_unused = font_face.properties.iter().any(|property| match property {
+              FontFaceProperty::FontFamily(FontFamily::FamilyName(name)) => {
+                !referenced_names.is_font_family_used(name)
+              }
+              _ => false,
+            });
+            if is_unused {
+              continue;
+            }
+          }
         }
         _ => {}
       }
@@ -449,6 +480,59 @@ impl<'i> CssRuleList<'i> {
   }
 }
 
+impl<'i> CssRuleList<'i> {
+  /// Offsets the source index of the location of each rule, including nested rules.
+  pub(crate) fn offset_source_index(&mut self, offset: u32) {
+    for rule in &mut self.0 {
+      let loc = match rule {
+        CssRule::Media(media) => {
+          media.rules.offset_source_index(offset);
+          &mut media.loc
+        }
+        CssRule::Import(import) => &mut import.loc,
+        CssRule::Style(style) => {
+          style.rules.offset_source_index(offset);
+          &mut style.loc
+        }
+        CssRule::Keyframes(keyframes) => &mut keyframes.loc,
+        CssRule::FontFace(font_face) => &mut font_face.loc,
+        CssRule::FontPaletteValues(font_palette_values) => &mut font_palette_values.loc,
+        CssRule::Page(page) => &mut page.loc,
+        CssRule::Supports(supports) => {
+          supports.rules.offset_source_index(offset);
+          &mut supports.loc
+        }
+        CssRule::CounterStyle(counter_style) => &mut counter_style.loc,
+        CssRule::Namespace(namespace) => &mut namespace.loc,
+        CssRule::MozDocument(document) => {
+          document.rules.offset_source_index(offset);
+          &mut document.loc
+        }
+        CssRule::Nesting(nesting) => {
+          nesting.style.rules.offset_source_index(offset);
+          nesting.style.loc.source_index += offset;
+          &mut nesting.loc
+        }
+        CssRule::Viewport(viewport) => &mut viewport.loc,
+        CssRule::CustomMedia(custom_media) => &mut custom_media.loc,
+        CssRule::LayerStatement(layer) => &mut layer.loc,
+        CssRule::LayerBlock(layer) => {
+          layer.rules.offset_source_index(offset);
+          &mut layer.loc
+        }
+        CssRule::Property(property) => &mut property.loc,
+        CssRule::Container(container) => {
+          container.rules.offset_source_index(offset);
+          &mut container.loc
+        }
+        CssRule::Unknown(unknown) => &mut unknown.loc,
+        CssRule::Ignored => continue,
+      };
+      loc.source_index += offset;
+    }
+  }
+}
+
 fn merge_style_rules<'i>(
   style: &mut StyleRule<'i>,
   last_style_rule: &mut StyleRule<'i>,
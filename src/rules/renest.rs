@@ -0,0 +1,200 @@
+//! Re-nesting of style rules that share a leading compound selector.
+//!
+//! This is the inverse of the flattening performed when printing nested rules for targets
+//! without support for CSS nesting. It is applied at the end of minification when the
+//! [nest_rules](crate::stylesheet::MinifyOptions::nest_rules) option is enabled.
+
+use super::style::StyleRule;
+use super::{CssRule, CssRuleList};
+use crate::declaration::DeclarationBlock;
+use crate::printer::{Printer, PrinterOptions};
+use crate::properties::Property;
+use crate::selector::Selectors;
+use crate::traits::ToCssWithContext;
+use crate::vendor_prefix::VendorPrefix;
+use parcel_selectors::parser::{Combinator, Component, Selector};
+use parcel_selectors::SelectorList;
+use smallvec::smallvec;
+
+type Compound<'i> = Vec<Component<'i, Selectors>>;
+
+impl<'i> CssRuleList<'i> {
+  /// Nests consecutive style rules whose selectors begin with the same compound selector.
+  ///
+  /// For example, `.foo { color: red } .foo .bar { color: green } .foo > .baz { color: blue }`
+  /// becomes `.foo { color: red; & .bar { color: green } & > .baz { color: blue } }`. A new
+  /// parent rule is only created when doing so reduces the size of the output.
+  pub(crate) fn nest_rules(&mut self) {
+    for rule in self.0.iter_mut() {
+      match rule {
+        CssRule::Style(style) => style.rules.nest_rules(),
+        CssRule::Media(media) => media.rules.nest_rules(),
+        CssRule::Supports(supports) => supports.rules.nest_rules(),
+        CssRule::Container(container) => container.rules.nest_rules(),
+        CssRule::LayerBlock(layer) => layer.rules.nest_rules(),
+        CssRule::MozDocument(document) => document.rules.nest_rules(),
+        _ => {}
+      }
+    }
+
+    let mut rules = std::mem::take(&mut self.0).into_iter().peekable();
+    while let Some(rule) = rules.next() {
+      let mut style = match rule {
+        CssRule::Style(style) => style,
+        rule => {
+          self.0.push(rule);
+          continue;
+        }
+      };
+
+      let (compound, combinator) = match leading_compound(&style) {
+        Some(leading) => leading,
+        None => {
+          self.0.push(CssRule::Style(style));
+          continue;
+        }
+      };
+
+      // A rule consisting of only the compound selector can become the parent of the following
+      // rules. Otherwise, a new parent rule is created to contain this rule and its siblings.
+      let is_parent = match combinator {
+        None => true,
+        Some(combinator) if is_nestable_combinator(combinator) => false,
+        Some(_) => {
+          self.0.push(CssRule::Style(style));
+          continue;
+        }
+      };
+
+      // Collect the following rules that start with the same compound selector.
+      let mut children = Vec::new();
+      while let Some(CssRule::Style(next)) = rules.peek() {
+        match leading_compound(next) {
+          Some((next_compound, Some(next_combinator)))
+            if next_compound == compound && is_nestable_combinator(next_combinator) =>
+          {
+            if let Some(CssRule::Style(next)) = rules.next() {
+              children.push(next);
+            }
+          }
+          _ => break,
+        }
+      }
+
+      let count = if is_parent { children.len() } else { children.len() + 1 };
+      if children.is_empty() || !saves_space(&compound, count, !is_parent) {
+        self.0.push(CssRule::Style(style));
+        self.0.extend(children.into_iter().map(CssRule::Style));
+        continue;
+      }
+
+      if is_parent {
+        style
+          .rules
+          .0
+          .extend(children.into_iter().map(|child| CssRule::Style(into_nested(child))));
+        self.0.push(CssRule::Style(style));
+      } else {
+        let loc = style.loc;
+        let nested = std::iter::once(style)
+          .chain(children)
+          .map(|child| CssRule::Style(into_nested(child)))
+          .collect();
+        self.0.push(CssRule::Style(StyleRule {
+          selectors: SelectorList(smallvec![Selector::from_vec2(compound)]),
+          vendor_prefix: VendorPrefix::empty(),
+          declarations: DeclarationBlock {
+            declarations: vec![],
+            important_declarations: vec![],
+          },
+          rules: CssRuleList(nested),
+          loc,
+        }));
+      }
+    }
+  }
+}
+
+/// Returns the leading compound selector of a style rule with a single selector,
+/// along with the combinator that follows it, if any.
+fn leading_compound<'i>(rule: &StyleRule<'i>) -> Option<(Compound<'i>, Option<Combinator>)> {
+  if rule.selectors.0.len() != 1 || !(rule.vendor_prefix.is_empty() || rule.vendor_prefix == VendorPrefix::None) {
+    return None;
+  }
+
+  // The `composes` property is not allowed within nested rules.
+  let has_composes = rule
+    .declarations
+    .iter()
+    .any(|(property, _)| matches!(property, Property::Composes(..)));
+  if has_composes {
+    return None;
+  }
+
+  let selector = &rule.selectors.0[0];
+  if selector.iter_raw_match_order().any(|c| matches!(c, Component::Nesting)) {
+    return None;
+  }
+
+  // Components are stored in match order, so the leading compound selector is the last one.
+  let compound = selector.iter_raw_match_order().as_slice().split(|c| c.is_combinator()).last()?;
+  if compound.is_empty() {
+    return None;
+  }
+
+  let combinator = selector.iter_raw_match_order().rev().find_map(|c| c.as_combinator());
+  Some((compound.to_vec(), combinator))
+}
+
+#[inline]
+fn is_nestable_combinator(combinator: Combinator) -> bool {
+  matches!(
+    combinator,
+    Combinator::Descendant | Combinator::Child | Combinator::NextSibling | Combinator::LaterSibling
+  )
+}
+
+/// Returns whether replacing the compound selector with `&` in `count` rules is smaller
+/// than the overhead of nesting them within a parent rule.
+fn saves_space(compound: &Compound, count: usize, new_parent: bool) -> bool {
+  let mut s = String::new();
+  {
+    let mut printer = Printer::new(
+      &mut s,
+      PrinterOptions {
+        minify: true,
+        ..PrinterOptions::default()
+      },
+    );
+    let selector = Selector::from_vec2(compound.clone());
+    if selector.to_css_with_context(&mut printer, None).is_err() {
+      return false;
+    }
+  }
+
+  // A new parent rule repeats the compound selector and adds braces, and an existing parent
+  // needs a semicolon after its last declaration.
+  let len = s.len();
+  let overhead = if new_parent { len + 2 } else { 1 };
+  count * (len.saturating_sub(1)) > overhead
+}
+
+/// Replaces the leading compound selector of a rule with the nesting selector.
+fn into_nested<'i>(mut rule: StyleRule<'i>) -> StyleRule<'i> {
+  let selector = &rule.selectors.0[0];
+  let combinators = selector.iter_raw_match_order().rev().filter_map(|c| c.as_combinator());
+  let compounds = selector.iter_raw_match_order().as_slice().split(|c| c.is_combinator()).rev();
+  let mut components = vec![Component::Nesting];
+  for (i, (compound, combinator)) in compounds.zip(combinators.map(Some).chain(std::iter::once(None))).enumerate()
+  {
+    if i > 0 {
+      components.extend(compound.iter().cloned());
+    }
+    if let Some(combinator) = combinator {
+      components.push(Component::Combinator(combinator));
+    }
+  }
+
+  rule.selectors = SelectorList(smallvec![Selector::from_vec2(components)]);
+  rule
+}
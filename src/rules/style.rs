@@ -66,10 +66,12 @@ impl<'i> StyleRule<'i> {
     }
 
     context.handler_context.context = DeclarationContext::StyleRule;
+    context.handler_context.loc = Some(self.loc);
     self
       .declarations
       .minify(context.handler, context.important_handler, context.handler_context);
     context.handler_context.context = DeclarationContext::None;
+    context.handler_context.loc = None;
 
     if !self.rules.0.is_empty() {
       self.rules.minify(context, unused)?;
@@ -241,7 +243,7 @@ impl<'a, 'i> StyleRule<'i> {
 
             dest.newline()?;
             decl.to_css(dest, $important)?;
-            if i != len - 1 || !dest.minify {
+            if i != len - 1 || !dest.minify || (supports_nesting && !self.rules.0.is_empty()) {
               dest.write_char(';')?;
             }
 
@@ -0,0 +1,209 @@
+//! Detection of unreferenced `@keyframes`, `@property`, and `@font-face` rules.
+
+use super::{CssRule, CssRuleList};
+use crate::declaration::DeclarationBlock;
+use crate::properties::animation::AnimationName;
+use crate::properties::custom::{Token, TokenList, TokenOrValue, UnresolvedColor};
+use crate::properties::font::FontFamily;
+use crate::properties::{Property, PropertyId};
+use crate::rules::font_palette_values::FontPaletteValuesProperty;
+use std::collections::HashSet;
+
+/// The names referenced by declarations within a style sheet, used to determine
+/// which `@keyframes`, `@property`, and `@font-face` rules can be removed.
+#[derive(Debug, Default)]
+pub(crate) struct ReferencedNames {
+  /// Animation names referenced by the `animation-name` and `animation` properties.
+  animations: HashSet<String>,
+  /// Custom properties that are declared, referenced via `var()`, or transitioned.
+  custom_properties: HashSet<String>,
+  /// Lower cased font family names referenced by the `font-family` and `font` properties,
+  /// or `None` if unused `@font-face` rules should be preserved.
+  font_families: Option<HashSet<String>>,
+  /// Identifiers and strings within unparsed values. These may reference any of the above,
+  /// e.g. via a custom property, so they are treated as potential references.
+  tokens: HashSet<String>,
+}
+
+impl ReferencedNames {
+  pub fn new(rules: &CssRuleList, used_symbols: &HashSet<String>, font_faces: bool) -> Self {
+    let mut names = ReferencedNames {
+      font_families: if font_faces { Some(HashSet::new()) } else { None },
+      ..ReferencedNames::default()
+    };
+
+    // Names referenced externally, e.g. from JavaScript, are always considered used.
+    for symbol in used_symbols {
+      names.animations.insert(symbol.clone());
+      names.custom_properties.insert(symbol.clone());
+      if let Some(font_families) = &mut names.font_families {
+        font_families.insert(symbol.to_lowercase());
+      }
+    }
+
+    names.visit_rules(rules);
+    names
+  }
+
+  pub fn is_keyframes_used(&self, name: &str) -> bool {
+    self.animations.contains(name) || self.tokens.contains(name)
+  }
+
+  pub fn is_property_used(&self, name: &str) -> bool {
+    self.custom_properties.contains(name) || self.tokens.contains(name)
+  }
+
+  pub fn is_font_family_used(&self, name: &str) -> bool {
+    let font_families = match &self.font_families {
+      Some(font_families) => font_families,
+      None => return true,
+    };
+
+    let name = name.to_lowercase();
+    if font_families.contains(&name) {
+      return true;
+    }
+
+    // Multi-word family names may appear as a sequence of identifiers in unparsed values.
+    self.tokens.iter().any(|token| token.eq_ignore_ascii_case(&name))
+      || name
+        .split(' ')
+        .all(|word| self.tokens.iter().any(|token| token.eq_ignore_ascii_case(word)))
+  }
+
+  fn visit_rules(&mut self, rules: &CssRuleList) {
+    for rule in &rules.0 {
+      match rule {
+        CssRule::Style(style) => {
+          self.visit_declarations(&style.declarations);
+          self.visit_rules(&style.rules);
+        }
+        CssRule::Nesting(nesting) => {
+          self.visit_declarations(&nesting.style.declarations);
+          self.visit_rules(&nesting.style.rules);
+        }
+        CssRule::Media(media) => self.visit_rules(&media.rules),
+        CssRule::Supports(supports) => self.visit_rules(&supports.rules),
+        CssRule::Container(container) => self.visit_rules(&container.rules),
+        CssRule::LayerBlock(layer) => self.visit_rules(&layer.rules),
+        CssRule::MozDocument(document) => self.visit_rules(&document.rules),
+        CssRule::Keyframes(keyframes) => {
+          for keyframe in &keyframes.keyframes {
+            self.visit_declarations(&keyframe.declarations);
+          }
+        }
+        CssRule::Page(page) => self.visit_declarations(&page.declarations),
+        CssRule::Viewport(viewport) => self.visit_declarations(&viewport.declarations),
+        CssRule::FontPaletteValues(palette) => {
+          for property in &palette.properties {
+            if let FontPaletteValuesProperty::FontFamily(family) = property {
+              self.visit_font_family(family);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn visit_declarations(&mut self, declarations: &DeclarationBlock) {
+    for property in declarations
+      .declarations
+      .iter()
+      .chain(declarations.important_declarations.iter())
+    {
+      self.visit_property(property);
+    }
+  }
+
+  fn visit_property(&mut self, property: &Property) {
+    match property {
+      Property::AnimationName(names, _) => {
+        for name in names {
+          self.visit_animation_name(name);
+        }
+      }
+      Property::Animation(animations, _) => {
+        for animation in animations {
+          self.visit_animation_name(&animation.name);
+        }
+      }
+      Property::TransitionProperty(properties, _) => {
+        for property_id in properties {
+          self.visit_property_id(property_id);
+        }
+      }
+      Property::Transition(transitions, _) => {
+        for transition in transitions {
+          self.visit_property_id(&transition.property);
+        }
+      }
+      Property::FontFamily(families) => {
+        for family in families {
+          self.visit_font_family(family);
+        }
+      }
+      Property::Font(font) => {
+        for family in &font.family {
+          self.visit_font_family(family);
+        }
+      }
+      Property::Custom(custom) => {
+        self.custom_properties.insert(custom.name.to_string());
+        self.visit_tokens(&custom.value);
+      }
+      Property::Unparsed(unparsed) => self.visit_tokens(&unparsed.value),
+      _ => {}
+    }
+  }
+
+  fn visit_animation_name(&mut self, name: &AnimationName) {
+    if let AnimationName::Ident(ident) = name {
+      self.animations.insert(ident.0.to_string());
+    }
+  }
+
+  fn visit_property_id(&mut self, property_id: &PropertyId) {
+    if let PropertyId::Custom(name) = property_id {
+      self.custom_properties.insert(name.to_string());
+    }
+  }
+
+  fn visit_font_family(&mut self, family: &FontFamily) {
+    if let (FontFamily::FamilyName(name), Some(font_families)) = (family, &mut self.font_families) {
+      font_families.insert(name.to_lowercase());
+    }
+  }
+
+  fn visit_tokens(&mut self, tokens: &TokenList) {
+    for token in &tokens.0 {
+      match token {
+        TokenOrValue::Token(Token::Ident(ident)) => {
+          self.tokens.insert(ident.to_string());
+        }
+        TokenOrValue::Token(Token::String(string)) => {
+          self.tokens.insert(string.to_string());
+        }
+        TokenOrValue::Var(var) => {
+          self.custom_properties.insert(var.name.ident.0.to_string());
+          if let Some(fallback) = &var.fallback {
+            self.visit_tokens(fallback);
+          }
+        }
+        TokenOrValue::Attr(attr) => {
+          if let Some(fallback) = &attr.fallback {
+            self.visit_tokens(fallback);
+          }
+        }
+        TokenOrValue::Env(env) => {
+          if let Some(fallback) = &env.fallback {
+            self.visit_tokens(fallback);
+          }
+        }
+        TokenOrValue::UnresolvedColor(UnresolvedColor::RGB { alpha, .. })
+        | TokenOrValue::UnresolvedColor(UnresolvedColor::HSL { alpha, .. }) => self.visit_tokens(alpha),
+        _ => {}
+      }
+    }
+  }
+}
@@ -0,0 +1,349 @@
+//! Streaming parsing.
+//!
+//! Tools that only need to observe the contents of a style sheet, such as collecting statistics over
+//! a large amount of CSS, do not need to build a complete [StyleSheet](super::stylesheet::StyleSheet).
+//! The functions in this module invoke a [ParseHandler](ParseHandler) for each rule and declaration
+//! instead, and drop each rule once it has been visited.
+//!
+//! Grouping rules such as `@media`, `@supports`, `@container`, `@layer`, and `@-moz-document` are
+//! not collected into memory. Their callbacks are invoked as soon as the prelude has been parsed,
+//! and the rules nested within them are visited and dropped one at a time. Memory usage is therefore
+//! bounded by the size of the largest style rule, keyframes rule, or other non-grouping rule rather
+//! than the size of the entire style sheet.
+//!
+//! # Example
+//!
+//! ```
+//! use lightningcss::{
+//!   properties::Property,
+//!   stream::{parse_stylesheet, ParseHandler},
+//!   stylesheet::ParserOptions,
+//! };
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!   declarations: usize,
+//!   important: usize,
+//! }
+//!
+//! impl<'i> ParseHandler<'i> for Counter {
+//!   fn declaration(&mut self, _property: &Property<'i>, important: bool) {
+//!     self.declarations += 1;
+//!     if important {
+//!       self.important += 1;
+//!     }
+//!   }
+//! }
+//!
+//! let mut counter = Counter::default();
+//! parse_stylesheet(
+//!   ".foo { color: red !important } @media print { .bar { color: green; width: 10px } }",
+//!   ParserOptions::default(),
+//!   &mut counter,
+//! )
+//! .unwrap();
+//! assert_eq!(counter.declarations, 3);
+//! assert_eq!(counter.important, 1);
+//! ```
+
+use crate::declaration::DeclarationBlock;
+use crate::error::{Error, ParserError};
+use crate::parser::{AtRulePrelude, NestedRuleParser, TopLevelRuleParser};
+use crate::properties::Property;
+use crate::rules::{
+  container::ContainerRule, document::MozDocumentRule, layer::LayerBlockRule, media::MediaRule,
+  supports::SupportsRule, CssRule, CssRuleList,
+};
+use crate::selector::Selectors;
+use crate::stylesheet::ParserOptions;
+use cssparser::{
+  AtRuleParser, CowRcStr, ParseError, Parser, ParserInput, ParserState, QualifiedRuleParser, RuleListParser,
+};
+use parcel_selectors::SelectorList;
+
+/// Receives rules and declarations as they are parsed.
+///
+/// All methods have default implementations that do nothing, so handlers only need to
+/// implement the callbacks they are interested in.
+pub trait ParseHandler<'i> {
+  /// Called when a rule is visited, before any of its declarations or nested rules.
+  ///
+  /// Grouping rules such as `@media` are passed with an empty rule list, because the rules
+  /// nested within them have not been parsed yet.
+  fn enter_rule(&mut self, _rule: &CssRule<'i>) {}
+
+  /// Called after all of the declarations and nested rules within a rule have been visited.
+  fn exit_rule(&mut self, _rule: &CssRule<'i>) {}
+
+  /// Called for each declaration, along with whether it is `!important`.
+  ///
+  /// Declarations within style rules, `@page`, `@viewport`, and keyframes are visited.
+  fn declaration(&mut self, _property: &Property<'i>, _important: bool) {}
+}
+
+/// Parses a style sheet, invoking the handler for each rule and declaration.
+///
+/// The callbacks for a grouping rule are invoked as its nested rules are parsed. The callbacks
+/// for any other rule are invoked after the whole rule has been parsed.
+pub fn parse_stylesheet<'i, H: ParseHandler<'i>>(
+  code: &'i str,
+  options: ParserOptions<'_, 'i>,
+  handler: &mut H,
+) -> Result<(), Error<ParserError<'i>>> {
+  let mut input = ParserInput::new(&code);
+  let mut parser = Parser::new(&mut input);
+  let rule_list_parser = RuleListParser::new_for_stylesheet(
+    &mut parser,
+    TopLevelStreamingParser {
+      parser: TopLevelRuleParser::new(&options),
+      handler,
+    },
+  );
+
+  for rule in rule_list_parser {
+    if let Err((e, _)) = rule {
+      if options.error_recovery {
+        options.warn(e);
+        continue;
+      }
+
+      return Err(Error::from(e, options.filename.clone()));
+    }
+  }
+
+  Ok(())
+}
+
+/// Parses a list of declarations, such as a style attribute, invoking the handler for each declaration
+/// as soon as it is parsed.
+pub fn parse_declarations<'i, H: ParseHandler<'i>>(
+  code: &'i str,
+  options: ParserOptions<'_, 'i>,
+  handler: &mut H,
+) -> Result<(), Error<ParserError<'i>>> {
+  let mut input = ParserInput::new(&code);
+  let mut parser = Parser::new(&mut input);
+  DeclarationBlock::parse_streaming(&mut parser, &options, |property, important| {
+    handler.declaration(&property, important)
+  })
+  .map_err(|e| Error::from(e, options.filename.clone()))
+}
+
+/// Visits each top-level rule as soon as it has been parsed.
+struct TopLevelStreamingParser<'a, 'o, 'i, 'h, H> {
+  parser: TopLevelRuleParser<'a, 'o, 'i>,
+  handler: &'h mut H,
+}
+
+impl<'a, 'o, 'i, 'h, H: ParseHandler<'i>> AtRuleParser<'i> for TopLevelStreamingParser<'a, 'o, 'i, 'h, H> {
+  type Prelude = AtRulePrelude<'i>;
+  type AtRule = ();
+  type Error = ParserError<'i>;
+
+  fn parse_prelude<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    AtRuleParser::parse_prelude(&mut self.parser, name, input)
+  }
+
+  fn parse_block<'t>(
+    &mut self,
+    prelude: Self::Prelude,
+    start: &ParserState,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<(), ParseError<'i, Self::Error>> {
+    stream_block(self.parser.block_parser(), prelude, start, input, self.handler)
+  }
+
+  fn rule_without_block(&mut self, prelude: Self::Prelude, start: &ParserState) -> Result<(), ()> {
+    let (_, rule) = AtRuleParser::rule_without_block(&mut self.parser, prelude, start)?;
+    visit_parsed_rule(&rule, self.handler);
+    Ok(())
+  }
+}
+
+impl<'a, 'o, 'i, 'h, H: ParseHandler<'i>> QualifiedRuleParser<'i> for TopLevelStreamingParser<'a, 'o, 'i, 'h, H> {
+  type Prelude = SelectorList<'i, Selectors>;
+  type QualifiedRule = ();
+  type Error = ParserError<'i>;
+
+  fn parse_prelude<'t>(
+    &mut self,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    QualifiedRuleParser::parse_prelude(&mut self.parser, input)
+  }
+
+  fn parse_block<'t>(
+    &mut self,
+    prelude: Self::Prelude,
+    start: &ParserState,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<(), ParseError<'i, Self::Error>> {
+    let (_, rule) = QualifiedRuleParser::parse_block(&mut self.parser, prelude, start, input)?;
+    visit_parsed_rule(&rule, self.handler);
+    Ok(())
+  }
+}
+
+/// Visits each rule nested within a grouping rule as soon as it has been parsed.
+struct NestedStreamingParser<'a, 'o, 'i, 'h, H> {
+  parser: NestedRuleParser<'a, 'o, 'i>,
+  handler: &'h mut H,
+}
+
+impl<'a, 'o, 'i, 'h, H: ParseHandler<'i>> AtRuleParser<'i> for NestedStreamingParser<'a, 'o, 'i, 'h, H> {
+  type Prelude = AtRulePrelude<'i>;
+  type AtRule = ();
+  type Error = ParserError<'i>;
+
+  fn parse_prelude<'t>(
+    &mut self,
+    name: CowRcStr<'i>,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    AtRuleParser::parse_prelude(&mut self.parser, name, input)
+  }
+
+  fn parse_block<'t>(
+    &mut self,
+    prelude: Self::Prelude,
+    start: &ParserState,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<(), ParseError<'i, Self::Error>> {
+    stream_block(self.parser.clone(), prelude, start, input, self.handler)
+  }
+
+  fn rule_without_block(&mut self, prelude: Self::Prelude, start: &ParserState) -> Result<(), ()> {
+    let rule = AtRuleParser::rule_without_block(&mut self.parser, prelude, start)?;
+    visit_parsed_rule(&rule, self.handler);
+    Ok(())
+  }
+}
+
+impl<'a, 'o, 'i, 'h, H: ParseHandler<'i>> QualifiedRuleParser<'i> for NestedStreamingParser<'a, 'o, 'i, 'h, H> {
+  type Prelude = SelectorList<'i, Selectors>;
+  type QualifiedRule = ();
+  type Error = ParserError<'i>;
+
+  fn parse_prelude<'t>(
+    &mut self,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+    QualifiedRuleParser::parse_prelude(&mut self.parser, input)
+  }
+
+  fn parse_block<'t>(
+    &mut self,
+    prelude: Self::Prelude,
+    start: &ParserState,
+    input: &mut Parser<'i, 't>,
+  ) -> Result<(), ParseError<'i, Self::Error>> {
+    let rule = QualifiedRuleParser::parse_block(&mut self.parser, prelude, start, input)?;
+    visit_parsed_rule(&rule, self.handler);
+    Ok(())
+  }
+}
+
+/// Parses the block of an at-rule. Grouping rules are entered immediately and their nested rules
+/// are streamed one at a time, while all other rules are fully parsed and then visited.
+fn stream_block<'a, 'o, 'i, 't, H: ParseHandler<'i>>(
+  mut parser: NestedRuleParser<'a, 'o, 'i>,
+  prelude: AtRulePrelude<'i>,
+  start: &ParserState,
+  input: &mut Parser<'i, 't>,
+  handler: &mut H,
+) -> Result<(), ParseError<'i, ParserError<'i>>> {
+  let loc = parser.loc(start);
+  let rules = CssRuleList(vec![]);
+  let rule = match prelude {
+    AtRulePrelude::Media(query) => CssRule::Media(MediaRule { query, rules, loc }),
+    AtRulePrelude::Supports(condition) => CssRule::Supports(SupportsRule { condition, rules, loc }),
+    AtRulePrelude::Container(name, condition) => CssRule::Container(ContainerRule {
+      name,
+      condition,
+      rules,
+      loc,
+    }),
+    AtRulePrelude::MozDocument => CssRule::MozDocument(MozDocumentRule { rules, loc }),
+    AtRulePrelude::Layer(names) if names.len() <= 1 => CssRule::LayerBlock(LayerBlockRule {
+      name: names.into_iter().next(),
+      rules,
+      loc,
+    }),
+    prelude => {
+      let rule = AtRuleParser::parse_block(&mut parser, prelude, start, input)?;
+      visit_parsed_rule(&rule, handler);
+      return Ok(());
+    }
+  };
+
+  handler.enter_rule(&rule);
+  let options = parser.options;
+  let mut iter = RuleListParser::new_for_nested_rule(
+    input,
+    NestedStreamingParser {
+      parser,
+      handler: &mut *handler,
+    },
+  );
+  while let Some(result) = iter.next() {
+    if let Err((e, _)) = result {
+      if options.error_recovery {
+        options.warn(e);
+        continue;
+      }
+      return Err(e);
+    }
+  }
+  handler.exit_rule(&rule);
+  Ok(())
+}
+
+fn visit_parsed_rule<'i, H: ParseHandler<'i>>(rule: &CssRule<'i>, handler: &mut H) {
+  if !matches!(rule, CssRule::Ignored) {
+    visit_rule(rule, handler);
+  }
+}
+
+fn visit_rule<'i, H: ParseHandler<'i>>(rule: &CssRule<'i>, handler: &mut H) {
+  handler.enter_rule(rule);
+  match rule {
+    CssRule::Style(style) => {
+      visit_declarations(&style.declarations, handler);
+      visit_rules(&style.rules, handler);
+    }
+    CssRule::Nesting(nesting) => {
+      visit_declarations(&nesting.style.declarations, handler);
+      visit_rules(&nesting.style.rules, handler);
+    }
+    CssRule::Media(media) => visit_rules(&media.rules, handler),
+    CssRule::Supports(supports) => visit_rules(&supports.rules, handler),
+    CssRule::Container(container) => visit_rules(&container.rules, handler),
+    CssRule::LayerBlock(layer) => visit_rules(&layer.rules, handler),
+    CssRule::MozDocument(document) => visit_rules(&document.rules, handler),
+    CssRule::Keyframes(keyframes) => {
+      for keyframe in &keyframes.keyframes {
+        visit_declarations(&keyframe.declarations, handler);
+      }
+    }
+    CssRule::Page(page) => visit_declarations(&page.declarations, handler),
+    CssRule::Viewport(viewport) => visit_declarations(&viewport.declarations, handler),
+    _ => {}
+  }
+  handler.exit_rule(rule);
+}
+
+fn visit_rules<'i, H: ParseHandler<'i>>(rules: &CssRuleList<'i>, handler: &mut H) {
+  for rule in &rules.0 {
+    visit_rule(rule, handler);
+  }
+}
+
+fn visit_declarations<'i, H: ParseHandler<'i>>(declarations: &DeclarationBlock<'i>, handler: &mut H) {
+  for (property, important) in declarations.iter() {
+    handler.declaration(property, important);
+  }
+}
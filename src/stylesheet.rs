@@ -5,18 +5,26 @@
 
 use crate::compat::Feature;
 use crate::context::{DeclarationContext, PropertyHandlerContext};
+use crate::contrast::{ContrastOptions, ContrastWarning};
 use crate::css_modules::{CssModule, CssModuleExports, CssModuleReferences};
 use crate::declaration::{DeclarationBlock, DeclarationHandler};
 use crate::dependencies::Dependency;
 use crate::error::{Error, ErrorLocation, MinifyErrorKind, ParserError, PrinterError, PrinterErrorKind};
 use crate::parser::TopLevelRuleParser;
 use crate::printer::Printer;
-use crate::rules::{CssRule, CssRuleList, MinifyContext};
+use crate::properties::size::WritingMode;
+use crate::properties::text::TextDirection;
+use crate::rules::import::ImportRule;
+use crate::rules::layer::{LayerBlockRule, LayerName, LayerStatementRule};
+use crate::rules::unused::ReferencedNames;
+use crate::rules::{CssRule, CssRuleList, Location, MinifyContext};
 use crate::targets::Browsers;
 use crate::traits::ToCss;
+use crate::variables::InlineVariablesOptions;
 use cssparser::{Parser, ParserInput, RuleListParser};
 use parcel_sourcemap::SourceMap;
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
 
 pub use crate::parser::ParserOptions;
 pub use crate::printer::PrinterOptions;
@@ -72,6 +80,54 @@ pub struct StyleSheet<'i, 'o> {
   options: ParserOptions<'o, 'i>,
 }
 
+/// Returns the number of rules at the start of the list that must precede all other rules,
+/// i.e. `@layer` statements, `@import` and `@namespace` rules.
+fn prelude_len(rules: &[CssRule]) -> usize {
+  rules
+    .iter()
+    .position(|rule| {
+      !matches!(
+        rule,
+        CssRule::LayerStatement(..) | CssRule::Import(..) | CssRule::Namespace(..) | CssRule::Ignored
+      )
+    })
+    .unwrap_or(rules.len())
+}
+
+/// Returns the names of the top-level cascade layers declared by the given rules, in the order in which
+/// they are first declared, along with the location of the first declaration.
+fn declared_layers<'i>(rules: &[CssRule<'i>]) -> (Vec<LayerName<'i>>, Option<Location>) {
+  let mut names: Vec<LayerName<'i>> = Vec::new();
+  let mut loc = None;
+  let mut add = |name: &LayerName<'i>, rule_loc: Location| {
+    if !names.contains(name) {
+      names.push(name.clone());
+      loc.get_or_insert(rule_loc);
+    }
+  };
+
+  for rule in rules {
+    match rule {
+      CssRule::LayerStatement(layer) => {
+        for name in &layer.names {
+          add(name, layer.loc);
+        }
+      }
+      CssRule::LayerBlock(LayerBlockRule {
+        name: Some(name), loc, ..
+      }) => add(name, *loc),
+      CssRule::Import(ImportRule {
+        layer: Some(Some(name)),
+        loc,
+        ..
+      }) => add(name, *loc),
+      _ => {}
+    }
+  }
+
+  (names, loc)
+}
+
 /// Options for the `minify` function of a [StyleSheet](StyleSheet)
 /// or [StyleAttribute](StyleAttribute).
 #[derive(Default)]
@@ -81,6 +137,34 @@ pub struct MinifyOptions {
   /// A list of known unused symbols, including CSS class names,
   /// ids, and `@keyframe` names. The declarations of these will be removed.
   pub unused_symbols: HashSet<String>,
+  /// Whether to remove `@keyframes` rules that are never referenced by an animation,
+  /// and `@property` rules for custom properties that never appear in the style sheet.
+  pub remove_unused_rules: bool,
+  /// Whether to also remove `@font-face` rules whose font family is never used.
+  /// Only applies when `remove_unused_rules` is enabled.
+  pub remove_unused_font_faces: bool,
+  /// A list of names that are referenced externally, e.g. from JavaScript, which should
+  /// not be removed by `remove_unused_rules`. This includes `@keyframes` names, custom
+  /// property names, and font family names.
+  pub used_symbols: HashSet<String>,
+  /// The writing mode assumed when compiling logical sizing properties such as `block-size`
+  /// to physical properties for targets that do not support them. Defaults to `horizontal-tb`.
+  pub writing_mode: WritingMode,
+  /// The inline base direction assumed when compiling logical properties and values, such as
+  /// `margin-inline-start` or `text-align: start`, to physical ones for targets that do not support
+  /// them. If not set, rules using the `:dir()` pseudo class are generated for both directions.
+  pub direction: Option<TextDirection>,
+  /// Whether to nest style rules that begin with the same compound selector, e.g. `.foo .bar`
+  /// within `.foo`, to reduce the size of the output. This is only applied when all targets
+  /// support CSS nesting, or no targets are specified.
+  pub nest_rules: bool,
+  /// Whether to generate `::-webkit-scrollbar` rules that emulate `scrollbar-width` and `scrollbar-color`
+  /// for WebKit-based targets that do not support them. These rules style scrollbars differently from the
+  /// standard properties, so they are only generated when enabled.
+  pub webkit_scrollbar_rules: bool,
+  /// A list that will be appended to when a warning occurs, e.g. when a property
+  /// cannot be compiled for the configured targets without changing its meaning.
+  pub warnings: Option<Arc<RwLock<Vec<Error<MinifyErrorKind>>>>>,
 }
 
 /// A result returned from `to_css`, including the serialize CSS
@@ -149,9 +233,102 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
     SourceMap::from_data_url("/", source_map_url).ok()
   }
 
+  /// Appends the rules of another style sheet to this one, as if the source files were concatenated.
+  ///
+  /// The source files of the other style sheet are appended to `sources`, and the locations of its
+  /// rules are updated to match, so source maps and CSS module hashes remain correct. Since `@import`
+  /// and `@namespace` rules must appear at the start of a style sheet, they are hoisted above the
+  /// existing rules, and any that are already present in this style sheet are removed. When `@import`
+  /// rules are hoisted, an `@layer` statement is added if needed to preserve the order of the cascade
+  /// layers declared by this style sheet. `@charset` rules are not preserved by the parser, so they
+  /// never need to be deduplicated.
+  pub fn merge(&mut self, other: StyleSheet<'i, '_>) {
+    let mut other_rules = other.rules;
+    other_rules.offset_source_index(self.sources.len() as u32);
+    self.sources.extend(other.sources);
+
+    let body = other_rules.0.split_off(prelude_len(&other_rules.0));
+    let mut statements = Vec::new();
+    let mut imports = Vec::new();
+    let mut namespaces = Vec::new();
+    for rule in other_rules.0 {
+      match rule {
+        CssRule::Import(import) => {
+          let exists = self.rules.0.iter().any(|rule| match rule {
+            CssRule::Import(existing) => {
+              existing.url == import.url
+                && existing.layer == import.layer
+                && existing.supports == import.supports
+                && existing.media == import.media
+            }
+            _ => false,
+          });
+          if !exists {
+            imports.push(CssRule::Import(import));
+          }
+        }
+        CssRule::Namespace(namespace) => {
+          let exists = self.rules.0.iter().any(|rule| match rule {
+            CssRule::Namespace(existing) => existing.prefix == namespace.prefix && existing.url == namespace.url,
+            _ => false,
+          });
+          if !exists {
+            namespaces.push(CssRule::Namespace(namespace));
+          }
+        }
+        CssRule::LayerStatement(..) => statements.push(rule),
+        _ => {}
+      }
+    }
+
+    let prelude_end = prelude_len(&self.rules.0);
+    self.rules.0.splice(prelude_end..prelude_end, namespaces);
+
+    if imports.is_empty() {
+      // @layer statements may appear anywhere, so they can stay in place.
+      self.rules.0.extend(statements);
+    } else {
+      let import_index = match self.rules.0[..prelude_end]
+        .iter()
+        .rposition(|rule| matches!(rule, CssRule::Import(..)))
+      {
+        Some(index) => index + 1,
+        None => self
+          .rules
+          .0
+          .iter()
+          .position(|rule| !matches!(rule, CssRule::LayerStatement(..) | CssRule::Ignored))
+          .unwrap_or(self.rules.0.len()),
+      };
+
+      // Hoisting rules above existing ones could change the order of cascade layers, which is determined
+      // by the order in which they are first declared. If this style sheet declares layers after the
+      // point where the rules are inserted, declare all of them up front to preserve the order.
+      let (layers, loc) = declared_layers(&self.rules.0);
+      let (preceding_layers, _) = declared_layers(&self.rules.0[..import_index]);
+      let hoisted = statements.into_iter().chain(imports);
+      self.rules.0.splice(import_index..import_index, hoisted);
+      if layers.len() > preceding_layers.len() {
+        if let Some(loc) = loc {
+          self
+            .rules
+            .0
+            .insert(0, CssRule::LayerStatement(LayerStatementRule { names: layers, loc }));
+        }
+      }
+    }
+
+    self.rules.0.extend(body);
+  }
+
   /// Minify and transform the style sheet for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) -> Result<(), Error<MinifyErrorKind>> {
     let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    context.writing_mode = options.writing_mode;
+    context.direction = options.direction;
+    context.webkit_scrollbar_rules = options.webkit_scrollbar_rules;
+    context.warnings = options.warnings.clone();
+    context.sources = &self.sources;
     let mut handler = DeclarationHandler::new(options.targets);
     let mut important_handler = DeclarationHandler::new(options.targets);
 
@@ -172,6 +349,26 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       None
     };
 
+    // Custom properties registered via @property are validated against their syntax wherever they are declared,
+    // so collect the registrations up front. @property rules are only valid at the top level.
+    for rule in &self.rules.0 {
+      if let CssRule::Property(rule) = rule {
+        context.registered_properties.insert(rule.name.0.clone(), rule.syntax.clone());
+      }
+    }
+
+    // Unused rules may be referenced from anywhere in the style sheet, including after their
+    // definition, so collect all referenced names up front.
+    let referenced_names = if options.remove_unused_rules {
+      Some(ReferencedNames::new(
+        &self.rules,
+        &options.used_symbols,
+        options.remove_unused_font_faces,
+      ))
+    } else {
+      None
+    };
+
     let mut ctx = MinifyContext {
       targets: &options.targets,
       handler: &mut handler,
@@ -180,6 +377,7 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       unused_symbols: &options.unused_symbols,
       custom_media,
       css_modules: self.options.css_modules.is_some(),
+      referenced_names,
     };
 
     self.rules.minify(&mut ctx, false).map_err(|e| Error {
@@ -190,13 +388,40 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
       )),
     })?;
 
+    // Re-nest rules that share a selector prefix if all targets support nesting.
+    if options.nest_rules
+      && options
+        .targets
+        .map_or(true, |targets| Feature::CssNesting.is_compatible(targets))
+    {
+      self.rules.nest_rules();
+    }
+
     Ok(())
   }
 
+  /// Inlines `var()` references to custom properties whose values are known statically, and removes
+  /// the `:root` declarations that are no longer referenced. See the [variables](super::variables)
+  /// module for details.
+  ///
+  /// Inlined values are substituted as unparsed tokens, so they are not simplified further by
+  /// [minify](StyleSheet::minify) aside from whitespace and colors.
+  pub fn inline_variables(&mut self, options: &InlineVariablesOptions<'i>) -> Result<(), Error<ParserError<'i>>> {
+    crate::variables::inline_variables(&mut self.rules, options)
+  }
+
+  /// Checks the contrast between the foreground and background colors declared in each style rule,
+  /// and returns a warning for each pair with insufficient contrast. See the [contrast](super::contrast)
+  /// module for details.
+  pub fn check_contrast(&self, options: &ContrastOptions) -> Vec<Error<ContrastWarning>> {
+    crate::contrast::check_contrast(&self.rules, &self.sources, options)
+  }
+
   /// Serialize the style sheet to a CSS string.
   pub fn to_css(&self, options: PrinterOptions) -> Result<ToCssResult, Error<PrinterErrorKind>> {
     // Make sure we always have capacity > 0: https://github.com/napi-rs/napi-rs/issues/1124.
     let mut dest = String::with_capacity(1);
+
     let mut printer = Printer::new(&mut dest, options);
 
     printer.sources = Some(&self.sources);
@@ -282,6 +507,9 @@ impl<'i> StyleAttribute<'i> {
   /// Minify and transform the style attribute for the provided browser targets.
   pub fn minify(&mut self, options: MinifyOptions) {
     let mut context = PropertyHandlerContext::new(options.targets, &options.unused_symbols);
+    context.writing_mode = options.writing_mode;
+    context.direction = options.direction;
+    context.warnings = options.warnings.clone();
     let mut handler = DeclarationHandler::new(options.targets);
     let mut important_handler = DeclarationHandler::new(options.targets);
     context.context = DeclarationContext::StyleAttribute;
@@ -222,3 +222,52 @@ pub trait Zero {
   /// Returns whether the value is zero.
   fn is_zero(&self) -> bool;
 }
+
+/// A trait for values that have equivalent keyword and numeric forms,
+/// e.g. `font-weight: bold` and `font-weight: 700`.
+///
+/// When minifying, the shorter of the two forms is printed, or the numeric form if they have
+/// the same length. Otherwise, values are printed in the form they were written, unless the
+/// [prefer_keywords](crate::printer::PrinterOptions::prefer_keywords) option is enabled.
+pub trait KeywordEquivalent {
+  /// The keyword form of the value.
+  type Keyword: ToCss;
+
+  /// The numeric form of the value.
+  type Numeric: ToCss;
+
+  /// Returns the equivalent keyword, if any.
+  fn to_keyword(&self) -> Option<Self::Keyword>;
+
+  /// Returns the equivalent numeric value.
+  fn to_numeric(&self) -> Self::Numeric;
+
+  /// Returns whether the value was specified as a keyword.
+  fn is_keyword(&self) -> bool;
+
+  /// Serializes the value in its normalized form.
+  fn to_css_normalized<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    let keyword = self.to_keyword();
+    if dest.minify {
+      let numeric = self.to_numeric();
+      if let Some(keyword) = keyword {
+        let options = || PrinterOptions {
+          minify: true,
+          ..PrinterOptions::default()
+        };
+        if keyword.to_css_string(options())?.len() < numeric.to_css_string(options())?.len() {
+          return keyword.to_css(dest);
+        }
+      }
+      return numeric.to_css(dest);
+    }
+
+    match keyword {
+      Some(keyword) if self.is_keyword() || dest.prefer_keywords => keyword.to_css(dest),
+      _ => self.to_numeric().to_css(dest),
+    }
+  }
+}
@@ -0,0 +1,212 @@
+//! CSS anchor positioning functions.
+
+use super::ident::DashedIdent;
+use super::length::LengthPercentage;
+use super::percentage::Percentage;
+use crate::error::{ParserError, PrinterError};
+use crate::macros::enum_property;
+use crate::printer::Printer;
+use crate::traits::{Parse, ToCss};
+use cssparser::*;
+
+/// An [anchor()](https://drafts.csswg.org/css-anchor-position-1/#anchor-pos) function,
+/// which refers to the position of a side of an anchor element. Used in the inset properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnchorFunction<'i> {
+  /// The name of the anchor element, including the leading `--`. If omitted, the default anchor is used.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub anchor: Option<DashedIdent<'i>>,
+  /// The side of the anchor element to refer to.
+  pub side: AnchorSide,
+  /// A fallback value, used if the anchor element cannot be found.
+  pub fallback: Option<LengthPercentage>,
+}
+
+impl<'i> Parse<'i> for AnchorFunction<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("anchor")?;
+    input.parse_nested_block(|input| {
+      let mut anchor = input.try_parse(DashedIdent::parse).ok();
+      let side = AnchorSide::parse(input)?;
+      if anchor.is_none() {
+        anchor = input.try_parse(DashedIdent::parse).ok();
+      }
+
+      let fallback = if input.try_parse(|input| input.expect_comma()).is_ok() {
+        Some(LengthPercentage::parse(input)?)
+      } else {
+        None
+      };
+
+      Ok(AnchorFunction { anchor, side, fallback })
+    })
+  }
+}
+
+impl<'i> ToCss for AnchorFunction<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("anchor(")?;
+    if let Some(anchor) = &self.anchor {
+      dest.write_dashed_ident(&anchor.0, false)?;
+      dest.write_char(' ')?;
+    }
+    self.side.to_css(dest)?;
+    if let Some(fallback) = &self.fallback {
+      dest.delim(',', false)?;
+      fallback.to_css(dest)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+enum_property! {
+  /// A keyword for the [side](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side)
+  /// of an anchor element, as used in the `anchor()` function.
+  pub enum AnchorSideKeyword {
+    /// The same side as the inset property the function is used in.
+    "inside": Inside,
+    /// The opposite side to the inset property the function is used in.
+    "outside": Outside,
+    /// The top side.
+    "top": Top,
+    /// The left side.
+    "left": Left,
+    /// The right side.
+    "right": Right,
+    /// The bottom side.
+    "bottom": Bottom,
+    /// The start side, in the writing mode of the containing block.
+    "start": Start,
+    /// The end side, in the writing mode of the containing block.
+    "end": End,
+    /// The start side, in the writing mode of the positioned element.
+    "self-start": SelfStart,
+    /// The end side, in the writing mode of the positioned element.
+    "self-end": SelfEnd,
+    /// Halfway between the start and end sides.
+    "center": Center,
+  }
+}
+
+/// The [side](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side) of an anchor
+/// element, as used in the `anchor()` function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum AnchorSide {
+  /// A side keyword.
+  Keyword(AnchorSideKeyword),
+  /// A position between the start and end sides.
+  Percentage(Percentage),
+}
+
+impl<'i> Parse<'i> for AnchorSide {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    if let Ok(percentage) = input.try_parse(Percentage::parse) {
+      return Ok(AnchorSide::Percentage(percentage));
+    }
+
+    Ok(AnchorSide::Keyword(AnchorSideKeyword::parse(input)?))
+  }
+}
+
+impl ToCss for AnchorSide {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      AnchorSide::Keyword(keyword) => keyword.to_css(dest),
+      AnchorSide::Percentage(percentage) => percentage.to_css(dest),
+    }
+  }
+}
+
+/// An [anchor-size()](https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn) function,
+/// which refers to the size of an anchor element. Used in the sizing properties.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnchorSizeFunction<'i> {
+  /// The name of the anchor element, including the leading `--`. If omitted, the default anchor is used.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub anchor: Option<DashedIdent<'i>>,
+  /// The dimension of the anchor element to refer to. If omitted, the same axis as the sizing property is used.
+  pub size: Option<AnchorSize>,
+  /// A fallback value, used if the anchor element cannot be found.
+  pub fallback: Option<LengthPercentage>,
+}
+
+impl<'i> Parse<'i> for AnchorSizeFunction<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    input.expect_function_matching("anchor-size")?;
+    input.parse_nested_block(|input| {
+      let mut anchor = input.try_parse(DashedIdent::parse).ok();
+      let size = input.try_parse(AnchorSize::parse).ok();
+      if anchor.is_none() {
+        anchor = input.try_parse(DashedIdent::parse).ok();
+      }
+
+      // The comma is omitted if there is no anchor name or size before the fallback.
+      let fallback = if anchor.is_none() && size.is_none() {
+        input.try_parse(LengthPercentage::parse).ok()
+      } else if input.try_parse(|input| input.expect_comma()).is_ok() {
+        Some(LengthPercentage::parse(input)?)
+      } else {
+        None
+      };
+
+      Ok(AnchorSizeFunction { anchor, size, fallback })
+    })
+  }
+}
+
+impl<'i> ToCss for AnchorSizeFunction<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    dest.write_str("anchor-size(")?;
+    if let Some(anchor) = &self.anchor {
+      dest.write_dashed_ident(&anchor.0, false)?;
+    }
+    if let Some(size) = &self.size {
+      if self.anchor.is_some() {
+        dest.write_char(' ')?;
+      }
+      size.to_css(dest)?;
+    }
+    if let Some(fallback) = &self.fallback {
+      if self.anchor.is_some() || self.size.is_some() {
+        dest.delim(',', false)?;
+      }
+      fallback.to_css(dest)?;
+    }
+    dest.write_char(')')
+  }
+}
+
+enum_property! {
+  /// A [dimension](https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-size) of an
+  /// anchor element, as used in the `anchor-size()` function.
+  pub enum AnchorSize {
+    /// The width of the anchor element.
+    "width": Width,
+    /// The height of the anchor element.
+    "height": Height,
+    /// The size of the anchor element in the block axis of the containing block.
+    "block": Block,
+    /// The size of the anchor element in the inline axis of the containing block.
+    "inline": Inline,
+    /// The size of the anchor element in the block axis of the positioned element.
+    "self-block": SelfBlock,
+    /// The size of the anchor element in the inline axis of the positioned element.
+    "self-inline": SelfInline,
+  }
+}
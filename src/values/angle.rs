@@ -108,6 +108,22 @@ impl Angle {
       Angle::Turn(turn) => turn * 360.0,
     }
   }
+
+  /// Returns the angle in gradians.
+  pub fn to_gradians(&self) -> CSSNumber {
+    match self {
+      Angle::Grad(grad) => *grad,
+      _ => self.to_degrees() * 400.0 / 360.0,
+    }
+  }
+
+  /// Returns the angle in turns.
+  pub fn to_turns(&self) -> CSSNumber {
+    match self {
+      Angle::Turn(turn) => *turn,
+      _ => self.to_degrees() / 360.0,
+    }
+  }
 }
 
 impl Zero for Angle {
@@ -151,6 +167,14 @@ impl std::ops::Mul<CSSNumber> for Angle {
   }
 }
 
+impl std::ops::Div<CSSNumber> for Angle {
+  type Output = Self;
+
+  fn div(self, other: CSSNumber) -> Angle {
+    self.map(|v| v / other)
+  }
+}
+
 impl AddInternal for Angle {
   fn add(self, other: Self) -> Self {
     self + other
@@ -218,6 +242,7 @@ impl Sign for Angle {
 
 impl_op!(Angle, std::ops::Rem, rem);
 impl_op!(Angle, std::ops::Add, add);
+impl_op!(Angle, std::ops::Sub, sub);
 
 /// A CSS [`<angle-percentage>`](https://www.w3.org/TR/css-values-4/#typedef-angle-percentage) value.
 /// May be specified as either an angle or a percentage that resolves to an angle.
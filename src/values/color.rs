@@ -3,6 +3,7 @@
 use super::angle::Angle;
 use super::number::CSSNumber;
 use super::percentage::Percentage;
+use super::string::CowArcStr;
 use crate::compat::Feature;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
@@ -2465,6 +2466,222 @@ fn parse_color_mix<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CssColor, Parse
   })
 }
 
+/// An algorithm used to compute the contrast between two colors.
+///
+/// See [CssColor::contrast](CssColor::contrast).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(rename_all = "lowercase")
+)]
+pub enum ContrastAlgorithm {
+  /// The [WCAG 2.1 contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio), between 1 and 21.
+  Wcag21,
+  /// The [APCA](https://github.com/Myndex/apca-w3) lightness contrast (Lc), roughly between -108 and 106.
+  Apca,
+}
+
+impl Default for ContrastAlgorithm {
+  fn default() -> ContrastAlgorithm {
+    ContrastAlgorithm::Wcag21
+  }
+}
+
+impl CssColor {
+  /// Returns the contrast of this color as a foreground color against the given background color,
+  /// using the given algorithm. Returns `None` if either color is `currentColor`.
+  ///
+  /// The WCAG 2.1 ratio is symmetric. The APCA value is signed: positive for dark text on a light
+  /// background, and negative for light text on a dark background.
+  pub fn contrast(&self, background: &CssColor, algorithm: ContrastAlgorithm) -> Option<f32> {
+    if matches!(self, CssColor::CurrentColor) || matches!(background, CssColor::CurrentColor) {
+      return None;
+    }
+
+    let text = SRGB::from(self).resolve();
+    let background = SRGB::from(background).resolve();
+    Some(match algorithm {
+      ContrastAlgorithm::Wcag21 => {
+        let a = relative_luminance(&text);
+        let b = relative_luminance(&background);
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+      }
+      ContrastAlgorithm::Apca => apca_contrast(&text, &background),
+    })
+  }
+}
+
+// https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+fn relative_luminance(rgb: &SRGB) -> f32 {
+  fn channel(c: f32) -> f32 {
+    if c <= 0.04045 {
+      c / 12.92
+    } else {
+      ((c + 0.055) / 1.055).powf(2.4)
+    }
+  }
+
+  0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
+}
+
+// https://github.com/Myndex/apca-w3, using the 0.0.98G-4g constants.
+fn apca_contrast(text: &SRGB, background: &SRGB) -> f32 {
+  fn luminance(rgb: &SRGB) -> f32 {
+    let y = 0.2126729 * rgb.r.powf(2.4) + 0.7151522 * rgb.g.powf(2.4) + 0.0721750 * rgb.b.powf(2.4);
+    // Soft clamp near black.
+    if y < 0.022 {
+      y + (0.022 - y).powf(1.414)
+    } else {
+      y
+    }
+  }
+
+  let y_text = luminance(text);
+  let y_background = luminance(background);
+  if (y_background - y_text).abs() < 0.0005 {
+    return 0.0;
+  }
+
+  let sapc = if y_background > y_text {
+    // Dark text on a light background.
+    let sapc = (y_background.powf(0.56) - y_text.powf(0.57)) * 1.14;
+    if sapc < 0.1 {
+      0.0
+    } else {
+      sapc - 0.027
+    }
+  } else {
+    // Light text on a dark background.
+    let sapc = (y_background.powf(0.65) - y_text.powf(0.62)) * 1.14;
+    if sapc > -0.1 {
+      0.0
+    } else {
+      sapc + 0.027
+    }
+  };
+
+  sapc * 100.0
+}
+
+/// Parses the `color-contrast()` and `contrast-color()` functions, and computes the winning color
+/// using the given algorithm. Returns an error if any of the arguments is not a literal color,
+/// e.g. `currentColor` or a `var()` reference, so that the function can be preserved as is.
+pub(crate) fn parse_contrast_function<'i, 't>(
+  f: &CowArcStr<'i>,
+  input: &mut Parser<'i, 't>,
+  algorithm: ContrastAlgorithm,
+) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
+  match_ignore_ascii_case! { &*f,
+    "color-contrast" => input.parse_nested_block(|input| parse_color_contrast(input, algorithm)),
+    "contrast-color" => input.parse_nested_block(|input| parse_contrast_color(input, algorithm)),
+    _ => Err(input.new_custom_error(ParserError::InvalidValue))
+  }
+}
+
+// https://drafts.csswg.org/css-color-6/#colorcontrast
+fn parse_color_contrast<'i, 't>(
+  input: &mut Parser<'i, 't>,
+  algorithm: ContrastAlgorithm,
+) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
+  let background = CssColor::parse(input)?;
+  input.expect_ident_matching("vs")?;
+  let mut candidates = vec![CssColor::parse(input)?];
+  while input.try_parse(|input| input.expect_comma()).is_ok() {
+    candidates.push(CssColor::parse(input)?);
+  }
+
+  if candidates.len() < 2 {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+
+  let target = if input.try_parse(|input| input.expect_ident_matching("to")).is_ok() {
+    Some(parse_target_contrast(input, algorithm)?)
+  } else {
+    None
+  };
+
+  // The winning color can only be computed at build time if all of the colors are known.
+  if matches!(background, CssColor::CurrentColor) || candidates.iter().any(|c| matches!(c, CssColor::CurrentColor))
+  {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+
+  // Return the first color that meets the target contrast, or the color with the highest contrast.
+  let mut best = 0;
+  let mut best_ratio = 0.0;
+  for i in 0..candidates.len() {
+    // APCA contrast is signed depending on the polarity, so compare the magnitude.
+    let ratio = candidates[i].contrast(&background, algorithm).unwrap().abs();
+    if let Some(target) = target {
+      if ratio >= target {
+        return Ok(candidates.swap_remove(i));
+      }
+    }
+
+    if ratio > best_ratio {
+      best = i;
+      best_ratio = ratio;
+    }
+  }
+
+  Ok(candidates.swap_remove(best))
+}
+
+fn parse_target_contrast<'i, 't>(
+  input: &mut Parser<'i, 't>,
+  algorithm: ContrastAlgorithm,
+) -> Result<f32, ParseError<'i, ParserError<'i>>> {
+  if let Ok(ratio) = input.try_parse(CSSNumber::parse) {
+    return Ok(ratio);
+  }
+
+  // The WCAG levels are defined in terms of contrast ratios, and have no APCA equivalent.
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  if algorithm != ContrastAlgorithm::Wcag21 {
+    return Err(location.new_unexpected_token_error(cssparser::Token::Ident(ident.clone())));
+  }
+
+  match_ignore_ascii_case! { &*ident,
+    "aa" => Ok(4.5),
+    "aa-large" => Ok(3.0),
+    "aaa" => Ok(7.0),
+    "aaa-large" => Ok(4.5),
+    _ => Err(location.new_unexpected_token_error(
+      cssparser::Token::Ident(ident.clone())
+    ))
+  }
+}
+
+// https://drafts.csswg.org/css-color-5/#contrast-color
+fn parse_contrast_color<'i, 't>(
+  input: &mut Parser<'i, 't>,
+  algorithm: ContrastAlgorithm,
+) -> Result<CssColor, ParseError<'i, ParserError<'i>>> {
+  let background = CssColor::parse(input)?;
+  if matches!(background, CssColor::CurrentColor) {
+    return Err(input.new_custom_error(ParserError::InvalidValue));
+  }
+
+  let white = CssColor::RGBA(RGBA {
+    red: 255,
+    green: 255,
+    blue: 255,
+    alpha: 255,
+  });
+  let black = CssColor::RGBA(RGBA {
+    red: 0,
+    green: 0,
+    blue: 0,
+    alpha: 255,
+  });
+
+  let white_ratio = white.contrast(&background, algorithm).unwrap().abs();
+  let black_ratio = black.contrast(&background, algorithm).unwrap().abs();
+  Ok(if white_ratio >= black_ratio { white } else { black })
+}
+
 impl CssColor {
   fn get_type_id(&self) -> TypeId {
     match self {
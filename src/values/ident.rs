@@ -21,12 +21,7 @@ impl<'i> Parse<'i> for CustomIdent<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let location = input.current_source_location();
     let ident = input.expect_ident()?;
-    let valid = match_ignore_ascii_case! { &ident,
-      "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => false,
-      _ => true
-    };
-
-    if !valid {
+    if is_reserved_keyword(&ident) {
       return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())));
     }
 
@@ -34,6 +29,34 @@ impl<'i> Parse<'i> for CustomIdent<'i> {
   }
 }
 
+impl<'i> CustomIdent<'i> {
+  /// Parses a `<custom-ident>`, additionally excluding the given keywords (ASCII case-insensitively).
+  ///
+  /// Many properties and rules exclude keywords that would otherwise be ambiguous with
+  /// their other values, e.g. `none` or `span`.
+  pub fn parse_except<'t>(
+    input: &mut Parser<'i, 't>,
+    excluded: &[&str],
+  ) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = CustomIdent::parse(input)?;
+    if excluded.iter().any(|keyword| ident.0.eq_ignore_ascii_case(keyword)) {
+      return Err(location.new_unexpected_token_error(Token::Ident(ident.0.as_ref().to_owned().into())));
+    }
+
+    Ok(ident)
+  }
+}
+
+/// Returns whether the identifier is a [CSS-wide keyword](https://www.w3.org/TR/css-values-4/#css-wide-keywords)
+/// or `default`, neither of which are valid as a `<custom-ident>`.
+pub(crate) fn is_reserved_keyword(ident: &str) -> bool {
+  match_ignore_ascii_case! { ident,
+    "initial" | "inherit" | "unset" | "default" | "revert" | "revert-layer" => true,
+    _ => false
+  }
+}
+
 impl<'i> ToCss for CustomIdent<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -124,6 +124,13 @@ macro_rules! define_length_units {
     }
 
     impl LengthValue {
+      /// Returns whether the given string is a length unit, ignoring case.
+      pub(crate) fn is_unit(unit: &str) -> bool {
+        $(
+          unit.eq_ignore_ascii_case(stringify!($name))
+        )||+
+      }
+
       /// Returns the numeric value and unit string for the length value.
       pub fn to_unit_value(&self) -> (CSSNumber, &str) {
         match self {
@@ -165,6 +172,14 @@ macro_rules! define_length_units {
       }
     }
 
+    impl std::ops::Div<CSSNumber> for LengthValue {
+      type Output = Self;
+
+      fn div(self, other: CSSNumber) -> LengthValue {
+        self.map(|value| value / other)
+      }
+    }
+
     impl std::cmp::PartialOrd<LengthValue> for LengthValue {
       fn partial_cmp(&self, other: &LengthValue) -> Option<std::cmp::Ordering> {
         use LengthValue::*;
@@ -503,6 +518,22 @@ impl std::ops::Mul<CSSNumber> for Length {
   }
 }
 
+impl std::ops::Div<CSSNumber> for Length {
+  type Output = Self;
+
+  fn div(self, other: CSSNumber) -> Length {
+    self * (1.0 / other)
+  }
+}
+
+impl std::ops::Sub<Length> for Length {
+  type Output = Self;
+
+  fn sub(self, other: Length) -> Length {
+    self + other * -1.0
+  }
+}
+
 impl std::ops::Add<Length> for Length {
   type Output = Self;
 
@@ -749,16 +780,27 @@ impl Zero for LengthOrNumber {
 
 impl<'i> Parse<'i> for LengthOrNumber {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+
     // Parse number first so unitless numbers are not parsed as lengths.
-    if let Ok(number) = input.try_parse(CSSNumber::parse) {
-      return Ok(LengthOrNumber::Number(number));
-    }
+    let value = if let Ok(number) = input.try_parse(CSSNumber::parse) {
+      LengthOrNumber::Number(number)
+    } else if let Ok(length) = Length::parse(input) {
+      LengthOrNumber::Length(length)
+    } else {
+      return Err(input.new_error_for_next_token());
+    };
 
-    if let Ok(length) = Length::parse(input) {
-      return Ok(LengthOrNumber::Length(length));
+    // All properties that accept this type (tab-size and the border image outsets) are non-negative.
+    let is_negative = match &value {
+      LengthOrNumber::Number(number) => *number < 0.0,
+      LengthOrNumber::Length(length) => length.try_sign().map_or(false, |sign| sign < 0.0),
+    };
+    if is_negative {
+      return Err(location.new_custom_error(ParserError::InvalidValue));
     }
 
-    Err(input.new_error_for_next_token())
+    Ok(value)
   }
 }
 
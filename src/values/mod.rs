@@ -28,6 +28,7 @@
 #![deny(missing_docs)]
 
 pub mod alpha;
+pub mod anchor;
 pub mod angle;
 pub mod calc;
 pub mod color;
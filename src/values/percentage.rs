@@ -86,6 +86,14 @@ impl std::ops::Mul<CSSNumber> for Percentage {
   }
 }
 
+impl std::ops::Div<CSSNumber> for Percentage {
+  type Output = Self;
+
+  fn div(self, other: CSSNumber) -> Percentage {
+    Percentage(self.0 / other)
+  }
+}
+
 impl AddInternal for Percentage {
   fn add(self, other: Self) -> Self {
     self + other
@@ -135,6 +143,7 @@ impl Sign for Percentage {
 
 impl_op!(Percentage, std::ops::Rem, rem);
 impl_op!(Percentage, std::ops::Add, add);
+impl_op!(Percentage, std::ops::Sub, sub);
 
 impl_try_from_angle!(Percentage);
 
@@ -136,7 +136,9 @@ impl ToCss for Position {
   where
     W: std::fmt::Write,
   {
-    match (&self.x, &self.y) {
+    let x = self.x.normalize();
+    let y = self.y.normalize();
+    match (&x, &y) {
       (x_pos @ &HorizontalPosition::Side(side, Some(_)), &VerticalPosition::Length(ref y_lp))
         if side != HorizontalPositionKeyword::Left =>
       {
@@ -265,6 +267,29 @@ impl<S> PositionComponent<S> {
   }
 }
 
+impl<S: Copy + Into<LengthPercentage>> PositionComponent<S> {
+  /// Converts a side keyword with an offset to an equivalent length from the top-left corner where possible,
+  /// e.g. `left 10px` => `10px`, and `right 25%` => `75%`.
+  fn normalize(&self) -> Self {
+    match self {
+      PositionComponent::Side(side, Some(offset)) => {
+        let start: LengthPercentage = (*side).into();
+        if start.is_zero() {
+          // `left` or `top`.
+          PositionComponent::Length(offset.clone())
+        } else if offset.is_zero() {
+          PositionComponent::Side(*side, None)
+        } else if let LengthPercentage::Percentage(Percentage(p)) = offset {
+          PositionComponent::Length(LengthPercentage::Percentage(Percentage(1.0 - p)))
+        } else {
+          self.clone()
+        }
+      }
+      _ => self.clone(),
+    }
+  }
+}
+
 impl<'i, S: Parse<'i>> Parse<'i> for PositionComponent<S> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     if input.try_parse(|i| i.expect_ident_matching("center")).is_ok() {
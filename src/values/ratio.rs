@@ -14,9 +14,9 @@ pub struct Ratio(pub CSSNumber, pub CSSNumber);
 
 impl<'i> Parse<'i> for Ratio {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let first = CSSNumber::parse(input)?;
+    let first = parse_non_negative(input)?;
     let second = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
-      CSSNumber::parse(input)?
+      parse_non_negative(input)?
     } else {
       1.0
     };
@@ -28,11 +28,42 @@ impl<'i> Parse<'i> for Ratio {
 impl Ratio {
   /// Parses a ratio where both operands are required.
   pub fn parse_required<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
-    let first = CSSNumber::parse(input)?;
+    let first = parse_non_negative(input)?;
     input.expect_delim('/')?;
-    let second = CSSNumber::parse(input)?;
+    let second = parse_non_negative(input)?;
     Ok(Ratio(first, second))
   }
+
+  /// Returns the ratio reduced to its lowest terms. This only applies when both operands
+  /// are positive integers, e.g. `16/8` becomes `2/1`.
+  pub fn simplify(&self) -> Ratio {
+    let Ratio(numerator, denominator) = *self;
+    if numerator <= 0.0 || denominator <= 0.0 || numerator.fract() != 0.0 || denominator.fract() != 0.0 {
+      return self.clone();
+    }
+
+    let divisor = gcd(numerator, denominator);
+    Ratio(numerator / divisor, denominator / divisor)
+  }
+}
+
+/// Both operands of a ratio must be non-negative.
+fn parse_non_negative<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CSSNumber, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let number = CSSNumber::parse(input)?;
+  if number < 0.0 {
+    return Err(location.new_custom_error(ParserError::InvalidValue));
+  }
+  Ok(number)
+}
+
+fn gcd(mut a: CSSNumber, mut b: CSSNumber) -> CSSNumber {
+  while b != 0.0 {
+    let r = a % b;
+    a = b;
+    b = r;
+  }
+  a
 }
 
 impl ToCss for Ratio {
@@ -40,10 +71,11 @@ impl ToCss for Ratio {
   where
     W: std::fmt::Write,
   {
-    self.0.to_css(dest)?;
-    if self.1 != 1.0 {
+    let ratio = if dest.minify { self.simplify() } else { self.clone() };
+    ratio.0.to_css(dest)?;
+    if ratio.1 != 1.0 {
       dest.delim('/', true)?;
-      self.1.to_css(dest)?;
+      ratio.1.to_css(dest)?;
     }
     Ok(())
   }
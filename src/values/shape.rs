@@ -1,13 +1,17 @@
 //! CSS shape values for masking and clipping.
 
-use super::length::LengthPercentage;
+use super::angle::Angle;
+use super::length::{LengthPercentage, LengthPercentageOrAuto};
+use super::percentage::Percentage;
 use super::position::Position;
 use super::rect::Rect;
+use super::size::Size2D;
 use crate::error::{ParserError, PrinterError};
 use crate::macros::enum_property;
 use crate::printer::Printer;
 use crate::properties::border_radius::BorderRadius;
-use crate::traits::{Parse, ToCss};
+use crate::traits::{Parse, ToCss, TrySign, Zero};
+use crate::values::string::CowArcStr;
 use cssparser::*;
 
 /// A CSS [`<basic-shape>`](https://www.w3.org/TR/css-shapes-1/#basic-shape-functions) value.
@@ -17,15 +21,24 @@ use cssparser::*;
   derive(serde::Serialize, serde::Deserialize),
   serde(tag = "type", content = "value", rename_all = "kebab-case")
 )]
-pub enum BasicShape {
+pub enum BasicShape<'i> {
   /// An inset rectangle.
   Inset(InsetRect),
+  /// A rectangle defined by its position and size.
+  Xywh(XywhRect),
+  /// A rectangle defined by the distances of its edges from the top and left edges of the reference box.
+  Rect(RectShape),
   /// A circle.
   Circle(Circle),
   /// An ellipse.
   Ellipse(Ellipse),
   /// A polygon.
   Polygon(Polygon),
+  /// An SVG path.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  Path(Path<'i>),
+  /// A shape defined by a list of drawing commands.
+  Shape(Shape),
 }
 
 /// An [`inset()`](https://www.w3.org/TR/css-shapes-1/#funcdef-inset) rectangle shape.
@@ -38,6 +51,33 @@ pub struct InsetRect {
   pub radius: BorderRadius,
 }
 
+/// An [`xywh()`](https://drafts.csswg.org/css-shapes-1/#funcdef-basic-shape-xywh) rectangle shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XywhRect {
+  /// The distance of the left edge from the left edge of the reference box.
+  pub x: LengthPercentage,
+  /// The distance of the top edge from the top edge of the reference box.
+  pub y: LengthPercentage,
+  /// The width of the rectangle.
+  pub width: LengthPercentage,
+  /// The height of the rectangle.
+  pub height: LengthPercentage,
+  /// A corner radius for the rectangle.
+  pub radius: BorderRadius,
+}
+
+/// A [`rect()`](https://drafts.csswg.org/css-shapes-1/#funcdef-basic-shape-rect) rectangle shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RectShape {
+  /// The distances of the top, right, bottom, and left edges from the top or left edge of the reference box.
+  /// The `auto` keyword places the edge at the corresponding edge of the reference box.
+  pub rect: Rect<LengthPercentageOrAuto>,
+  /// A corner radius for the rectangle.
+  pub radius: BorderRadius,
+}
+
 /// A [`circle()`](https://www.w3.org/TR/css-shapes-1/#funcdef-circle) shape.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -94,9 +134,145 @@ pub struct Polygon {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
   /// The x position of the point.
-  x: LengthPercentage,
+  pub x: LengthPercentage,
   /// the y position of the point.
-  y: LengthPercentage,
+  pub y: LengthPercentage,
+}
+
+/// A [`path()`](https://www.w3.org/TR/css-shapes-1/#funcdef-basic-shape-path) shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path<'i> {
+  /// The fill rule used to determine the interior of the path.
+  pub fill_rule: FillRule,
+  /// An SVG path data string.
+  #[cfg_attr(feature = "serde", serde(borrow))]
+  pub path: CowArcStr<'i>,
+}
+
+/// A [`shape()`](https://drafts.csswg.org/css-shapes-2/#shape-function) shape.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shape {
+  /// The fill rule used to determine the interior of the shape.
+  pub fill_rule: FillRule,
+  /// The starting point of the first sub-path.
+  pub start: Point,
+  /// The drawing commands.
+  pub commands: Vec<ShapeCommand>,
+}
+
+/// A drawing command within a `shape()` function.
+///
+/// See [Shape](Shape).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", rename_all = "kebab-case")
+)]
+pub enum ShapeCommand {
+  /// Starts a new sub-path at the given point.
+  Move {
+    /// The end point of the command.
+    end: CommandEndPoint,
+  },
+  /// Draws a straight line to the given point.
+  Line {
+    /// The end point of the command.
+    end: CommandEndPoint,
+  },
+  /// Draws a horizontal line.
+  HLine {
+    /// Whether the x coordinate is relative to the current point.
+    by: bool,
+    /// The x coordinate of the end point.
+    x: LengthPercentage,
+  },
+  /// Draws a vertical line.
+  VLine {
+    /// Whether the y coordinate is relative to the current point.
+    by: bool,
+    /// The y coordinate of the end point.
+    y: LengthPercentage,
+  },
+  /// Draws a quadratic or cubic Bézier curve.
+  Curve {
+    /// The end point of the command.
+    end: CommandEndPoint,
+    /// The first control point.
+    control1: Point,
+    /// The second control point, for a cubic curve.
+    control2: Option<Point>,
+  },
+  /// Draws a smooth Bézier curve, reflecting the control point of the previous curve.
+  Smooth {
+    /// The end point of the command.
+    end: CommandEndPoint,
+    /// The second control point, for a cubic curve.
+    control: Option<Point>,
+  },
+  /// Draws an elliptical arc.
+  Arc {
+    /// The end point of the command.
+    end: CommandEndPoint,
+    /// The x and y radii of the ellipse.
+    radius: Size2D<LengthPercentage>,
+    /// The direction of the arc.
+    sweep: ArcSweep,
+    /// Whether to draw the larger or smaller of the possible arcs.
+    size: ArcSize,
+    /// The rotation of the ellipse.
+    rotate: Angle,
+  },
+  /// Closes the current sub-path.
+  Close,
+}
+
+/// The end point of a [ShapeCommand](ShapeCommand).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(tag = "type", content = "value", rename_all = "kebab-case")
+)]
+pub enum CommandEndPoint {
+  /// A point relative to the top-left corner of the reference box.
+  To(Point),
+  /// A point relative to the current point.
+  By(Point),
+}
+
+enum_property! {
+  /// The direction of an `arc` command within a `shape()` function.
+  pub enum ArcSweep {
+    /// Counter-clockwise.
+    Ccw,
+    /// Clockwise.
+    Cw,
+  }
+}
+
+impl Default for ArcSweep {
+  fn default() -> ArcSweep {
+    ArcSweep::Ccw
+  }
+}
+
+enum_property! {
+  /// The size of an `arc` command within a `shape()` function.
+  pub enum ArcSize {
+    /// The smaller of the possible arcs.
+    Small,
+    /// The larger of the possible arcs.
+    Large,
+  }
+}
+
+impl Default for ArcSize {
+  fn default() -> ArcSize {
+    ArcSize::Small
+  }
 }
 
 enum_property! {
@@ -118,15 +294,19 @@ impl Default for FillRule {
   }
 }
 
-impl<'i> Parse<'i> for BasicShape {
+impl<'i> Parse<'i> for BasicShape<'i> {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let location = input.current_source_location();
     let f = input.expect_function()?;
     match_ignore_ascii_case! { &f,
       "inset" => Ok(BasicShape::Inset(input.parse_nested_block(InsetRect::parse)?)),
+      "xywh" => Ok(BasicShape::Xywh(input.parse_nested_block(XywhRect::parse)?)),
+      "rect" => Ok(BasicShape::Rect(input.parse_nested_block(RectShape::parse)?)),
       "circle" => Ok(BasicShape::Circle(input.parse_nested_block(Circle::parse)?)),
       "ellipse" => Ok(BasicShape::Ellipse(input.parse_nested_block(Ellipse::parse)?)),
       "polygon" => Ok(BasicShape::Polygon(input.parse_nested_block(Polygon::parse)?)),
+      "path" => Ok(BasicShape::Path(input.parse_nested_block(Path::parse)?)),
+      "shape" => Ok(BasicShape::Shape(input.parse_nested_block(Shape::parse)?)),
       _ => Err(location.new_unexpected_token_error(Token::Ident(f.clone()))),
     }
   }
@@ -135,15 +315,103 @@ impl<'i> Parse<'i> for BasicShape {
 impl<'i> Parse<'i> for InsetRect {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let rect = Rect::parse(input)?;
-    let radius = if input.try_parse(|input| input.expect_ident_matching("round")).is_ok() {
-      BorderRadius::parse(input)?
-    } else {
-      BorderRadius::default()
-    };
+    let radius = parse_round(input)?;
     Ok(InsetRect { rect, radius })
   }
 }
 
+fn parse_round<'i, 't>(input: &mut Parser<'i, 't>) -> Result<BorderRadius, ParseError<'i, ParserError<'i>>> {
+  if input.try_parse(|input| input.expect_ident_matching("round")).is_ok() {
+    BorderRadius::parse(input)
+  } else {
+    Ok(BorderRadius::default())
+  }
+}
+
+impl<'i> Parse<'i> for XywhRect {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let x = LengthPercentage::parse(input)?;
+    let y = LengthPercentage::parse(input)?;
+    let width = LengthPercentage::parse(input)?;
+    let height = LengthPercentage::parse(input)?;
+    if width.is_sign_negative() || height.is_sign_negative() {
+      return Err(input.new_custom_error(ParserError::InvalidValue));
+    }
+
+    let radius = parse_round(input)?;
+    Ok(XywhRect {
+      x,
+      y,
+      width,
+      height,
+      radius,
+    })
+  }
+}
+
+impl XywhRect {
+  /// Converts the rectangle to an equivalent `inset()` rectangle.
+  pub fn to_inset(&self) -> InsetRect {
+    InsetRect {
+      rect: Rect::new(
+        self.y.clone(),
+        distance_from_end(&self.x, &self.width),
+        distance_from_end(&self.y, &self.height),
+        self.x.clone(),
+      ),
+      radius: self.radius.clone(),
+    }
+  }
+}
+
+/// Returns the distance between the end of a span and the far edge of the reference box, i.e. `100% - start - size`.
+fn distance_from_end(start: &LengthPercentage, size: &LengthPercentage) -> LengthPercentage {
+  let res = LengthPercentage::Percentage(Percentage(1.0)) + start.clone() * -1.0 + size.clone() * -1.0;
+  if res.is_zero() {
+    LengthPercentage::zero()
+  } else {
+    res
+  }
+}
+
+impl<'i> Parse<'i> for RectShape {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let top = LengthPercentageOrAuto::parse(input)?;
+    let right = LengthPercentageOrAuto::parse(input)?;
+    let bottom = LengthPercentageOrAuto::parse(input)?;
+    let left = LengthPercentageOrAuto::parse(input)?;
+    let radius = parse_round(input)?;
+    Ok(RectShape {
+      rect: Rect::new(top, right, bottom, left),
+      radius,
+    })
+  }
+}
+
+impl RectShape {
+  /// Converts the rectangle to an equivalent `inset()` rectangle.
+  pub fn to_inset(&self) -> InsetRect {
+    let start = |v: &LengthPercentageOrAuto| match v {
+      LengthPercentageOrAuto::Auto => LengthPercentage::zero(),
+      LengthPercentageOrAuto::LengthPercentage(v) => v.clone(),
+    };
+    let end = |v: &LengthPercentageOrAuto| match v {
+      LengthPercentageOrAuto::Auto => LengthPercentage::zero(),
+      LengthPercentageOrAuto::LengthPercentage(v) => distance_from_end(v, &LengthPercentage::zero()),
+    };
+
+    InsetRect {
+      rect: Rect::new(
+        start(&self.rect.0),
+        end(&self.rect.1),
+        end(&self.rect.2),
+        start(&self.rect.3),
+      ),
+      radius: self.radius.clone(),
+    }
+  }
+}
+
 impl<'i> Parse<'i> for Circle {
   fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
     let radius = input.try_parse(ShapeRadius::parse).unwrap_or_default();
@@ -223,7 +491,164 @@ impl<'i> Parse<'i> for Point {
   }
 }
 
-impl ToCss for BasicShape {
+impl<'i> Parse<'i> for Path<'i> {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let fill_rule = input.try_parse(FillRule::parse);
+    if fill_rule.is_ok() {
+      input.expect_comma()?;
+    }
+
+    let path = input.expect_string_cloned()?;
+    Ok(Path {
+      fill_rule: fill_rule.unwrap_or_default(),
+      path: path.into(),
+    })
+  }
+}
+
+impl<'i> Parse<'i> for Shape {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let fill_rule = input.try_parse(FillRule::parse);
+    if fill_rule.is_ok() {
+      // Earlier drafts separated the fill rule with a comma.
+      let _ = input.try_parse(|input| input.expect_comma());
+    }
+
+    input.expect_ident_matching("from")?;
+    let start = Point::parse(input)?;
+    input.expect_comma()?;
+    let commands = input.parse_comma_separated(ShapeCommand::parse)?;
+    Ok(Shape {
+      fill_rule: fill_rule.unwrap_or_default(),
+      start,
+      commands,
+    })
+  }
+}
+
+impl<'i> Parse<'i> for ShapeCommand {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let location = input.current_source_location();
+    let ident = input.expect_ident_cloned()?;
+    match_ignore_ascii_case! { &*ident,
+      "move" => {
+        let end = CommandEndPoint::parse(input)?;
+        Ok(ShapeCommand::Move { end })
+      },
+      "line" => {
+        let end = CommandEndPoint::parse(input)?;
+        Ok(ShapeCommand::Line { end })
+      },
+      "hline" => {
+        let by = parse_by(input)?;
+        let x = LengthPercentage::parse(input)?;
+        Ok(ShapeCommand::HLine { by, x })
+      },
+      "vline" => {
+        let by = parse_by(input)?;
+        let y = LengthPercentage::parse(input)?;
+        Ok(ShapeCommand::VLine { by, y })
+      },
+      "curve" => {
+        let end = CommandEndPoint::parse(input)?;
+        input.expect_ident_matching("with")?;
+        let control1 = Point::parse(input)?;
+        let control2 = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+          Some(Point::parse(input)?)
+        } else {
+          None
+        };
+        Ok(ShapeCommand::Curve { end, control1, control2 })
+      },
+      "smooth" => {
+        let end = CommandEndPoint::parse(input)?;
+        let control = if input.try_parse(|input| input.expect_ident_matching("with")).is_ok() {
+          Some(Point::parse(input)?)
+        } else {
+          None
+        };
+        Ok(ShapeCommand::Smooth { end, control })
+      },
+      "arc" => {
+        let end = CommandEndPoint::parse(input)?;
+        let mut radius = None;
+        let mut sweep = None;
+        let mut size = None;
+        let mut rotate = None;
+        loop {
+          if radius.is_none() && input.try_parse(|input| input.expect_ident_matching("of")).is_ok() {
+            radius = Some(Size2D::parse(input)?);
+            continue;
+          }
+
+          if sweep.is_none() {
+            if let Ok(value) = input.try_parse(ArcSweep::parse) {
+              sweep = Some(value);
+              continue;
+            }
+          }
+
+          if size.is_none() {
+            if let Ok(value) = input.try_parse(ArcSize::parse) {
+              size = Some(value);
+              continue;
+            }
+          }
+
+          if rotate.is_none() && input.try_parse(|input| input.expect_ident_matching("rotate")).is_ok() {
+            rotate = Some(Angle::parse(input)?);
+            continue;
+          }
+
+          break;
+        }
+
+        let radius = match radius {
+          Some(radius) => radius,
+          None => return Err(input.new_custom_error(ParserError::InvalidValue)),
+        };
+
+        Ok(ShapeCommand::Arc {
+          end,
+          radius,
+          sweep: sweep.unwrap_or_default(),
+          size: size.unwrap_or_default(),
+          rotate: rotate.unwrap_or(Angle::Deg(0.0)),
+        })
+      },
+      "close" => Ok(ShapeCommand::Close),
+      _ => Err(location.new_unexpected_token_error(
+        cssparser::Token::Ident(ident.clone())
+      ))
+    }
+  }
+}
+
+fn parse_by<'i, 't>(input: &mut Parser<'i, 't>) -> Result<bool, ParseError<'i, ParserError<'i>>> {
+  let location = input.current_source_location();
+  let ident = input.expect_ident()?;
+  match_ignore_ascii_case! { &*ident,
+    "to" => Ok(false),
+    "by" => Ok(true),
+    _ => Err(location.new_unexpected_token_error(
+      cssparser::Token::Ident(ident.clone())
+    ))
+  }
+}
+
+impl<'i> Parse<'i> for CommandEndPoint {
+  fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, ParserError<'i>>> {
+    let by = parse_by(input)?;
+    let point = Point::parse(input)?;
+    if by {
+      Ok(CommandEndPoint::By(point))
+    } else {
+      Ok(CommandEndPoint::To(point))
+    }
+  }
+}
+
+impl<'i> ToCss for BasicShape<'i> {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
     W: std::fmt::Write,
@@ -234,6 +659,8 @@ impl ToCss for BasicShape {
         rect.to_css(dest)?;
         dest.write_char(')')
       }
+      BasicShape::Xywh(xywh) => write_rect_or_inset("xywh(", xywh, || xywh.to_inset(), dest),
+      BasicShape::Rect(rect) => write_rect_or_inset("rect(", rect, || rect.to_inset(), dest),
       BasicShape::Circle(circle) => {
         dest.write_str("circle(")?;
         circle.to_css(dest)?;
@@ -249,6 +676,16 @@ impl ToCss for BasicShape {
         poly.to_css(dest)?;
         dest.write_char(')')
       }
+      BasicShape::Path(path) => {
+        dest.write_str("path(")?;
+        path.to_css(dest)?;
+        dest.write_char(')')
+      }
+      BasicShape::Shape(shape) => {
+        dest.write_str("shape(")?;
+        shape.to_css(dest)?;
+        dest.write_char(')')
+      }
     }
   }
 }
@@ -267,6 +704,81 @@ impl ToCss for InsetRect {
   }
 }
 
+/// Writes an `xywh()` or `rect()` shape, or an equivalent `inset()` shape if that is shorter when minifying.
+fn write_rect_or_inset<W, T, F>(
+  name: &str,
+  value: &T,
+  to_inset: F,
+  dest: &mut Printer<W>,
+) -> Result<(), PrinterError>
+where
+  W: std::fmt::Write,
+  T: ToCss,
+  F: FnOnce() -> InsetRect,
+{
+  if dest.minify {
+    let mut original = String::new();
+    {
+      let mut printer = Printer::new(&mut original, dest.scratch_options());
+      printer.write_str(name)?;
+      value.to_css(&mut printer)?;
+      printer.write_char(')')?;
+    }
+
+    let mut inset = String::new();
+    BasicShape::Inset(to_inset()).to_css(&mut Printer::new(&mut inset, dest.scratch_options()))?;
+
+    if inset.len() < original.len() {
+      return dest.write_str(&inset);
+    }
+    return dest.write_str(&original);
+  }
+
+  dest.write_str(name)?;
+  value.to_css(dest)?;
+  dest.write_char(')')
+}
+
+impl ToCss for XywhRect {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.x.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.y.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.width.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.height.to_css(dest)?;
+    if self.radius != BorderRadius::default() {
+      dest.write_str(" round ")?;
+      self.radius.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
+impl ToCss for RectShape {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    self.rect.0.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.rect.1.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.rect.2.to_css(dest)?;
+    dest.write_char(' ')?;
+    self.rect.3.to_css(dest)?;
+    if self.radius != BorderRadius::default() {
+      dest.write_str(" round ")?;
+      self.radius.to_css(dest)?;
+    }
+    Ok(())
+  }
+}
+
 impl ToCss for Circle {
   fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
   where
@@ -362,3 +874,133 @@ impl ToCss for Point {
     self.y.to_css(dest)
   }
 }
+
+impl<'i> ToCss for Path<'i> {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.fill_rule != FillRule::default() {
+      self.fill_rule.to_css(dest)?;
+      dest.delim(',', false)?;
+    }
+
+    serialize_string(&self.path, dest)?;
+    Ok(())
+  }
+}
+
+impl ToCss for Shape {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    if self.fill_rule != FillRule::default() {
+      self.fill_rule.to_css(dest)?;
+      dest.write_char(' ')?;
+    }
+
+    dest.write_str("from ")?;
+    self.start.to_css(dest)?;
+    for command in &self.commands {
+      dest.delim(',', false)?;
+      command.to_css(dest)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl ToCss for ShapeCommand {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      ShapeCommand::Move { end } => {
+        dest.write_str("move ")?;
+        end.to_css(dest)
+      }
+      ShapeCommand::Line { end } => {
+        dest.write_str("line ")?;
+        end.to_css(dest)
+      }
+      ShapeCommand::HLine { by, x } => {
+        dest.write_str(if *by { "hline by " } else { "hline to " })?;
+        x.to_css(dest)
+      }
+      ShapeCommand::VLine { by, y } => {
+        dest.write_str(if *by { "vline by " } else { "vline to " })?;
+        y.to_css(dest)
+      }
+      ShapeCommand::Curve {
+        end,
+        control1,
+        control2,
+      } => {
+        dest.write_str("curve ")?;
+        end.to_css(dest)?;
+        dest.write_str(" with ")?;
+        control1.to_css(dest)?;
+        if let Some(control2) = control2 {
+          dest.delim('/', true)?;
+          control2.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeCommand::Smooth { end, control } => {
+        dest.write_str("smooth ")?;
+        end.to_css(dest)?;
+        if let Some(control) = control {
+          dest.write_str(" with ")?;
+          control.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeCommand::Arc {
+        end,
+        radius,
+        sweep,
+        size,
+        rotate,
+      } => {
+        dest.write_str("arc ")?;
+        end.to_css(dest)?;
+        dest.write_str(" of ")?;
+        radius.to_css(dest)?;
+        if *sweep != ArcSweep::default() {
+          dest.write_char(' ')?;
+          sweep.to_css(dest)?;
+        }
+        if *size != ArcSize::default() {
+          dest.write_char(' ')?;
+          size.to_css(dest)?;
+        }
+        if !rotate.is_zero() {
+          dest.write_str(" rotate ")?;
+          rotate.to_css(dest)?;
+        }
+        Ok(())
+      }
+      ShapeCommand::Close => dest.write_str("close"),
+    }
+  }
+}
+
+impl ToCss for CommandEndPoint {
+  fn to_css<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
+    match self {
+      CommandEndPoint::To(point) => {
+        dest.write_str("to ")?;
+        point.to_css(dest)
+      }
+      CommandEndPoint::By(point) => {
+        dest.write_str("by ")?;
+        point.to_css(dest)
+      }
+    }
+  }
+}
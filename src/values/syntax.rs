@@ -1,9 +1,11 @@
 //! CSS syntax strings
 
 use super::number::{CSSInteger, CSSNumber};
+use super::percentage::DimensionPercentage;
 use super::string::CowArcStr;
 use crate::error::{ParserError, PrinterError};
 use crate::printer::Printer;
+use crate::properties::custom::{Token as CustomToken, TokenList, TokenOrValue};
 use crate::traits::{Parse, ToCss};
 use crate::values;
 use cssparser::*;
@@ -269,6 +271,102 @@ impl<'i> SyntaxString {
   }
 }
 
+impl<'i> ParsedComponent<'i> {
+  /// Converts the value to a list of tokens, for use as the value of a custom property.
+  /// Returns `None` for values that cannot be represented as simple tokens, such as images
+  /// and `calc()` expressions that could not be simplified.
+  pub(crate) fn to_token_list<'a>(&self) -> Option<TokenList<'a>> {
+    let mut tokens = Vec::new();
+    self.append_tokens(&mut tokens)?;
+    Some(TokenList(tokens))
+  }
+
+  fn append_tokens<'a>(&self, tokens: &mut Vec<TokenOrValue<'a>>) -> Option<()> {
+    let token = match self {
+      ParsedComponent::Length(values::length::Length::Value(v)) => length_token(v),
+      ParsedComponent::LengthPercentage(values::length::LengthPercentage::Dimension(v)) => length_token(v),
+      ParsedComponent::Percentage(v) | ParsedComponent::LengthPercentage(DimensionPercentage::Percentage(v)) => {
+        CustomToken::Percentage {
+          has_sign: false,
+          unit_value: v.0,
+          int_value: int_value(v.0 * 100.0),
+        }
+      }
+      ParsedComponent::Number(v) => CustomToken::Number {
+        has_sign: false,
+        value: *v,
+        int_value: int_value(*v),
+      },
+      ParsedComponent::Integer(v) => CustomToken::Number {
+        has_sign: false,
+        value: *v as f32,
+        int_value: Some(*v),
+      },
+      ParsedComponent::Angle(v) => {
+        let (value, unit) = match v {
+          values::angle::Angle::Deg(v) => (*v, "deg"),
+          values::angle::Angle::Rad(v) => (*v, "rad"),
+          values::angle::Angle::Grad(v) => (*v, "grad"),
+          values::angle::Angle::Turn(v) => (*v, "turn"),
+        };
+        dimension_token(value, unit)
+      }
+      ParsedComponent::Time(v) => match v {
+        values::time::Time::Seconds(v) => dimension_token(*v, "s"),
+        values::time::Time::Milliseconds(v) => dimension_token(*v, "ms"),
+      },
+      ParsedComponent::Color(color) => {
+        tokens.push(TokenOrValue::Color(color.clone()));
+        return Some(());
+      }
+      ParsedComponent::CustomIdent(values::ident::CustomIdent(v)) | ParsedComponent::Literal(v) => {
+        CustomToken::Ident(v.as_ref().to_owned().into())
+      }
+      ParsedComponent::Repeated(components, multiplier) => {
+        for (i, component) in components.iter().enumerate() {
+          if i > 0 {
+            match multiplier {
+              Multiplier::Comma => tokens.push(TokenOrValue::Token(CustomToken::Comma)),
+              Multiplier::Space => tokens.push(TokenOrValue::Token(CustomToken::WhiteSpace(" "))),
+              Multiplier::None => unreachable!(),
+            }
+          }
+          component.append_tokens(tokens)?;
+        }
+        return Some(());
+      }
+      _ => return None,
+    };
+
+    tokens.push(TokenOrValue::Token(token));
+    Some(())
+  }
+}
+
+fn length_token<'a>(length: &values::length::LengthValue) -> CustomToken<'a> {
+  // Zero lengths keep their unit, since the value may be substituted where a unitless zero is invalid,
+  // e.g. within calc().
+  let (value, unit) = length.to_unit_value();
+  dimension_token(value, unit)
+}
+
+fn dimension_token<'a>(value: CSSNumber, unit: &str) -> CustomToken<'a> {
+  CustomToken::Dimension {
+    has_sign: false,
+    value,
+    int_value: int_value(value),
+    unit: unit.to_owned().into(),
+  }
+}
+
+fn int_value(value: CSSNumber) -> Option<i32> {
+  if value.fract() == 0.0 && value.abs() <= i32::MAX as CSSNumber {
+    Some(value as i32)
+  } else {
+    None
+  }
+}
+
 impl SyntaxComponent {
   fn parse_string(input: &mut &str) -> Result<SyntaxComponent, ()> {
     let kind = SyntaxComponentKind::parse_string(input)?;
@@ -363,8 +461,19 @@ impl ToCss for SyntaxString {
     W: std::fmt::Write,
   {
     dest.write_char('"')?;
+    self.to_css_unquoted(dest)?;
+    dest.write_char('"')
+  }
+}
+
+impl SyntaxString {
+  /// Serializes the syntax without surrounding quotes, e.g. within the `type()` function.
+  pub(crate) fn to_css_unquoted<W>(&self, dest: &mut Printer<W>) -> Result<(), PrinterError>
+  where
+    W: std::fmt::Write,
+  {
     match self {
-      SyntaxString::Universal => dest.write_char('*')?,
+      SyntaxString::Universal => dest.write_char('*'),
       SyntaxString::Components(components) => {
         let mut first = true;
         for component in components {
@@ -376,10 +485,9 @@ impl ToCss for SyntaxString {
 
           component.to_css(dest)?;
         }
+        Ok(())
       }
     }
-
-    dest.write_char('"')
   }
 }
 
@@ -0,0 +1,334 @@
+//! Compile-time substitution of `var()` references.
+//!
+//! Style sheets that define design tokens as custom properties on `:root` often never change them
+//! at runtime. For these, [StyleSheet::inline_variables](super::stylesheet::StyleSheet::inline_variables)
+//! replaces each `var()` reference with the value of the custom property, and removes the declarations
+//! from `:root` once nothing references them anymore. Values may also be provided explicitly via
+//! [InlineVariablesOptions](InlineVariablesOptions), in which case they take precedence over the
+//! values declared in the style sheet.
+//!
+//! A custom property is only inlined if its value is known statically, i.e. it is only declared in
+//! top-level `:root` rules, and its value does not depend on the element it is used on. Custom properties
+//! that are also declared in other rules (e.g. to implement a dark theme), or within conditional rules
+//! such as `@media`, are left untouched, as are values that reference `attr()` or other custom properties
+//! that cannot be inlined, or custom properties set to a CSS-wide keyword such as `initial`. Custom
+//! properties may still be referenced from outside the style sheet, e.g. from JavaScript or inline styles,
+//! so this pass should only be enabled when that is not the case.
+//!
+//! Values are substituted as tokens, so declarations that referenced them are not simplified any further
+//! during minification, e.g. `calc(var(--size) * 2)` becomes `calc(10px*2)` rather than `20px`.
+
+use crate::declaration::DeclarationBlock;
+use crate::error::{Error, ParserError};
+use crate::properties::custom::{CSSWideKeyword, Token, TokenList, TokenOrValue, UnresolvedColor};
+use crate::properties::Property;
+use crate::rules::style::StyleRule;
+use crate::rules::{CssRule, CssRuleList};
+use crate::stylesheet::ParserOptions;
+use crate::traits::Parse;
+use cssparser::{Parser, ParserInput};
+use parcel_selectors::parser::Component;
+use std::collections::{HashMap, HashSet};
+
+/// Options for [StyleSheet::inline_variables](super::stylesheet::StyleSheet::inline_variables).
+#[derive(Debug, Clone, Default)]
+pub struct InlineVariablesOptions<'i> {
+  /// Values for custom properties, keyed by name including the leading `--`.
+  /// These take precedence over values declared in `:root` rules.
+  pub values: HashMap<String, &'i str>,
+  /// Whether to also inline the values of custom properties declared in top-level `:root` rules.
+  pub root: bool,
+}
+
+pub(crate) fn inline_variables<'i>(
+  rules: &mut CssRuleList<'i>,
+  options: &InlineVariablesOptions<'i>,
+) -> Result<(), Error<ParserError<'i>>> {
+  // Collect the values declared in `:root`, and the names of custom properties declared anywhere else.
+  let mut declared = HashMap::new();
+  let mut dynamic = HashSet::new();
+  for_each_block(rules, true, &mut |block, is_root| {
+    for (property, _) in block.iter() {
+      if let Property::Custom(custom) = property {
+        if !is_root {
+          dynamic.insert(custom.name.to_string());
+        } else if options.root {
+          // Important declarations are visited last, so they take precedence.
+          declared.insert(custom.name.to_string(), custom.value.clone());
+        }
+      }
+    }
+  });
+
+  for (name, value) in &options.values {
+    let mut input = ParserInput::new(*value);
+    let mut parser = Parser::new(&mut input);
+    let value = TokenList::parse(&mut parser, &ParserOptions::default(), 0).map_err(|err| Error {
+      loc: None,
+      ..Error::from(err, String::new())
+    })?;
+    declared.insert(name.clone(), value);
+  }
+
+  // CSS-wide keywords apply to the custom property itself, e.g. `initial` makes it guaranteed-invalid,
+  // so they cannot be substituted for references.
+  declared.retain(|name, value| !dynamic.contains(name) && !is_css_wide_keyword(value));
+
+  let mut resolver = Resolver {
+    declared: &declared,
+    resolved: HashMap::new(),
+    visiting: HashSet::new(),
+  };
+  let values: HashMap<String, TokenList<'i>> = declared
+    .keys()
+    .filter_map(|name| resolver.resolve(name).map(|value| (name.clone(), value)))
+    .collect();
+
+  if values.is_empty() {
+    return Ok(());
+  }
+
+  let mut referenced = HashSet::new();
+  for_each_block(rules, true, &mut |block, _| {
+    for property in block.iter_mut() {
+      if let Some(tokens) = token_list_mut(property) {
+        substitute(tokens, &values);
+        collect_references(tokens, &mut referenced);
+      }
+    }
+  });
+
+  // Remove declarations from `:root` that were inlined and are no longer referenced.
+  let is_unused = |property: &Property| match property {
+    Property::Custom(custom) => values.contains_key(&*custom.name) && !referenced.contains(&*custom.name),
+    _ => false,
+  };
+  for_each_block(rules, true, &mut |block, is_root| {
+    if is_root {
+      block.declarations.retain(|property| !is_unused(property));
+      block.important_declarations.retain(|property| !is_unused(property));
+    }
+  });
+
+  rules.0.retain(|rule| match rule {
+    CssRule::Style(style) => !is_root_rule(style) || !style.declarations.is_empty() || !style.rules.0.is_empty(),
+    _ => true,
+  });
+
+  Ok(())
+}
+
+/// Resolves the values of custom properties, substituting references to other custom properties.
+struct Resolver<'a, 'i> {
+  declared: &'a HashMap<String, TokenList<'i>>,
+  resolved: HashMap<String, Option<TokenList<'i>>>,
+  visiting: HashSet<String>,
+}
+
+impl<'a, 'i> Resolver<'a, 'i> {
+  /// Returns the fully substituted value of a custom property, or `None` if it cannot be inlined.
+  fn resolve(&mut self, name: &str) -> Option<TokenList<'i>> {
+    if let Some(value) = self.resolved.get(name) {
+      return value.clone();
+    }
+
+    let mut value = self.declared.get(name)?.clone();
+
+    // Cyclic references are invalid at computed value time, so they are never inlined.
+    if !self.visiting.insert(name.to_owned()) {
+      return None;
+    }
+
+    let mut names = HashSet::new();
+    collect_references(&value, &mut names);
+    let values: HashMap<String, TokenList<'i>> = names
+      .into_iter()
+      .filter_map(|name| self.resolve(&name).map(|value| (name, value)))
+      .collect();
+    substitute(&mut value, &values);
+
+    self.visiting.remove(name);
+
+    let value = if has_references(&value) { None } else { Some(value) };
+    self.resolved.insert(name.to_owned(), value.clone());
+    value
+  }
+}
+
+/// Calls the callback for each declaration block within a list of rules, along with
+/// whether the block belongs to a top-level `:root` rule.
+fn for_each_block<'i, F>(rules: &mut CssRuleList<'i>, top_level: bool, f: &mut F)
+where
+  F: FnMut(&mut DeclarationBlock<'i>, bool),
+{
+  for rule in rules.0.iter_mut() {
+    match rule {
+      CssRule::Style(style) => {
+        let is_root = top_level && is_root_rule(style);
+        f(&mut style.declarations, is_root);
+        for_each_block(&mut style.rules, false, f);
+      }
+      CssRule::Nesting(nesting) => {
+        f(&mut nesting.style.declarations, false);
+        for_each_block(&mut nesting.style.rules, false, f);
+      }
+      CssRule::Media(media) => for_each_block(&mut media.rules, false, f),
+      CssRule::Supports(supports) => for_each_block(&mut supports.rules, false, f),
+      CssRule::Container(container) => for_each_block(&mut container.rules, false, f),
+      CssRule::LayerBlock(layer) => for_each_block(&mut layer.rules, false, f),
+      CssRule::MozDocument(document) => for_each_block(&mut document.rules, false, f),
+      CssRule::Keyframes(keyframes) => {
+        for keyframe in keyframes.keyframes.iter_mut() {
+          f(&mut keyframe.declarations, false);
+        }
+      }
+      CssRule::Page(page) => f(&mut page.declarations, false),
+      CssRule::Viewport(viewport) => f(&mut viewport.declarations, false),
+      _ => {}
+    }
+  }
+}
+
+fn is_root_rule(style: &StyleRule) -> bool {
+  style.selectors.0.len() == 1
+    && matches!(
+      style.selectors.0[0].iter_raw_match_order().as_slice(),
+      [Component::Root]
+    )
+}
+
+fn is_css_wide_keyword(tokens: &TokenList) -> bool {
+  match &tokens.0[..] {
+    [TokenOrValue::Token(Token::Ident(ident))] => CSSWideKeyword::parse_string(&**ident).is_ok(),
+    _ => false,
+  }
+}
+
+fn token_list_mut<'a, 'i>(property: &'a mut Property<'i>) -> Option<&'a mut TokenList<'i>> {
+  match property {
+    Property::Custom(custom) => Some(&mut custom.value),
+    Property::Unparsed(unparsed) => Some(&mut unparsed.value),
+    _ => None,
+  }
+}
+
+/// Replaces `var()` references to the given custom properties with their values.
+fn substitute<'i>(tokens: &mut TokenList<'i>, values: &HashMap<String, TokenList<'i>>) {
+  let mut result = Vec::with_capacity(tokens.0.len());
+  let mut iter = std::mem::take(&mut tokens.0).into_iter().peekable();
+  while let Some(token) = iter.next() {
+    match token {
+      TokenOrValue::Var(mut var) => {
+        let value = if var.name.from.is_none() {
+          values.get(&*var.name.ident.0)
+        } else {
+          None
+        };
+
+        if let Some(value) = value {
+          // Whitespace is not stored after a var() reference, so add it back where needed.
+          if result.last().map_or(false, needs_whitespace_after) {
+            result.push(TokenOrValue::Token(Token::WhiteSpace(" ")));
+          }
+          result.extend(value.0.iter().cloned());
+          if iter.peek().map_or(false, needs_whitespace_before) {
+            result.push(TokenOrValue::Token(Token::WhiteSpace(" ")));
+          }
+        } else {
+          if let Some(fallback) = &mut var.fallback {
+            substitute(fallback, values);
+          }
+          result.push(TokenOrValue::Var(var));
+        }
+      }
+      TokenOrValue::Env(mut env) => {
+        if let Some(fallback) = &mut env.fallback {
+          substitute(fallback, values);
+        }
+        result.push(TokenOrValue::Env(env));
+      }
+      TokenOrValue::Attr(mut attr) => {
+        if let Some(fallback) = &mut attr.fallback {
+          substitute(fallback, values);
+        }
+        result.push(TokenOrValue::Attr(attr));
+      }
+      TokenOrValue::UnresolvedColor(mut color) => {
+        match &mut color {
+          UnresolvedColor::RGB { alpha, .. } | UnresolvedColor::HSL { alpha, .. } => substitute(alpha, values),
+        }
+        result.push(TokenOrValue::UnresolvedColor(color));
+      }
+      token => result.push(token),
+    }
+  }
+
+  tokens.0 = result;
+}
+
+fn needs_whitespace_after(token: &TokenOrValue) -> bool {
+  !matches!(
+    token,
+    TokenOrValue::Token(
+      Token::WhiteSpace(..)
+        | Token::Comma
+        | Token::Delim(..)
+        | Token::Function(..)
+        | Token::ParenthesisBlock
+        | Token::SquareBracketBlock
+        | Token::CurlyBracketBlock
+    )
+  )
+}
+
+fn needs_whitespace_before(token: &TokenOrValue) -> bool {
+  !matches!(
+    token,
+    TokenOrValue::Token(
+      Token::WhiteSpace(..)
+        | Token::Comma
+        | Token::Delim(..)
+        | Token::CloseParenthesis
+        | Token::CloseSquareBracket
+        | Token::CloseCurlyBracket
+    )
+  )
+}
+
+/// Collects the names of the custom properties referenced by a token list, including within fallbacks.
+fn collect_references(tokens: &TokenList, names: &mut HashSet<String>) {
+  for token in &tokens.0 {
+    match token {
+      TokenOrValue::Var(var) => {
+        names.insert(var.name.ident.0.to_string());
+        if let Some(fallback) = &var.fallback {
+          collect_references(fallback, names);
+        }
+      }
+      TokenOrValue::Env(env) => {
+        if let Some(fallback) = &env.fallback {
+          collect_references(fallback, names);
+        }
+      }
+      TokenOrValue::Attr(attr) => {
+        if let Some(fallback) = &attr.fallback {
+          collect_references(fallback, names);
+        }
+      }
+      TokenOrValue::UnresolvedColor(UnresolvedColor::RGB { alpha, .. })
+      | TokenOrValue::UnresolvedColor(UnresolvedColor::HSL { alpha, .. }) => collect_references(alpha, names),
+      _ => {}
+    }
+  }
+}
+
+/// Returns whether a token list depends on the element it is used on, via `var()` or `attr()`.
+fn has_references(tokens: &TokenList) -> bool {
+  tokens.0.iter().any(|token| match token {
+    TokenOrValue::Var(..) | TokenOrValue::Attr(..) => true,
+    TokenOrValue::Env(env) => env.fallback.as_ref().map_or(false, has_references),
+    TokenOrValue::UnresolvedColor(UnresolvedColor::RGB { alpha, .. })
+    | TokenOrValue::UnresolvedColor(UnresolvedColor::HSL { alpha, .. }) => has_references(alpha),
+    _ => false,
+  })
+}